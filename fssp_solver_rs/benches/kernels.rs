@@ -0,0 +1,161 @@
+//! Medições de desempenho dos núcleos do solver: cálculo do makespan, operadores de
+//! cruzamento e busca local por inserção, em instâncias de tamanhos padrão.
+//!
+//! Executar com `cargo bench --bench kernels [filtro]`. Sem `FSSP_BENCH_INSTANCES`, as
+//! instâncias são geradas pelo gerador de Taillard (tempos uniformes em [1, 99]) nos
+//! tamanhos 20x5, 50x10, 100x20 e 200x20; com a variável apontando para um diretório,
+//! são usadas as instâncias nele contidas. As medições e a comparação com a execução
+//! anterior (salva em `target/criterion`) ficam a cargo do criterion.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use fssp_solver_rs::crossover::Crossover;
+use fssp_solver_rs::fssp_core::{load_instance, FSSPInstance, Job};
+use fssp_solver_rs::instance_format::from_processing_times;
+use fssp_solver_rs::local_search::insertion_descent;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+/// Tamanhos (tarefas, máquinas) e sementes das instâncias geradas.
+const GENERATED_SIZES: [(usize, usize, i64); 4] = [
+    (20, 5, 873_654_221),
+    (50, 10, 1_539_989_115),
+    (100, 20, 1_219_303_390),
+    (200, 20, 471_503_978),
+];
+/// Quantidade de sequências aleatórias percorridas pelas medições.
+const SEQUENCES: usize = 64;
+
+/// Gerador pseudoaleatório de Taillard (1993): congruencial com módulo 2^31 - 1.
+fn taillard_unif(seed: &mut i64, low: u64, high: u64) -> u64 {
+    const A: i64 = 16_807;
+    const B: i64 = 127_773;
+    const C: i64 = 2_836;
+    const M: i64 = 2_147_483_647;
+    let k = *seed / B;
+    *seed = A * (*seed % B) - k * C;
+    if *seed < 0 {
+        *seed += M;
+    }
+    let value01 = *seed as f64 / M as f64;
+    low + (value01 * (high - low + 1) as f64) as u64
+}
+
+/// Gera uma instância com tempos uniformes em [1, 99], sorteados máquina a máquina
+/// como no gerador original de Taillard.
+fn generate_instance(n_jobs: usize, n_machines: usize, mut seed: i64) -> FSSPInstance {
+    let mut processing_times = vec![vec![0; n_machines]; n_jobs];
+    for machine in 0..n_machines {
+        for row in processing_times.iter_mut() {
+            row[machine] = taillard_unif(&mut seed, 1, 99);
+        }
+    }
+    from_processing_times(processing_times).expect("instância gerada válida")
+}
+
+/// Instâncias medidas: as do diretório em `FSSP_BENCH_INSTANCES`, se definido, ou as
+/// geradas nos tamanhos padrão.
+fn bench_instances() -> Vec<(String, FSSPInstance)> {
+    let Some(dir) = std::env::var_os("FSSP_BENCH_INSTANCES") else {
+        return GENERATED_SIZES
+            .iter()
+            .map(|&(n, m, seed)| (format!("{}x{}", n, m), generate_instance(n, m, seed)))
+            .collect();
+    };
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("Erro ao ler o diretório {:?}: {}", dir, e))
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| match load_instance(&path.to_string_lossy()) {
+            Ok(instance) => Some((
+                path.file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                instance,
+            )),
+            Err(e) => {
+                eprintln!("Ignorando {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Sequências aleatórias (com semente fixa) de uma instância.
+fn random_sequences(instance: &FSSPInstance) -> Vec<Vec<Job>> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..SEQUENCES)
+        .map(|_| {
+            let mut sequence: Vec<Job> = (0..instance.n_jobs as Job).collect();
+            sequence.shuffle(&mut rng);
+            sequence
+        })
+        .collect()
+}
+
+/// Mede o cálculo do makespan, os operadores de cruzamento e a busca local por
+/// inserção em cada instância; as medições de cada núcleo formam um grupo, com uma
+/// entrada por instância.
+fn kernels(c: &mut Criterion) {
+    let instances: Vec<(String, FSSPInstance, Vec<Vec<Job>>)> = bench_instances()
+        .into_iter()
+        .map(|(label, instance)| {
+            let sequences = random_sequences(&instance);
+            (label, instance, sequences)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("makespan");
+    for (label, instance, sequences) in &instances {
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            let mut i = 0;
+            b.iter(|| {
+                i += 1;
+                black_box(instance.calculate_makespan(black_box(&sequences[i % SEQUENCES])))
+            });
+        });
+    }
+    group.finish();
+
+    for crossover in Crossover::ALL {
+        let mut group = c.benchmark_group(format!("crossover/{}", crossover));
+        for (label, _, sequences) in &instances {
+            group.bench_function(BenchmarkId::from_parameter(label), |b| {
+                let mut rng = StdRng::seed_from_u64(7);
+                let mut i = 0;
+                b.iter(|| {
+                    i += 1;
+                    let p1 = &sequences[i % SEQUENCES];
+                    let p2 = &sequences[(i + 1) % SEQUENCES];
+                    black_box(crossover.apply(p1, p2, &mut rng))
+                });
+            });
+        }
+        group.finish();
+    }
+
+    let mut group = c.benchmark_group("insertion_descent");
+    for (label, instance, sequences) in &instances {
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            let mut i = 0;
+            b.iter(|| {
+                i += 1;
+                let mut sequence = sequences[i % SEQUENCES].clone();
+                black_box(insertion_descent(instance, &mut sequence));
+                sequence
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, kernels);
+criterion_main!(benches);
@@ -0,0 +1,330 @@
+/*
+ * Interface C do fssp_solver_rs (módulo `c_api`).
+ *
+ * Compile a biblioteca com `cargo build --release` e ligue com
+ * target/release/libfssp_solver_rs.so (ou .a/.dylib/.dll).
+ *
+ * Instâncias e resultados são ponteiros opacos: crie-os com as funções
+ * `*_new`/`fssp_solve` e libere-os com as funções `*_free`. Em caso de erro,
+ * as funções retornam NULL (ou -1) e `fssp_last_error` descreve a falha.
+ */
+
+#ifndef FSSP_SOLVER_H
+#define FSSP_SOLVER_H
+
+/* Gerado por cbindgen a partir de src/c_api.rs: não edite manualmente. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Algoritmo memético (padrão).
+ */
+#define FSSP_ALGORITHM_MEMETIC 0
+
+/**
+ * GRASP com alfa reativo.
+ */
+#define FSSP_ALGORITHM_GRASP 1
+
+/**
+ * Busca local iterada.
+ */
+#define FSSP_ALGORITHM_ILS 2
+
+/**
+ * Branch-and-bound exato.
+ */
+#define FSSP_ALGORITHM_BB 3
+
+/**
+ * Evolução diferencial sobre chaves aleatórias.
+ */
+#define FSSP_ALGORITHM_DE 4
+
+/**
+ * Algoritmo genético de chaves aleatórias viciadas.
+ */
+#define FSSP_ALGORITHM_BRKGA 5
+
+/**
+ * Otimização por enxame de partículas sobre chaves aleatórias.
+ */
+#define FSSP_ALGORITHM_PSO 6
+
+/**
+ * Heurística construtiva CDS (Campbell, Dudek e Smith).
+ */
+#define FSSP_ALGORITHM_CDS 7
+
+/**
+ * Heurística construtiva de Palmer.
+ */
+#define FSSP_ALGORITHM_PALMER 8
+
+/**
+ * Heurística construtiva NEH (desempate pela primeira posição).
+ */
+#define FSSP_ALGORITHM_NEH 9
+
+/**
+ * Iterated Greedy.
+ */
+#define FSSP_ALGORITHM_IG 10
+
+/**
+ * Recozimento simulado.
+ */
+#define FSSP_ALGORITHM_SA 11
+
+/**
+ * Busca tabu.
+ */
+#define FSSP_ALGORITHM_TABU 12
+
+/**
+ * Colônia de formigas MAX-MIN.
+ */
+#define FSSP_ALGORITHM_ACO 13
+
+/**
+ * Hiper-heurística sobre os demais algoritmos.
+ */
+#define FSSP_ALGORITHM_HH 14
+
+/**
+ * Portfólio com o modelo embutido (escolha pelas características da instância).
+ */
+#define FSSP_ALGORITHM_AUTO 15
+
+/**
+ * Minimiza o makespan (padrão).
+ */
+#define FSSP_OBJECTIVE_MAKESPAN 0
+
+/**
+ * Minimiza o atraso ponderado total (exige datas de entrega).
+ */
+#define FSSP_OBJECTIVE_TWT 1
+
+/**
+ * Minimiza o tempo de fluxo total.
+ */
+#define FSSP_OBJECTIVE_FLOWTIME 2
+
+/**
+ * Minimiza o atraso total (exige datas de entrega).
+ */
+#define FSSP_OBJECTIVE_TT 3
+
+/**
+ * Minimiza o atraso máximo (exige datas de entrega).
+ */
+#define FSSP_OBJECTIVE_TMAX 4
+
+/**
+ * Minimiza o tempo de fluxo ponderado total (pesos de `fssp_instance_set_weights`).
+ */
+#define FSSP_OBJECTIVE_TWFT 5
+
+/**
+ * Representa uma instância do Problema de Escalonamento Flow Shop (FSSP).
+ */
+typedef struct FSSPInstance FSSPInstance;
+
+/**
+ * Resultado de `fssp_solve`.
+ */
+typedef struct FsspResult FsspResult;
+
+/**
+ * Parâmetros de `fssp_solve`. Use `fssp_params_default` para obter os valores
+ * padrão da linha de comando e altere apenas os campos desejados.
+ */
+typedef struct FsspParams {
+  /**
+   * `FSSP_ALGORITHM_*`.
+   */
+  uint32_t algorithm;
+  /**
+   * `FSSP_OBJECTIVE_*`.
+   */
+  uint32_t objective;
+  /**
+   * Gerações (ou iterações) máximas.
+   */
+  size_t max_generations;
+  /**
+   * Tamanho da população (memético, DE, BRKGA e PSO).
+   */
+  size_t population_size;
+  /**
+   * Taxa de mutação.
+   */
+  double mutation_rate;
+  /**
+   * Taxa de busca local.
+   */
+  double local_search_rate;
+  /**
+   * Número de ilhas (menor que 2 desativa o modelo).
+   */
+  size_t islands;
+  /**
+   * Tempo máximo em segundos (0 ou negativo: sem limite; NaN, infinito ou acima
+   * do maior tempo representável: erro).
+   */
+  double max_duration;
+} FsspParams;
+
+/**
+ * Tipo dos tempos de processamento, instantes e valores da função objetivo. Com 64
+ * bits, o makespan não estoura mesmo em instâncias grandes com tempos altos (ex:
+ * 800 tarefas com tempos de até 10⁶). Tempos fracionários são representados em
+ * ponto fixo: uma instância com `time_decimals = d` armazena cada tempo multiplicado
+ * por 10^d, de modo que os cálculos continuam exatos e inteiros; `TimeValue`
+ * exibe os valores nas unidades originais.
+ */
+typedef uint64_t FsspTime;
+
+
+
+
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Mensagem do último erro ocorrido na thread corrente, ou `NULL` se não houve
+ * erro. O texto pertence à biblioteca e é válido até a próxima chamada que falhe.
+ */
+const char *fssp_last_error(void);
+
+/**
+ * Cria uma instância a partir da matriz de tempos de processamento, armazenada por
+ * linhas: `processing_times[j * n_machines + i]` é o tempo da tarefa `j` na
+ * máquina `i`. Os tempos são lidos em 32 bits e acumulados em 64 bits, de modo
+ * que os resultados não estouram. Retorna `NULL` em caso de erro, inclusive com
+ * dimensões nulas ou cujo produto não cabe em `size_t`.
+ *
+ * # Safety
+ *
+ * `processing_times` deve apontar para `n_jobs * n_machines` valores válidos.
+ */
+struct FSSPInstance *fssp_instance_new(size_t n_jobs,
+                                       size_t n_machines,
+                                       const uint32_t *processing_times);
+
+/**
+ * Define o instante de liberação de cada tarefa (`n_jobs` valores). Retorna 0 em
+ * caso de sucesso e -1 em caso de erro.
+ *
+ * # Safety
+ *
+ * `instance` deve ter sido criada por `fssp_instance_new` e `release_dates` deve
+ * apontar para `n_jobs` valores válidos.
+ */
+int fssp_instance_set_release_dates(struct FSSPInstance *instance, const uint32_t *release_dates);
+
+/**
+ * Define a data de entrega e, opcionalmente, o peso de cada tarefa (`n_jobs`
+ * valores cada), necessários aos objetivos de atraso (`FSSP_OBJECTIVE_TWT`,
+ * `FSSP_OBJECTIVE_TT` e `FSSP_OBJECTIVE_TMAX`). `weights` pode ser `NULL` (mantém os
+ * pesos atuais, 1 se não definidos). Retorna 0 em caso de sucesso e -1 em caso de erro.
+ *
+ * # Safety
+ *
+ * `instance` deve ter sido criada por `fssp_instance_new`; `due_dates` e, se não
+ * nulo, `weights` devem apontar para `n_jobs` valores válidos.
+ */
+int fssp_instance_set_due_dates(struct FSSPInstance *instance,
+                                const uint32_t *due_dates,
+                                const uint32_t *weights);
+
+/**
+ * Define o peso de cada tarefa (`n_jobs` valores) nos objetivos ponderados
+ * (`FSSP_OBJECTIVE_TWT` e `FSSP_OBJECTIVE_TWFT`). Retorna 0 em caso de sucesso e -1
+ * em caso de erro.
+ *
+ * # Safety
+ *
+ * `instance` deve ter sido criada por `fssp_instance_new` e `weights` deve apontar
+ * para `n_jobs` valores válidos.
+ */
+int fssp_instance_set_weights(struct FSSPInstance *instance, const uint32_t *weights);
+
+/**
+ * Libera uma instância criada por `fssp_instance_new`. Aceita `NULL`.
+ *
+ * # Safety
+ *
+ * `instance` deve ser `NULL` ou um ponteiro ainda não liberado retornado por
+ * `fssp_instance_new`.
+ */
+void fssp_instance_free(struct FSSPInstance *instance);
+
+/**
+ * Parâmetros padrão (os mesmos da linha de comando).
+ */
+struct FsspParams fssp_params_default(void);
+
+/**
+ * Resolve a instância com os parâmetros informados (`NULL` usa os padrões). Retorna
+ * o resultado, que deve ser liberado com `fssp_result_free`, ou `NULL` em caso de
+ * erro.
+ *
+ * # Safety
+ *
+ * `instance` deve ter sido criada por `fssp_instance_new`; `params` deve ser `NULL`
+ * ou apontar para um `FsspParams` válido.
+ */
+struct FsspResult *fssp_solve(const struct FSSPInstance *instance, const struct FsspParams *params);
+
+/**
+ * Valor da melhor solução na função objetivo escolhida no campo `objective` de
+ * `FsspParams` (`FSSP_OBJECTIVE_*`).
+ *
+ * # Safety
+ *
+ * `result` deve ter sido retornado por `fssp_solve` e ainda não liberado.
+ */
+FsspTime fssp_result_value(const struct FsspResult *result);
+
+/**
+ * Makespan da melhor solução.
+ *
+ * # Safety
+ *
+ * `result` deve ter sido retornado por `fssp_solve` e ainda não liberado.
+ */
+FsspTime fssp_result_makespan(const struct FsspResult *result);
+
+/**
+ * Copia até `capacity` tarefas da melhor sequência (base 0) para `out` e retorna o
+ * número de tarefas da sequência. Com `out` nulo, apenas retorna o tamanho.
+ *
+ * # Safety
+ *
+ * `result` deve ter sido retornado por `fssp_solve` e ainda não liberado; `out`
+ * deve ser `NULL` ou apontar para `capacity` posições graváveis.
+ */
+size_t fssp_result_sequence(const struct FsspResult *result, uint32_t *out, size_t capacity);
+
+/**
+ * Libera um resultado retornado por `fssp_solve`. Aceita `NULL`.
+ *
+ * # Safety
+ *
+ * `result` deve ser `NULL` ou um ponteiro ainda não liberado retornado por
+ * `fssp_solve`.
+ */
+void fssp_result_free(struct FsspResult *result);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* FSSP_SOLVER_H */
@@ -0,0 +1,215 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics;
+use crate::local_search::{self, SearchLimits};
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::Rng;
+use tracing::{debug, debug_span};
+
+/// Parâmetros da colônia de formigas.
+#[derive(Debug, Clone)]
+pub struct AcoParams {
+    pub iterations: usize, // Número de iterações (ciclos de construção).
+    pub ants: usize,       // Formigas (soluções construídas) por iteração.
+    pub evaporation: f64,  // Taxa de evaporação do feromônio (entre 0 e 1).
+    pub exploitation: f64, // Probabilidade de escolher a tarefa de maior feromônio.
+}
+
+impl Default for AcoParams {
+    fn default() -> Self {
+        AcoParams {
+            iterations: 100,
+            ants: 10,
+            evaporation: 0.25,
+            exploitation: 0.9,
+        }
+    }
+}
+
+/// Sistema de formigas MAX-MIN (MMAS) de Stützle (1998): o feromônio `tau[j][k]`
+/// indica a atratividade de posicionar a tarefa `j` na posição `k`. Cada formiga
+/// preenche as posições em ordem, escolhendo com probabilidade `exploitation` a
+/// tarefa de maior feromônio e, caso contrário, sorteando proporcionalmente ao
+/// feromônio. A melhor formiga da iteração passa pela busca local por inserção e
+/// reforça as suas posições; o feromônio é mantido entre `tau_max = 1 / (rho * C*)`
+/// e `tau_max / (2n)`, onde `C*` é o melhor makespan encontrado.
+pub struct AntColony {
+    instance: FSSPInstance,           // Instância do problema FSSP.
+    params: AcoParams,                // Parâmetros da colônia (iterações = critério de parada).
+    initial_solutions: Vec<Vec<Job>>, // Soluções fornecidas usadas como incumbentes iniciais.
+    pub best_sequence: Vec<Job>,      // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,          // O makespan da melhor sequência encontrada.
+}
+
+impl AntColony {
+    /// Cria uma nova instância do `AntColony`.
+    pub fn new(instance: FSSPInstance, params: AcoParams) -> Self {
+        AntColony {
+            instance,
+            params,
+            initial_solutions: Vec::new(),
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Define soluções (já validadas) que iniciam a busca como incumbentes: a melhor
+    /// delas (ou a do NEH, se for melhor) define o feromônio inicial.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.initial_solutions = solutions;
+        self
+    }
+
+    /// Limites `(tau_min, tau_max)` do feromônio para o melhor makespan atual.
+    fn _trail_limits(&self) -> (f64, f64) {
+        let tau_max = 1.0 / (self.params.evaporation * self.best_makespan.max(1) as f64);
+        (tau_max / (2 * self.instance.n_jobs).max(1) as f64, tau_max)
+    }
+
+    /// Constrói a sequência de uma formiga a partir do feromônio.
+    fn _construct<R: Rng>(&self, trail: &[Vec<f64>], rng: &mut R) -> Vec<Job> {
+        let n = self.instance.n_jobs;
+        let mut unscheduled: Vec<Job> = (0..n as Job).collect();
+        let mut sequence = Vec::with_capacity(n);
+        while !unscheduled.is_empty() {
+            let pos = sequence.len();
+            let weight = |job: Job| trail[job as usize][pos];
+            let idx = if rng.gen::<f64>() < self.params.exploitation {
+                (0..unscheduled.len())
+                    .max_by(|&a, &b| weight(unscheduled[a]).total_cmp(&weight(unscheduled[b])))
+                    .unwrap()
+            } else {
+                let total: f64 = unscheduled.iter().map(|&job| weight(job)).sum();
+                let mut r = rng.gen::<f64>() * total;
+                unscheduled
+                    .iter()
+                    .position(|&job| {
+                        r -= weight(job);
+                        r < 0.0
+                    })
+                    .unwrap_or(unscheduled.len() - 1)
+            };
+            sequence.push(unscheduled.swap_remove(idx));
+        }
+        sequence
+    }
+}
+
+impl Solver for AntColony {
+    fn name(&self) -> &'static str {
+        "aco"
+    }
+
+    /// Executa a colônia até o número máximo de iterações ou o esgotamento do
+    /// orçamento. A busca local de cada iteração também é interrompida no instante
+    /// limite ou ao atingir o máximo de avaliações.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let limits = SearchLimits {
+            deadline: budget.deadline,
+            max_evaluations: budget.max_evaluations,
+            ..SearchLimits::default()
+        };
+        let n = self.instance.n_jobs;
+
+        // Incumbente inicial: a melhor entre o NEH e as soluções fornecidas.
+        let neh = heuristics::neh(&self.instance);
+        let (makespan, sequence) = self
+            .initial_solutions
+            .iter()
+            .chain(std::iter::once(&neh))
+            .map(|seq| (self.instance.evaluate(seq), seq))
+            .min_by_key(|&(makespan, _)| makespan)
+            .unwrap();
+        self.best_makespan = makespan;
+        self.best_sequence = sequence.clone();
+        let mut trail = vec![vec![self._trail_limits().1; n]; n];
+
+        for iter in 0..self.params.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            let mut makespan_sum = 0.0;
+            let mut iteration_best: Option<(Time, Vec<Job>)> = None;
+            for _ in 0..self.params.ants.max(1) {
+                let sequence = self._construct(&trail, &mut rng);
+                let makespan = self.instance.evaluate(&sequence);
+                makespan_sum += makespan as f64;
+                if iteration_best
+                    .as_ref()
+                    .is_none_or(|(best, _)| makespan < *best)
+                {
+                    iteration_best = Some((makespan, sequence));
+                }
+            }
+            let (_, mut sequence) = iteration_best.unwrap();
+            let makespan =
+                local_search::insertion_descent_limited(&self.instance, &mut sequence, &limits);
+            if makespan < self.best_makespan {
+                self.best_makespan = makespan;
+                self.best_sequence = sequence.clone();
+                debug!(makespan, "Nova melhor solução");
+                observer.on_new_best(&Solution {
+                    sequence: self.best_sequence.clone(),
+                    makespan,
+                });
+            }
+
+            // Evaporação e reforço das posições da melhor formiga da iteração.
+            let (tau_min, tau_max) = self._trail_limits();
+            let keep = 1.0 - self.params.evaporation;
+            for row in trail.iter_mut() {
+                for tau in row.iter_mut() {
+                    *tau *= keep;
+                }
+            }
+            for (pos, &job) in sequence.iter().enumerate() {
+                trail[job as usize][pos] += 1.0 / makespan.max(1) as f64;
+            }
+            for row in trail.iter_mut() {
+                for tau in row.iter_mut() {
+                    *tau = tau.clamp(tau_min, tau_max);
+                }
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_makespan,
+                generation_best: makespan,
+                mean_makespan: makespan_sum / self.params.ants.max(1) as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
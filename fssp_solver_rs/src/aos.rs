@@ -0,0 +1,255 @@
+use crate::crossover::Crossover;
+use crate::fssp_core::Time;
+use crate::local_search::LocalSearch;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Taxa de aprendizado das estimativas de qualidade dos operadores.
+const LEARNING_RATE: f64 = 0.3;
+/// Probabilidade mínima de cada operador na correspondência de probabilidades,
+/// dividida pelo número de operadores.
+const MIN_PROBABILITY: f64 = 0.2;
+/// Probabilidade de escolher um operador aleatório no Q-learning.
+const EPSILON: f64 = 0.1;
+/// Fator de desconto do valor do estado seguinte no Q-learning.
+const DISCOUNT: f64 = 0.5;
+/// Estados do Q-learning: a busca estagnou (0) ou melhorou (1) na última geração.
+const STATES: usize = 2;
+
+/// Estratégias de seleção adaptativa de operadores (AOS).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AosStrategy {
+    ProbabilityMatching, // Probabilidade proporcional à qualidade estimada de cada operador.
+    Ucb,                 // Bandido UCB1: qualidade média mais bônus de exploração.
+    #[default]
+    QLearning, // Q-learning com estados de melhoria/estagnação e escolha epsilon-gulosa.
+}
+
+impl FromStr for AosStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pm" | "probability-matching" => Ok(AosStrategy::ProbabilityMatching),
+            "ucb" => Ok(AosStrategy::Ucb),
+            "qlearning" | "q-learning" => Ok(AosStrategy::QLearning),
+            _ => Err(format!(
+                "Estratégia de seleção de operadores desconhecida '{}' (use pm, ucb ou qlearning)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for AosStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AosStrategy::ProbabilityMatching => "pm",
+            AosStrategy::Ucb => "ucb",
+            AosStrategy::QLearning => "qlearning",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Seletor de um operador entre `arms` alternativas, que aprende com as recompensas
+/// (entre 0 e 1) obtidas pelos operadores escolhidos.
+#[derive(Debug, Clone)]
+pub struct OperatorSelector {
+    strategy: AosStrategy,       // Estratégia de seleção.
+    quality: Vec<[f64; STATES]>, // Qualidade estimada de cada operador (por estado).
+    uses: Vec<u64>,              // Número de escolhas de cada operador.
+    rewards: Vec<f64>,           // Soma das recompensas de cada operador (UCB).
+}
+
+impl OperatorSelector {
+    pub fn new(strategy: AosStrategy, arms: usize) -> Self {
+        OperatorSelector {
+            strategy,
+            quality: vec![[0.0; STATES]; arms],
+            uses: vec![0; arms],
+            rewards: vec![0.0; arms],
+        }
+    }
+
+    /// Número de escolhas de cada operador.
+    pub fn uses(&self) -> &[u64] {
+        &self.uses
+    }
+
+    /// Escolhe o operador da próxima geração no estado informado (usado apenas
+    /// pelo Q-learning).
+    pub fn select<R: Rng>(&mut self, state: usize, rng: &mut R) -> usize {
+        let arms = self.uses.len();
+        let arm = match self.strategy {
+            AosStrategy::ProbabilityMatching => {
+                let min = MIN_PROBABILITY / arms as f64;
+                let total: f64 = self.quality.iter().map(|q| q[0]).sum();
+                let probability = |arm: usize| {
+                    if total > 0.0 {
+                        min + (1.0 - arms as f64 * min) * self.quality[arm][0] / total
+                    } else {
+                        1.0 / arms as f64
+                    }
+                };
+                let mut draw = rng.gen::<f64>();
+                (0..arms)
+                    .find(|&arm| {
+                        draw -= probability(arm);
+                        draw < 0.0
+                    })
+                    .unwrap_or(arms - 1)
+            }
+            AosStrategy::Ucb => match self.uses.iter().position(|&n| n == 0) {
+                Some(untried) => untried,
+                None => {
+                    let total = self.uses.iter().sum::<u64>() as f64;
+                    let score = |arm: usize| {
+                        let n = self.uses[arm] as f64;
+                        self.rewards[arm] / n + (2.0 * total.ln() / n).sqrt()
+                    };
+                    (0..arms)
+                        .max_by(|&a, &b| score(a).total_cmp(&score(b)))
+                        .unwrap()
+                }
+            },
+            AosStrategy::QLearning => {
+                if rng.gen::<f64>() < EPSILON {
+                    rng.gen_range(0..arms)
+                } else {
+                    (0..arms)
+                        .max_by(|&a, &b| self.quality[a][state].total_cmp(&self.quality[b][state]))
+                        .unwrap()
+                }
+            }
+        };
+        self.uses[arm] += 1;
+        arm
+    }
+
+    /// Atualiza a qualidade do operador escolhido no estado `state` com a recompensa
+    /// obtida, que levou ao estado `next_state`.
+    pub fn update(&mut self, state: usize, arm: usize, reward: f64, next_state: usize) {
+        self.rewards[arm] += reward;
+        match self.strategy {
+            AosStrategy::ProbabilityMatching | AosStrategy::Ucb => {
+                let quality = &mut self.quality[arm][0];
+                *quality += LEARNING_RATE * (reward - *quality);
+            }
+            AosStrategy::QLearning => {
+                let future = self
+                    .quality
+                    .iter()
+                    .map(|q| q[next_state])
+                    .fold(0.0, f64::max);
+                let quality = &mut self.quality[arm][state];
+                *quality += LEARNING_RATE * (reward + DISCOUNT * future - *quality);
+            }
+        }
+    }
+}
+
+/// Seleção adaptativa dos operadores de cruzamento e de busca local do algoritmo
+/// memético. A cada geração, um seletor escolhe o cruzamento e outro a busca local;
+/// ao fim dela, ambos recebem a mesma recompensa: a soma das melhorias relativas dos
+/// filhos sobre seus pais por avaliação da função objetivo, normalizada pela maior
+/// já observada. Operadores caros (como a ILS) só são preferidos se a melhoria
+/// compensar as avaliações que consomem.
+#[derive(Debug, Clone)]
+pub struct AdaptiveOperators {
+    pub crossovers: Vec<Crossover>,          // Cruzamentos disponíveis.
+    pub local_searches: Vec<LocalSearch>,    // Buscas locais disponíveis.
+    crossover_selector: OperatorSelector,    // Seletor do cruzamento.
+    local_search_selector: OperatorSelector, // Seletor da busca local.
+    chosen: (usize, usize),                  // Operadores escolhidos na geração atual.
+    state: usize,                            // Estado da busca no início da geração.
+    gain: f64,                               // Melhorias relativas dos filhos na geração.
+    start_evaluations: u64,                  // Avaliações no início da geração.
+    max_reward: f64,                         // Maior recompensa bruta observada.
+}
+
+impl AdaptiveOperators {
+    /// Cria o controle com os operadores disponíveis (listas não vazias).
+    pub fn new(
+        strategy: AosStrategy,
+        crossovers: Vec<Crossover>,
+        local_searches: Vec<LocalSearch>,
+    ) -> Self {
+        AdaptiveOperators {
+            crossover_selector: OperatorSelector::new(strategy, crossovers.len()),
+            local_search_selector: OperatorSelector::new(strategy, local_searches.len()),
+            crossovers,
+            local_searches,
+            chosen: (0, 0),
+            state: 0,
+            gain: 0.0,
+            start_evaluations: 0,
+            max_reward: 0.0,
+        }
+    }
+
+    /// Número de gerações em que cada operador foi usado (ex: `ox=12 pmx=3; swap=15`).
+    pub fn usage(&self) -> String {
+        fn _counts<T: fmt::Display>(operators: &[T], uses: &[u64]) -> String {
+            operators
+                .iter()
+                .zip(uses)
+                .map(|(operator, n)| format!("{}={}", operator, n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+        format!(
+            "{}; {}",
+            _counts(&self.crossovers, self.crossover_selector.uses()),
+            _counts(&self.local_searches, self.local_search_selector.uses())
+        )
+    }
+
+    /// Escolhe os operadores da geração que começa, dadas as avaliações feitas até
+    /// agora.
+    pub(crate) fn begin_generation<R: Rng>(
+        &mut self,
+        evaluations: u64,
+        rng: &mut R,
+    ) -> (Crossover, LocalSearch) {
+        self.chosen = (
+            self.crossover_selector.select(self.state, rng),
+            self.local_search_selector.select(self.state, rng),
+        );
+        self.gain = 0.0;
+        self.start_evaluations = evaluations;
+        (
+            self.crossovers[self.chosen.0],
+            self.local_searches[self.chosen.1],
+        )
+    }
+
+    /// Registra um filho avaliado e o valor do pai correspondente.
+    pub(crate) fn record(&mut self, parent: Time, child: Time) {
+        if child < parent {
+            self.gain += (parent - child) as f64 / parent as f64;
+        }
+    }
+
+    /// Encerra a geração, recompensando os operadores escolhidos. `improved` indica
+    /// se a geração melhorou a melhor solução global. Retorna a recompensa.
+    pub(crate) fn end_generation(&mut self, evaluations: u64, improved: bool) -> f64 {
+        let spent = evaluations.saturating_sub(self.start_evaluations).max(1);
+        let raw = self.gain / spent as f64;
+        self.max_reward = self.max_reward.max(raw);
+        let reward = if self.max_reward > 0.0 {
+            raw / self.max_reward
+        } else {
+            0.0
+        };
+        let next_state = usize::from(improved);
+        let (crossover, local_search) = self.chosen;
+        self.crossover_selector
+            .update(self.state, crossover, reward, next_state);
+        self.local_search_selector
+            .update(self.state, local_search, reward, next_state);
+        self.state = next_state;
+        reward
+    }
+}
@@ -0,0 +1,136 @@
+use crate::error::{FsspError, Result};
+use crate::fssp_core::Time;
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+/// Melhores makespans conhecidos (limitantes superiores) das 120 instâncias de Taillard
+/// (ta001 a ta120), na ordem das instâncias.
+const TAILLARD_UPPER_BOUNDS: [Time; 120] = [
+    1278, 1359, 1081, 1293, 1235, 1195, 1234, 1206, 1230, 1108, // 20x5
+    1582, 1659, 1496, 1377, 1419, 1397, 1484, 1538, 1593, 1591, // 20x10
+    2297, 2099, 2326, 2223, 2291, 2226, 2273, 2200, 2237, 2178, // 20x20
+    2724, 2834, 2621, 2751, 2863, 2829, 2725, 2683, 2552, 2782, // 50x5
+    2991, 2867, 2839, 3063, 2976, 3006, 3093, 3037, 2897, 3065, // 50x10
+    3850, 3704, 3640, 3720, 3610, 3681, 3704, 3691, 3743, 3756, // 50x20
+    5493, 5268, 5175, 5014, 5250, 5135, 5246, 5094, 5448, 5322, // 100x5
+    5770, 5349, 5676, 5781, 5467, 5303, 5595, 5617, 5871, 5845, // 100x10
+    6202, 6183, 6271, 6269, 6314, 6364, 6268, 6401, 6275, 6434, // 100x20
+    10862, 10480, 10922, 10889, 10524, 10329, 10854, 10730, 10438, 10675, // 200x10
+    11195, 11203, 11281, 11275, 11259, 11176, 11360, 11334, 11192, 11288, // 200x20
+    26040, 26520, 26371, 26456, 26334, 26477, 26389, 26560, 26005, 26457, // 500x20
+];
+
+/// Tabela de melhores makespans conhecidos, indexada pelo nome da instância.
+#[derive(Debug, Clone, Default)]
+pub struct BestKnown {
+    values: HashMap<String, Time>, // Melhor makespan conhecido por instância.
+}
+
+impl BestKnown {
+    /// Cria uma tabela vazia.
+    pub fn new() -> Self {
+        BestKnown::default()
+    }
+
+    /// Cria uma tabela com os valores embutidos das instâncias de Taillard. Os do
+    /// benchmark VRF não são embutidos e devem ser carregados com `load`.
+    pub fn builtin() -> Self {
+        let mut table = BestKnown::new();
+        for (idx, &makespan) in TAILLARD_UPPER_BOUNDS.iter().enumerate() {
+            table.insert(&format!("ta{:03}", idx + 1), makespan);
+        }
+        table
+    }
+
+    /// Acrescenta (ou substitui) os valores de outra tabela.
+    pub fn extend(&mut self, other: BestKnown) {
+        self.values.extend(other.values);
+    }
+
+    /// Carrega uma tabela de um arquivo (veja `parse`).
+    #[cfg(feature = "fs")]
+    pub fn load(path: &Path) -> Result<Self> {
+        BestKnown::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Interpreta uma tabela com uma instância por linha no formato `nome makespan`
+    /// (separados por espaço ou vírgula). Linhas vazias ou iniciadas por `#` são
+    /// ignoradas.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut table = BestKnown::new();
+
+        for (line_idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|s| !s.is_empty())
+                .collect();
+            let value = match parts.as_slice() {
+                [name, value] => value.parse().ok().map(|v| (name.to_string(), v)),
+                _ => None,
+            };
+            match value {
+                Some((name, makespan)) => table.insert(&name, makespan),
+                None => {
+                    return Err(FsspError::Format {
+                        line: line_idx + 1,
+                        message: "esperado `nome makespan` na tabela de melhores soluções"
+                            .to_string(),
+                    })
+                }
+            }
+        }
+        Ok(table)
+    }
+
+    /// Registra (ou substitui) o melhor makespan conhecido de uma instância. Nomes
+    /// reconhecidos são guardados na forma normalizada.
+    pub fn insert(&mut self, name: &str, makespan: Time) {
+        let key = normalize_name(name).unwrap_or_else(|| name.to_string());
+        self.values.insert(key, makespan);
+    }
+
+    /// Retorna o melhor makespan conhecido de uma instância, se registrado.
+    /// O nome é procurado como informado e, em seguida, na forma normalizada
+    /// (ex: "Tai001", "ta1" e "tai_001" são reconhecidos como "ta001", e
+    /// "VFR100_20_1_Gap" e "vfr100_20_1" como "VFR100_20_1").
+    pub fn get(&self, name: &str) -> Option<Time> {
+        self.values
+            .get(name)
+            .or_else(|| normalize_name(name).and_then(|n| self.values.get(&n)))
+            .copied()
+    }
+}
+
+/// Desvio percentual relativo (RPD) de um makespan em relação ao melhor conhecido.
+pub fn relative_percentage_deviation(makespan: Time, best_known: Time) -> f64 {
+    100.0 * (makespan as f64 - best_known as f64) / best_known as f64
+}
+
+/// Normaliza nomes de instâncias de Taillard para a forma `taNNN` e do benchmark
+/// VRF (Vallada, Ruiz e Framinan) para a forma `VFRn_m_k`, sem o sufixo `_Gap` dos
+/// arquivos distribuídos pelos autores.
+fn normalize_name(name: &str) -> Option<String> {
+    let lower = name.to_ascii_lowercase();
+    if let Some(rest) = lower.strip_prefix("vfr") {
+        let rest = rest.strip_suffix("_gap").unwrap_or(rest);
+        let parts: Vec<usize> = rest
+            .split('_')
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()?;
+        return match parts[..] {
+            [jobs, machines, index] => Some(format!("VFR{}_{}_{}", jobs, machines, index)),
+            _ => None,
+        };
+    }
+    let rest = lower
+        .strip_prefix("tai")
+        .or_else(|| lower.strip_prefix("ta"))?;
+    let digits = rest.trim_start_matches(['_', '-']);
+    let number: usize = digits.parse().ok()?;
+    Some(format!("ta{:03}", number))
+}
@@ -1,77 +1,1054 @@
-use fssp_solver_rs::fssp_core::load_instance;
-use fssp_solver_rs::solver::MemeticAlgorithm;
+use clap::Parser;
+use fssp_solver_rs::best_known::{relative_percentage_deviation, BestKnown};
+use fssp_solver_rs::budget::Budget;
+use fssp_solver_rs::csv_record;
+use fssp_solver_rs::error::{FsspError, Result};
+use fssp_solver_rs::fssp_core::{load_instance, FSSPInstance, Time};
+use fssp_solver_rs::instance_format::InstanceFormat;
+use fssp_solver_rs::observer::{Control, GenerationStats, Observer};
+use fssp_solver_rs::solver::{MemeticAlgorithm, MemeticParams, Solver};
 use rayon::prelude::*;
-use std::fs::File;
-use std::io::{self, Write};
-use std::path::Path;
-
-fn main() -> io::Result<()> {
-    // 1. Carrega a instância mais desafiadora (`fssp_instance_05.txt`).
-    let instance_path = "./src/instances/fssp_instance_05.txt";
-    let instance = load_instance(instance_path).expect("Failed to load FSSP instance");
-
-    // 2. Define uma gama de parâmetros a serem testados para o Algoritmo Memético
-    let population_sizes = vec![50, 100];
-    let generations = vec![100, 200];
-    let mutation_rates = vec![0.01, 0.05, 0.1];
-    let local_search_rates = vec![0.1, 0.2, 0.3];
-    let num_runs = 5; // Para robustez estatística
-
-    // Itera sobre todas as combinações de parâmetros para gerar a lista de tarefas
-    let mut all_combinations: Vec<(usize, usize, f64, f64)> = Vec::new();
-    for &pop_size in &population_sizes {
-        for &gens in &generations {
-            for &mut_rate in &mutation_rates {
-                for &ls_rate in &local_search_rates {
-                    all_combinations.push((pop_size, gens, mut_rate, ls_rate));
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Cabeçalho do arquivo de resultados.
+const HEADER: &str = "instance,population_size,generations,mutation_rate,local_search_rate,mean_makespan,std_dev_makespan";
+/// Cabeçalho do arquivo com as execuções individuais (formato longo).
+const RUNS_HEADER: &str = "instance,population_size,generations,mutation_rate,local_search_rate,run,seed,makespan,runtime_seconds,generations_completed,evaluations";
+/// Cabeçalho do arquivo com o desvio percentual relativo médio de cada combinação.
+const RPD_HEADER: &str =
+    "population_size,generations,mutation_rate,local_search_rate,instances,runs,arpd,std_dev_rpd";
+/// Versão do protocolo do modo distribuído; coordenador e nós devem usar a mesma.
+const PROTOCOL_VERSION: u32 = 1;
+/// Tempo máximo para o nó de trabalho se apresentar após conectar.
+const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
+/// Tempo durante o qual o nó de trabalho tenta conectar ao coordenador.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Executa uma grade de parâmetros do Algoritmo Memético sobre um conjunto de instâncias.
+#[derive(Parser, Debug)]
+#[command(about, long_about = None)]
+struct Cli {
+    /// Arquivo TOML com a especificação do experimento.
+    #[arg(default_value = "experiment.toml")]
+    spec: PathBuf,
+
+    /// Arquivo CSV de resultados (substitui o `output` da especificação).
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Arquivo CSV com cada execução individual (substitui o `runs_output` da especificação).
+    #[arg(long)]
+    runs_output: Option<PathBuf>,
+
+    /// Arquivo CSV com o desvio percentual relativo médio (ARPD) de cada combinação
+    /// (substitui o `rpd_output` da especificação).
+    #[arg(long)]
+    rpd_output: Option<PathBuf>,
+
+    /// Tabela de melhores makespans conhecidos, somada aos valores embutidos das
+    /// instâncias de Taillard (substitui o `best_known` da especificação).
+    #[arg(long)]
+    best_known: Option<PathBuf>,
+
+    /// Retoma um experimento interrompido, pulando as combinações já registradas no
+    /// arquivo de resultados.
+    #[arg(long)]
+    resume: bool,
+
+    /// Execuções por combinação de parâmetros (substitui o `runs` da especificação).
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: Option<u32>,
+
+    /// Tempo máximo de cada execução, em segundos (substitui o `max_duration` da
+    /// especificação).
+    #[arg(long)]
+    max_duration: Option<f64>,
+
+    /// Máximo de avaliações da função objetivo por execução (substitui o
+    /// `max_evaluations` da especificação).
+    #[arg(long)]
+    max_evaluations: Option<u64>,
+
+    /// Modo distribuído: atua como coordenador, escutando neste endereço (ex:
+    /// 0.0.0.0:7070) e distribuindo as combinações pendentes entre os nós de
+    /// trabalho conectados, em vez de executá-las localmente.
+    #[arg(long, value_name = "ENDEREÇO", conflicts_with = "worker")]
+    serve: Option<String>,
+
+    /// Modo distribuído: atua como nó de trabalho, executando as combinações
+    /// recebidas do coordenador neste endereço. A especificação não é lida.
+    #[arg(long, value_name = "ENDEREÇO")]
+    worker: Option<String>,
+
+    /// Combinações executadas simultaneamente pelo nó de trabalho (padrão: número
+    /// de núcleos).
+    #[arg(long, requires = "worker", value_parser = clap::value_parser!(u32).range(1..))]
+    threads: Option<u32>,
+
+    /// Segredo compartilhado entre o coordenador e os nós de trabalho, obrigatório
+    /// no modo distribuído: conexões com outro segredo são recusadas.
+    #[arg(long, env = "FSSP_EXPERIMENT_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+}
+
+impl Cli {
+    /// Segredo do modo distribuído.
+    fn token(&self) -> Result<&str> {
+        match self.token.as_deref() {
+            Some(token) if !token.is_empty() => Ok(token),
+            _ => Err(FsspError::Config(
+                "o modo distribuído exige um segredo compartilhado (--token ou \
+                 FSSP_EXPERIMENT_TOKEN)"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+/// Especificação de um experimento.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExperimentSpec {
+    instances: Vec<String>, // Padrões glob dos arquivos de instância.
+    #[serde(default = "default_runs")]
+    runs: usize, // Execuções por combinação de parâmetros.
+    #[serde(default)]
+    max_duration: Option<f64>, // Tempo máximo de cada execução, em segundos.
+    #[serde(default)]
+    max_evaluations: Option<u64>, // Máximo de avaliações da função objetivo por execução.
+    #[serde(default)]
+    seed: Option<u64>, // Semente base: a execução `r` usa `seed + r` (aleatória se ausente).
+    #[serde(default = "default_output")]
+    output: PathBuf, // Arquivo CSV de resultados.
+    #[serde(default = "default_runs_output")]
+    runs_output: PathBuf, // Arquivo CSV com cada execução individual.
+    #[serde(default = "default_rpd_output")]
+    rpd_output: PathBuf, // Arquivo CSV com o ARPD de cada combinação.
+    #[serde(default)]
+    best_known: Option<PathBuf>, // Tabela de melhores makespans conhecidos.
+    #[serde(default)]
+    grid: Grid, // Grade de parâmetros.
+}
+
+/// Valores testados de cada parâmetro do Algoritmo Memético. Em `generations`, o
+/// valor 0 remove o limite de gerações: a execução é limitada apenas pelo tempo ou
+/// pelas avaliações.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Grid {
+    population_size: Vec<usize>,
+    generations: Vec<usize>,
+    mutation_rate: Vec<f64>,
+    local_search_rate: Vec<f64>,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Grid {
+            population_size: vec![50, 100],
+            generations: vec![100, 200],
+            mutation_rate: vec![0.01, 0.05, 0.1],
+            local_search_rate: vec![0.1, 0.2, 0.3],
+        }
+    }
+}
+
+fn default_runs() -> usize {
+    5 // Para robustez estatística
+}
+
+fn default_output() -> PathBuf {
+    PathBuf::from("results.csv")
+}
+
+fn default_runs_output() -> PathBuf {
+    PathBuf::from("runs.csv")
+}
+
+fn default_rpd_output() -> PathBuf {
+    PathBuf::from("rpd.csv")
+}
+
+/// Resultado de uma execução individual.
+#[derive(Debug, Serialize, Deserialize)]
+struct RunRecord {
+    seed: u64,                    // Semente do gerador de números aleatórios.
+    makespan: Time,               // Melhor makespan obtido.
+    runtime_seconds: f64,         // Tempo de execução em segundos.
+    generations_completed: usize, // Gerações concluídas antes do critério de parada.
+    evaluations: u64,             // Avaliações da função objetivo.
+}
+
+/// Critérios de execução comuns a todas as combinações do experimento.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct RunSettings {
+    runs: usize,                  // Execuções por combinação.
+    seed: Option<u64>,            // Semente base (aleatória se ausente).
+    max_duration: Option<f64>,    // Tempo máximo de cada execução, em segundos.
+    max_evaluations: Option<u64>, // Máximo de avaliações por execução.
+}
+
+/// Apresentação do nó de trabalho, a primeira linha (JSON) de cada conexão.
+#[derive(Debug, Serialize, Deserialize)]
+struct Hello {
+    version: u32,  // Versão do protocolo do nó.
+    token: String, // Segredo compartilhado com o coordenador.
+}
+
+/// Resposta do coordenador à apresentação, uma linha (JSON).
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum HelloReply {
+    Accepted,         // O nó passa a receber combinações.
+    Rejected(String), // Versão ou segredo incorretos; a conexão é encerrada.
+}
+
+/// Combinação enviada pelo coordenador a um nó de trabalho, uma por linha (JSON).
+#[derive(Debug, Serialize, Deserialize)]
+struct WorkItem {
+    file_name: String, // Nome do arquivo da instância, usado para deduzir o formato.
+    text: String,      // Conteúdo do arquivo da instância.
+    population_size: usize,
+    generations: usize, // 0: sem limite de gerações.
+    mutation_rate: f64,
+    local_search_rate: f64,
+    settings: RunSettings,
+}
+
+/// Resposta do nó de trabalho a uma combinação, uma por linha (JSON).
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum WorkReply {
+    Records(Vec<RunRecord>), // Execuções concluídas.
+    Error(String),           // Falha ao executar a combinação.
+}
+
+/// Observador que registra o número de gerações concluídas.
+#[derive(Default)]
+struct GenerationCounter {
+    generations: usize,
+}
+
+impl Observer for GenerationCounter {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        self.generations = stats.generation;
+        Control::Continue
+    }
+}
+
+/// Uma combinação de parâmetros aplicada a uma instância.
+struct Task<'a> {
+    instance_name: String,
+    instance: &'a FSSPInstance,
+    path: &'a Path, // Arquivo da instância, enviado aos nós de trabalho.
+    params: MemeticParams,
+}
+
+impl Task<'_> {
+    /// Limite de gerações como informado na grade (0 quando não há limite).
+    fn generations(&self) -> usize {
+        match self.params.generations {
+            usize::MAX => 0,
+            generations => generations,
+        }
+    }
+
+    /// Identificação da tarefa, igual às primeiras colunas da linha de resultado
+    /// (com o nome da instância entre aspas se necessário, veja `csv_record::field`).
+    fn key(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            csv_record::field(&self.instance_name),
+            self.params.population_size,
+            self.generations(),
+            self.params.mutation_rate,
+            self.params.local_search_rate
+        )
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match &cli.worker {
+        Some(address) => cli
+            .token()
+            .and_then(|token| run_worker(address, token, cli.threads)),
+        None => run(&cli),
+    };
+    if let Err(e) = result {
+        eprintln!("Erro: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: &Cli) -> Result<()> {
+    // 1. Lê a especificação e carrega as instâncias.
+    let spec_text = fs::read_to_string(&cli.spec)?;
+    let spec: ExperimentSpec =
+        toml::from_str(&spec_text).map_err(|e| FsspError::Config(e.to_string()))?;
+    let output_path = cli.output.clone().unwrap_or_else(|| spec.output.clone());
+    let runs_path = cli
+        .runs_output
+        .clone()
+        .unwrap_or_else(|| spec.runs_output.clone());
+    let rpd_path = cli
+        .rpd_output
+        .clone()
+        .unwrap_or_else(|| spec.rpd_output.clone());
+    let mut best_known = BestKnown::builtin();
+    if let Some(path) = cli.best_known.as_ref().or(spec.best_known.as_ref()) {
+        best_known.extend(BestKnown::load(path)?);
+    }
+    let runs = cli.runs.map_or(spec.runs, |runs| runs as usize);
+    if runs == 0 {
+        return Err(FsspError::Config(
+            "runs deve ser ao menos 1 (execuções por combinação)".to_string(),
+        ));
+    }
+    let max_duration = cli.max_duration.or(spec.max_duration);
+    if max_duration.is_some_and(|d| !(d.is_finite() && d > 0.0)) {
+        return Err(FsspError::Config(
+            "max_duration deve ser um número positivo de segundos".to_string(),
+        ));
+    }
+    let max_evaluations = cli.max_evaluations.or(spec.max_evaluations);
+    // Sem limite de tempo nem de avaliações, toda combinação precisa de um limite
+    // de gerações.
+    if max_duration.is_none() && max_evaluations.is_none() && spec.grid.generations.contains(&0) {
+        return Err(FsspError::Config(
+            "generations = 0 (sem limite de gerações) exige max_duration ou max_evaluations"
+                .to_string(),
+        ));
+    }
+    let instances = load_instances(&spec.instances)?;
+
+    // 2. Gera todas as combinações de parâmetros para cada instância.
+    let mut tasks = Vec::new();
+    for (name, path, instance) in &instances {
+        for &population_size in &spec.grid.population_size {
+            for &generations in &spec.grid.generations {
+                for &mutation_rate in &spec.grid.mutation_rate {
+                    for &local_search_rate in &spec.grid.local_search_rate {
+                        let params = MemeticParams {
+                            population_size,
+                            generations: if generations == 0 {
+                                usize::MAX
+                            } else {
+                                generations
+                            },
+                            mutation_rate,
+                            local_search_rate,
+                        };
+                        // Combinações inválidas são recusadas antes de qualquer execução.
+                        params.validate()?;
+                        tasks.push(Task {
+                            instance_name: name.clone(),
+                            instance,
+                            path,
+                            params,
+                        });
+                    }
                 }
             }
         }
     }
 
-    // Process combinations em paralelo
-    let results: Vec<String> = all_combinations
-        .par_iter()
-        .map(|&(pop_size, gens, mut_rate, ls_rate)| {
-            let mut makespans = Vec::with_capacity(num_runs);
-            for _ in 0..num_runs {
-                let current_instance = instance.clone(); // Clona a instância para cada execução
-                let mut solver =
-                    MemeticAlgorithm::new(current_instance, pop_size, gens, mut_rate, ls_rate);
-                solver.run();
-                makespans.push(solver.best_makespan);
-            }
+    // 3. Ao retomar, descarta as combinações já presentes no arquivo de resultados.
+    let completed = if cli.resume {
+        completed_keys(&output_path)?
+    } else {
+        HashSet::new()
+    };
+    let total = tasks.len();
+    tasks.retain(|task| !completed.contains(&task.key()));
+    println!(
+        "{} combinações no experimento, {} pendentes.",
+        total,
+        tasks.len()
+    );
 
-            let sum: u32 = makespans.iter().sum();
-            let mean = sum as f64 / num_runs as f64;
+    let files = Mutex::new((
+        open_output(&output_path, HEADER, cli.resume)?,
+        open_output(&runs_path, RUNS_HEADER, cli.resume)?,
+        0usize, // Combinações concluídas nesta sessão.
+    ));
+    let pending = tasks.len();
+    let start_time = Instant::now();
 
-            let variance = makespans
-                .iter()
-                .map(|&m| {
-                    let diff = m as f64 - mean;
-                    diff * diff
-                })
-                .sum::<f64>()
-                / num_runs as f64;
-            let std_dev = variance.sqrt();
-
-            format!(
-                "{},{},{},{},{:.2},{:.2}",
-                pop_size, gens, mut_rate, ls_rate, mean, std_dev
-            )
+    let settings = RunSettings {
+        runs,
+        seed: spec.seed,
+        max_duration,
+        max_evaluations,
+    };
+
+    // 4. Grava e descarrega cada resultado assim que concluído, para que um
+    //    experimento interrompido possa ser retomado, e exibe o progresso.
+    let record = |key: &str, records: &[RunRecord]| -> Result<()> {
+        let makespans: Vec<Time> = records.iter().map(|r| r.makespan).collect();
+
+        let mean = makespans.iter().map(|&m| m as f64).sum::<f64>() / makespans.len() as f64;
+        let variance = makespans
+            .iter()
+            .map(|&m| {
+                let diff = m as f64 - mean;
+                diff * diff
+            })
+            .sum::<f64>()
+            / makespans.len() as f64;
+        let std_dev = variance.sqrt();
+
+        // As execuções são gravadas junto com o resumo, mantendo os dois arquivos
+        // consistentes caso o experimento seja interrompido.
+        let mut files = files.lock().unwrap();
+        let (summary, runs, done) = &mut *files;
+        for (run, record) in records.iter().enumerate() {
+            writeln!(
+                runs,
+                "{},{},{},{},{:.6},{},{}",
+                key,
+                run,
+                record.seed,
+                record.makespan,
+                record.runtime_seconds,
+                record.generations_completed,
+                record.evaluations
+            )?;
+        }
+        writeln!(summary, "{},{:.2},{:.2}", key, mean, std_dev)?;
+        runs.flush()?;
+        summary.flush()?;
+
+        // Progresso, com a estimativa do tempo restante pela média das combinações
+        // concluídas.
+        *done += 1;
+        let elapsed = start_time.elapsed().as_secs_f64();
+        let remaining = elapsed / *done as f64 * (pending - *done) as f64;
+        let mut stdout = std::io::stdout().lock();
+        writeln!(
+            stdout,
+            "[{}/{}] {}: média {:.2} (desvio {:.2}) | decorrido {:.1}s | restante ~{:.1}s",
+            *done, pending, key, mean, std_dev, elapsed, remaining
+        )?;
+        stdout.flush()?;
+        Ok(())
+    };
+
+    // Processa as combinações em paralelo nesta máquina ou, no modo distribuído,
+    // nos nós de trabalho conectados ao coordenador.
+    match &cli.serve {
+        Some(address) => serve(address, cli.token()?, &tasks, settings, &record)?,
+        None => tasks.par_iter().try_for_each(|task| -> Result<()> {
+            let records = run_task(task.instance, task.params, settings)?;
+            record(&task.key(), &records)
+        })?,
+    }
+    // Descarrega e fecha os arquivos antes de reler as execuções.
+    drop(files);
+
+    // 5. Consolida as execuções de todas as instâncias (inclusive as de sessões
+    //    anteriores, ao retomar) no ARPD de cada combinação.
+    write_rpd_summary(&runs_path, &rpd_path, &instances, &best_known)?;
+
+    println!(
+        "Resultados do experimento salvos em {} (execuções em {}, ARPD em {})",
+        output_path.display(),
+        runs_path.display(),
+        rpd_path.display()
+    );
+    Ok(())
+}
+
+/// Calcula o desvio percentual relativo (RPD) de cada execução registrada em
+/// `runs_path` em relação ao melhor makespan conhecido da instância e grava, em
+/// `rpd_path`, a média (ARPD) e o desvio padrão por combinação de parâmetros,
+/// considerando todas as instâncias, da melhor para a pior combinação. Instâncias
+/// sem valor na tabela usam como referência o melhor makespan obtido nelas em todo
+/// o experimento. Execuções de instâncias fora da especificação são ignoradas.
+fn write_rpd_summary(
+    runs_path: &Path,
+    rpd_path: &Path,
+    instances: &[(String, PathBuf, FSSPInstance)],
+    best_known: &BestKnown,
+) -> Result<()> {
+    // Makespans por instância e combinação (as quatro colunas de parâmetros).
+    let mut makespans: BTreeMap<String, Vec<(String, Time)>> = BTreeMap::new();
+    let mut observed: HashMap<String, Time> = HashMap::new();
+    for record in csv_record::records(BufReader::new(File::open(runs_path)?)).skip(1) {
+        let (_, fields) = record?;
+        if fields.len() != RUNS_HEADER.split(',').count() {
+            continue;
+        }
+        let (instance, params) = (&fields[0], fields[1..5].join(","));
+        let makespan: Time = fields[7].parse().map_err(|_| {
+            FsspError::Config(format!(
+                "makespan inválido '{}' em {}",
+                fields[7],
+                runs_path.display()
+            ))
+        })?;
+        let best = observed.entry(instance.to_string()).or_insert(makespan);
+        *best = (*best).min(makespan);
+        makespans
+            .entry(params)
+            .or_default()
+            .push((instance.to_string(), makespan));
+    }
+
+    // Referência de cada instância, na mesma escala dos makespans gravados.
+    let mut references = HashMap::new();
+    for (name, _, instance) in instances {
+        let reference = match best_known.get(name) {
+            Some(bks) => bks.saturating_mul(instance.time_scale()),
+            None => match observed.get(name) {
+                Some(&best) => {
+                    println!(
+                        "Aviso: {} sem melhor makespan conhecido; usando o melhor obtido no experimento ({})",
+                        name, best
+                    );
+                    best
+                }
+                None => continue,
+            },
+        };
+        references.insert(name.as_str(), reference.max(1));
+    }
+
+    let mut rows = Vec::new();
+    for (params, runs) in &makespans {
+        let rpds: Vec<f64> = runs
+            .iter()
+            .filter_map(|(name, makespan)| {
+                references
+                    .get(name.as_str())
+                    .map(|&reference| relative_percentage_deviation(*makespan, reference))
+            })
+            .collect();
+        if rpds.is_empty() {
+            continue;
+        }
+        let covered: HashSet<&str> = runs
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| references.contains_key(name))
+            .collect();
+        let mean = rpds.iter().sum::<f64>() / rpds.len() as f64;
+        let variance = rpds
+            .iter()
+            .map(|rpd| (rpd - mean) * (rpd - mean))
+            .sum::<f64>()
+            / rpds.len() as f64;
+        rows.push((params, covered.len(), rpds.len(), mean, variance.sqrt()));
+    }
+    rows.sort_by(|a, b| a.3.total_cmp(&b.3));
+
+    let mut file = File::create(rpd_path)?;
+    writeln!(file, "{}", RPD_HEADER)?;
+    for (params, covered, runs, arpd, std_dev) in &rows {
+        writeln!(
+            file,
+            "{},{},{},{:.4},{:.4}",
+            params, covered, runs, arpd, std_dev
+        )?;
+    }
+    file.flush()?;
+
+    if let Some((params, covered, _, arpd, std_dev)) = rows.first() {
+        println!(
+            "Melhor combinação (population_size,generations,mutation_rate,local_search_rate = {}): ARPD {:.4}% (desvio {:.4}) em {} instâncias",
+            params, arpd, std_dev, covered
+        );
+    }
+    Ok(())
+}
+
+/// Executa as `settings.runs` execuções de uma combinação de parâmetros; a execução
+/// `r` usa a semente `seed + r` (aleatória sem semente base).
+fn run_task(
+    instance: &FSSPInstance,
+    params: MemeticParams,
+    settings: RunSettings,
+) -> Result<Vec<RunRecord>> {
+    let max_duration = settings.max_duration.map(Duration::from_secs_f64);
+    (0..settings.runs)
+        .map(|run| {
+            let seed = settings
+                .seed
+                .map_or_else(rand::random, |seed| seed.wrapping_add(run as u64));
+            // Clona a instância para cada execução
+            let mut solver = MemeticAlgorithm::new(instance.clone(), params)?.with_seed(seed);
+            Ok(run_once(
+                &mut solver,
+                seed,
+                max_duration,
+                settings.max_evaluations,
+            ))
         })
-        .collect();
+        .collect()
+}
 
-    // 5. Salva os resultados em um arquivo `results.csv`
-    let output_path = Path::new("results.csv");
-    let mut file = File::create(&output_path)?;
+/// Executa o solver uma vez, com o tempo e as avaliações limitados pelo orçamento,
+/// e registra o resultado.
+fn run_once(
+    solver: &mut dyn Solver,
+    seed: u64,
+    max_duration: Option<Duration>,
+    max_evaluations: Option<u64>,
+) -> RunRecord {
+    let mut counter = GenerationCounter::default();
+    let start_time = Instant::now();
+    solver.run_with_observer(
+        start_time,
+        &Budget::with_duration(start_time, max_duration).with_max_evaluations(max_evaluations),
+        &mut counter,
+    );
+    RunRecord {
+        seed,
+        makespan: solver.best_makespan(),
+        runtime_seconds: start_time.elapsed().as_secs_f64(),
+        generations_completed: counter.generations,
+        evaluations: solver.evaluations(),
+    }
+}
 
-    writeln!(file, "population_size,generations,mutation_rate,local_search_rate,mean_makespan,std_dev_makespan")?;
-    for line in results {
-        writeln!(file, "{}", line)?;
+/// Coordenador do modo distribuído: escuta em `address` e entrega as combinações,
+/// uma de cada vez, a cada conexão de um nó de trabalho que se apresente com
+/// `token`, registrando os resultados com `record`. A combinação de uma conexão
+/// encerrada antes da resposta volta à fila. Retorna quando todas as combinações
+/// forem concluídas.
+fn serve(
+    address: &str,
+    token: &str,
+    tasks: &[Task],
+    settings: RunSettings,
+    record: &(dyn Fn(&str, &[RunRecord]) -> Result<()> + Sync),
+) -> Result<()> {
+    let listener = TcpListener::bind(address)?;
+    // Sem bloquear, para perceber o fim do experimento entre duas conexões.
+    listener.set_nonblocking(true)?;
+    println!(
+        "Coordenador escutando em {}; aguardando nós de trabalho.",
+        address
+    );
+    // Combinações ainda não entregues e número de combinações em andamento.
+    let queue = Mutex::new(((0..tasks.len()).rev().collect::<Vec<_>>(), 0usize));
+    let failure = Mutex::new(None);
+    thread::scope(|scope| -> Result<()> {
+        loop {
+            let finished = {
+                let (waiting, running) = &*queue.lock().unwrap();
+                waiting.is_empty() && *running == 0
+            };
+            if finished || failure.lock().unwrap().is_some() {
+                return Ok(());
+            }
+            match listener.accept() {
+                Ok((stream, peer)) => {
+                    println!("Nó de trabalho conectado: {}", peer);
+                    let (queue, failure) = (&queue, &failure);
+                    scope.spawn(move || {
+                        if let Err(e) = serve_worker(stream, token, tasks, settings, record, queue)
+                        {
+                            // Após uma falha, apenas as combinações em andamento são
+                            // concluídas.
+                            queue.lock().unwrap().0.clear();
+                            failure.lock().unwrap().get_or_insert(e);
+                        }
+                    });
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    })?;
+    failure.into_inner().unwrap().map_or(Ok(()), Err)
+}
+
+/// Atende um nó de trabalho até a fila esvaziar ou a conexão ser encerrada. Uma
+/// conexão que não se apresenta com a versão do protocolo e o segredo corretos é
+/// encerrada sem receber combinações.
+fn serve_worker(
+    stream: TcpStream,
+    token: &str,
+    tasks: &[Task],
+    settings: RunSettings,
+    record: &(dyn Fn(&str, &[RunRecord]) -> Result<()> + Sync),
+    queue: &Mutex<(Vec<usize>, usize)>,
+) -> Result<()> {
+    stream.set_nonblocking(false)?;
+    let peer = stream.peer_addr()?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    writer.set_read_timeout(Some(HELLO_TIMEOUT))?;
+    match accept_worker(&mut reader, &mut writer, token) {
+        Ok(HelloReply::Accepted) => {}
+        Ok(HelloReply::Rejected(reason)) => {
+            println!("Aviso: conexão de {} recusada ({})", peer, reason);
+            return Ok(());
+        }
+        Err(e) => {
+            println!(
+                "Aviso: conexão de {} encerrada na apresentação ({})",
+                peer, e
+            );
+            return Ok(());
+        }
+    }
+    // As execuções de uma combinação podem demorar: sem limite para a resposta.
+    writer.set_read_timeout(None)?;
+    loop {
+        let index = {
+            let (waiting, running) = &mut *queue.lock().unwrap();
+            let Some(index) = waiting.pop() else {
+                return Ok(());
+            };
+            *running += 1;
+            index
+        };
+        let task = &tasks[index];
+        let item = WorkItem {
+            file_name: task
+                .path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned(),
+            text: fs::read_to_string(task.path)?,
+            population_size: task.params.population_size,
+            generations: task.generations(),
+            mutation_rate: task.params.mutation_rate,
+            local_search_rate: task.params.local_search_rate,
+            settings,
+        };
+        let reply = exchange(&mut reader, &mut writer, &item);
+        let finished = || queue.lock().unwrap().1 -= 1;
+        match reply {
+            Ok(WorkReply::Records(records)) => {
+                let result = record(&task.key(), &records);
+                finished();
+                result?;
+            }
+            Ok(WorkReply::Error(message)) => {
+                finished();
+                return Err(FsspError::Config(format!(
+                    "{} (nó {}): {}",
+                    task.key(),
+                    peer,
+                    message
+                )));
+            }
+            Err(e) => {
+                println!(
+                    "Aviso: conexão com {} encerrada ({}); a combinação {} volta à fila",
+                    peer,
+                    e,
+                    task.key()
+                );
+                queue.lock().unwrap().0.push(index);
+                finished();
+                return Ok(());
+            }
+        }
     }
+}
+
+/// Lê a apresentação do nó de trabalho e responde: aceita a conexão se a versão do
+/// protocolo e o segredo conferem. Retorna a resposta enviada.
+fn accept_worker(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    token: &str,
+) -> io::Result<HelloReply> {
+    let hello: Hello = receive(reader)?;
+    let reply = if hello.version != PROTOCOL_VERSION {
+        HelloReply::Rejected(format!(
+            "versão do protocolo {} (o coordenador usa a {})",
+            hello.version, PROTOCOL_VERSION
+        ))
+    } else if !same_token(&hello.token, token) {
+        HelloReply::Rejected("segredo incorreto".to_string())
+    } else {
+        HelloReply::Accepted
+    };
+    send(writer, &reply)?;
+    Ok(reply)
+}
 
-    println!("Experiment results saved to results.csv");
+/// Compara os segredos em tempo que não depende da posição da primeira diferença.
+fn same_token(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+/// Envia uma combinação ao nó de trabalho e aguarda a resposta.
+fn exchange(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    item: &WorkItem,
+) -> io::Result<WorkReply> {
+    send(writer, item)?;
+    receive(reader)
+}
+
+/// Envia uma mensagem em uma linha (JSON).
+fn send(writer: &mut impl Write, message: &impl Serialize) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, message)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
 
+/// Lê a mensagem da próxima linha (JSON); erro se a conexão foi encerrada.
+fn receive<T: DeserializeOwned>(reader: &mut impl BufRead) -> io::Result<T> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(serde_json::from_str(&line)?)
+}
+
+/// Nó de trabalho do modo distribuído: abre `threads` conexões com o coordenador
+/// (uma por núcleo, por padrão), apresentando-se com `token`, e, em cada uma,
+/// executa as combinações recebidas até o coordenador encerrá-la.
+fn run_worker(address: &str, token: &str, threads: Option<u32>) -> Result<()> {
+    let threads = threads.map_or_else(
+        || thread::available_parallelism().map_or(1, |n| n.get()),
+        |threads| threads as usize,
+    );
+    println!(
+        "Nó de trabalho com {} conexões ao coordenador {}.",
+        threads, address
+    );
+    // Indica que o coordenador concluiu o experimento: as conexões que ainda não
+    // foram abertas deixam de ser tentadas.
+    let finished = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| scope.spawn(|| work(address, token, &finished)))
+            .collect();
+        handles
+            .into_iter()
+            .try_for_each(|handle| handle.join().expect("a conexão não entra em pânico"))
+    })?;
+    println!("Experimento concluído pelo coordenador.");
+    Ok(())
+}
+
+/// Executa as combinações recebidas por uma conexão com o coordenador. Enquanto
+/// o coordenador não aceita conexões (ex: ainda não iniciou), tenta novamente a
+/// cada segundo, por até `CONNECT_TIMEOUT` ou até outra conexão do nó terminar
+/// o experimento (`finished`).
+fn work(address: &str, token: &str, finished: &AtomicBool) -> Result<()> {
+    let deadline = Instant::now() + CONNECT_TIMEOUT;
+    let stream = loop {
+        match TcpStream::connect(address) {
+            Ok(stream) => break stream,
+            Err(_) if finished.load(Ordering::Relaxed) => return Ok(()),
+            Err(e) if Instant::now() >= deadline => return Err(e.into()),
+            Err(_) => thread::sleep(Duration::from_secs(1)),
+        }
+    };
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    send(
+        &mut writer,
+        &Hello {
+            version: PROTOCOL_VERSION,
+            token: token.to_string(),
+        },
+    )?;
+    if let HelloReply::Rejected(reason) = receive(&mut reader)? {
+        return Err(FsspError::Config(format!(
+            "o coordenador {} recusou a conexão: {}",
+            address, reason
+        )));
+    }
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 0 {
+        let item: WorkItem = serde_json::from_str(&line).map_err(io::Error::from)?;
+        line.clear();
+        let reply = match execute(&item) {
+            Ok(records) => WorkReply::Records(records),
+            Err(e) => WorkReply::Error(e.to_string()),
+        };
+        send(&mut writer, &reply)?;
+    }
+    finished.store(true, Ordering::Relaxed);
     Ok(())
 }
+
+/// Interpreta a instância recebida e executa a combinação localmente.
+fn execute(item: &WorkItem) -> Result<Vec<RunRecord>> {
+    let instance =
+        InstanceFormat::detect(Path::new(&item.file_name), &item.text).parse(&item.text)?;
+    let params = MemeticParams {
+        population_size: item.population_size,
+        generations: if item.generations == 0 {
+            usize::MAX
+        } else {
+            item.generations
+        },
+        mutation_rate: item.mutation_rate,
+        local_search_rate: item.local_search_rate,
+    };
+    params.validate()?;
+    run_task(&instance, params, item.settings)
+}
+
+/// Carrega as instâncias que correspondem aos padrões glob, na ordem dos padrões.
+fn load_instances(patterns: &[String]) -> Result<Vec<(String, PathBuf, FSSPInstance)>> {
+    let mut instances = Vec::new();
+    for pattern in patterns {
+        let paths = glob::glob(pattern).map_err(|e| {
+            FsspError::Config(format!(
+                "padrão de instâncias inválido '{}': {}",
+                pattern, e
+            ))
+        })?;
+        for path in paths.filter_map(|p| p.ok()) {
+            let instance = load_instance(path.to_str().unwrap())?;
+            instances.push((instance_name(&path), path, instance));
+        }
+    }
+    if instances.is_empty() {
+        return Err(FsspError::Config(
+            "nenhuma instância corresponde aos padrões informados".to_string(),
+        ));
+    }
+    Ok(instances)
+}
+
+/// Nome da instância a partir do caminho, ex: "fssp_instance_05".
+fn instance_name(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Abre um arquivo de saída: ao retomar, acrescenta ao arquivo existente; caso
+/// contrário, cria o arquivo e grava o cabeçalho.
+fn open_output(path: &Path, header: &str, resume: bool) -> Result<File> {
+    if resume && path.exists() {
+        return Ok(OpenOptions::new().append(true).open(path)?);
+    }
+    let mut file = File::create(path)?;
+    writeln!(file, "{}", header)?;
+    Ok(file)
+}
+
+/// Combinações já registradas em um arquivo de resultados existente.
+fn completed_keys(path: &Path) -> Result<HashSet<String>> {
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+    let mut keys = HashSet::new();
+    for record in csv_record::records(BufReader::new(File::open(path)?)).skip(1) {
+        let (_, fields) = record?;
+        // As duas últimas colunas são a média e o desvio padrão.
+        if let [key @ .., _, _] = fields.as_slice() {
+            let key: Vec<Cow<str>> = key.iter().map(|f| csv_record::field(f)).collect();
+            keys.insert(key.join(","));
+        }
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTANCE: &str = "4 3\n5 9 8\n9 3 10\n9 4 5\n4 8 8\n";
+
+    /// Conecta um nó de trabalho com `token` a um coordenador local e executa
+    /// `coordinator` com a conexão aceita; retorna o resultado do nó.
+    fn loopback(
+        token: &str,
+        coordinator: impl FnOnce(&mut BufReader<TcpStream>, &mut TcpStream),
+    ) -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let finished = AtomicBool::new(false);
+        thread::scope(|scope| {
+            let worker = scope.spawn(|| work(&address, token, &finished));
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut writer = stream;
+            coordinator(&mut reader, &mut writer);
+            // Encerra a conexão, concluindo o experimento para o nó.
+            drop((reader, writer));
+            worker.join().unwrap()
+        })
+    }
+
+    #[test]
+    fn worker_results_match_local_run() {
+        let settings = RunSettings {
+            runs: 2,
+            seed: Some(7),
+            max_duration: None,
+            max_evaluations: None,
+        };
+        let params = MemeticParams {
+            population_size: 10,
+            generations: 5,
+            mutation_rate: 0.1,
+            local_search_rate: 0.2,
+        };
+        let mut remote = Vec::new();
+        loopback("segredo", |reader, writer| {
+            let reply = accept_worker(reader, writer, "segredo").unwrap();
+            assert!(matches!(reply, HelloReply::Accepted));
+            let item = WorkItem {
+                file_name: "instancia.txt".to_string(),
+                text: INSTANCE.to_string(),
+                population_size: params.population_size,
+                generations: params.generations,
+                mutation_rate: params.mutation_rate,
+                local_search_rate: params.local_search_rate,
+                settings,
+            };
+            match exchange(reader, writer, &item).unwrap() {
+                WorkReply::Records(records) => remote = records,
+                WorkReply::Error(message) => panic!("{}", message),
+            }
+        })
+        .unwrap();
+
+        let instance = InstanceFormat::detect(Path::new("instancia.txt"), INSTANCE)
+            .parse(INSTANCE)
+            .unwrap();
+        let local = run_task(&instance, params, settings).unwrap();
+        let summary = |records: &[RunRecord]| -> Vec<_> {
+            records
+                .iter()
+                .map(|r| (r.seed, r.makespan, r.generations_completed, r.evaluations))
+                .collect()
+        };
+        assert_eq!(summary(&remote), summary(&local));
+    }
+
+    #[test]
+    fn worker_with_wrong_token_is_rejected() {
+        let result = loopback("outro", |reader, writer| {
+            let reply = accept_worker(reader, writer, "segredo").unwrap();
+            assert!(matches!(reply, HelloReply::Rejected(_)));
+        });
+        assert!(matches!(result, Err(FsspError::Config(_))));
+    }
+}
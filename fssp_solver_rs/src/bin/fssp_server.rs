@@ -0,0 +1,496 @@
+use clap::Parser;
+use fssp_solver_rs::config::SolverConfig;
+use fssp_solver_rs::error::Result;
+use fssp_solver_rs::fssp_core::{self, parse_instance, FSSPInstance, Time};
+use fssp_solver_rs::observer::{Control, GenerationStats, Observer, Solution};
+use fssp_solver_rs::web;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+use tracing::{info, warn, Level};
+
+/// Tamanho máximo aceito para o corpo de uma requisição.
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Serviço HTTP para resolver instâncias remotamente: envio de instâncias, execução
+/// de tarefas de otimização em segundo plano, acompanhamento do progresso e
+/// consulta dos resultados, em JSON.
+#[derive(Parser, Debug)]
+#[command(about, long_about = None)]
+struct Cli {
+    /// Endereço e porta em que o serviço escuta.
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    bind: String,
+
+    /// Tarefas de otimização executadas simultaneamente; as demais aguardam na fila
+    /// (padrão: número de núcleos).
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    workers: Option<u32>,
+
+    /// Instâncias mantidas em memória; ao atingir o limite, a mais antiga é descartada.
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    max_instances: u32,
+
+    /// Tarefas mantidas em memória; ao atingir o limite, a tarefa encerrada mais
+    /// antiga é descartada, e novas tarefas são recusadas se nenhuma estiver encerrada.
+    #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    max_jobs: u32,
+
+    /// Tempo máximo, em segundos, de espera pelos dados de uma requisição.
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u64).range(1..))]
+    read_timeout: u64,
+}
+
+/// Situação de uma tarefa de otimização.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum JobStatus {
+    Queued,  // Aguardando um executor livre.
+    Running, // Em execução.
+    Done,    // Concluída (inclusive quando cancelada, com a melhor solução até então).
+    Failed,  // Encerrada com erro (ver `error`).
+}
+
+/// Estado de uma tarefa, compartilhado entre a thread do solver e as requisições.
+#[derive(Debug, Serialize)]
+struct Job {
+    id: usize,                          // Identificador da tarefa.
+    instance: usize,                    // Identificador da instância resolvida.
+    status: JobStatus,                  // Situação atual.
+    generation: usize,                  // Última geração (ou iteração) concluída.
+    best_value: Option<Time>,           // Melhor valor encontrado até agora.
+    best_sequence: Vec<fssp_core::Job>, // Melhor sequência encontrada até agora.
+    elapsed_seconds: f64,               // Tempo decorrido na última atualização.
+    cancel_requested: bool,             // Cancelamento solicitado pelo cliente.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>, // Mensagem de erro (situação `failed`).
+    #[serde(skip)]
+    result: Option<Value>, // Resultado final em JSON (situação `done`, veja `web::SolveResult`).
+}
+
+/// Estado global do serviço.
+struct ServerState {
+    instances: BTreeMap<usize, Arc<FSSPInstance>>, // Instâncias enviadas, pelo identificador.
+    jobs: BTreeMap<usize, Arc<Mutex<Job>>>,        // Tarefas criadas, pelo identificador.
+    next_instance: usize,                          // Identificador da próxima instância.
+    next_job: usize,                               // Identificador da próxima tarefa.
+    max_instances: usize,                          // Máximo de instâncias mantidas.
+    max_jobs: usize,                               // Máximo de tarefas mantidas.
+    queue: mpsc::Sender<QueuedJob>,                // Fila de tarefas dos executores.
+}
+
+type SharedState = Arc<Mutex<ServerState>>;
+
+/// Corpo de `POST /jobs`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JobRequest {
+    instance: usize, // Identificador da instância.
+    #[serde(default)]
+    params: SolverConfig, // Parâmetros do solver (mesmos campos do arquivo TOML).
+}
+
+/// Tarefa aguardando um executor, com a instância e os parâmetros já validados.
+struct QueuedJob {
+    job: Arc<Mutex<Job>>,
+    instance: FSSPInstance,
+    params: SolverConfig,
+}
+
+/// Observador que publica o progresso no estado da tarefa e atende cancelamentos.
+struct JobObserver {
+    job: Arc<Mutex<Job>>,
+}
+
+impl Observer for JobObserver {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        let mut job = self.job.lock().unwrap();
+        job.generation = stats.generation;
+        job.elapsed_seconds = stats.elapsed.as_secs_f64();
+        if job.best_value.is_none_or(|best| stats.best_makespan < best) {
+            job.best_value = Some(stats.best_makespan);
+        }
+        if job.cancel_requested {
+            Control::Stop
+        } else {
+            Control::Continue
+        }
+    }
+
+    fn on_new_best(&mut self, solution: &Solution) {
+        let mut job = self.job.lock().unwrap();
+        if job.best_value.is_none_or(|best| solution.makespan <= best) {
+            job.best_value = Some(solution.makespan);
+            job.best_sequence = solution.sequence.clone();
+        }
+    }
+}
+
+/// Resposta HTTP: código de situação e corpo JSON.
+struct Response {
+    status: u16,
+    body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    tracing_subscriber::fmt()
+        .with_max_level(Level::INFO)
+        .with_writer(std::io::stderr)
+        .init();
+
+    let listener = match TcpListener::bind(&cli.bind) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Erro: não foi possível escutar em {}: {}", cli.bind, e);
+            std::process::exit(1);
+        }
+    };
+    let workers = cli.workers.map_or_else(
+        || thread::available_parallelism().map_or(1, |n| n.get()),
+        |n| n as usize,
+    );
+    info!(address = %cli.bind, workers, "Serviço iniciado");
+
+    // Executores das tarefas: cada um retira a próxima tarefa da fila compartilhada.
+    let (queue, receiver) = mpsc::channel::<QueuedJob>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..workers {
+        let receiver = Arc::clone(&receiver);
+        thread::spawn(move || loop {
+            let next = receiver.lock().unwrap().recv();
+            match next {
+                Ok(queued) => run_job(queued),
+                Err(_) => break,
+            }
+        });
+    }
+
+    let state: SharedState = Arc::new(Mutex::new(ServerState {
+        instances: BTreeMap::new(),
+        jobs: BTreeMap::new(),
+        next_instance: 0,
+        next_job: 0,
+        max_instances: cli.max_instances as usize,
+        max_jobs: cli.max_jobs as usize,
+        queue,
+    }));
+    let read_timeout = Duration::from_secs(cli.read_timeout);
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let state = Arc::clone(&state);
+                thread::spawn(move || {
+                    if let Err(e) = handle_connection(stream, &state, read_timeout) {
+                        warn!(error = %e, "Falha ao atender requisição");
+                    }
+                });
+            }
+            Err(e) => warn!(error = %e, "Falha ao aceitar conexão"),
+        }
+    }
+}
+
+/// Lê uma requisição HTTP/1.1, encaminha-a e escreve a resposta. Clientes que
+/// ficam `timeout` sem enviar ou receber dados são desconectados.
+fn handle_connection(stream: TcpStream, state: &SharedState, timeout: Duration) -> Result<()> {
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return write_response(stream, Response::error(400, "requisição malformada"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return write_response(
+            stream,
+            Response::error(413, "corpo da requisição muito grande"),
+        );
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8_lossy(&body);
+
+    let response = route(&method, &path, &body, state);
+    info!(%method, %path, status = response.status, "Requisição");
+    write_response(stream, response)
+}
+
+/// Escreve a resposta e encerra a conexão.
+fn write_response(mut stream: TcpStream, response: Response) -> Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        503 => "Service Unavailable",
+        _ => "Unprocessable Entity",
+    };
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Encaminha a requisição para o tratador do recurso.
+///
+/// - `POST /instances`: corpo no formato dos arquivos de instância.
+/// - `GET /instances/{id}`: dimensões da instância.
+/// - `POST /jobs`: `{"instance": id, "params": {...}}` coloca uma tarefa na fila.
+/// - `GET /jobs/{id}`: situação e progresso (geração, melhor valor e sequência).
+/// - `GET /jobs/{id}/result`: resultado final.
+/// - `DELETE /jobs/{id}`: cancela a tarefa, preservando a melhor solução.
+fn route(method: &str, path: &str, body: &str, state: &SharedState) -> Response {
+    let segments: Vec<&str> = path
+        .split('?')
+        .next()
+        .unwrap_or("")
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    let id = |s: &str| s.parse::<usize>().ok();
+
+    match (method, segments.as_slice()) {
+        ("POST", ["instances"]) => create_instance(body, state),
+        ("GET", ["instances", i]) => match id(i).and_then(|i| instance(state, i)) {
+            Some(instance) => Response::ok(json!({
+                "id": i.parse::<usize>().unwrap_or_default(),
+                "n_jobs": instance.n_jobs,
+                "n_machines": instance.n_machines,
+            })),
+            None => Response::error(404, "instância não encontrada"),
+        },
+        ("POST", ["jobs"]) => create_job(body, state),
+        ("GET", ["jobs", j]) => match id(j).and_then(|j| job(state, j)) {
+            Some(job) => Response::ok(json!(*job.lock().unwrap())),
+            None => Response::error(404, "tarefa não encontrada"),
+        },
+        ("GET", ["jobs", j, "result"]) => match id(j).and_then(|j| job(state, j)) {
+            Some(job) => job_result(&job.lock().unwrap()),
+            None => Response::error(404, "tarefa não encontrada"),
+        },
+        ("DELETE", ["jobs", j]) => match id(j).and_then(|j| job(state, j)) {
+            Some(job) => {
+                let mut job = job.lock().unwrap();
+                job.cancel_requested = true;
+                Response {
+                    status: 202,
+                    body: json!({ "id": job.id, "status": job.status }),
+                }
+            }
+            None => Response::error(404, "tarefa não encontrada"),
+        },
+        (_, ["instances", ..]) | (_, ["jobs", ..]) => Response::error(405, "método não permitido"),
+        _ => Response::error(404, "recurso não encontrado"),
+    }
+}
+
+fn instance(state: &SharedState, id: usize) -> Option<Arc<FSSPInstance>> {
+    state.lock().unwrap().instances.get(&id).cloned()
+}
+
+fn job(state: &SharedState, id: usize) -> Option<Arc<Mutex<Job>>> {
+    state.lock().unwrap().jobs.get(&id).cloned()
+}
+
+/// `POST /instances`: interpreta e registra a instância, descartando a mais antiga
+/// se o limite de instâncias foi atingido. Tarefas já criadas não são afetadas.
+fn create_instance(body: &str, state: &SharedState) -> Response {
+    match parse_instance(body) {
+        Ok(instance) => {
+            let (n_jobs, n_machines) = (instance.n_jobs, instance.n_machines);
+            let mut state = state.lock().unwrap();
+            if state.instances.len() >= state.max_instances {
+                state.instances.pop_first();
+            }
+            let id = state.next_instance;
+            state.next_instance += 1;
+            state.instances.insert(id, Arc::new(instance));
+            Response {
+                status: 201,
+                body: json!({
+                    "id": id,
+                    "n_jobs": n_jobs,
+                    "n_machines": n_machines,
+                }),
+            }
+        }
+        Err(e) => Response::error(400, e.to_string()),
+    }
+}
+
+/// `POST /jobs`: valida os parâmetros e coloca a tarefa na fila dos executores. Se o
+/// limite de tarefas foi atingido, descarta a tarefa encerrada mais antiga.
+fn create_job(body: &str, state: &SharedState) -> Response {
+    let request: JobRequest = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(e) => return Response::error(400, e.to_string()),
+    };
+    if request.params.portfolio.model.is_some() {
+        // O serviço não lê arquivos do servidor indicados pelo cliente.
+        return Response::error(400, "portfolio.model não é aceito pelo serviço");
+    }
+    let Some(source) = instance(state, request.instance) else {
+        return Response::error(404, "instância não encontrada");
+    };
+    let mut instance = FSSPInstance::clone(&source);
+    if let Err(e) = instance.set_objective(request.params.objective.unwrap_or_default()) {
+        return Response::error(400, e.to_string());
+    }
+
+    let mut state = state.lock().unwrap();
+    if state.jobs.len() >= state.max_jobs {
+        let finished = state.jobs.iter().find_map(|(&id, job)| {
+            matches!(
+                job.lock().unwrap().status,
+                JobStatus::Done | JobStatus::Failed
+            )
+            .then_some(id)
+        });
+        match finished {
+            Some(id) => {
+                state.jobs.remove(&id);
+            }
+            None => {
+                return Response::error(
+                    503,
+                    "limite de tarefas atingido: aguarde o término das tarefas na fila",
+                )
+            }
+        }
+    }
+    let id = state.next_job;
+    let job = Arc::new(Mutex::new(Job {
+        id,
+        instance: request.instance,
+        status: JobStatus::Queued,
+        generation: 0,
+        best_value: None,
+        best_sequence: Vec::new(),
+        elapsed_seconds: 0.0,
+        cancel_requested: false,
+        error: None,
+        result: None,
+    }));
+    let queued = QueuedJob {
+        job: Arc::clone(&job),
+        instance,
+        params: request.params,
+    };
+    if state.queue.send(queued).is_err() {
+        return Response::error(503, "nenhum executor disponível");
+    }
+    state.next_job += 1;
+    state.jobs.insert(id, job);
+    info!(job = id, instance = request.instance, "Tarefa na fila");
+
+    Response {
+        status: 202,
+        body: json!({ "id": id, "status": JobStatus::Queued }),
+    }
+}
+
+/// Executa uma tarefa da fila, publicando o progresso e o resultado no seu estado.
+/// Um pânico do solver encerra a tarefa com erro em vez de derrubar o executor.
+fn run_job(queued: QueuedJob) {
+    let QueuedJob {
+        job,
+        instance,
+        params,
+    } = queued;
+    {
+        let mut job = job.lock().unwrap();
+        if job.cancel_requested {
+            job.error = Some("tarefa cancelada antes de iniciar".to_string());
+            job.status = JobStatus::Failed;
+            return;
+        }
+        job.status = JobStatus::Running;
+        info!(job = job.id, "Tarefa iniciada");
+    }
+
+    let mut observer = JobObserver {
+        job: Arc::clone(&job),
+    };
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        web::solve(&instance, &params, &mut observer)
+    }));
+    let mut job = job.lock().unwrap_or_else(PoisonError::into_inner);
+    match outcome {
+        Ok(Ok(result)) => {
+            job.best_value = Some(result.value);
+            job.best_sequence = result.sequence.clone();
+            job.result = Some(result.to_json(&instance));
+            job.status = JobStatus::Done;
+        }
+        Ok(Err(e)) => {
+            job.error = Some(e.to_string());
+            job.status = JobStatus::Failed;
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|m| m.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            job.error = Some(format!("erro interno do solver: {}", message));
+            job.status = JobStatus::Failed;
+        }
+    }
+    info!(job = job.id, status = ?job.status, "Tarefa encerrada");
+}
+
+/// `GET /jobs/{id}/result`: resultado final, se disponível.
+fn job_result(job: &Job) -> Response {
+    match (job.status, &job.result) {
+        (JobStatus::Done, Some(result)) => Response::ok(result.clone()),
+        (JobStatus::Failed, _) => Response::error(422, job.error.clone().unwrap_or_default()),
+        _ => Response::error(409, "tarefa ainda na fila ou em execução"),
+    }
+}
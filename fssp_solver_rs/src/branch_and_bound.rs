@@ -0,0 +1,344 @@
+use crate::budget::Budget;
+use crate::fssp_core::{sublot_time, FSSPInstance, Job, MachineFront, Objective, Time};
+use crate::heuristics;
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use tracing::{debug, info, warn};
+
+/// Maior número de tarefas para o qual o branch-and-bound costuma terminar em tempo
+/// razoável; acima disso, a execução é permitida, mas um aviso é emitido.
+pub const RECOMMENDED_MAX_JOBS: usize = 15;
+/// Nós explorados entre verificações do limite de tempo e notificações ao observador.
+const CHECKPOINT_NODES: usize = 10_000;
+
+/// Estado de um nó da árvore: frente de máquinas do prefixo e custo acumulado das
+/// tarefas do prefixo (veja `ObjectiveFunction::combine`).
+#[derive(Clone, PartialEq, Eq)]
+struct Node {
+    front: MachineFront,
+    cost: Time,
+}
+
+/// Branch-and-bound exato por ramificação progressiva: cada nível fixa a próxima
+/// tarefa da sequência. Usa limitantes inferiores baseados em máquinas, a regra de
+/// dominância por troca das duas últimas tarefas e a solução NEH como limitante
+/// superior inicial. Indicado para instâncias pequenas (até ~15 tarefas).
+pub struct BranchAndBound {
+    instance: FSSPInstance,      // Instância do problema FSSP.
+    tails: Vec<Vec<Time>>,       // tails[j][i]: último sublote de j da máquina i em diante.
+    prefix: Vec<Job>,            // Sequência parcial do nó corrente.
+    scheduled: Vec<bool>,        // Tarefas já presentes no prefixo.
+    remaining_load: Vec<Time>,   // Carga das tarefas não escalonadas em cada máquina.
+    nodes: usize,                // Nós explorados.
+    checkpoints: usize,          // Notificações já enviadas ao observador.
+    deadline: Option<Instant>,   // Instante limite da execução.
+    start_time: Instant,         // Início da execução.
+    stopped: bool,               // A busca foi interrompida antes de terminar.
+    pub best_sequence: Vec<Job>, // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,     // Valor da função objetivo da melhor sequência.
+    pub proven_optimal: bool,    // A árvore foi explorada por completo.
+}
+
+impl BranchAndBound {
+    /// Cria uma nova instância do `BranchAndBound`.
+    pub fn new(instance: FSSPInstance) -> Self {
+        // Com sublotes, apenas o último sublote de uma tarefa precisa passar pelas
+        // máquinas seguintes depois que a tarefa termina em uma máquina.
+        let tails = instance
+            .processing_times
+            .iter()
+            .enumerate()
+            .map(|(job, times)| {
+                let lots = instance.sublots(job);
+                let mut tail: Vec<Time> = times
+                    .iter()
+                    .map(|&time| sublot_time(time, lots, lots - 1))
+                    .collect();
+                for i in (0..tail.len().saturating_sub(1)).rev() {
+                    tail[i] += tail[i + 1];
+                }
+                tail
+            })
+            .collect();
+        BranchAndBound {
+            tails,
+            prefix: Vec::with_capacity(instance.n_jobs),
+            scheduled: vec![false; instance.n_jobs],
+            remaining_load: vec![0; instance.n_machines],
+            nodes: 0,
+            checkpoints: 0,
+            deadline: None,
+            start_time: Instant::now(),
+            stopped: false,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+            proven_optimal: false,
+            instance,
+        }
+    }
+
+    /// Nó obtido ao acrescentar `job` ao final do prefixo representado por `node`.
+    fn _child(&self, node: &Node, job: usize) -> Node {
+        let front = node.front.with_job(&self.instance, job as Job);
+        let objective = self.instance.objective.function();
+        let cost = objective.combine(
+            node.cost,
+            objective.job_cost(&self.instance, job as Job, front.makespan()),
+        );
+        Node { front, cost }
+    }
+
+    /// Limitante inferior do nó, supondo as tarefas não escalonadas em `remaining_load`.
+    ///
+    /// Makespan: para cada máquina `i`, a frente do prefixo mais a carga restante em
+    /// `i` mais a menor cauda (máquinas após `i`) entre as tarefas restantes.
+    /// Demais objetivos: o custo do prefixo combinado, para cada tarefa restante, ao
+    /// custo que ela teria se fosse a próxima da sequência.
+    fn _lower_bound(&self, node: &Node) -> Time {
+        let completion_times = &node.front.completion_times;
+        let remaining = (0..self.instance.n_jobs).filter(|&job| !self.scheduled[job]);
+        let objective = self.instance.objective.function();
+        match self.instance.objective {
+            Objective::Makespan => {
+                let n_machines = self.instance.n_machines;
+                let mut min_tail = vec![Time::MAX; n_machines];
+                let mut any = false;
+                for job in remaining {
+                    any = true;
+                    for (i, min) in min_tail.iter_mut().enumerate() {
+                        let tail = if i + 1 < n_machines {
+                            self.tails[job][i + 1]
+                        } else {
+                            0
+                        };
+                        *min = (*min).min(tail);
+                    }
+                }
+                if !any {
+                    return node.front.makespan();
+                }
+                (0..n_machines)
+                    .map(|i| completion_times[i] + self.remaining_load[i] + min_tail[i])
+                    .max()
+                    .unwrap_or(0)
+            }
+            _ => remaining.fold(node.cost, |bound, job| {
+                let earliest = completion_times
+                    .iter()
+                    .zip(&self.tails[job])
+                    .map(|(&c, &tail)| c + tail)
+                    .max()
+                    .unwrap_or(0);
+                objective.combine(
+                    bound,
+                    objective.job_cost(&self.instance, job as Job, earliest),
+                )
+            }),
+        }
+    }
+
+    /// Regra de dominância: o filho que termina em `(a, b)` é descartado se trocar as
+    /// duas últimas tarefas produz uma frente e um custo não piores (com empate, é
+    /// mantida apenas a ordem com a menor tarefa primeiro).
+    fn _dominated(&self, grandparent: &Node, child: &Node, a: usize, b: usize) -> bool {
+        let swapped = self._child(&self._child(grandparent, b), a);
+        let no_worse = swapped.cost <= child.cost
+            && swapped
+                .front
+                .completion_times
+                .iter()
+                .zip(&child.front.completion_times)
+                .all(|(s, c)| s <= c);
+        if !no_worse {
+            return false;
+        }
+        swapped != *child || b < a
+    }
+
+    /// Explora a subárvore do nó corrente. `grandparent` é o nó anterior à última
+    /// tarefa do prefixo, usado na regra de dominância.
+    fn _branch(&mut self, node: &Node, grandparent: Option<&Node>, observer: &mut dyn Observer) {
+        self.nodes += 1;
+        if self.nodes.is_multiple_of(CHECKPOINT_NODES) && !self._checkpoint(observer) {
+            self.stopped = true;
+        }
+        if self.stopped {
+            return;
+        }
+
+        if self.prefix.len() == self.instance.n_jobs {
+            let value = node.cost;
+            if value < self.best_makespan {
+                self.best_makespan = value;
+                self.best_sequence = self.prefix.clone();
+                debug!(makespan = value, nodes = self.nodes, "Nova melhor solução");
+                observer.on_new_best(&Solution {
+                    sequence: self.best_sequence.clone(),
+                    makespan: value,
+                });
+            }
+            return;
+        }
+
+        // Gera os filhos, descarta os dominados e os ordena pelo limitante inferior.
+        let mut children = Vec::new();
+        for job in 0..self.instance.n_jobs {
+            if self.scheduled[job] {
+                continue;
+            }
+            let child = self._child(node, job);
+            if let (Some(grandparent), Some(&last)) = (grandparent, self.prefix.last()) {
+                if self._dominated(grandparent, &child, last as usize, job) {
+                    continue;
+                }
+            }
+            self._schedule(job);
+            let bound = self._lower_bound(&child);
+            self._unschedule(job);
+            if bound < self.best_makespan {
+                children.push((bound, job, child));
+            }
+        }
+        children.sort_by_key(|&(bound, job, _)| (bound, job));
+
+        for (bound, job, child) in children {
+            // O limitante superior pode ter melhorado em um irmão anterior.
+            if bound >= self.best_makespan || self.stopped {
+                break;
+            }
+            self._schedule(job);
+            self._branch(&child, Some(node), observer);
+            self._unschedule(job);
+        }
+    }
+
+    /// Acrescenta `job` ao prefixo.
+    fn _schedule(&mut self, job: usize) {
+        self.prefix.push(job as Job);
+        self.scheduled[job] = true;
+        for (load, &time) in self
+            .remaining_load
+            .iter_mut()
+            .zip(&self.instance.processing_times[job])
+        {
+            *load -= time;
+        }
+    }
+
+    /// Remove `job` (a última tarefa) do prefixo.
+    fn _unschedule(&mut self, job: usize) {
+        self.prefix.pop();
+        self.scheduled[job] = false;
+        for (load, &time) in self
+            .remaining_load
+            .iter_mut()
+            .zip(&self.instance.processing_times[job])
+        {
+            *load += time;
+        }
+    }
+
+    /// Verifica o limite de tempo e notifica o observador. Retorna `false` se a busca
+    /// deve ser interrompida.
+    fn _checkpoint(&mut self, observer: &mut dyn Observer) -> bool {
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            info!(
+                limit_seconds = (self.deadline.unwrap() - self.start_time).as_secs_f32(),
+                "Limite de tempo atingido. Encerrando..."
+            );
+            return false;
+        }
+        self.checkpoints += 1;
+        let stats = GenerationStats {
+            generation: self.checkpoints,
+            best_makespan: self.best_makespan,
+            generation_best: self.best_makespan,
+            mean_makespan: self.best_makespan as f64,
+            mutation_rate: 0.0,
+            diversity: None,
+            duplicate_rate: None,
+            operators: None,
+            evaluations: self.instance.evaluations.get(),
+            elapsed: self.start_time.elapsed(),
+        };
+        stats.trace();
+        observer.on_generation(&stats) == Control::Continue
+    }
+}
+
+impl Solver for BranchAndBound {
+    fn name(&self) -> &'static str {
+        "bb"
+    }
+
+    /// Explora a árvore até provar a otimalidade ou atingir o limite de tempo. O
+    /// observador é notificado a cada `CHECKPOINT_NODES` nós explorados.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        if self.instance.n_jobs > RECOMMENDED_MAX_JOBS {
+            warn!(
+                n_jobs = self.instance.n_jobs,
+                "Branch-and-bound em instância com mais de {} tarefas; a prova de otimalidade pode não terminar.",
+                RECOMMENDED_MAX_JOBS
+            );
+        }
+        self.start_time = start_time;
+        self.deadline = budget.deadline;
+        self.nodes = 0;
+        self.stopped = false;
+
+        // Limitante superior inicial: solução NEH.
+        self.best_sequence = heuristics::neh(&self.instance);
+        self.best_makespan = self.instance.evaluate(&self.best_sequence);
+
+        self.prefix.clear();
+        self.scheduled.fill(false);
+        for (i, load) in self.remaining_load.iter_mut().enumerate() {
+            *load = self
+                .instance
+                .processing_times
+                .iter()
+                .map(|times| times[i])
+                .sum();
+        }
+        let root = Node {
+            front: MachineFront::new(self.instance.n_machines),
+            cost: 0,
+        };
+        self._branch(&root, None, observer);
+
+        self.proven_optimal = !self.stopped;
+        if self.proven_optimal {
+            info!(
+                nodes = self.nodes,
+                value = self.best_makespan,
+                "Otimalidade provada."
+            );
+        } else {
+            warn!(
+                nodes = self.nodes,
+                "Busca interrompida: a melhor solução encontrada pode não ser ótima."
+            );
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -0,0 +1,141 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job};
+use crate::key_population::{KeyAlgorithm, KeyPopulation};
+use crate::random_key;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+/// Parâmetros do BRKGA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrkgaParams {
+    pub elite_fraction: f64,  // Fração da população na partição de elite.
+    pub mutant_fraction: f64, // Fração da população substituída por mutantes.
+    pub elite_bias: f64,      // Probabilidade de herdar cada chave do pai de elite.
+}
+
+impl Default for BrkgaParams {
+    fn default() -> Self {
+        BrkgaParams {
+            elite_fraction: 0.2,
+            mutant_fraction: 0.15,
+            elite_bias: 0.7,
+        }
+    }
+}
+
+/// Algoritmo genético de chaves aleatórias viciadas (BRKGA). A cada geração, a
+/// população (vetores de chaves decodificados pela regra LOV, veja `random_key`)
+/// é dividida em elite e não elite: a elite é copiada para a geração seguinte,
+/// uma parte é substituída por mutantes (chaves aleatórias) e o restante é gerado
+/// por cruzamento uniforme parametrizado entre um pai de elite e um pai não elite,
+/// herdando cada chave do pai de elite com probabilidade `elite_bias`. Os filhos
+/// são decodificados e avaliados em paralelo (com o recurso `parallel`). Como na
+/// evolução diferencial, a melhor solução é refinada por busca local por inserção
+/// sempre que muda.
+pub struct Brkga {
+    state: KeyPopulation, // População de chaves, do melhor para o pior indivíduo.
+    params: BrkgaParams,  // Tamanhos das partições e viés de elite.
+}
+
+impl Brkga {
+    /// Cria uma nova instância do `Brkga`.
+    pub fn new(instance: FSSPInstance, population_size: usize, generations: usize) -> Self {
+        Brkga {
+            state: KeyPopulation::new(instance, population_size.max(2), generations),
+            params: BrkgaParams::default(),
+        }
+    }
+
+    /// Define os tamanhos das partições e o viés de elite.
+    pub fn with_params(mut self, params: BrkgaParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Define soluções (já validadas) inseridas na população inicial antes da
+    /// solução NEH e das chaves aleatórias.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.state.initial_solutions = solutions;
+        self
+    }
+
+    /// Tamanhos das partições de elite e de mutantes: a elite tem ao menos um
+    /// indivíduo, e sobra ao menos um indivíduo não elite.
+    fn _partitions(&self) -> (usize, usize) {
+        let size = self.state.size;
+        let elite =
+            ((size as f64 * self.params.elite_fraction).round() as usize).clamp(1, size - 1);
+        let mutants =
+            ((size as f64 * self.params.mutant_fraction).round() as usize).min(size - elite);
+        (elite, mutants)
+    }
+
+    /// Ordena a população do melhor para o pior indivíduo.
+    fn _sort_population(&mut self) {
+        let state = &mut self.state;
+        let mut order: Vec<usize> = (0..state.keys.len()).collect();
+        order.sort_by_key(|&idx| state.fitness[idx]);
+        state.keys = order
+            .iter()
+            .map(|&idx| std::mem::take(&mut state.keys[idx]))
+            .collect();
+        state.fitness = order.iter().map(|&idx| state.fitness[idx]).collect();
+    }
+}
+
+impl KeyAlgorithm for Brkga {
+    const NAME: &'static str = "brkga";
+
+    fn state(&self) -> &KeyPopulation {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut KeyPopulation {
+        &mut self.state
+    }
+
+    /// População inicial ordenada do melhor para o pior indivíduo.
+    fn initialize(&mut self, rng: &mut ThreadRng) {
+        self.state.initialize(rng);
+        self._sort_population();
+    }
+
+    /// Gera a próxima geração: elite copiada, mutantes e filhos do cruzamento
+    /// uniforme parametrizado. Apenas os novos indivíduos são avaliados.
+    fn step(&mut self, rng: &mut ThreadRng, _budget: &Budget) {
+        let n = self.state.instance.n_jobs;
+        let population = &self.state.keys;
+        let size = population.len();
+        let (elite, mutants) = self._partitions();
+        let offspring: Vec<Vec<f64>> = (elite..size)
+            .map(|idx| {
+                if idx < elite + mutants {
+                    return random_key::random_keys(n, rng);
+                }
+                let elite_parent = &population[rng.gen_range(0..elite)];
+                let other_parent = &population[rng.gen_range(elite..size)];
+                elite_parent
+                    .iter()
+                    .zip(other_parent)
+                    .map(|(&e, &o)| {
+                        if rng.gen::<f64>() < self.params.elite_bias {
+                            e
+                        } else {
+                            o
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let values = self.state.evaluate(&offspring);
+        self.state.keys.truncate(elite);
+        self.state.fitness.truncate(elite);
+        self.state.keys.extend(offspring);
+        self.state.fitness.extend(values);
+        self._sort_population();
+    }
+
+    fn mutation_rate(&self) -> f64 {
+        self.params.mutant_fraction
+    }
+}
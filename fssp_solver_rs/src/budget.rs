@@ -0,0 +1,110 @@
+use crate::time::Instant;
+use std::time::Duration;
+use tracing::info;
+
+/// Orçamento computacional de uma execução, compartilhado por todos os componentes
+/// do solver. Além das verificações entre gerações, o instante limite e o máximo de
+/// avaliações são consultados dentro dos laços de cruzamento e de busca local, de
+/// modo que os limites são respeitados mesmo quando uma única geração é lenta. As
+/// avaliações são as registradas no contador da instância do solver
+/// (`FSSPInstance::evaluations`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Budget {
+    pub deadline: Option<Instant>,      // Instante limite da execução.
+    pub max_evaluations: Option<u64>,   // Máximo de avaliações da função objetivo.
+    pub max_generations: Option<usize>, // Máximo de gerações (ou iterações).
+}
+
+impl Budget {
+    /// Cria um orçamento ilimitado: a execução termina apenas pelo critério de
+    /// parada do próprio algoritmo.
+    pub fn new() -> Self {
+        Budget::default()
+    }
+
+    /// Cria um orçamento que termina `max_duration` após `start_time` (sem limite de
+    /// tempo se `None`).
+    pub fn with_duration(start_time: Instant, max_duration: Option<Duration>) -> Self {
+        Budget {
+            deadline: max_duration.map(|duration| start_time + duration),
+            ..Budget::default()
+        }
+    }
+
+    /// Define o número máximo de avaliações da função objetivo.
+    pub fn with_max_evaluations(mut self, max_evaluations: Option<u64>) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Define o número máximo de gerações (ou iterações, nos algoritmos de
+    /// trajetória), aplicado além do critério de parada do algoritmo.
+    pub fn with_max_generations(mut self, max_generations: Option<usize>) -> Self {
+        self.max_generations = max_generations;
+        self
+    }
+
+    /// Indica se o instante limite já foi atingido.
+    pub fn time_exceeded(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Indica se `evaluations` avaliações esgotam o orçamento.
+    pub fn evaluations_exceeded(&self, evaluations: u64) -> bool {
+        self.max_evaluations.is_some_and(|max| evaluations >= max)
+    }
+
+    /// Indica se o tempo ou as avaliações (`evaluations` já realizadas) se
+    /// esgotaram. É a verificação feita dentro de uma geração.
+    pub fn exhausted(&self, evaluations: u64) -> bool {
+        self.time_exceeded() || self.evaluations_exceeded(evaluations)
+    }
+
+    /// Orçamento restante após `evaluations` avaliações, para uma fase seguinte da
+    /// execução que conta as avaliações a partir de zero.
+    pub fn remaining(&self, evaluations: u64) -> Budget {
+        Budget {
+            max_evaluations: self
+                .max_evaluations
+                .map(|max| max.saturating_sub(evaluations)),
+            ..*self
+        }
+    }
+
+    /// Indica se `completed` gerações concluídas esgotam o orçamento.
+    pub fn generations_exceeded(&self, completed: usize) -> bool {
+        self.max_generations.is_some_and(|max| completed >= max)
+    }
+
+    /// Verificação feita no início de cada geração: retorna `true` se o orçamento de
+    /// gerações, de avaliações ou de tempo se esgotou, registrando no log o motivo
+    /// (exceto o fim das gerações).
+    pub(crate) fn should_stop(
+        &self,
+        start_time: Instant,
+        completed: usize,
+        evaluations: u64,
+    ) -> bool {
+        if self.generations_exceeded(completed) {
+            return true;
+        }
+        if self.evaluations_exceeded(evaluations) {
+            info!(
+                max_evaluations = self.max_evaluations,
+                "Limite de avaliações atingido. Encerrando..."
+            );
+            return true;
+        }
+        if self.time_exceeded() {
+            info!(
+                limit_seconds = self
+                    .deadline
+                    .map_or(0.0, |deadline| (deadline - start_time).as_secs_f32()),
+                "Limite de tempo atingido. Encerrando..."
+            );
+            return true;
+        }
+        false
+    }
+}
@@ -0,0 +1,469 @@
+//! Interface C para incorporar o solver em outras linguagens (C, C++, MATLAB,
+//! Python via ctypes). As declarações correspondentes estão em
+//! `include/fssp_solver.h`, gerado a partir deste módulo pelo cbindgen (veja
+//! `cbindgen.toml`); os comentários `///` dos itens exportados vão para o cabeçalho.
+//!
+//! Instâncias e resultados são manipulados por ponteiros opacos criados e liberados
+//! pelas funções deste módulo. Em caso de erro, as funções retornam `NULL` (ou um
+//! código negativo) e a mensagem pode ser consultada com `fssp_last_error`.
+
+use crate::budget::Budget;
+use crate::config::{BrkgaConfig, DeConfig, MemeticConfig, PsoConfig, SolverConfig};
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{EvaluationCounter, FSSPInstance, Job, Objective, Time};
+use crate::solver::Algorithm;
+use crate::time::Instant;
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CString};
+use std::ptr;
+use std::slice;
+use std::time::Duration;
+
+/// Algoritmo memético (padrão).
+pub const FSSP_ALGORITHM_MEMETIC: u32 = 0;
+/// GRASP com alfa reativo.
+pub const FSSP_ALGORITHM_GRASP: u32 = 1;
+/// Busca local iterada.
+pub const FSSP_ALGORITHM_ILS: u32 = 2;
+/// Branch-and-bound exato.
+pub const FSSP_ALGORITHM_BB: u32 = 3;
+/// Evolução diferencial sobre chaves aleatórias.
+pub const FSSP_ALGORITHM_DE: u32 = 4;
+/// Algoritmo genético de chaves aleatórias viciadas.
+pub const FSSP_ALGORITHM_BRKGA: u32 = 5;
+/// Otimização por enxame de partículas sobre chaves aleatórias.
+pub const FSSP_ALGORITHM_PSO: u32 = 6;
+/// Heurística construtiva CDS (Campbell, Dudek e Smith).
+pub const FSSP_ALGORITHM_CDS: u32 = 7;
+/// Heurística construtiva de Palmer.
+pub const FSSP_ALGORITHM_PALMER: u32 = 8;
+/// Heurística construtiva NEH (desempate pela primeira posição).
+pub const FSSP_ALGORITHM_NEH: u32 = 9;
+/// Iterated Greedy.
+pub const FSSP_ALGORITHM_IG: u32 = 10;
+/// Recozimento simulado.
+pub const FSSP_ALGORITHM_SA: u32 = 11;
+/// Busca tabu.
+pub const FSSP_ALGORITHM_TABU: u32 = 12;
+/// Colônia de formigas MAX-MIN.
+pub const FSSP_ALGORITHM_ACO: u32 = 13;
+/// Hiper-heurística sobre os demais algoritmos.
+pub const FSSP_ALGORITHM_HH: u32 = 14;
+/// Portfólio com o modelo embutido (escolha pelas características da instância).
+pub const FSSP_ALGORITHM_AUTO: u32 = 15;
+
+/// Minimiza o makespan (padrão).
+pub const FSSP_OBJECTIVE_MAKESPAN: u32 = 0;
+/// Minimiza o atraso ponderado total (exige datas de entrega).
+pub const FSSP_OBJECTIVE_TWT: u32 = 1;
+/// Minimiza o tempo de fluxo total.
+pub const FSSP_OBJECTIVE_FLOWTIME: u32 = 2;
+/// Minimiza o atraso total (exige datas de entrega).
+pub const FSSP_OBJECTIVE_TT: u32 = 3;
+/// Minimiza o atraso máximo (exige datas de entrega).
+pub const FSSP_OBJECTIVE_TMAX: u32 = 4;
+/// Minimiza o tempo de fluxo ponderado total (pesos de `fssp_instance_set_weights`).
+pub const FSSP_OBJECTIVE_TWFT: u32 = 5;
+
+/// Parâmetros de `fssp_solve`. Use `fssp_params_default` para obter os valores
+/// padrão da linha de comando e altere apenas os campos desejados.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FsspParams {
+    /// `FSSP_ALGORITHM_*`.
+    pub algorithm: u32,
+    /// `FSSP_OBJECTIVE_*`.
+    pub objective: u32,
+    /// Gerações (ou iterações) máximas.
+    pub max_generations: usize,
+    /// Tamanho da população (memético, DE, BRKGA e PSO).
+    pub population_size: usize,
+    /// Taxa de mutação.
+    pub mutation_rate: f64,
+    /// Taxa de busca local.
+    pub local_search_rate: f64,
+    /// Número de ilhas (menor que 2 desativa o modelo).
+    pub islands: usize,
+    /// Tempo máximo em segundos (0 ou negativo: sem limite; NaN, infinito ou acima
+    /// do maior tempo representável: erro).
+    pub max_duration: f64,
+}
+
+/// Resultado de `fssp_solve`.
+pub struct FsspResult {
+    value: Time,        // Valor da função objetivo.
+    makespan: Time,     // Makespan da melhor sequência.
+    sequence: Vec<Job>, // Melhor sequência (base 0).
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Registra a mensagem de erro da última chamada na thread corrente.
+fn _set_error(error: &FsspError) {
+    let message = CString::new(error.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Converte o resultado de uma operação em ponteiro para o C, registrando o erro.
+fn _into_ptr<T>(result: Result<T>) -> *mut T {
+    match result {
+        Ok(value) => Box::into_raw(Box::new(value)),
+        Err(e) => {
+            _set_error(&e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Converte o resultado de uma operação em código de retorno (0 ou -1).
+fn _into_code(result: Result<()>) -> c_int {
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            _set_error(&e);
+            -1
+        }
+    }
+}
+
+/// Converte valores de 32 bits recebidos da interface C no tipo interno de tempos.
+fn _widen(values: &[u32]) -> Vec<Time> {
+    values.iter().map(|&v| Time::from(v)).collect()
+}
+
+/// Erro para ponteiros nulos recebidos do C.
+fn _null_error(name: &str) -> FsspError {
+    FsspError::Config(format!("ponteiro nulo em '{}'", name))
+}
+
+/// Mensagem do último erro ocorrido na thread corrente, ou `NULL` se não houve
+/// erro. O texto pertence à biblioteca e é válido até a próxima chamada que falhe.
+#[no_mangle]
+pub extern "C" fn fssp_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// Cria uma instância a partir da matriz de tempos de processamento, armazenada por
+/// linhas: `processing_times[j * n_machines + i]` é o tempo da tarefa `j` na
+/// máquina `i`. Os tempos são lidos em 32 bits e acumulados em 64 bits, de modo
+/// que os resultados não estouram. Retorna `NULL` em caso de erro, inclusive com
+/// dimensões nulas ou cujo produto não cabe em `size_t`.
+///
+/// # Safety
+///
+/// `processing_times` deve apontar para `n_jobs * n_machines` valores válidos.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_instance_new(
+    n_jobs: usize,
+    n_machines: usize,
+    processing_times: *const u32,
+) -> *mut FSSPInstance {
+    _into_ptr((|| {
+        if processing_times.is_null() {
+            return Err(_null_error("processing_times"));
+        }
+        if n_jobs == 0 || n_machines == 0 {
+            return Err(FsspError::Config(
+                "a instância deve ter ao menos uma tarefa e uma máquina".to_string(),
+            ));
+        }
+        let len = n_jobs.checked_mul(n_machines).ok_or_else(|| {
+            FsspError::Config(format!(
+                "dimensões da instância muito grandes: {} tarefas e {} máquinas",
+                n_jobs, n_machines
+            ))
+        })?;
+        let times = slice::from_raw_parts(processing_times, len);
+        Ok(FSSPInstance {
+            n_jobs,
+            n_machines,
+            processing_times: times.chunks(n_machines).map(_widen).collect(),
+            release_dates: None,
+            due_dates: None,
+            weights: None,
+            unavailability: vec![Vec::new(); n_machines],
+            objective: Objective::Makespan,
+            evaluations: EvaluationCounter::default(),
+            time_decimals: 0,
+            uncertainty: None,
+            sublots: None,
+            transfer_times: None,
+        })
+    })())
+}
+
+/// Define o instante de liberação de cada tarefa (`n_jobs` valores). Retorna 0 em
+/// caso de sucesso e -1 em caso de erro.
+///
+/// # Safety
+///
+/// `instance` deve ter sido criada por `fssp_instance_new` e `release_dates` deve
+/// apontar para `n_jobs` valores válidos.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_instance_set_release_dates(
+    instance: *mut FSSPInstance,
+    release_dates: *const u32,
+) -> c_int {
+    _into_code((|| {
+        let instance = instance.as_mut().ok_or_else(|| _null_error("instance"))?;
+        if release_dates.is_null() {
+            return Err(_null_error("release_dates"));
+        }
+        instance.release_dates = Some(_widen(slice::from_raw_parts(
+            release_dates,
+            instance.n_jobs,
+        )));
+        Ok(())
+    })())
+}
+
+/// Define a data de entrega e, opcionalmente, o peso de cada tarefa (`n_jobs`
+/// valores cada), necessários aos objetivos de atraso (`FSSP_OBJECTIVE_TWT`,
+/// `FSSP_OBJECTIVE_TT` e `FSSP_OBJECTIVE_TMAX`). `weights` pode ser `NULL` (mantém os
+/// pesos atuais, 1 se não definidos). Retorna 0 em caso de sucesso e -1 em caso de erro.
+///
+/// # Safety
+///
+/// `instance` deve ter sido criada por `fssp_instance_new`; `due_dates` e, se não
+/// nulo, `weights` devem apontar para `n_jobs` valores válidos.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_instance_set_due_dates(
+    instance: *mut FSSPInstance,
+    due_dates: *const u32,
+    weights: *const u32,
+) -> c_int {
+    _into_code((|| {
+        let instance = instance.as_mut().ok_or_else(|| _null_error("instance"))?;
+        if due_dates.is_null() {
+            return Err(_null_error("due_dates"));
+        }
+        let n_jobs = instance.n_jobs;
+        instance.due_dates = Some(_widen(slice::from_raw_parts(due_dates, n_jobs)));
+        if !weights.is_null() {
+            instance.weights = Some(_widen(slice::from_raw_parts(weights, n_jobs)));
+        }
+        Ok(())
+    })())
+}
+
+/// Define o peso de cada tarefa (`n_jobs` valores) nos objetivos ponderados
+/// (`FSSP_OBJECTIVE_TWT` e `FSSP_OBJECTIVE_TWFT`). Retorna 0 em caso de sucesso e -1
+/// em caso de erro.
+///
+/// # Safety
+///
+/// `instance` deve ter sido criada por `fssp_instance_new` e `weights` deve apontar
+/// para `n_jobs` valores válidos.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_instance_set_weights(
+    instance: *mut FSSPInstance,
+    weights: *const u32,
+) -> c_int {
+    _into_code((|| {
+        let instance = instance.as_mut().ok_or_else(|| _null_error("instance"))?;
+        if weights.is_null() {
+            return Err(_null_error("weights"));
+        }
+        instance.weights = Some(_widen(slice::from_raw_parts(weights, instance.n_jobs)));
+        Ok(())
+    })())
+}
+
+/// Libera uma instância criada por `fssp_instance_new`. Aceita `NULL`.
+///
+/// # Safety
+///
+/// `instance` deve ser `NULL` ou um ponteiro ainda não liberado retornado por
+/// `fssp_instance_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_instance_free(instance: *mut FSSPInstance) {
+    if !instance.is_null() {
+        drop(Box::from_raw(instance));
+    }
+}
+
+/// Parâmetros padrão (os mesmos da linha de comando).
+#[no_mangle]
+pub extern "C" fn fssp_params_default() -> FsspParams {
+    FsspParams {
+        algorithm: FSSP_ALGORITHM_MEMETIC,
+        objective: FSSP_OBJECTIVE_MAKESPAN,
+        max_generations: 100,
+        population_size: 100,
+        mutation_rate: 0.3,
+        local_search_rate: 0.6,
+        islands: 1,
+        max_duration: 0.0,
+    }
+}
+
+/// Converte os parâmetros do C na configuração equivalente do solver.
+fn _config(params: &FsspParams) -> Result<SolverConfig> {
+    let algorithm = match params.algorithm {
+        FSSP_ALGORITHM_MEMETIC => Algorithm::Memetic,
+        FSSP_ALGORITHM_GRASP => Algorithm::Grasp,
+        FSSP_ALGORITHM_ILS => Algorithm::Ils,
+        FSSP_ALGORITHM_BB => Algorithm::BranchAndBound,
+        FSSP_ALGORITHM_DE => Algorithm::Differential,
+        FSSP_ALGORITHM_BRKGA => Algorithm::Brkga,
+        FSSP_ALGORITHM_PSO => Algorithm::Pso,
+        FSSP_ALGORITHM_CDS => Algorithm::Cds,
+        FSSP_ALGORITHM_PALMER => Algorithm::Palmer,
+        FSSP_ALGORITHM_NEH => Algorithm::Neh,
+        FSSP_ALGORITHM_IG => Algorithm::Ig,
+        FSSP_ALGORITHM_SA => Algorithm::Sa,
+        FSSP_ALGORITHM_TABU => Algorithm::Tabu,
+        FSSP_ALGORITHM_ACO => Algorithm::Aco,
+        FSSP_ALGORITHM_HH => Algorithm::Hh,
+        FSSP_ALGORITHM_AUTO => Algorithm::Auto,
+        other => {
+            return Err(FsspError::Config(format!(
+                "algoritmo desconhecido: {}",
+                other
+            )))
+        }
+    };
+    let objective = match params.objective {
+        FSSP_OBJECTIVE_MAKESPAN => Objective::Makespan,
+        FSSP_OBJECTIVE_TWT => Objective::WeightedTardiness,
+        FSSP_OBJECTIVE_FLOWTIME => Objective::TotalFlowtime,
+        FSSP_OBJECTIVE_TT => Objective::TotalTardiness,
+        FSSP_OBJECTIVE_TMAX => Objective::MaxTardiness,
+        FSSP_OBJECTIVE_TWFT => Objective::WeightedFlowtime,
+        other => {
+            return Err(FsspError::Config(format!(
+                "objetivo desconhecido: {}",
+                other
+            )))
+        }
+    };
+    Ok(SolverConfig {
+        objective: Some(objective),
+        algorithm: Some(algorithm),
+        max_generations: Some(params.max_generations),
+        ma: MemeticConfig {
+            population_size: Some(params.population_size),
+            mutation_rate: Some(params.mutation_rate),
+            local_search_rate: Some(params.local_search_rate),
+            islands: Some(params.islands),
+            ..MemeticConfig::default()
+        },
+        de: DeConfig {
+            population_size: Some(params.population_size),
+            ..DeConfig::default()
+        },
+        brkga: BrkgaConfig {
+            population_size: Some(params.population_size),
+            ..BrkgaConfig::default()
+        },
+        pso: PsoConfig {
+            population_size: Some(params.population_size),
+            ..PsoConfig::default()
+        },
+        ..SolverConfig::default()
+    })
+}
+
+/// Resolve a instância com os parâmetros informados (`NULL` usa os padrões). Retorna
+/// o resultado, que deve ser liberado com `fssp_result_free`, ou `NULL` em caso de
+/// erro.
+///
+/// # Safety
+///
+/// `instance` deve ter sido criada por `fssp_instance_new`; `params` deve ser `NULL`
+/// ou apontar para um `FsspParams` válido.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_solve(
+    instance: *const FSSPInstance,
+    params: *const FsspParams,
+) -> *mut FsspResult {
+    _into_ptr((|| {
+        let instance = instance.as_ref().ok_or_else(|| _null_error("instance"))?;
+        let params = params
+            .as_ref()
+            .copied()
+            .unwrap_or_else(|| fssp_params_default());
+        let config = _config(&params)?;
+
+        let mut instance = instance.clone();
+        instance.set_objective(config.objective.unwrap_or_default())?;
+        let max_duration = match params.max_duration {
+            seconds if seconds <= 0.0 => None,
+            seconds => Some(Duration::try_from_secs_f64(seconds).map_err(|_| {
+                FsspError::Config(format!(
+                    "max_duration deve ser um número finito de segundos, não {}",
+                    seconds
+                ))
+            })?),
+        };
+
+        let mut solver = config.build_solver(&instance)?;
+        let start_time = Instant::now();
+        solver.run(start_time, &Budget::with_duration(start_time, max_duration));
+        let sequence = solver.best_sequence().to_vec();
+        Ok(FsspResult {
+            value: solver.best_makespan(),
+            makespan: instance.calculate_makespan(&sequence),
+            sequence,
+        })
+    })())
+}
+
+/// Valor da melhor solução na função objetivo escolhida no campo `objective` de
+/// `FsspParams` (`FSSP_OBJECTIVE_*`).
+///
+/// # Safety
+///
+/// `result` deve ter sido retornado por `fssp_solve` e ainda não liberado.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_result_value(result: *const FsspResult) -> Time {
+    result.as_ref().map_or(0, |r| r.value)
+}
+
+/// Makespan da melhor solução.
+///
+/// # Safety
+///
+/// `result` deve ter sido retornado por `fssp_solve` e ainda não liberado.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_result_makespan(result: *const FsspResult) -> Time {
+    result.as_ref().map_or(0, |r| r.makespan)
+}
+
+/// Copia até `capacity` tarefas da melhor sequência (base 0) para `out` e retorna o
+/// número de tarefas da sequência. Com `out` nulo, apenas retorna o tamanho.
+///
+/// # Safety
+///
+/// `result` deve ter sido retornado por `fssp_solve` e ainda não liberado; `out`
+/// deve ser `NULL` ou apontar para `capacity` posições graváveis.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_result_sequence(
+    result: *const FsspResult,
+    out: *mut u32,
+    capacity: usize,
+) -> usize {
+    let Some(result) = result.as_ref() else {
+        return 0;
+    };
+    if !out.is_null() {
+        let len = capacity.min(result.sequence.len());
+        slice::from_raw_parts_mut(out, len).copy_from_slice(&result.sequence[..len]);
+    }
+    result.sequence.len()
+}
+
+/// Libera um resultado retornado por `fssp_solve`. Aceita `NULL`.
+///
+/// # Safety
+///
+/// `result` deve ser `NULL` ou um ponteiro ainda não liberado retornado por
+/// `fssp_solve`.
+#[no_mangle]
+pub unsafe extern "C" fn fssp_result_free(result: *mut FsspResult) {
+    if !result.is_null() {
+        drop(Box::from_raw(result));
+    }
+}
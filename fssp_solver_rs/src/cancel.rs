@@ -0,0 +1,61 @@
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Sinal de cancelamento cooperativo, compartilhável entre threads (ex: acionado por
+/// um tratador de Ctrl-C). Os solvers o consultam ao final de cada geração e
+/// encerram a execução preservando a melhor solução encontrada.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>, // Cancelamento solicitado.
+}
+
+impl CancellationToken {
+    /// Cria um token ainda não cancelado.
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    /// Solicita o cancelamento de todas as execuções que compartilham o token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Indica se o cancelamento foi solicitado.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Observador que repassa os eventos a `inner` e encerra a execução assim que o
+/// token é cancelado.
+pub struct CancellableObserver<'a> {
+    inner: &'a mut dyn Observer, // Observador decorado.
+    token: CancellationToken,    // Sinal de cancelamento consultado a cada geração.
+}
+
+impl<'a> CancellableObserver<'a> {
+    /// Decora `inner` com a verificação de `token`.
+    pub fn new(inner: &'a mut dyn Observer, token: CancellationToken) -> Self {
+        CancellableObserver { inner, token }
+    }
+}
+
+impl Observer for CancellableObserver<'_> {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        let control = self.inner.on_generation(stats);
+        if self.token.is_cancelled() {
+            Control::Stop
+        } else {
+            control
+        }
+    }
+
+    fn on_new_best(&mut self, solution: &Solution) {
+        self.inner.on_new_best(solution);
+    }
+
+    fn incoming(&mut self) -> Option<Solution> {
+        self.inner.incoming()
+    }
+}
@@ -0,0 +1,450 @@
+use crate::aos::AosStrategy;
+use crate::crossover::Crossover;
+use crate::energy::SpeedProfile;
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{FSSPInstance, Job, Objective};
+use crate::heuristics::NehTieBreaking;
+use crate::ils::{Acceptance, IlsParams};
+use crate::island::Topology;
+use crate::local_search::{ImprovementStrategy, LocalSearch};
+use crate::mutation::MutationMix;
+use crate::non_permutation::ScheduleType;
+use crate::registry;
+use crate::robust::RobustCriterion;
+use crate::selection::Selection;
+use crate::solver::{Algorithm, LocalSearchPolicy, PhaseSchedule, Replacement, Solver};
+use crate::stochastic::NoiseModel;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Parâmetros do solver lidos de um arquivo TOML. Todos os campos são opcionais:
+/// campos ausentes mantêm o valor padrão ou o valor informado na linha de comando.
+/// Os nomes dos campos são os mesmos das opções da linha de comando, com `_` no
+/// lugar de `-`. Os parâmetros de cada algoritmo ficam na seção do seu prefixo na
+/// linha de comando (ex: `--ma.population-size 50` equivale a `population_size = 50`
+/// na seção `[ma]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SolverConfig {
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub objective: Option<Objective>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub schedule_type: Option<ScheduleType>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub speeds: Option<SpeedProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub energy_weight: Option<f64>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub robust: Option<RobustCriterion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scenario_samples: Option<u64>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub stochastic: Option<NoiseModel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replications: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_replications: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resample_z: Option<f64>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<Algorithm>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_generations: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_evaluations: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_dir: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub best_known: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_sharing: Option<bool>,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub ma: MemeticConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub ils: IlsConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub ig: IgConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub sa: SaConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub tabu: TabuConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub aco: AcoConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub de: DeConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub brkga: BrkgaConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub pso: PsoConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub neh: NehConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub hh: HhConfig,
+    #[serde(default, skip_serializing_if = "_is_unset")]
+    pub portfolio: PortfolioConfig,
+}
+
+impl SolverConfig {
+    /// Carrega a configuração de um arquivo TOML.
+    #[cfg(feature = "fs")]
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        SolverConfig::from_toml(&text)
+    }
+
+    /// Interpreta a configuração a partir de um texto TOML.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        toml::from_str(text).map_err(|e| FsspError::Config(e.to_string()))
+    }
+
+    /// Serializa a configuração em TOML.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).map_err(|e| FsspError::Config(e.to_string()))
+    }
+
+    /// Constrói o solver do algoritmo configurado para `instance`, usando os padrões
+    /// da linha de comando nos campos ausentes. A função objetivo já deve estar
+    /// definida na instância. Parâmetros do algoritmo memético fora dos intervalos
+    /// válidos produzem um erro.
+    pub fn build_solver(&self, instance: &FSSPInstance) -> Result<Box<dyn Solver>> {
+        self.build_solver_with(instance, Vec::new())
+    }
+
+    /// Como `build_solver`, partindo das soluções iniciais fornecidas (já validadas
+    /// contra a instância). O algoritmo é obtido do registro (`registry::entry`).
+    pub fn build_solver_with(
+        &self,
+        instance: &FSSPInstance,
+        initial_solutions: Vec<Vec<Job>>,
+    ) -> Result<Box<dyn Solver>> {
+        registry::entry(self.algorithm.unwrap_or(Algorithm::Memetic)).build(
+            instance,
+            self,
+            initial_solutions,
+        )
+    }
+}
+
+/// Parâmetros do algoritmo memético e do modelo de ilhas (seção `[ma]` e opções
+/// `--ma.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MemeticConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub population_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutation_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_search_rate: Option<f64>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub selection: Option<Selection>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub crossover: Option<Crossover>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub mutation: Option<MutationMix>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub local_search: Option<LocalSearch>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<Replacement>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elite_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fitness_cache: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator_stats: Option<bool>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub aos: Option<AosStrategy>,
+    #[serde(
+        default,
+        with = "string_list_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aos_crossovers: Option<Vec<Crossover>>,
+    #[serde(
+        default,
+        with = "string_list_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aos_local_searches: Option<Vec<LocalSearch>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ls_max_moves: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ls_max_millis: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ls_first_improvement: Option<bool>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub ls_strategy: Option<ImprovementStrategy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ls_random_order: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ls_dont_look_bits: Option<bool>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub ls_policy: Option<LocalSearchPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_after: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_elite: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_relinking_every: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_relinking_elites: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub two_phase: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exploration_mutation_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exploration_local_search_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intensification_elite: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vns_iterations: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub islands: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migration_interval: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migration_rate: Option<f64>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub topology: Option<Topology>,
+}
+
+impl MemeticConfig {
+    /// Cronograma em duas fases, se `two_phase` estiver definido.
+    pub fn phase_schedule(&self) -> Option<PhaseSchedule> {
+        let defaults = PhaseSchedule::default();
+        self.two_phase.map(|switch_at| PhaseSchedule {
+            switch_at,
+            exploration_mutation_rate: self
+                .exploration_mutation_rate
+                .unwrap_or(defaults.exploration_mutation_rate),
+            exploration_local_search_rate: self
+                .exploration_local_search_rate
+                .unwrap_or(defaults.exploration_local_search_rate),
+            elite_fraction: self
+                .intensification_elite
+                .unwrap_or(defaults.elite_fraction),
+        })
+    }
+}
+
+/// Parâmetros da busca local iterada (seção `[ils]` e opções `--ils.*`), usados pelo
+/// algoritmo `ils` e pela busca local `ils` do algoritmo memético. As iterações só
+/// se aplicam à busca local do memético: o algoritmo `ils` usa `max_generations`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IlsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iterations: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strength: Option<usize>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub acceptance: Option<Acceptance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_after: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_perturbation: Option<bool>,
+}
+
+impl IlsConfig {
+    /// Parâmetros da ILS, com os padrões da linha de comando nos campos ausentes.
+    pub fn params(&self) -> IlsParams {
+        IlsParams {
+            iterations: self.iterations.unwrap_or(20),
+            perturbation_strength: self.strength.unwrap_or(2),
+            acceptance: self.acceptance.unwrap_or(Acceptance::Better),
+            temperature: self.temperature.unwrap_or(0.4),
+            restart_after: self.restart_after,
+            adaptive_perturbation: self.adaptive_perturbation.unwrap_or(false),
+        }
+    }
+}
+
+/// Parâmetros do Iterated Greedy (seção `[ig]` e opções `--ig.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IgConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destruction_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_perturbation: Option<bool>,
+}
+
+/// Parâmetros do recozimento simulado (seção `[sa]` e opções `--sa.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SaConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_temp: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooling_rate: Option<f64>,
+}
+
+/// Parâmetros da busca tabu (seção `[tabu]` e opções `--tabu.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TabuConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenure: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub candidates: Option<usize>,
+}
+
+/// Parâmetros da colônia de formigas (seção `[aco]` e opções `--aco.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AcoConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ants: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evaporation: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exploitation: Option<f64>,
+}
+
+/// Parâmetros da evolução diferencial (seção `[de]` e opções `--de.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub population_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crossover_rate: Option<f64>,
+}
+
+/// Parâmetros do BRKGA (seção `[brkga]` e opções `--brkga.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BrkgaConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub population_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elite: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutants: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bias: Option<f64>,
+}
+
+/// Parâmetros do enxame de partículas (seção `[pso]` e opções `--pso.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PsoConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub population_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inertia: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cognitive: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub social: Option<f64>,
+}
+
+/// Parâmetros da heurística NEH (seção `[neh]` e opções `--neh.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NehConfig {
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub tie_breaking: Option<NehTieBreaking>,
+}
+
+/// Parâmetros da hiper-heurística (seção `[hh]` e opções `--hh.*`). Os algoritmos
+/// de baixo nível usam os parâmetros das suas próprias seções.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HhConfig {
+    #[serde(
+        default,
+        with = "string_list_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub algorithms: Option<Vec<Algorithm>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slice_generations: Option<usize>,
+    #[serde(default, with = "string_opt", skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<AosStrategy>,
+}
+
+/// Parâmetros do portfólio (seção `[portfolio]` e opções `--portfolio.*`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PortfolioConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<PathBuf>,
+}
+
+/// Indica se a seção não define nenhum parâmetro (omitida na serialização).
+fn _is_unset<T: Default + PartialEq>(section: &T) -> bool {
+    *section == T::default()
+}
+
+/// (De)serialização de opções representadas por texto, usando `FromStr` e `Display`
+/// (ex: `crossover = "pmx"`).
+mod string_opt {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.collect_str(v),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map(Some).map_err(de::Error::custom)
+    }
+}
+
+/// (De)serialização de listas de opções representadas por texto, usando `FromStr` e
+/// `Display` (ex: `aos_crossovers = ["ox", "pmx"]`).
+mod string_list_opt {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<Vec<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(values) => serializer.collect_seq(values.iter().map(ToString::to_string)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let texts = Vec::<String>::deserialize(deserializer)?;
+        texts
+            .iter()
+            .map(|text| text.parse().map_err(de::Error::custom))
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+}
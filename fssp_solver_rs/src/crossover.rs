@@ -0,0 +1,174 @@
+use crate::fssp_core::Job;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Operadores de cruzamento disponíveis para permutações de tarefas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossover {
+    Ox,       // Order Crossover: segmento central de um pai, restante na ordem do outro.
+    Pmx,      // Partially Mapped Crossover: segmento central e mapeamento dos conflitos.
+    TwoPoint, // Two-point order: extremos de um pai, segmento central na ordem do outro.
+    Sbx,      // Similar Block Order Crossover: preserva blocos comuns aos dois pais.
+}
+
+impl FromStr for Crossover {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ox" => Ok(Crossover::Ox),
+            "pmx" => Ok(Crossover::Pmx),
+            "tp" | "two-point" => Ok(Crossover::TwoPoint),
+            "sbx" => Ok(Crossover::Sbx),
+            _ => Err(format!(
+                "Cruzamento desconhecido '{}' (use ox, pmx, tp ou sbx)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Crossover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Crossover::Ox => "ox",
+            Crossover::Pmx => "pmx",
+            Crossover::TwoPoint => "tp",
+            Crossover::Sbx => "sbx",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Crossover {
+    /// Todos os operadores de cruzamento.
+    pub const ALL: [Crossover; 4] = [
+        Crossover::Ox,
+        Crossover::Pmx,
+        Crossover::TwoPoint,
+        Crossover::Sbx,
+    ];
+
+    /// Aplica o operador a dois pais, gerando dois filhos com os mesmos pontos de corte.
+    pub fn apply<R: Rng>(&self, p1: &[Job], p2: &[Job], rng: &mut R) -> (Vec<Job>, Vec<Job>) {
+        let n_jobs = p1.len();
+
+        // Define os pontos de corte para o cruzamento.
+        let (start, end) = {
+            let mut v = [rng.gen_range(0..n_jobs), rng.gen_range(0..n_jobs)];
+            v.sort_unstable();
+            (v[0], v[1])
+        };
+
+        match self {
+            Crossover::Ox => (ox_child(p1, p2, start, end), ox_child(p2, p1, start, end)),
+            Crossover::Pmx => (pmx_child(p1, p2, start, end), pmx_child(p2, p1, start, end)),
+            Crossover::TwoPoint => (
+                two_point_child(p1, p2, start, end),
+                two_point_child(p2, p1, start, end),
+            ),
+            Crossover::Sbx => (sbx_child(p1, p2, start), sbx_child(p2, p1, start)),
+        }
+    }
+}
+
+/// Filho do OX: copia `donor[start..=end]` e preenche as demais posições, da esquerda
+/// para a direita, com os genes restantes na ordem em que aparecem em `other`.
+fn ox_child(donor: &[Job], other: &[Job], start: usize, end: usize) -> Vec<Job> {
+    let mut child = vec![Job::MAX; donor.len()];
+    // Genes já presentes no filho, consultados em tempo constante.
+    let mut used = vec![false; donor.len()];
+
+    // Copia o segmento central do pai para o filho.
+    child[start..=end].copy_from_slice(&donor[start..=end]);
+    for &gene in &donor[start..=end] {
+        used[gene as usize] = true;
+    }
+
+    // Preenche o restante do filho com genes do outro pai.
+    let mut remaining = other.iter().copied().filter(|&gene| !used[gene as usize]);
+    for gene in child.iter_mut() {
+        if *gene == Job::MAX {
+            *gene = remaining.next().unwrap();
+        }
+    }
+    child
+}
+
+/// Filho do PMX: copia `donor[start..=end]` e posiciona os genes de `other` fora do
+/// segmento, resolvendo conflitos pelo mapeamento entre os segmentos dos dois pais.
+fn pmx_child(donor: &[Job], other: &[Job], start: usize, end: usize) -> Vec<Job> {
+    let n_jobs = donor.len();
+    let mut child = vec![Job::MAX; n_jobs];
+    // Posição de cada tarefa em `donor`.
+    let mut donor_pos = vec![0; n_jobs];
+    for (idx, &job) in donor.iter().enumerate() {
+        donor_pos[job as usize] = idx;
+    }
+
+    child[start..=end].copy_from_slice(&donor[start..=end]);
+    let in_segment = |idx: usize| idx >= start && idx <= end;
+
+    for idx in (0..n_jobs).filter(|&idx| !in_segment(idx)) {
+        // Segue o mapeamento até encontrar um gene fora do segmento copiado.
+        let mut gene = other[idx];
+        while in_segment(donor_pos[gene as usize]) {
+            gene = other[donor_pos[gene as usize]];
+        }
+        child[idx] = gene;
+    }
+    child
+}
+
+/// Filho do cruzamento de dois pontos baseado em ordem: mantém os extremos de `donor`
+/// e preenche o segmento central com os genes restantes na ordem de `other`.
+fn two_point_child(donor: &[Job], other: &[Job], start: usize, end: usize) -> Vec<Job> {
+    let mut child = donor.to_vec();
+    let mut used = vec![false; donor.len()];
+    for (idx, &gene) in donor.iter().enumerate() {
+        used[gene as usize] = idx < start || idx > end;
+    }
+
+    let mut remaining = other.iter().copied().filter(|&gene| !used[gene as usize]);
+    for gene in child[start..=end].iter_mut() {
+        *gene = remaining.next().unwrap();
+    }
+    child
+}
+
+/// Filho do SBOX (Ruiz et al., 2006): blocos de pelo menos duas tarefas consecutivas
+/// iguais nas mesmas posições dos dois pais são preservados, as posições antes do
+/// ponto de corte vêm de `donor` e as lacunas são preenchidas na ordem de `other`.
+fn sbx_child(donor: &[Job], other: &[Job], cut: usize) -> Vec<Job> {
+    let n_jobs = donor.len();
+    let mut child = vec![Job::MAX; n_jobs];
+    let mut used = vec![false; n_jobs];
+
+    // Blocos similares: posição igual nos dois pais e vizinha também igual.
+    for idx in 0..n_jobs {
+        let same = |k: usize| donor[k] == other[k];
+        let in_block =
+            same(idx) && ((idx > 0 && same(idx - 1)) || (idx + 1 < n_jobs && same(idx + 1)));
+        if in_block {
+            child[idx] = donor[idx];
+            used[donor[idx] as usize] = true;
+        }
+    }
+
+    // Posições anteriores ao ponto de corte herdadas de `donor`.
+    for idx in 0..cut {
+        if child[idx] == Job::MAX && !used[donor[idx] as usize] {
+            child[idx] = donor[idx];
+            used[donor[idx] as usize] = true;
+        }
+    }
+
+    let mut remaining = other.iter().copied().filter(|&gene| !used[gene as usize]);
+    for gene in child.iter_mut() {
+        if *gene == Job::MAX {
+            *gene = remaining.next().unwrap();
+        }
+    }
+    child
+}
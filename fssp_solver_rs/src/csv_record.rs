@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+use std::io::{self, BufRead};
+
+/// Campo de CSV: entre aspas (com as aspas internas duplicadas) se contém vírgula,
+/// aspas ou quebra de linha, como em nomes de instância arbitrários.
+pub fn field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\"")).into()
+    } else {
+        value.into()
+    }
+}
+
+/// Registros de um CSV gravado com `field`: campos entre aspas podem conter
+/// vírgulas, aspas duplicadas e quebras de linha. Cada registro vem com o número da
+/// linha (a partir de 1) em que começa; linhas em branco são ignoradas, e aspas
+/// sem fechamento ao final do arquivo são um erro.
+pub fn records<R: BufRead>(reader: R) -> impl Iterator<Item = io::Result<(usize, Vec<String>)>> {
+    let mut lines = reader.lines().enumerate();
+    std::iter::from_fn(move || {
+        let mut fields = Vec::new();
+        let mut current = String::new();
+        let mut quoted = false;
+        let mut start = None;
+        loop {
+            let (idx, line) = match lines.next() {
+                Some((idx, Ok(line))) => (idx, line),
+                Some((_, Err(e))) => return Some(Err(e)),
+                None if quoted => {
+                    return Some(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("aspas sem fechamento no registro da linha {}", start? + 1),
+                    )))
+                }
+                None => return None,
+            };
+            if start.is_none() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                start = Some(idx);
+            } else {
+                // A quebra de linha faz parte do campo entre aspas.
+                current.push('\n');
+            }
+            let mut chars = line.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if quoted && chars.peek() == Some(&'"') => {
+                        chars.next();
+                        current.push('"');
+                    }
+                    '"' if quoted => quoted = false,
+                    '"' if current.is_empty() => quoted = true,
+                    ',' if !quoted => fields.push(std::mem::take(&mut current)),
+                    _ => current.push(c),
+                }
+            }
+            if !quoted {
+                fields.push(current);
+                return start.map(|idx| Ok((idx + 1, fields)));
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_read_back_quoted_fields() {
+        let values = ["a,b", "say \"hi\"", "two\nlines", "plain", ""];
+        let line: Vec<Cow<str>> = values.iter().map(|v| field(v)).collect();
+        let text = format!("header\n\n{}\nlast,1\n", line.join(","));
+        let read: Vec<(usize, Vec<String>)> =
+            records(text.as_bytes()).collect::<io::Result<_>>().unwrap();
+        assert_eq!(read.len(), 3);
+        assert_eq!(read[0], (1, vec!["header".to_string()]));
+        assert_eq!(read[1], (3, values.map(String::from).to_vec()));
+        assert_eq!(read[2], (5, vec!["last".to_string(), "1".to_string()]));
+        assert!(records("\"open,1\n".as_bytes()).next().unwrap().is_err());
+    }
+}
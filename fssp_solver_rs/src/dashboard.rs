@@ -0,0 +1,269 @@
+use crate::error::Result;
+use crate::fssp_core::{FSSPInstance, Time};
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// Intervalo entre mensagens de manutenção da conexão de eventos, que também
+/// detectam clientes desconectados.
+const KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Página do painel: gráficos do melhor makespan, do makespan médio e da
+/// diversidade e o diagrama de Gantt da melhor solução, atualizados por eventos.
+const PAGE: &str = include_str!("dashboard.html");
+
+/// Uma execução acompanhada pelo painel.
+struct Run {
+    name: String,        // Nome da instância.
+    machines: usize,     // Número de máquinas (linhas do diagrama de Gantt).
+    points: Vec<Value>,  // Estatísticas de cada geração.
+    best: Option<Value>, // Melhor solução e suas operações.
+    best_version: u64,   // Incrementado a cada nova melhor solução.
+}
+
+/// Estado compartilhado entre os observadores e as conexões do painel.
+#[derive(Default)]
+struct DashboardState {
+    runs: Vec<Run>, // Execuções, na ordem em que começaram.
+    version: u64,   // Incrementado a cada alteração, acordando as conexões.
+}
+
+type Shared = Arc<(Mutex<DashboardState>, Condvar)>;
+
+/// Painel web local que acompanha as execuções em tempo real. Serve uma página em
+/// `/`, um fluxo de eventos (Server-Sent Events) em `/events` e o estado completo
+/// em JSON em `/state`. As conexões são atendidas em threads próprias, sem
+/// interferir nos solvers.
+pub struct Dashboard {
+    shared: Shared,         // Estado publicado pelos observadores.
+    local_addr: SocketAddr, // Endereço efetivo do servidor.
+}
+
+impl Dashboard {
+    /// Inicia o servidor do painel em `addr` (ex: "127.0.0.1:8090").
+    pub fn start(addr: &str) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let shared: Shared = Arc::default();
+        let server_state = Arc::clone(&shared);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = Arc::clone(&server_state);
+                thread::spawn(move || {
+                    if let Err(e) = _handle_connection(stream, &state) {
+                        debug!(error = %e, "Conexão do painel encerrada");
+                    }
+                });
+            }
+        });
+        info!("Painel disponível em http://{}", local_addr);
+        Ok(Dashboard { shared, local_addr })
+    }
+
+    /// Endereço em que o painel está escutando.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Registra uma nova execução sobre `instance` e retorna o observador que
+    /// publica seu progresso.
+    pub fn observer(&self, name: &str, instance: &FSSPInstance) -> DashboardObserver {
+        let (lock, condvar) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        state.runs.push(Run {
+            name: name.to_string(),
+            machines: instance.n_machines,
+            points: Vec::new(),
+            best: None,
+            best_version: 0,
+        });
+        state.version += 1;
+        condvar.notify_all();
+        DashboardObserver {
+            shared: Arc::clone(&self.shared),
+            run: state.runs.len() - 1,
+            instance: instance.clone(),
+        }
+    }
+}
+
+/// Observador que publica as estatísticas de cada geração e a melhor solução de
+/// uma execução no painel.
+pub struct DashboardObserver {
+    shared: Shared,         // Estado do painel.
+    run: usize,             // Índice da execução no painel.
+    instance: FSSPInstance, // Instância usada para decodificar as soluções.
+}
+
+impl DashboardObserver {
+    /// Aplica `update` à execução e acorda as conexões.
+    fn _publish(&self, update: impl FnOnce(&mut Run)) {
+        let (lock, condvar) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        update(&mut state.runs[self.run]);
+        state.version += 1;
+        condvar.notify_all();
+    }
+}
+
+impl Observer for DashboardObserver {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        let point = json!({
+            "generation": stats.generation,
+            "best": stats.best_makespan,
+            "mean": stats.mean_makespan,
+            "diversity": stats.diversity,
+            "elapsed": stats.elapsed.as_secs_f64(),
+        });
+        self._publish(|run| run.points.push(point));
+        Control::Continue
+    }
+
+    fn on_new_best(&mut self, solution: &Solution) {
+        // Operações [máquina, tarefa, início, fim] do escalonamento da solução.
+        let schedule = self.instance.decode_schedule(&solution.sequence);
+        let operations: Vec<[Time; 4]> = solution
+            .sequence
+            .iter()
+            .flat_map(|&job| {
+                let schedule = &schedule;
+                (0..self.instance.n_machines).map(move |machine| {
+                    [
+                        machine as Time,
+                        job as Time,
+                        schedule.start_times[job as usize][machine],
+                        schedule.completion_times[job as usize][machine],
+                    ]
+                })
+            })
+            .collect();
+        let best = json!({
+            "makespan": solution.makespan,
+            "sequence": solution.sequence,
+            "operations": operations,
+        });
+        self._publish(|run| {
+            run.best = Some(best);
+            run.best_version += 1;
+        });
+    }
+}
+
+/// Atende uma conexão HTTP do painel.
+fn _handle_connection(stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Descarta os cabeçalhos.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    match (method, path) {
+        ("GET", "/") => _write_response(stream, "200 OK", "text/html; charset=utf-8", PAGE),
+        ("GET", "/state") => {
+            let body = _snapshot(&shared.0.lock().unwrap()).to_string();
+            _write_response(stream, "200 OK", "application/json", &body)
+        }
+        ("GET", "/events") => _stream_events(stream, shared),
+        _ => _write_response(stream, "404 Not Found", "text/plain", "não encontrado"),
+    }
+}
+
+/// Envia uma resposta completa e encerra a conexão.
+fn _write_response(
+    mut stream: TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Estado completo do painel em JSON.
+fn _snapshot(state: &DashboardState) -> Value {
+    let runs: Vec<Value> = state
+        .runs
+        .iter()
+        .map(|run| {
+            json!({
+                "name": run.name,
+                "machines": run.machines,
+                "points": run.points,
+                "best": run.best,
+            })
+        })
+        .collect();
+    json!({ "runs": runs })
+}
+
+/// Transmite as alterações do estado como Server-Sent Events até o cliente se
+/// desconectar. Cada conexão recebe primeiro todo o histórico e, depois, apenas
+/// os eventos novos: `run` (nova execução), `generation` (estatísticas de uma
+/// geração) e `best` (nova melhor solução).
+fn _stream_events(mut stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n"
+    )?;
+
+    // Quantidade de gerações e versão da melhor solução já enviadas por execução.
+    let mut sent_points: Vec<usize> = Vec::new();
+    let mut sent_best: Vec<u64> = Vec::new();
+    let mut seen_version = None;
+    let (lock, condvar) = &**shared;
+    loop {
+        let mut events = String::new();
+        {
+            let mut state = lock.lock().unwrap();
+            if seen_version == Some(state.version) {
+                state = condvar.wait_timeout(state, KEEP_ALIVE).unwrap().0;
+            }
+            if seen_version == Some(state.version) {
+                events.push_str(": keep-alive\n\n");
+            }
+            seen_version = Some(state.version);
+
+            for (idx, run) in state.runs.iter().enumerate() {
+                if idx == sent_points.len() {
+                    let data = json!({ "run": idx, "name": run.name, "machines": run.machines });
+                    events.push_str(&format!("event: run\ndata: {}\n\n", data));
+                    sent_points.push(0);
+                    sent_best.push(0);
+                }
+                for point in &run.points[sent_points[idx]..] {
+                    let data = json!({ "run": idx, "point": point });
+                    events.push_str(&format!("event: generation\ndata: {}\n\n", data));
+                }
+                sent_points[idx] = run.points.len();
+                if let Some(best) = run
+                    .best
+                    .as_ref()
+                    .filter(|_| run.best_version != sent_best[idx])
+                {
+                    let data = json!({ "run": idx, "best": best });
+                    events.push_str(&format!("event: best\ndata: {}\n\n", data));
+                    sent_best[idx] = run.best_version;
+                }
+            }
+        }
+        stream.write_all(events.as_bytes())?;
+        stream.flush()?;
+    }
+}
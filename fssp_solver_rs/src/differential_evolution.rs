@@ -0,0 +1,103 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job};
+use crate::key_population::{KeyAlgorithm, KeyPopulation};
+use crate::random_key;
+use rand::rngs::ThreadRng;
+use rand::seq::index;
+use rand::Rng;
+
+/// Tamanho mínimo da população: a mutação usa três indivíduos distintos do alvo.
+const MIN_POPULATION: usize = 4;
+
+/// Evolução diferencial discreta sobre chaves aleatórias (estratégia
+/// DE/rand/1/bin). Cada indivíduo é um vetor de chaves reais decodificado em uma
+/// sequência pela regra LOV (veja `random_key`). Para cada alvo, o vetor mutante
+/// `a + F * (b - c)` é combinado com o alvo por cruzamento binomial (taxa `CR`), e
+/// o teste substitui o alvo se não for pior. A cada geração em que a melhor
+/// solução da população muda, ela é refinada por busca local por inserção e suas
+/// chaves são reordenadas para a sequência melhorada (aprendizado lamarckiano).
+pub struct DifferentialEvolution {
+    state: KeyPopulation, // População de chaves e melhor solução encontrada.
+    scale: f64,           // Fator de escala F da diferença de vetores.
+    crossover_rate: f64,  // Taxa CR do cruzamento binomial.
+}
+
+impl DifferentialEvolution {
+    /// Cria uma nova instância da `DifferentialEvolution` (com ao menos quatro
+    /// indivíduos).
+    pub fn new(instance: FSSPInstance, population_size: usize, generations: usize) -> Self {
+        DifferentialEvolution {
+            state: KeyPopulation::new(instance, population_size.max(MIN_POPULATION), generations),
+            scale: 0.5,
+            crossover_rate: 0.9,
+        }
+    }
+
+    /// Define o fator de escala F e a taxa de cruzamento CR.
+    pub fn with_params(mut self, scale: f64, crossover_rate: f64) -> Self {
+        self.scale = scale;
+        self.crossover_rate = crossover_rate;
+        self
+    }
+
+    /// Define soluções (já validadas) inseridas na população inicial antes da
+    /// solução NEH e das chaves aleatórias.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.state.initial_solutions = solutions;
+        self
+    }
+
+    /// Vetor de teste do alvo `target`: mutação DE/rand/1 seguida de cruzamento
+    /// binomial com o alvo.
+    fn _trial<R: Rng>(&self, target: usize, rng: &mut R) -> Vec<f64> {
+        let population = &self.state.keys;
+        let (a, b, c) = loop {
+            let picked = index::sample(rng, population.len(), 3);
+            let (a, b, c) = (picked.index(0), picked.index(1), picked.index(2));
+            if ![a, b, c].contains(&target) {
+                break (a, b, c);
+            }
+        };
+        let (a, b, c) = (&population[a], &population[b], &population[c]);
+        let forced = rng.gen_range(0..self.state.instance.n_jobs);
+        population[target]
+            .iter()
+            .enumerate()
+            .map(|(k, &key)| {
+                if k == forced || rng.gen::<f64>() < self.crossover_rate {
+                    a[k] + self.scale * (b[k] - c[k])
+                } else {
+                    key
+                }
+            })
+            .collect()
+    }
+}
+
+impl KeyAlgorithm for DifferentialEvolution {
+    const NAME: &'static str = "de";
+
+    fn state(&self) -> &KeyPopulation {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut KeyPopulation {
+        &mut self.state
+    }
+
+    /// Substitui cada alvo pelo seu vetor de teste, se não for pior. O orçamento é
+    /// verificado também a cada vetor de teste.
+    fn step(&mut self, rng: &mut ThreadRng, budget: &Budget) {
+        for target in 0..self.state.keys.len() {
+            if budget.exhausted(self.state.instance.evaluations.get()) {
+                break;
+            }
+            let trial = self._trial(target, rng);
+            let value = self.state.instance.evaluate(&random_key::decode(&trial));
+            if value <= self.state.fitness[target] {
+                self.state.keys[target] = trial;
+                self.state.fitness[target] = value;
+            }
+        }
+    }
+}
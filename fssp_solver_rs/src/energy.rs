@@ -0,0 +1,466 @@
+use crate::budget::Budget;
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{max, FSSPInstance, Job, Schedule, Time};
+use crate::observer::{Control, GenerationStats, Observer};
+use crate::time::Instant;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+use tracing::{debug, debug_span};
+
+/// Nível de velocidade de uma operação: na velocidade `factor`, uma operação de
+/// tempo nominal `p` dura `⌈p / factor⌉` e consome `power` por unidade de tempo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedLevel {
+    pub factor: f64, // Multiplicador da velocidade nominal.
+    pub power: f64,  // Potência consumida por unidade de tempo.
+}
+
+/// Velocidades disponíveis para cada operação, no formato `fator:potência,...`
+/// (ex: `1:1,1.25:1.5625,1.5:2.25`, com potência proporcional ao quadrado da
+/// velocidade).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeedProfile {
+    pub levels: Vec<SpeedLevel>, // Níveis de velocidade, na ordem informada.
+}
+
+impl FromStr for SpeedProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let error = || {
+            format!(
+                "Velocidades inválidas '{}' (use fator:potência separados por vírgula, ex: 1:1,1.5:2.25)",
+                s
+            )
+        };
+        let levels = s
+            .split(',')
+            .map(|level| {
+                let (factor, power) = level.trim().split_once(':').ok_or_else(error)?;
+                let factor: f64 = factor.trim().parse().map_err(|_| error())?;
+                let power: f64 = power.trim().parse().map_err(|_| error())?;
+                if !(factor > 0.0 && factor.is_finite() && power >= 0.0 && power.is_finite()) {
+                    return Err(error());
+                }
+                Ok(SpeedLevel { factor, power })
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(SpeedProfile { levels })
+    }
+}
+
+impl fmt::Display for SpeedProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let levels: Vec<String> = self
+            .levels
+            .iter()
+            .map(|level| format!("{}:{}", level.factor, level.power))
+            .collect();
+        write!(f, "{}", levels.join(","))
+    }
+}
+
+impl SpeedProfile {
+    /// Nível mais próximo da velocidade nominal (fator 1), usado na solução inicial.
+    pub fn nominal(&self) -> usize {
+        (0..self.levels.len())
+            .min_by(|&a, &b| {
+                let distance = |level: usize| (self.levels[level].factor - 1.0).abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Duração de cada operação em cada nível, `[nível][tarefa][máquina]`. Retorna erro
+    /// se alguma duração ou o horizonte do nível mais lento (veja
+    /// `FSSPInstance::check_horizon`) não cabe em `Time`.
+    pub fn durations(&self, instance: &FSSPInstance) -> Result<Vec<Vec<Vec<Time>>>> {
+        self.levels
+            .iter()
+            .map(|level| {
+                let durations = instance
+                    .processing_times
+                    .iter()
+                    .map(|times| {
+                        times
+                            .iter()
+                            .map(|&p| {
+                                let duration = (p as f64 / level.factor).ceil();
+                                // `Time::MAX as f64` é arredondado para 2^64.
+                                if duration < Time::MAX as f64 {
+                                    Ok(duration as Time)
+                                } else {
+                                    Err(_overflow(level))
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect::<Result<Vec<Vec<Time>>>>()?;
+                match instance._horizon_with(&durations) {
+                    Some(_) => Ok(durations),
+                    None => Err(_overflow(level)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Erro de uma velocidade cujas durações não cabem em `Time`.
+fn _overflow(level: &SpeedLevel) -> FsspError {
+    FsspError::Instance(format!(
+        "na velocidade {}, as durações das operações excedem o maior instante representável ({})",
+        level.factor,
+        Time::MAX
+    ))
+}
+
+/// Escalonamento com velocidades: sequência permutacional e o nível de velocidade
+/// de cada operação.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeedSchedule {
+    pub sequence: Vec<Job>,      // Sequência de tarefas.
+    pub speeds: Vec<Vec<usize>>, // Nível de velocidade [tarefa][máquina].
+}
+
+/// Solução avaliada: makespan e energia consumida por um escalonamento.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnergyPoint {
+    pub makespan: Time,          // Makespan do escalonamento.
+    pub energy: f64,             // Energia total (duração x potência de cada operação).
+    pub schedule: SpeedSchedule, // Escalonamento avaliado.
+}
+
+impl EnergyPoint {
+    /// Indica se este ponto domina `other` (não é pior em nenhum critério e é
+    /// melhor em ao menos um).
+    pub fn dominates(&self, other: &EnergyPoint) -> bool {
+        self.makespan <= other.makespan
+            && self.energy <= other.energy
+            && (self.makespan < other.makespan || self.energy < other.energy)
+    }
+}
+
+/// Fronteira de Pareto (makespan x energia) das soluções avaliadas durante a busca,
+/// ordenada por makespan crescente.
+#[derive(Debug, Clone, Default)]
+pub struct ParetoFront {
+    pub points: Vec<EnergyPoint>, // Soluções não dominadas.
+}
+
+impl ParetoFront {
+    /// Insere o ponto se ele não for dominado (nem repetido), removendo os pontos
+    /// que ele domina. Retorna se o ponto foi inserido.
+    pub fn insert(&mut self, point: &EnergyPoint) -> bool {
+        if self.points.iter().any(|p| {
+            p.dominates(point) || (p.makespan == point.makespan && p.energy == point.energy)
+        }) {
+            return false;
+        }
+        self.points.retain(|p| !point.dominates(p));
+        let idx = self.points.partition_point(|p| p.makespan < point.makespan);
+        self.points.insert(idx, point.clone());
+        true
+    }
+}
+
+/// Busca local iterada para o FSSP com velocidades ajustáveis. Cada operação roda
+/// em um dos níveis de `SpeedProfile`, e a busca minimiza a soma ponderada
+/// `(1 - λ)·C_max/C_ref + λ·E/E_ref`, onde `λ` é o peso da energia e `C_ref` e `E_ref`
+/// são o makespan e a energia da solução inicial (todas as operações na velocidade
+/// nominal). Todas as soluções avaliadas alimentam a fronteira de Pareto.
+pub struct EnergySearch {
+    instance: FSSPInstance,         // Instância do problema FSSP.
+    profile: SpeedProfile,          // Velocidades disponíveis.
+    durations: Vec<Vec<Vec<Time>>>, // Duração [nível][tarefa][máquina].
+    energy_weight: f64,             // Peso λ da energia na soma ponderada.
+    reference: (f64, f64),          // Makespan e energia de referência.
+    iterations: usize,              // Número de iterações (critério de parada).
+    perturbation_strength: usize,   // Mutações aplicadas em cada perturbação.
+    pub best: EnergyPoint,          // Melhor solução segundo a soma ponderada.
+    pub best_value: f64,            // Soma ponderada da melhor solução.
+    pub front: ParetoFront,         // Fronteira de Pareto das soluções avaliadas.
+}
+
+impl EnergySearch {
+    /// Cria a busca a partir da sequência permutacional `initial`, com todas as
+    /// operações na velocidade nominal. `energy_weight` (entre 0 e 1) é o peso da
+    /// energia na soma ponderada. Retorna erro se as durações de alguma velocidade
+    /// não cabem em `Time` (veja `SpeedProfile::durations`).
+    pub fn new(
+        instance: FSSPInstance,
+        profile: SpeedProfile,
+        energy_weight: f64,
+        initial: &[Job],
+        iterations: usize,
+        perturbation_strength: usize,
+    ) -> Result<Self> {
+        let durations = profile.durations(&instance)?;
+        let schedule = SpeedSchedule {
+            sequence: initial.to_vec(),
+            speeds: vec![vec![profile.nominal(); instance.n_machines]; instance.n_jobs],
+        };
+        let mut search = EnergySearch {
+            instance,
+            profile,
+            durations,
+            energy_weight: energy_weight.clamp(0.0, 1.0),
+            reference: (1.0, 1.0),
+            iterations,
+            perturbation_strength,
+            best: EnergyPoint {
+                makespan: 0,
+                energy: 0.0,
+                schedule: schedule.clone(),
+            },
+            best_value: 1.0,
+            front: ParetoFront::default(),
+        };
+        let point = search.evaluate(schedule);
+        search.reference = (
+            (point.makespan as f64).max(1.0),
+            point.energy.max(f64::MIN_POSITIVE),
+        );
+        search.best_value = search.weighted(&point);
+        search.best = point;
+        Ok(search)
+    }
+
+    /// Decodifica o escalonamento, calculando o makespan e a energia, e o registra
+    /// na fronteira de Pareto e no contador de avaliações da instância.
+    pub fn evaluate(&mut self, schedule: SpeedSchedule) -> EnergyPoint {
+        self.instance.evaluations.add(1);
+        let mut c: Vec<Time> = vec![0; self.instance.n_machines];
+        let mut energy = 0.0;
+        for &job in &schedule.sequence {
+            let job = job as usize;
+            for machine in 0..self.instance.n_machines {
+                let level = schedule.speeds[job][machine];
+                let duration = self.durations[level][job][machine];
+                let ready = match machine {
+                    0 => max(c[0], self.instance.release_date(job)),
+                    _ => max(
+                        c[machine - 1] + self.instance.transfer_time(job, machine - 1),
+                        c[machine],
+                    ),
+                };
+                c[machine] = self.instance.earliest_start(machine, ready, duration) + duration;
+                energy += duration as f64 * self.profile.levels[level].power;
+            }
+        }
+        let point = EnergyPoint {
+            makespan: c.last().copied().unwrap_or(0),
+            energy,
+            schedule,
+        };
+        self.front.insert(&point);
+        point
+    }
+
+    /// Decodifica o escalonamento em instantes de início e término de cada operação,
+    /// com as durações das velocidades escolhidas.
+    pub fn decode_schedule(&self, schedule: &SpeedSchedule) -> Schedule {
+        let (n_jobs, n_machines) = (self.instance.n_jobs, self.instance.n_machines);
+        let mut start_times = vec![vec![0; n_machines]; n_jobs];
+        let mut completion_times = vec![vec![0; n_machines]; n_jobs];
+        let mut c: Vec<Time> = vec![0; n_machines];
+        for &job in &schedule.sequence {
+            let job = job as usize;
+            for machine in 0..n_machines {
+                let duration = self.durations[schedule.speeds[job][machine]][job][machine];
+                let ready = match machine {
+                    0 => max(c[0], self.instance.release_date(job)),
+                    _ => max(
+                        c[machine - 1] + self.instance.transfer_time(job, machine - 1),
+                        c[machine],
+                    ),
+                };
+                let start = self.instance.earliest_start(machine, ready, duration);
+                c[machine] = start + duration;
+                start_times[job][machine] = start;
+                completion_times[job][machine] = c[machine];
+            }
+        }
+        Schedule {
+            sequence: schedule.sequence.clone(),
+            start_times,
+            completion_times,
+            makespan: c.last().copied().unwrap_or(0),
+        }
+    }
+
+    /// Soma ponderada normalizada do makespan e da energia (menor é melhor).
+    pub fn weighted(&self, point: &EnergyPoint) -> f64 {
+        (1.0 - self.energy_weight) * point.makespan as f64 / self.reference.0
+            + self.energy_weight * point.energy / self.reference.1
+    }
+
+    /// Busca local: tenta cada nível de velocidade em cada operação e reinsere cada
+    /// tarefa na melhor posição da sequência, repetindo enquanto houver melhoria ou
+    /// até o esgotamento de `budget`.
+    fn _descent(&mut self, point: EnergyPoint, budget: &Budget) -> (EnergyPoint, f64) {
+        let mut current_value = self.weighted(&point);
+        let mut current = point;
+        let (n_jobs, n_machines) = (self.instance.n_jobs, self.instance.n_machines);
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for job in 0..n_jobs {
+                for machine in 0..n_machines {
+                    for level in 0..self.profile.levels.len() {
+                        if budget.exhausted(self.instance.evaluations.get()) {
+                            return (current, current_value);
+                        }
+                        if level == current.schedule.speeds[job][machine] {
+                            continue;
+                        }
+                        let mut schedule = current.schedule.clone();
+                        schedule.speeds[job][machine] = level;
+                        let candidate = self.evaluate(schedule);
+                        let value = self.weighted(&candidate);
+                        if value < current_value {
+                            (current, current_value) = (candidate, value);
+                            improved = true;
+                        }
+                    }
+                }
+            }
+            for idx in 0..n_jobs {
+                let mut best: Option<(EnergyPoint, f64)> = None;
+                for pos in 0..n_jobs {
+                    if budget.exhausted(self.instance.evaluations.get()) {
+                        return (current, current_value);
+                    }
+                    if pos == idx {
+                        continue;
+                    }
+                    let mut schedule = current.schedule.clone();
+                    let job = schedule.sequence.remove(idx);
+                    schedule.sequence.insert(pos, job);
+                    let candidate = self.evaluate(schedule);
+                    let value = self.weighted(&candidate);
+                    if value < best.as_ref().map_or(current_value, |b| b.1) {
+                        best = Some((candidate, value));
+                    }
+                }
+                if let Some(best) = best {
+                    (current, current_value) = best;
+                    improved = true;
+                }
+            }
+        }
+        (current, current_value)
+    }
+
+    /// Perturbação: altera a velocidade de uma operação aleatória ou move uma tarefa
+    /// aleatória para outra posição da sequência.
+    fn _mutate<R: Rng>(&self, schedule: &mut SpeedSchedule, rng: &mut R) {
+        let n = schedule.sequence.len();
+        if rng.gen_bool(0.5) || n < 2 {
+            let job = rng.gen_range(0..n);
+            let machine = rng.gen_range(0..self.instance.n_machines);
+            schedule.speeds[job][machine] = rng.gen_range(0..self.profile.levels.len());
+        } else {
+            let job = schedule.sequence.remove(rng.gen_range(0..n));
+            schedule.sequence.insert(rng.gen_range(0..n), job);
+        }
+    }
+
+    /// Executa a busca até o número de iterações ou o esgotamento do orçamento. O
+    /// resultado nunca é pior que a solução inicial segundo a soma ponderada.
+    pub fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+
+        let (mut current, mut current_value) = self._descent(self.best.clone(), budget);
+        if current_value < self.best_value {
+            self.best_value = current_value;
+            self.best = current.clone();
+        }
+
+        for iter in 0..self.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            let mut schedule = current.schedule.clone();
+            for _ in 0..self.perturbation_strength {
+                self._mutate(&mut schedule, &mut rng);
+            }
+            let candidate = self.evaluate(schedule);
+            let (candidate, value) = self._descent(candidate, budget);
+            if value <= current_value {
+                current = candidate;
+                current_value = value;
+            }
+            if current_value < self.best_value {
+                self.best_value = current_value;
+                self.best = current.clone();
+                debug!(
+                    value = self.best_value,
+                    makespan = self.best.makespan,
+                    energy = self.best.energy,
+                    "Nova melhor solução"
+                );
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best.makespan,
+                generation_best: current.makespan,
+                mean_makespan: current.makespan as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+    }
+
+    /// Velocidades disponíveis.
+    pub fn profile(&self) -> &SpeedProfile {
+        &self.profile
+    }
+
+    /// Avaliações da função objetivo realizadas pela busca.
+    pub fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance_format::from_processing_times;
+
+    #[test]
+    fn durations_reject_overflowing_speeds() {
+        let instance =
+            from_processing_times(vec![vec![Time::MAX / 4, Time::MAX / 4], vec![3, 5]]).unwrap();
+        let fits: SpeedProfile = "1:1,2:4".parse().unwrap();
+        assert!(fits.durations(&instance).is_ok());
+
+        // Uma operação de ⌈p / 0.1⌉ não cabe em `Time`; com 0.5, cada duração cabe,
+        // mas o horizonte não.
+        for levels in ["1:1,0.1:0.5", "1:1,0.5:0.5", "1:1,1e-300:0.5"] {
+            let profile: SpeedProfile = levels.parse().unwrap();
+            assert!(matches!(
+                profile.durations(&instance),
+                Err(FsspError::Instance(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use thiserror::Error;
+
+/// Erros produzidos pela biblioteca.
+#[derive(Debug, Error)]
+pub enum FsspError {
+    /// Falha de leitura ou escrita de arquivo.
+    #[error("erro de E/S: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Valor que não pôde ser interpretado, com a posição no arquivo (base 1).
+    #[error("linha {line}, coluna {column}: valor inválido '{token}' ({reason})")]
+    Parse {
+        line: usize,
+        column: usize,
+        token: String,
+        reason: String,
+    },
+
+    /// Estrutura do arquivo inconsistente (linhas ou colunas faltando ou sobrando).
+    #[error("linha {line}: {message}")]
+    Format { line: usize, message: String },
+
+    /// Instância com dados inconsistentes, fora de um formato baseado em linhas.
+    #[error("instância inválida: {0}")]
+    Instance(String),
+
+    /// Arquivo de configuração inválido.
+    #[error("configuração inválida: {0}")]
+    Config(String),
+
+    /// Sequência ou escalonamento que viola as restrições da instância.
+    #[error("solução inválida: {0}")]
+    InvalidSolution(String),
+}
+
+/// Tipo `Result` da biblioteca.
+pub type Result<T> = std::result::Result<T, FsspError>;
+
+/// Divide uma linha em tokens separados por espaços, retornando a coluna (base 1)
+/// de início de cada token.
+pub(crate) fn tokens_with_columns(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (idx, ch) in line.char_indices() {
+        match (ch.is_whitespace(), start) {
+            (false, None) => start = Some(idx),
+            (true, Some(s)) => {
+                tokens.push((s, &line[s..idx]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, &line[s..]));
+    }
+    tokens
+        .into_iter()
+        .map(|(byte_idx, token)| (line[..byte_idx].chars().count() + 1, token))
+        .collect()
+}
+
+/// Interpreta um token numérico, produzindo `FsspError::Parse` com a posição em caso de falha.
+pub(crate) fn parse_token<T>(line: usize, column: usize, token: &str) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    token.parse().map_err(|e: T::Err| FsspError::Parse {
+        line,
+        column,
+        token: token.to_string(),
+        reason: e.to_string(),
+    })
+}
@@ -0,0 +1,175 @@
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Capacidade padrão do cache de aptidão (número de sequências).
+pub const DEFAULT_CAPACITY: usize = 100_000;
+
+/// Cache de avaliações indexado pelo hash da sequência, evitando recalcular a
+/// função objetivo de indivíduos reavaliados (elite, duplicados, migrantes). A chave
+/// combina dois hashes independentes de 64 bits, de modo que duas sequências
+/// diferentes só compartilham uma entrada se colidirem nos dois (probabilidade
+/// desprezível mesmo com bilhões de entradas), sem o custo de armazenar as
+/// sequências. Ao
+/// atingir a capacidade, o cache é esvaziado, o que limita a memória sem o custo
+/// de manter a ordem de uso das entradas.
+#[derive(Debug, Clone)]
+pub struct FitnessCache {
+    values: HashMap<u128, (Time, bool)>, // Valor por hash da sequência e se foi só sondada.
+    capacity: usize,                     // Número máximo de entradas (0 desativa o cache).
+    pub hits: u64,                       // Avaliações atendidas pelo cache.
+    pub misses: u64,                     // Avaliações calculadas e armazenadas.
+}
+
+impl FitnessCache {
+    /// Cria um cache com até `capacity` entradas; `0` desativa o cache.
+    pub fn new(capacity: usize) -> Self {
+        FitnessCache {
+            values: HashMap::new(),
+            capacity,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Cria o cache de aptidão de `instance`, desativado se a função objetivo é
+    /// estimada com reamostragem adaptativa: o valor armazenado seria a primeira
+    /// estimativa, e a sequência nunca seria reamostrada.
+    pub fn for_instance(instance: &FSSPInstance, capacity: usize) -> Self {
+        if instance.has_adaptive_estimates() {
+            Self::new(0)
+        } else {
+            Self::new(capacity)
+        }
+    }
+
+    /// Avalia a sequência segundo a função objetivo da instância, consultando o
+    /// cache antes de calcular.
+    pub fn evaluate(&mut self, instance: &FSSPInstance, sequence: &[Job]) -> Time {
+        self._evaluate(instance, sequence, false)
+    }
+
+    /// Avalia a sequência como `evaluate`, mas sem marcá-la como avaliada em
+    /// `contains`: usada nas medições que não fazem parte da busca (ex: o registro de
+    /// operadores), para não alterar a seleção da política de busca local `unique`.
+    pub fn probe(&mut self, instance: &FSSPInstance, sequence: &[Job]) -> Time {
+        self._evaluate(instance, sequence, true)
+    }
+
+    fn _evaluate(&mut self, instance: &FSSPInstance, sequence: &[Job], probe: bool) -> Time {
+        if self.capacity == 0 {
+            return instance.evaluate(sequence);
+        }
+        let key = _hash(sequence);
+        if let Some((value, probed)) = self.values.get_mut(&key) {
+            self.hits += 1;
+            *probed &= probe;
+            return *value;
+        }
+        self.misses += 1;
+        let value = instance.evaluate(sequence);
+        if self.values.len() >= self.capacity {
+            self.values.clear();
+        }
+        self.values.insert(key, (value, probe));
+        value
+    }
+
+    /// Avalia uma população, consultando o cache antes de calcular. As sequências
+    /// ausentes do cache são avaliadas em lote (veja
+    /// `FSSPInstance::evaluate_population`), uma única vez cada mesmo quando
+    /// repetidas na população.
+    pub fn evaluate_batch<'a, I>(&mut self, instance: &FSSPInstance, sequences: I) -> Vec<Time>
+    where
+        I: IntoIterator<Item = &'a [Job]>,
+    {
+        let sequences: Vec<&[Job]> = sequences.into_iter().collect();
+        if self.capacity == 0 {
+            return instance.evaluate_population(&sequences);
+        }
+        let keys: Vec<u128> = sequences.iter().map(|seq| _hash(seq)).collect();
+        // Sequências ausentes do cache, sem repetição.
+        let mut pending: HashMap<u128, usize> = HashMap::new();
+        let mut missing: Vec<&[Job]> = Vec::new();
+        for (key, &sequence) in keys.iter().zip(&sequences) {
+            match self.values.get_mut(key) {
+                Some((_, probed)) => *probed = false,
+                None if !pending.contains_key(key) => {
+                    pending.insert(*key, missing.len());
+                    missing.push(sequence);
+                }
+                None => {}
+            }
+        }
+        let computed = instance.evaluate_population(&missing);
+        self.misses += missing.len() as u64;
+        self.hits += (sequences.len() - missing.len()) as u64;
+        let values: Vec<Time> = keys
+            .iter()
+            .map(|key| match pending.get(key) {
+                Some(&idx) => computed[idx],
+                None => self.values[key].0,
+            })
+            .collect();
+        if self.values.len() + missing.len() > self.capacity {
+            self.values.clear();
+        }
+        for (key, idx) in pending {
+            self.values.insert(key, (computed[idx], false));
+        }
+        values
+    }
+
+    /// Registra o valor já calculado de uma sequência (por exemplo, o retornado pela
+    /// busca local), para que consultas seguintes não a reavaliem. Não conta como
+    /// consulta nas estatísticas.
+    pub fn insert(&mut self, sequence: &[Job], value: Time) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.values.len() >= self.capacity {
+            self.values.clear();
+        }
+        self.values.insert(_hash(sequence), (value, false));
+    }
+
+    /// Indica se a sequência já foi avaliada e permanece no cache (sempre `false`
+    /// com o cache desativado). Sequências apenas sondadas (`probe`) não contam. Não
+    /// altera as estatísticas.
+    pub fn contains(&self, sequence: &[Job]) -> bool {
+        self.capacity > 0
+            && self
+                .values
+                .get(&_hash(sequence))
+                .is_some_and(|&(_, probed)| !probed)
+    }
+
+    /// Fração das consultas atendidas pelo cache (0 se não houve consultas).
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+
+    /// Descarta as entradas e zera as estatísticas.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+/// Hash de 128 bits da sequência: dois hashes de 64 bits, o segundo precedido de um
+/// prefixo, de modo que os dois são independentes.
+fn _hash(sequence: &[Job]) -> u128 {
+    let mut first = DefaultHasher::new();
+    sequence.hash(&mut first);
+    let mut second = DefaultHasher::new();
+    second.write_u64(0x9e37_79b9_7f4a_7c15);
+    sequence.hash(&mut second);
+    (u128::from(first.finish()) << 64) | u128::from(second.finish())
+}
@@ -0,0 +1,173 @@
+//! Prefixo fixo da sequência: as primeiras tarefas (já iniciadas na linha de
+//! produção) mantêm a sua ordem, e apenas as demais são otimizadas, como no
+//! reescalonamento em horizonte rolante. O problema é reduzido a uma instância com
+//! as tarefas restantes em que cada máquina só fica disponível após terminar o
+//! prefixo (uma indisponibilidade inicial), de modo que qualquer algoritmo a
+//! resolve sem conhecer o prefixo; a solução é então expandida para a instância
+//! original.
+
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{EvaluationCounter, FSSPInstance, Job, MachineFront, Time};
+use crate::non_permutation::NonPermutationSchedule;
+
+/// Prefixo fixo de uma instância e a correspondência entre as tarefas da instância
+/// reduzida e as da original.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedPrefix {
+    prefix: Vec<Job>,         // Tarefas fixas, na ordem de processamento.
+    remaining: Vec<Job>,      // Tarefa original de cada tarefa da instância reduzida.
+    machine_ready: Vec<Time>, // Término do prefixo em cada máquina.
+}
+
+impl FixedPrefix {
+    /// Fixa as tarefas de `prefix` (a partir de 0) nas primeiras posições. O
+    /// prefixo não pode repetir tarefas e deve deixar ao menos uma a otimizar.
+    pub fn new(instance: &FSSPInstance, prefix: Vec<Job>) -> Result<Self> {
+        let mut fixed = vec![false; instance.n_jobs];
+        for &job in &prefix {
+            match fixed.get_mut(job as usize) {
+                None => {
+                    return Err(FsspError::Config(format!(
+                        "tarefa {} do prefixo fixo fora do intervalo da instância (0 a {})",
+                        job,
+                        instance.n_jobs - 1
+                    )))
+                }
+                Some(true) => {
+                    return Err(FsspError::Config(format!(
+                        "tarefa {} repetida no prefixo fixo",
+                        job
+                    )))
+                }
+                Some(seen) => *seen = true,
+            }
+        }
+        if prefix.len() == instance.n_jobs {
+            return Err(FsspError::Config(
+                "o prefixo fixo deve deixar ao menos uma tarefa a otimizar".to_string(),
+            ));
+        }
+
+        let mut front = MachineFront::new(instance.n_machines);
+        for &job in &prefix {
+            front.push(instance, job);
+        }
+        Ok(FixedPrefix {
+            remaining: (0..instance.n_jobs as Job)
+                .filter(|&job| !fixed[job as usize])
+                .collect(),
+            machine_ready: front.completion_times,
+            prefix,
+        })
+    }
+
+    /// Tarefas fixas, na ordem de processamento.
+    pub fn prefix(&self) -> &[Job] {
+        &self.prefix
+    }
+
+    /// Instante em que cada máquina termina o prefixo.
+    pub fn machine_ready(&self) -> &[Time] {
+        &self.machine_ready
+    }
+
+    /// Instância com as tarefas restantes, renumeradas a partir de 0 na ordem
+    /// original, em que cada máquina fica indisponível até terminar o prefixo. O
+    /// makespan de uma sequência reduzida é o da sequência expandida; nos demais
+    /// objetivos, o valor não inclui os custos das tarefas do prefixo.
+    pub fn reduce(&self, instance: &FSSPInstance) -> FSSPInstance {
+        let pick = |values: &Option<Vec<Time>>| {
+            values.as_ref().map(|values| {
+                self.remaining
+                    .iter()
+                    .map(|&job| values[job as usize])
+                    .collect()
+            })
+        };
+        let mut reduced = FSSPInstance {
+            n_jobs: self.remaining.len(),
+            n_machines: instance.n_machines,
+            processing_times: self
+                .remaining
+                .iter()
+                .map(|&job| instance.processing_times[job as usize].clone())
+                .collect(),
+            release_dates: pick(&instance.release_dates),
+            due_dates: pick(&instance.due_dates),
+            weights: pick(&instance.weights),
+            unavailability: instance.unavailability.clone(),
+            objective: instance.objective,
+            evaluations: EvaluationCounter::default(),
+            time_decimals: instance.time_decimals,
+            uncertainty: None,
+            sublots: instance.sublots.as_ref().map(|sublots| {
+                self.remaining
+                    .iter()
+                    .map(|&job| sublots[job as usize])
+                    .collect()
+            }),
+            transfer_times: instance.transfer_times.as_ref().map(|transfer_times| {
+                self.remaining
+                    .iter()
+                    .map(|&job| transfer_times[job as usize].clone())
+                    .collect()
+            }),
+        };
+        for (machine, &ready) in self.machine_ready.iter().enumerate() {
+            if ready > 0 {
+                reduced.add_unavailability(machine, 0, ready);
+            }
+        }
+        reduced
+    }
+
+    /// Sequência completa da instância original: o prefixo seguido das tarefas da
+    /// sequência reduzida.
+    pub fn expand(&self, sequence: &[Job]) -> Vec<Job> {
+        self.prefix
+            .iter()
+            .copied()
+            .chain(sequence.iter().map(|&job| self.remaining[job as usize]))
+            .collect()
+    }
+
+    /// Escalonamento não permutacional da instância original: o prefixo seguido das
+    /// tarefas reduzidas em cada máquina.
+    pub fn expand_schedule(&self, schedule: &NonPermutationSchedule) -> NonPermutationSchedule {
+        NonPermutationSchedule {
+            orders: schedule
+                .orders
+                .iter()
+                .map(|order| self.expand(order))
+                .collect(),
+        }
+    }
+
+    /// Sequência reduzida correspondente a uma sequência completa que começa pelo
+    /// prefixo (ex: uma solução inicial).
+    pub fn restrict(&self, sequence: &[Job]) -> Result<Vec<Job>> {
+        let Some(rest) = sequence.strip_prefix(self.prefix.as_slice()) else {
+            return Err(FsspError::InvalidSolution(
+                "A sequência não começa pelo prefixo fixo.".to_string(),
+            ));
+        };
+        let mut reduced_index = vec![None; self.prefix.len() + self.remaining.len()];
+        for (idx, &job) in self.remaining.iter().enumerate() {
+            reduced_index[job as usize] = Some(idx as Job);
+        }
+        rest.iter()
+            .map(|&job| {
+                reduced_index
+                    .get(job as usize)
+                    .copied()
+                    .flatten()
+                    .ok_or_else(|| {
+                        FsspError::InvalidSolution(format!(
+                            "Tarefa {} do prefixo fixo repetida na sequência.",
+                            job
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
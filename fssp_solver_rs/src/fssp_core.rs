@@ -1,105 +1,1688 @@
+use crate::error::{parse_token, tokens_with_columns, FsspError, Result};
+#[cfg(feature = "fs")]
+use crate::instance_format::InstanceFormat;
+use crate::objective::{self, ObjectiveFunction};
+use crate::robust::Uncertainty;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::cell::RefCell;
 pub use std::cmp::max;
-pub use std::fs::File;
-pub use std::io::{BufRead, BufReader};
+use std::fmt;
 pub use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Tipo dos tempos de processamento, instantes e valores da função objetivo. Com 64
+/// bits, o makespan não estoura mesmo em instâncias grandes com tempos altos (ex:
+/// 800 tarefas com tempos de até 10⁶). Tempos fracionários são representados em
+/// ponto fixo: uma instância com `time_decimals = d` armazena cada tempo multiplicado
+/// por 10^d, de modo que os cálculos continuam exatos e inteiros; `TimeValue`
+/// exibe os valores nas unidades originais.
+pub type Time = u64;
+
+/// Tipo dos índices de tarefa nas sequências (permutações), usado por todos os
+/// operadores, buscas locais e decodificadores. Com 32 bits, uma sequência ocupa
+/// metade da memória de uma de `usize` em plataformas de 64 bits, reduzindo o
+/// tráfego de memória ao avaliar e copiar populações. Para indexar vetores por
+/// tarefa, converta com `job as usize`.
+pub type Job = u32;
+
+/// Maior número de casas decimais aceito nos tempos de uma instância.
+pub const MAX_TIME_DECIMALS: u32 = 9;
+
+/// Valor de tempo (ou da função objetivo) com as casas decimais da instância, para
+/// exibição e serialização nas unidades originais.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeValue {
+    pub value: Time,   // Valor armazenado (multiplicado por 10^decimals).
+    pub decimals: u32, // Casas decimais.
+}
+
+impl TimeValue {
+    /// Cria o valor a partir do valor armazenado e das casas decimais.
+    pub fn new(value: Time, decimals: u32) -> Self {
+        TimeValue { value, decimals }
+    }
+
+    /// Valor nas unidades originais.
+    pub fn as_f64(&self) -> f64 {
+        self.value as f64 / 10f64.powi(self.decimals as i32)
+    }
+}
+
+impl fmt::Display for TimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.decimals == 0 {
+            return f.pad(&self.value.to_string());
+        }
+        let scale = Time::pow(10, self.decimals);
+        f.pad(&format!(
+            "{}.{:0width$}",
+            self.value / scale,
+            self.value % scale,
+            width = self.decimals as usize
+        ))
+    }
+}
+
+impl serde::Serialize for TimeValue {
+    /// Inteiro se a instância não tem casas decimais; caso contrário, número real.
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        if self.decimals == 0 {
+            serializer.serialize_u64(self.value)
+        } else {
+            serializer.serialize_f64(self.as_f64())
+        }
+    }
+}
+
+/// Número de casas decimais de um token numérico (0 se inteiro).
+pub(crate) fn decimal_places(token: &str) -> u32 {
+    token
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len() as u32)
+}
+
+/// Maior número de casas decimais entre os tokens, limitado a `MAX_TIME_DECIMALS`
+/// (o excesso é apontado por `parse_time`).
+pub(crate) fn max_decimal_places<'a>(tokens: impl IntoIterator<Item = &'a str>) -> u32 {
+    tokens
+        .into_iter()
+        .map(decimal_places)
+        .max()
+        .unwrap_or(0)
+        .min(MAX_TIME_DECIMALS)
+}
+
+/// Interpreta um tempo com até `decimals` casas decimais, retornando-o multiplicado
+/// por 10^decimals, sem arredondamento.
+pub(crate) fn parse_time(line: usize, column: usize, token: &str, decimals: u32) -> Result<Time> {
+    let error = |reason: String| FsspError::Parse {
+        line,
+        column,
+        token: token.to_string(),
+        reason,
+    };
+    let (integer, fraction) = token.split_once('.').unwrap_or((token, ""));
+    if fraction.len() as u32 > decimals {
+        return Err(error(format!("mais de {} casas decimais", decimals)));
+    }
+    if !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(error("parte fracionária inválida".to_string()));
+    }
+    let integer: Time = integer.parse().map_err(|e| error(format!("{}", e)))?;
+    let fraction: Time = format!("{:0<width$}", fraction, width = decimals as usize)
+        .parse()
+        .unwrap_or(0);
+    integer
+        .checked_mul(Time::pow(10, decimals))
+        .and_then(|value| value.checked_add(fraction))
+        .ok_or_else(|| error("valor grande demais para o tipo de tempo".to_string()))
+}
+
+/// Função objetivo minimizada pelos algoritmos. Cada variante corresponde a uma
+/// implementação de `ObjectiveFunction` (veja `function`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Objective {
+    Makespan,                          // Tempo de conclusão da última tarefa (C_max).
+    TotalFlowtime,                     // Tempo de fluxo total: soma de C_j.
+    TotalTardiness,                    // Atraso total: soma de max(0, C_j - d_j).
+    MaxTardiness,                      // Atraso máximo: maior max(0, C_j - d_j).
+    WeightedTardiness,                 // Atraso ponderado total: soma de w_j * max(0, C_j - d_j).
+    WeightedFlowtime,                  // Tempo de fluxo ponderado total: soma de w_j * C_j.
+    Scalarized(objective::Scalarized), // Soma ponderada de C_max, soma de C_j e T_max.
+}
+
+// Implementado à mão: o atributo `#[default]` impediria o rustfmt de alinhar os
+// comentários das variantes.
+#[allow(clippy::derivable_impls)]
+impl Default for Objective {
+    fn default() -> Self {
+        Objective::Makespan
+    }
+}
+
+impl Objective {
+    /// Implementação da função objetivo.
+    pub fn function(&self) -> &dyn ObjectiveFunction {
+        match self {
+            Objective::Makespan => &objective::Makespan,
+            Objective::TotalFlowtime => &objective::TotalFlowtime,
+            Objective::TotalTardiness => &objective::TotalTardiness,
+            Objective::MaxTardiness => &objective::MaxTardiness,
+            Objective::WeightedTardiness => &objective::WeightedTardiness,
+            Objective::WeightedFlowtime => &objective::WeightedFlowtime,
+            Objective::Scalarized(scalarized) => scalarized,
+        }
+    }
+}
+
+impl FromStr for Objective {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        if let Some(weights) = lower.strip_prefix("weighted:") {
+            let weights: Vec<f64> = weights
+                .split(',')
+                .map(|w| w.trim().parse::<f64>())
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|_| format!("Pesos inválidos em '{}'", s))?;
+            let weights: [f64; 3] = weights.try_into().map_err(|_| {
+                format!(
+                    "'{}' deve ter três pesos: makespan, tempo de fluxo total e atraso máximo",
+                    s
+                )
+            })?;
+            return objective::Scalarized::new(weights).map(Objective::Scalarized);
+        }
+        match lower.as_str() {
+            "makespan" => Ok(Objective::Makespan),
+            "flowtime" | "tft" => Ok(Objective::TotalFlowtime),
+            "tt" => Ok(Objective::TotalTardiness),
+            "tmax" => Ok(Objective::MaxTardiness),
+            "twt" | "tardiness" => Ok(Objective::WeightedTardiness),
+            "twft" | "wft" => Ok(Objective::WeightedFlowtime),
+            _ => Err(format!(
+                "Objetivo desconhecido '{}' (use makespan, flowtime, tt, tmax, twt, twft ou weighted:w1,w2,w3)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Objective::Scalarized(scalarized) => {
+                let [makespan, flowtime, tardiness] = scalarized.weights;
+                write!(f, "weighted:{},{},{}", makespan, flowtime, tardiness)
+            }
+            _ => write!(f, "{}", self.function().name()),
+        }
+    }
+}
 
 /// Representa uma instância do Problema de Escalonamento Flow Shop (FSSP).
 #[derive(Debug, Clone)]
 pub struct FSSPInstance {
-    pub n_jobs: usize,                   // Número de tarefas.
-    pub n_machines: usize,               // Número de máquinas.
-    pub processing_times: Vec<Vec<u32>>, // Tempos de processamento [tarefa][máquina].
+    pub n_jobs: usize,                          // Número de tarefas.
+    pub n_machines: usize,                      // Número de máquinas.
+    pub processing_times: Vec<Vec<Time>>,       // Tempos de processamento [tarefa][máquina].
+    pub release_dates: Option<Vec<Time>>,       // Liberação de cada tarefa, se definida.
+    pub due_dates: Option<Vec<Time>>,           // Data de entrega de cada tarefa, se definida.
+    pub weights: Option<Vec<Time>>,             // Peso nos objetivos ponderados (1 se ausente).
+    pub unavailability: Vec<Vec<(Time, Time)>>, // Manutenções [início, fim) de cada máquina.
+    pub objective: Objective,                   // Função objetivo usada por `evaluate`.
+    pub evaluations: EvaluationCounter,         // Avaliações feitas com esta cópia da instância.
+    pub time_decimals: u32,                     // Casas decimais dos tempos (veja `Time`).
+    pub uncertainty: Option<Uncertainty>,       // Cenários de tempos, se incertos.
+    pub sublots: Option<Vec<u32>>,              // Sublotes de cada tarefa, se divididas.
+    pub transfer_times: Option<Vec<Vec<Time>>>, // Transporte [tarefa][máquina] até a seguinte.
+}
+
+/// Contador de avaliações da função objetivo. Cada cópia da instância recebe um
+/// contador próprio, zerado, de modo que solvers construídos a partir de clones da
+/// mesma instância contam apenas as próprias avaliações.
+#[derive(Debug, Default)]
+pub struct EvaluationCounter(AtomicU64);
+
+impl EvaluationCounter {
+    /// Registra `count` avaliações.
+    #[inline]
+    pub fn add(&self, count: u64) {
+        self.0.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Número de avaliações registradas.
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl Clone for EvaluationCounter {
+    fn clone(&self) -> Self {
+        EvaluationCounter::default()
+    }
+}
+
+/// Indica se `row` (com os valores multiplicados por `scale`) está no formato de
+/// pares `máquina tempo` com as máquinas em ordem (`0 p0 1 p1 ...`), usado pelos
+/// benchmarks de Taillard e VRF.
+fn is_machine_time_pairs(row: &[Time], n_machines: usize, scale: Time) -> bool {
+    row.len() == 2 * n_machines
+        && row
+            .iter()
+            .step_by(2)
+            .enumerate()
+            .all(|(machine, &index)| index == machine as Time * scale)
+}
+
+/// Carrega uma instância FSSP de um arquivo. O formato é deduzido pela extensão e
+/// pelo conteúdo (veja `InstanceFormat::detect`); o formato próprio é descrito em
+/// `parse_instance`.
+#[cfg(feature = "fs")]
+pub fn load_instance(filepath: &str) -> Result<FSSPInstance> {
+    let path = Path::new(filepath);
+    let text = std::fs::read_to_string(path)?;
+    InstanceFormat::detect(path, &text).parse(&text)
 }
 
-/// Carrega uma instância FSSP de um arquivo.
-/// O arquivo deve conter N e M na primeira linha, seguidos pelos tempos de processamento.
-pub fn load_instance(filepath: &str) -> Result<FSSPInstance, std::io::Error> {
-    let file = File::open(Path::new(filepath))?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
+/// Interpreta uma instância FSSP a partir do conteúdo de um arquivo.
+/// O texto deve conter N e M na primeira linha, seguidos pelos tempos de processamento.
+/// Opcionalmente, as linhas seguintes podem conter seções iniciadas por uma palavra-chave
+/// e seguidas de um valor por tarefa: `release_dates` (instantes de liberação),
+/// `due_dates` (datas de entrega) e `weights` (pesos). Os pesos também podem ser dados
+/// por uma coluna extra após os M tempos de cada tarefa, presente em todas as linhas.
+/// Linhas `unavailable m início fim` definem intervalos de manutenção da máquina `m`
+/// (a partir de 0), um por linha. Linhas `transfer t1 ... t(M-1)` definem os tempos
+/// de transporte entre máquinas consecutivas de todas as tarefas, e linhas
+/// `transfer j t1 ... t(M-1)`, os da tarefa `j` (a partir de 0), que prevalecem.
+/// As linhas de tempos também são aceitas no formato dos benchmarks de Taillard e
+/// VRF (Vallada, Ruiz e Framinan), com pares `máquina tempo` (`0 p0 1 p1 ...`).
+/// Tempos, instantes de liberação, datas de entrega, intervalos de manutenção e
+/// tempos de transporte podem ter casas decimais (ex: `12.75`); os pesos são
+/// inteiros. Erros de formato indicam a linha e a coluna do problema.
+pub fn parse_instance(text: &str) -> Result<FSSPInstance> {
+    let mut lines = text.lines();
 
     // Lê N (número de tarefas) e M (número de máquinas) da primeira linha.
-    let first_line = lines.next().ok_or_else(|| {
-        std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Arquivo vazio ou mal formatado",
-        )
-    })??;
-    let parts: Vec<usize> = first_line
-        .split_whitespace()
-        .map(|s| s.parse().unwrap())
-        .collect();
+    let first_line = lines.next().ok_or_else(|| FsspError::Format {
+        line: 1,
+        message: "arquivo vazio ou mal formatado".to_string(),
+    })?;
+    let parts: Vec<usize> = tokens_with_columns(first_line)
+        .into_iter()
+        .map(|(column, token)| parse_token(1, column, token))
+        .collect::<Result<_>>()?;
     if parts.len() < 2 {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "A primeira linha deve conter N e M",
-        ));
+        return Err(FsspError::Format {
+            line: 1,
+            message: "a primeira linha deve conter N e M".to_string(),
+        });
     }
     let (n_jobs, n_machines) = (parts[0], parts[1]);
+    if n_jobs == 0 || n_machines == 0 {
+        return Err(FsspError::Format {
+            line: 1,
+            message: "a instância deve ter ao menos uma tarefa e uma máquina".to_string(),
+        });
+    }
+
+    // Casas decimais dos tempos: o maior número encontrado na matriz e nas seções
+    // de instantes (os pesos são inteiros).
+    let time_decimals = max_decimal_places(
+        text.lines()
+            .skip(1)
+            .enumerate()
+            .filter(|&(idx, line)| {
+                idx < n_jobs
+                    || ["release_dates", "due_dates", "unavailable", "transfer"]
+                        .iter()
+                        .any(|keyword| line.trim_start().starts_with(keyword))
+            })
+            .flat_map(|(_, line)| line.split_whitespace())
+            .filter(|token| token.parse::<f64>().is_ok()),
+    );
+    let scale = Time::pow(10, time_decimals);
 
     // Lê os tempos de processamento das N linhas seguintes.
     let mut processing_times = Vec::with_capacity(n_jobs);
-    for line in lines.take(n_jobs) {
-        let row: Vec<u32> = line?
-            .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
+    let mut column_weights = Vec::new();
+    for (idx, line) in lines.by_ref().take(n_jobs).enumerate() {
+        let line_number = idx + 2;
+        let tokens = tokens_with_columns(line);
+        let mut row: Vec<Time> = tokens
+            .iter()
+            .map(|&(column, token)| parse_time(line_number, column, token, time_decimals))
+            .collect::<Result<_>>()?;
+        if is_machine_time_pairs(&row, n_machines, scale) {
+            row = row.into_iter().skip(1).step_by(2).collect();
+        } else if row.len() == n_machines + 1 {
+            // A coluna extra é o peso da tarefa, que deve estar em todas as linhas.
+            if column_weights.len() != idx {
+                return Err(_missing_weight_column(line_number));
+            }
+            let (column, token) = tokens[n_machines];
+            column_weights.push(parse_token(line_number, column, token)?);
+            row.pop();
+        } else if !column_weights.is_empty() {
+            return Err(_missing_weight_column(line_number));
+        }
         // Valida se o número de tempos por linha corresponde a M.
         if row.len() != n_machines {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "Número de tempos em uma linha não corresponde ao número de máquinas.",
-            ));
+            return Err(FsspError::Format {
+                line: line_number,
+                message: format!(
+                    "esperados {} tempos de processamento (um por máquina), encontrados {}",
+                    n_machines,
+                    row.len()
+                ),
+            });
         }
         processing_times.push(row);
     }
 
     // Valida se o número de linhas de tempo lidas corresponde a N.
     if processing_times.len() != n_jobs {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Número de linhas de tarefas não corresponde ao especificado.",
-        ));
+        return Err(FsspError::Format {
+            line: processing_times.len() + 2,
+            message: format!(
+                "esperadas {} linhas de tarefas, encontradas {}",
+                n_jobs,
+                processing_times.len()
+            ),
+        });
     }
 
-    Ok(FSSPInstance {
+    // Seções opcionais após a matriz de tempos.
+    let mut release_dates = None;
+    let mut due_dates = None;
+    let weights_in_column = !column_weights.is_empty();
+    let mut weights = weights_in_column.then_some(column_weights);
+    let mut unavailability = vec![Vec::new(); n_machines];
+    let mut transfer_times: Option<Vec<Vec<Time>>> = None;
+    let mut job_transfers = Vec::new();
+    for (idx, line) in lines.enumerate() {
+        let line_number = idx + n_jobs + 2;
+        let tokens = tokens_with_columns(line);
+        let Some((&(_, keyword), values)) = tokens.split_first() else {
+            continue;
+        };
+        if keyword == "unavailable" {
+            unavailability_interval(line_number, values, time_decimals, &mut unavailability)?;
+            continue;
+        }
+        if keyword == "transfer" {
+            let (job, row) = transfer_row(line_number, values, time_decimals, n_jobs, n_machines)?;
+            match job {
+                Some(job) => job_transfers.push((job, row)),
+                None => transfer_times = Some(vec![row; n_jobs]),
+            }
+            continue;
+        }
+        let section = match keyword {
+            "release_dates" => &mut release_dates,
+            "due_dates" => &mut due_dates,
+            "weights" if weights_in_column => {
+                return Err(FsspError::Format {
+                    line: line_number,
+                    message: "pesos já definidos pela coluna extra das linhas de tarefas"
+                        .to_string(),
+                })
+            }
+            "weights" => &mut weights,
+            _ => continue, // Conteúdo adicional é ignorado, como nos formatos usuais.
+        };
+        let row: Vec<Time> = values
+            .iter()
+            .map(|&(column, token)| match keyword {
+                "weights" => parse_token(line_number, column, token),
+                _ => parse_time(line_number, column, token, time_decimals),
+            })
+            .collect::<Result<_>>()?;
+        if row.len() != n_jobs {
+            return Err(FsspError::Format {
+                line: line_number,
+                message: format!(
+                    "esperados {} valores em '{}' (um por tarefa), encontrados {}",
+                    n_jobs,
+                    keyword,
+                    row.len()
+                ),
+            });
+        }
+        *section = Some(row);
+    }
+
+    unavailability.iter_mut().for_each(merge_intervals);
+    // Os tempos de uma tarefa prevalecem sobre os de todas as tarefas.
+    if !job_transfers.is_empty() {
+        let rows = transfer_times
+            .get_or_insert_with(|| vec![vec![0; n_machines.saturating_sub(1)]; n_jobs]);
+        for (job, row) in job_transfers {
+            rows[job] = row;
+        }
+    }
+
+    let instance = FSSPInstance {
         n_jobs,
         n_machines,
         processing_times,
-    })
+        release_dates,
+        due_dates,
+        weights,
+        unavailability,
+        objective: Objective::Makespan,
+        evaluations: EvaluationCounter::default(),
+        time_decimals,
+        uncertainty: None,
+        sublots: None,
+        transfer_times,
+    };
+    instance.check_horizon()?;
+    Ok(instance)
+}
+
+/// Lê os valores de uma linha `transfer`: `M - 1` tempos de transporte de todas as
+/// tarefas ou, com um valor a mais, a tarefa seguida dos seus tempos.
+fn transfer_row(
+    line_number: usize,
+    values: &[(usize, &str)],
+    time_decimals: u32,
+    n_jobs: usize,
+    n_machines: usize,
+) -> Result<(Option<usize>, Vec<Time>)> {
+    let n_transfers = n_machines.saturating_sub(1);
+    let (job, times) = match values.len() {
+        len if len == n_transfers => (None, values),
+        len if len == n_transfers + 1 => {
+            let (column, token) = values[0];
+            let job: usize = parse_token(line_number, column, token)?;
+            if job >= n_jobs {
+                return Err(FsspError::Format {
+                    line: line_number,
+                    message: format!(
+                        "tarefa {} fora do intervalo da instância (0 a {})",
+                        job,
+                        n_jobs - 1
+                    ),
+                });
+            }
+            (Some(job), &values[1..])
+        }
+        len => {
+            return Err(FsspError::Format {
+                line: line_number,
+                message: format!(
+                    "esperados {} tempos em 'transfer' (um entre cada par de máquinas \
+                     consecutivas), precedidos ou não da tarefa, encontrados {}",
+                    n_transfers, len
+                ),
+            })
+        }
+    };
+    let row = times
+        .iter()
+        .map(|&(column, token)| parse_time(line_number, column, token, time_decimals))
+        .collect::<Result<_>>()?;
+    Ok((job, row))
+}
+
+/// Ordena e une os intervalos de indisponibilidade sobrepostos de uma máquina.
+fn merge_intervals(intervals: &mut Vec<(Time, Time)>) {
+    intervals.sort_unstable();
+    let mut merged: Vec<(Time, Time)> = Vec::with_capacity(intervals.len());
+    for &(start, end) in intervals.iter() {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    *intervals = merged;
+}
+
+/// Erro de uma linha de tarefa cuja coluna de peso difere das linhas anteriores.
+fn _missing_weight_column(line: usize) -> FsspError {
+    FsspError::Format {
+        line,
+        message: "a coluna de pesos deve estar presente em todas as linhas de tarefas".to_string(),
+    }
+}
+
+/// Lê os valores de uma linha `unavailable m início fim`, acrescentando o intervalo à
+/// máquina correspondente.
+fn unavailability_interval(
+    line_number: usize,
+    values: &[(usize, &str)],
+    time_decimals: u32,
+    unavailability: &mut [Vec<(Time, Time)>],
+) -> Result<()> {
+    let error = |message: String| FsspError::Format {
+        line: line_number,
+        message,
+    };
+    let [(machine_column, machine), (start_column, start), (end_column, end)] = values else {
+        return Err(error(format!(
+            "esperados 3 valores em 'unavailable' (máquina, início e fim), encontrados {}",
+            values.len()
+        )));
+    };
+    let machine: usize = parse_token(line_number, *machine_column, machine)?;
+    let start = parse_time(line_number, *start_column, start, time_decimals)?;
+    let end = parse_time(line_number, *end_column, end, time_decimals)?;
+    if machine >= unavailability.len() {
+        return Err(error(format!(
+            "máquina {} fora do intervalo da instância (0 a {})",
+            machine,
+            unavailability.len() - 1
+        )));
+    }
+    if start >= end {
+        return Err(error(format!(
+            "intervalo de indisponibilidade vazio ou invertido ({} a {})",
+            TimeValue::new(start, time_decimals),
+            TimeValue::new(end, time_decimals)
+        )));
+    }
+    unavailability[machine].push((start, end));
+    Ok(())
+}
+
+/// Carrega uma ou mais sequências de tarefas de um arquivo (veja `parse_sequences`).
+#[cfg(feature = "fs")]
+pub fn load_sequences(filepath: &str) -> Result<Vec<Vec<Job>>> {
+    parse_sequences(&std::fs::read_to_string(Path::new(filepath))?)
+}
+
+/// Interpreta uma ou mais sequências de tarefas, uma por linha, com as
+/// tarefas numeradas a partir de 0 (como nos arquivos de resultado). Linhas vazias ou
+/// iniciadas por `#` são ignoradas. Em linhas rotuladas (`rótulo: valores`), apenas a
+/// "Melhor Sequencia" (ou "Best Sequence", nos resultados em inglês) e as linhas
+/// rotuladas por um número (o valor da função objetivo, nos arquivos de população)
+/// são consideradas, de modo que arquivos de resultado e de população podem ser
+/// usados diretamente. O valor do rótulo é ignorado.
+pub fn parse_sequences(text: &str) -> Result<Vec<Vec<Job>>> {
+    let mut sequences = Vec::new();
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_number = idx + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        // Separa o rótulo, se houver, preservando a coluna dos valores.
+        let (offset, values) = match line.split_once(':') {
+            Some((label, values))
+                if matches!(label.trim(), "Melhor Sequencia" | "Best Sequence")
+                    || label.trim().parse::<f64>().is_ok() =>
+            {
+                (label.chars().count() + 1, values)
+            }
+            Some(_) => continue,
+            None => (0, line),
+        };
+        let sequence: Vec<Job> = tokens_with_columns(values)
+            .into_iter()
+            .map(|(column, token)| parse_token(line_number, offset + column, token))
+            .collect::<Result<_>>()?;
+        sequences.push(sequence);
+    }
+
+    if sequences.is_empty() {
+        return Err(FsspError::Format {
+            line: 1,
+            message: "nenhuma sequência encontrada no arquivo".to_string(),
+        });
+    }
+    Ok(sequences)
 }
 
 impl FSSPInstance {
+    /// Define a função objetivo. Os objetivos de atraso exigem datas de entrega.
+    pub fn set_objective(&mut self, objective: Objective) -> Result<()> {
+        objective.function().check(self)?;
+        self.objective = objective;
+        Ok(())
+    }
+
+    /// Fator pelo qual os tempos da instância foram multiplicados (10^`time_decimals`).
+    pub fn time_scale(&self) -> Time {
+        Time::pow(10, self.time_decimals)
+    }
+
+    /// Valor de tempo (ou da função objetivo) nas unidades originais da instância.
+    pub fn time_value(&self, value: Time) -> TimeValue {
+        TimeValue::new(value, self.time_decimals)
+    }
+
+    /// Interpreta um instante nas unidades da instância (ex: de uma opção da linha de
+    /// comando), com até `time_decimals` casas decimais.
+    pub fn parse_time_value(&self, token: &str) -> Result<Time> {
+        parse_time(0, 0, token, self.time_decimals)
+    }
+
+    /// Acrescenta uma tarefa com os tempos de processamento informados, retornando o
+    /// seu índice. A tarefa é liberada em `release` e, nas seções definidas pela
+    /// instância, tem data de entrega `due_date` (obrigatória se a instância define
+    /// datas de entrega), peso `weight` (1 se `None`) e tempos de transporte
+    /// `transfer_times` (`M - 1` valores, obrigatórios se os tempos da instância
+    /// variam por tarefa; sem eles, valem os comuns a todas as tarefas). A instância
+    /// só é alterada se a tarefa for válida, inclusive quanto ao horizonte (veja
+    /// `check_horizon`).
+    pub fn push_job(
+        &mut self,
+        processing_times: Vec<Time>,
+        release: Time,
+        due_date: Option<Time>,
+        weight: Option<Time>,
+        transfer_times: Option<Vec<Time>>,
+    ) -> Result<Job> {
+        if processing_times.len() != self.n_machines {
+            return Err(FsspError::Instance(format!(
+                "a tarefa nova possui {} tempos de processamento, mas a instância possui {} máquinas",
+                processing_times.len(),
+                self.n_machines
+            )));
+        }
+        if self.uncertainty.is_some() {
+            return Err(FsspError::Instance(
+                "não é possível acrescentar tarefas a uma instância com cenários de tempos"
+                    .to_string(),
+            ));
+        }
+        let due_date = match (&self.due_dates, due_date) {
+            (Some(_), Some(due_date)) => Some(due_date),
+            (None, None) => None,
+            (Some(_), None) => {
+                return Err(FsspError::Instance(
+                    "a instância define datas de entrega: informe a data da tarefa nova"
+                        .to_string(),
+                ))
+            }
+            (None, Some(_)) => {
+                return Err(FsspError::Instance(
+                    "a instância não define datas de entrega".to_string(),
+                ))
+            }
+        };
+        if self.weights.is_none() && weight.is_some_and(|w| w != 1) {
+            return Err(FsspError::Instance(
+                "a instância não define pesos".to_string(),
+            ));
+        }
+        let n_transfers = self.n_machines.saturating_sub(1);
+        let transfer_times =
+            match (&self.transfer_times, transfer_times) {
+                (Some(_), Some(row)) if row.len() == n_transfers => Some(row),
+                (Some(_), Some(row)) => {
+                    return Err(FsspError::Instance(format!(
+                        "a tarefa nova possui {} tempos de transporte, esperados {}",
+                        row.len(),
+                        n_transfers
+                    )))
+                }
+                (Some(rows), None) if rows.windows(2).all(|pair| pair[0] == pair[1]) => Some(
+                    rows.first()
+                        .cloned()
+                        .unwrap_or_else(|| vec![0; n_transfers]),
+                ),
+                (Some(_), None) => return Err(FsspError::Instance(
+                    "a instância define tempos de transporte por tarefa: informe os da tarefa nova"
+                        .to_string(),
+                )),
+                (None, None) => None,
+                (None, Some(_)) => {
+                    return Err(FsspError::Instance(
+                        "a instância não define tempos de transporte".to_string(),
+                    ))
+                }
+            };
+        // Horizonte com a tarefa nova: a liberação pode adiar o início e os seus
+        // tempos somam-se aos demais.
+        let base = self._horizon_base();
+        let horizon = self.horizon().and_then(|horizon| {
+            processing_times
+                .iter()
+                .chain(transfer_times.iter().flatten())
+                .try_fold(
+                    horizon.checked_add(release.saturating_sub(base))?,
+                    |total, &p| total.checked_add(p),
+                )
+        });
+        if horizon.is_none() {
+            return Err(FsspError::Instance(format!(
+                "a tarefa nova faz a soma dos tempos exceder o maior instante representável ({})",
+                Time::MAX
+            )));
+        }
+
+        if release > 0 && self.release_dates.is_none() {
+            self.release_dates = Some(vec![0; self.n_jobs]);
+        }
+        self.processing_times.push(processing_times);
+        if let Some(release_dates) = &mut self.release_dates {
+            release_dates.push(release);
+        }
+        if let (Some(due_dates), Some(due_date)) = (&mut self.due_dates, due_date) {
+            due_dates.push(due_date);
+        }
+        if let Some(weights) = &mut self.weights {
+            weights.push(weight.unwrap_or(1));
+        }
+        if let Some(sublots) = &mut self.sublots {
+            sublots.push(1);
+        }
+        if let (Some(all), Some(row)) = (&mut self.transfer_times, transfer_times) {
+            all.push(row);
+        }
+        self.n_jobs += 1;
+        Ok((self.n_jobs - 1) as Job)
+    }
+
+    /// Acrescenta uma indisponibilidade [`start`, `end`) à máquina, unindo-a aos
+    /// intervalos que ela sobrepõe.
+    pub fn add_unavailability(&mut self, machine: usize, start: Time, end: Time) {
+        let intervals = &mut self.unavailability[machine];
+        intervals.push((start, end));
+        merge_intervals(intervals);
+    }
+
+    /// Limite superior do término de qualquer escalonamento semiativo: após a última
+    /// liberação e a última manutenção, sempre há uma operação em processamento.
+    /// Retorna `None` se o valor não cabe em `Time`.
+    pub fn horizon(&self) -> Option<Time> {
+        self._horizon_with(&self.processing_times)
+    }
+
+    /// Horizonte (veja `horizon`) com os tempos de processamento informados.
+    pub(crate) fn _horizon_with(&self, processing_times: &[Vec<Time>]) -> Option<Time> {
+        processing_times
+            .iter()
+            .flatten()
+            .chain(self.transfer_times.iter().flatten().flatten())
+            .try_fold(self._horizon_base(), |total, &p| total.checked_add(p))
+    }
+
+    /// Início do horizonte: a última liberação ou o fim da última manutenção.
+    fn _horizon_base(&self) -> Time {
+        let last_release = self
+            .release_dates
+            .as_ref()
+            .and_then(|r| r.iter().copied().max())
+            .unwrap_or(0);
+        let last_maintenance = self
+            .unavailability
+            .iter()
+            .flatten()
+            .map(|&(_, end)| end)
+            .max()
+            .unwrap_or(0);
+        max(last_release, last_maintenance)
+    }
+
+    /// Verifica se os instantes de qualquer escalonamento da instância cabem em
+    /// `Time` (veja `horizon`), de modo que o cálculo do makespan não estoura.
+    pub fn check_horizon(&self) -> Result<()> {
+        match self.horizon() {
+            Some(_) => Ok(()),
+            None => Err(FsspError::Instance(format!(
+                "a soma dos tempos de processamento excede o maior instante representável ({})",
+                Time::MAX
+            ))),
+        }
+    }
+
+    /// Multiplica todos os tempos da instância (processamento, liberações, datas de
+    /// entrega e manutenções) para representá-los com `decimals` casas decimais, que
+    /// não pode ser menor que o número atual. Usado para combinar instâncias com
+    /// precisões diferentes.
+    pub fn rescale(&mut self, decimals: u32) -> Result<()> {
+        if decimals < self.time_decimals || decimals > MAX_TIME_DECIMALS {
+            return Err(FsspError::Instance(format!(
+                "não é possível representar os tempos com {} casas decimais",
+                decimals
+            )));
+        }
+        let factor = Time::pow(10, decimals - self.time_decimals);
+        let overflow = || {
+            FsspError::Instance(format!(
+                "os tempos não cabem no tipo de tempo com {} casas decimais",
+                decimals
+            ))
+        };
+        let scale = |value: &mut Time| -> Result<()> {
+            *value = value.checked_mul(factor).ok_or_else(overflow)?;
+            Ok(())
+        };
+        for value in self
+            .processing_times
+            .iter_mut()
+            .flatten()
+            .chain(self.release_dates.iter_mut().flatten())
+            .chain(self.due_dates.iter_mut().flatten())
+            .chain(self.transfer_times.iter_mut().flatten().flatten())
+        {
+            scale(value)?;
+        }
+        for (start, end) in self.unavailability.iter_mut().flatten() {
+            scale(start)?;
+            scale(end)?;
+        }
+        self.time_decimals = decimals;
+        self.check_horizon()
+    }
+
+    /// Define os cenários de tempos de processamento avaliados por `evaluate` (veja
+    /// `robust::Uncertainty`), que devem ter as dimensões e a escala da instância.
+    pub fn set_uncertainty(&mut self, uncertainty: Uncertainty) -> Result<()> {
+        for times in &uncertainty.scenarios {
+            if times.len() != self.n_jobs || times.iter().any(|t| t.len() != self.n_machines) {
+                return Err(FsspError::Instance(format!(
+                    "os cenários devem ter {} tarefas e {} máquinas",
+                    self.n_jobs, self.n_machines
+                )));
+            }
+            if self._horizon_with(times).is_none() {
+                return Err(FsspError::Instance(format!(
+                    "a soma dos tempos de um cenário excede o maior instante representável ({})",
+                    Time::MAX
+                )));
+            }
+        }
+        self.uncertainty = Some(uncertainty);
+        Ok(())
+    }
+
+    /// Indica se `evaluate` retorna uma estimativa que pode mudar entre chamadas
+    /// para a mesma sequência (simulação com reamostragem adaptativa): com mais
+    /// replicações, uma sequência promissora é reestimada com mais precisão.
+    pub fn has_adaptive_estimates(&self) -> bool {
+        self.uncertainty
+            .as_ref()
+            .is_some_and(|uncertainty| uncertainty.sampling.is_some())
+    }
+
+    /// Cópia da instância que compartilha com esta a melhor média conhecida da
+    /// reamostragem adaptativa (veja `AdaptiveSampling::share`), usada pelas ilhas de
+    /// um mesmo modelo. Sem simulação, equivale a `clone`.
+    pub fn share_sampling(&self) -> Self {
+        let mut copy = self.clone();
+        if let (Some(copy), Some(uncertainty)) = (&mut copy.uncertainty, &self.uncertainty) {
+            copy.sampling = uncertainty.sampling.as_ref().map(|s| s.share());
+        }
+        copy
+    }
+
+    /// Instante a partir do qual a tarefa pode iniciar na primeira máquina (0 se a
+    /// instância não define instantes de liberação).
+    pub fn release_date(&self, job: usize) -> Time {
+        self.release_dates.as_ref().map_or(0, |r| r[job])
+    }
+
+    /// Peso da tarefa nos objetivos ponderados (1 se a instância não define pesos).
+    pub fn weight(&self, job: usize) -> Time {
+        self.weights.as_ref().map_or(1, |w| w[job])
+    }
+
+    /// Número de sublotes da tarefa (1 se a instância não divide as tarefas).
+    #[inline]
+    pub fn sublots(&self, job: usize) -> u32 {
+        self.sublots.as_ref().map_or(1, |s| s[job])
+    }
+
+    /// Tempo de transporte da tarefa da máquina `machine` até a seguinte (0 se a
+    /// instância não define tempos de transporte).
+    #[inline]
+    pub fn transfer_time(&self, job: usize, machine: usize) -> Time {
+        self.transfer_times.as_ref().map_or(0, |t| t[job][machine])
+    }
+
+    /// Divide cada tarefa no número de sublotes informado (lot streaming): os sublotes
+    /// de uma tarefa são processados em sequência em cada máquina e cada um segue
+    /// para a máquina seguinte assim que termina, sobrepondo o processamento da
+    /// tarefa em máquinas consecutivas. Os sublotes têm tamanhos iguais: o tempo de
+    /// cada operação é repartido entre eles, com as unidades de tempo restantes nos
+    /// primeiros (veja `sublot_time`). A sequência continua a ser uma permutação das
+    /// tarefas, de modo que todos os algoritmos otimizam a instância dividida.
+    pub fn set_sublots(&mut self, sublots: Vec<u32>) -> Result<()> {
+        if sublots.len() != self.n_jobs {
+            return Err(FsspError::Instance(format!(
+                "{} números de sublotes informados, mas a instância possui {} tarefas",
+                sublots.len(),
+                self.n_jobs
+            )));
+        }
+        if let Some(job) = sublots.iter().position(|&lots| lots == 0) {
+            return Err(FsspError::Instance(format!(
+                "a tarefa {} deve ter ao menos um sublote",
+                job
+            )));
+        }
+        self.sublots = sublots.iter().any(|&lots| lots > 1).then_some(sublots);
+        Ok(())
+    }
+
+    /// Primeiro instante, a partir de `ready`, em que uma operação de duração `duration`
+    /// cabe inteira na máquina sem sobrepor uma indisponibilidade. Operações não são
+    /// interrompidas: se não couberem antes da manutenção, começam após seu término.
+    #[inline]
+    pub fn earliest_start(&self, machine: usize, ready: Time, duration: Time) -> Time {
+        let mut start = ready;
+        for &(down_start, down_end) in &self.unavailability[machine] {
+            if start + duration <= down_start {
+                break;
+            }
+            if start < down_end {
+                start = down_end;
+            }
+        }
+        start
+    }
+
+    /// Avalia a sequência segundo a função objetivo da instância (menor é melhor).
+    /// É a avaliação usada por todos os algoritmos; sequências parciais são aceitas.
+    /// Com cenários de tempos (`uncertainty`), o valor é a agregação dos valores em
+    /// cada cenário. Cada chamada é registrada no contador de avaliações da instância.
+    pub fn evaluate(&self, sequence: &[Job]) -> Time {
+        self.evaluations.add(1);
+        if let Some(uncertainty) = &self.uncertainty {
+            return uncertainty.aggregate(
+                uncertainty
+                    .scenarios
+                    .iter()
+                    .map(|times| self.evaluate_scenario(times, sequence)),
+            );
+        }
+        self.objective.function().evaluate(self, sequence)
+    }
+
+    /// Avalia uma população inteira de uma vez, retornando o valor de cada sequência
+    /// na mesma ordem. As sequências são avaliadas em paralelo com o recurso
+    /// `parallel`; é o ponto de avaliação em lote dos algoritmos populacionais, útil
+    /// em populações grandes. Com o recurso `gpu`, populações a partir de
+    /// `gpu::MIN_GPU_POPULATION` sequências completas de instâncias suportadas (veja
+    /// `GpuEvaluator::supports`) são avaliadas na GPU, se houver adaptador.
+    pub fn evaluate_population<S: AsRef<[Job]> + Sync>(&self, sequences: &[S]) -> Vec<Time> {
+        #[cfg(feature = "gpu")]
+        if sequences.len() >= crate::gpu::MIN_GPU_POPULATION {
+            if let Some(values) =
+                crate::gpu::GpuEvaluator::shared().and_then(|gpu| gpu.makespans(self, sequences))
+            {
+                return values;
+            }
+        }
+        maybe_par_iter!(sequences)
+            .map(|sequence| self.evaluate(sequence.as_ref()))
+            .collect()
+    }
+
+    /// Valor da função objetivo da sequência com os tempos de processamento de um
+    /// cenário.
+    pub(crate) fn evaluate_scenario(
+        &self,
+        processing_times: &[Vec<Time>],
+        sequence: &[Job],
+    ) -> Time {
+        self.objective
+            .function()
+            .evaluate_with(self, processing_times, sequence)
+    }
+
+    /// Percorre a sequência com os tempos de processamento informados, chamando
+    /// `visit(tarefa, término)` com o término de cada tarefa na última máquina.
+    /// `visit` não deve avaliar outras sequências (o vetor de trabalho da thread
+    /// está em uso).
+    pub fn for_each_completion(
+        &self,
+        processing_times: &[Vec<Time>],
+        sequence: &[Job],
+        mut visit: impl FnMut(Job, Time),
+    ) {
+        MAKESPAN_BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            buffer.clear();
+            buffer.resize(self.n_machines, 0);
+            let c = buffer.as_mut_slice();
+            for &job in sequence {
+                self._append_operations(c, job, &processing_times[job as usize]);
+                visit(job, c[self.n_machines - 1]);
+            }
+        })
+    }
+
+    /// Calcula o atraso ponderado total, soma de `w_j * max(0, C_j - d_j)` sobre as
+    /// tarefas da sequência, onde `C_j` é o término da tarefa na última máquina.
+    /// Sem datas de entrega, o atraso é zero; sem pesos, todos valem 1.
+    pub fn calculate_total_weighted_tardiness(&self, sequence: &[Job]) -> Time {
+        let Some(due_dates) = &self.due_dates else {
+            return 0;
+        };
+        MAKESPAN_BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            buffer.clear();
+            buffer.resize(self.n_machines, 0);
+            let c = buffer.as_mut_slice();
+
+            let mut total: Time = 0;
+            for &job in sequence {
+                self._append_job(c, job);
+                let tardiness = c[self.n_machines - 1].saturating_sub(due_dates[job as usize]);
+                total = total.saturating_add(self.weight(job as usize).saturating_mul(tardiness));
+            }
+            total
+        })
+    }
+
     /// Calcula o **Makespan** (tempo total de conclusão) para uma dada sequência de tarefas.
     /// O Makespan é o tempo em que a última tarefa é finalizada na última máquina.
-    pub fn calculate_makespan(&self, sequence: &[usize]) -> u32 {
-        // Matriz 'c' armazena os tempos de conclusão: c[máquina][tarefa_na_sequência].
-        let mut c = vec![vec![0; self.n_jobs]; self.n_machines];
+    /// Sequências parciais (com apenas parte das tarefas) também são aceitas.
+    ///
+    /// Usa um buffer por thread reaproveitado entre chamadas, evitando alocações.
+    /// Em instâncias sem liberações nem manutenções, as tarefas são processadas em
+    /// blocos de quatro, com as cadeias de dependência intercaladas.
+    pub fn calculate_makespan(&self, sequence: &[Job]) -> Time {
+        MAKESPAN_BUFFER
+            .with(|buffer| self.calculate_makespan_with_buffer(sequence, &mut buffer.borrow_mut()))
+    }
+
+    /// Calcula o makespan usando `buffer` como vetor de trabalho (redimensionado
+    /// conforme necessário), permitindo reaproveitá-lo entre avaliações.
+    pub fn calculate_makespan_with_buffer(&self, sequence: &[Job], buffer: &mut Vec<Time>) -> Time {
+        if sequence.is_empty() {
+            return 0;
+        }
+
+        // 'c[i]' armazena o tempo de conclusão da última tarefa processada na máquina i.
+        // Cada tarefa atualiza o vetor no lugar, substituindo a matriz completa
+        // c[máquina][tarefa_na_sequência].
+        buffer.clear();
+        buffer.resize(self.n_machines, 0);
+        let c = buffer.as_mut_slice();
 
-        // Preenche a matriz de tempos de conclusão.
-        for j in 0..self.n_jobs {
+        if self._is_plain() {
+            // Sem liberações nem manutenções: as tarefas são acrescentadas em blocos
+            // de quatro, intercalando as cadeias de dependência (veja `_append_four`).
+            let blocked = if self.n_machines >= 3 {
+                sequence.len() / 4 * 4
+            } else {
+                0
+            };
+            let (blocks, rest) = sequence.split_at(blocked);
+            for block in blocks.chunks_exact(4) {
+                let times = |k: usize| self.processing_times[block[k] as usize].as_slice();
+                _append_four(c, [times(0), times(1), times(2), times(3)]);
+            }
+            for &job in rest {
+                _append_plain(c, &self.processing_times[job as usize]);
+            }
+            return c[self.n_machines - 1];
+        }
+
+        for &job_index in sequence {
             // Itera sobre as tarefas na sequência.
-            for i in 0..self.n_machines {
-                // Itera sobre as máquinas.
-                let job_index = sequence[j]; // ID da tarefa original.
-                let p_time = self.processing_times[job_index][i]; // Tempo de processamento.
-
-                if i == 0 && j == 0 {
-                    c[i][j] = p_time; // Primeira tarefa na primeira máquina.
-                } else if i == 0 {
-                    c[i][j] = c[i][j - 1] + p_time; // Primeira máquina, tarefas seguintes.
-                } else if j == 0 {
-                    c[i][j] = c[i - 1][j] + p_time; // Primeira tarefa, máquinas seguintes.
-                } else {
-                    // Outros casos: tempo de conclusão é o máximo entre:
-                    // - Término da mesma tarefa na máquina anterior.
-                    // - Término da tarefa anterior na mesma máquina.
-                    c[i][j] = max(c[i - 1][j], c[i][j - 1]) + p_time;
+            self._append_job(c, job_index);
+        }
+
+        // O Makespan final é o tempo de conclusão da última tarefa na última máquina.
+        c[self.n_machines - 1]
+    }
+
+    /// Limitante inferior de Taillard para o makespan: o maior entre a carga de cada
+    /// máquina somada ao menor tempo antes dela (cabeça) e ao menor tempo depois dela
+    /// (cauda), e o tempo total de processamento de cada tarefa. Não considera
+    /// liberações nem manutenções.
+    pub fn lower_bound(&self) -> Time {
+        let machine_bound = (0..self.n_machines)
+            .map(|machine| {
+                let head = self
+                    .processing_times
+                    .iter()
+                    .map(|times| times[..machine].iter().sum::<Time>())
+                    .min()
+                    .unwrap_or(0);
+                let tail = self
+                    .processing_times
+                    .iter()
+                    .map(|times| times[machine + 1..].iter().sum::<Time>())
+                    .min()
+                    .unwrap_or(0);
+                let load: Time = self.processing_times.iter().map(|t| t[machine]).sum();
+                head + load + tail
+            })
+            .max()
+            .unwrap_or(0);
+        let job_bound = self
+            .processing_times
+            .iter()
+            .map(|times| times.iter().sum::<Time>())
+            .max()
+            .unwrap_or(0);
+        max(machine_bound, job_bound)
+    }
+
+    /// Calcula a frente de máquinas após as primeiras `k` tarefas da sequência (ou
+    /// todas, se a sequência for menor): o término da última tarefa em cada máquina.
+    /// Permite estender prefixos sem reavaliá-los, como em heurísticas construtivas.
+    pub fn calculate_makespan_prefix(&self, sequence: &[Job], k: usize) -> MachineFront {
+        let mut front = MachineFront::new(self.n_machines);
+        for &job in &sequence[..k.min(sequence.len())] {
+            front.push(self, job);
+        }
+        front
+    }
+
+    /// Indica se a instância não tem liberações, manutenções, sublotes nem tempos de
+    /// transporte, caso em que cada operação começa assim que a máquina e a operação
+    /// anterior da tarefa terminam.
+    #[inline]
+    pub(crate) fn _is_plain(&self) -> bool {
+        self.release_dates.is_none()
+            && self.unavailability.iter().all(Vec::is_empty)
+            && self.sublots.is_none()
+            && self.transfer_times.is_none()
+    }
+
+    /// Acrescenta `job` ao escalonamento representado por `c`, em que `c[i]` é o
+    /// término da última tarefa processada na máquina `i`.
+    #[inline]
+    fn _append_job(&self, c: &mut [Time], job: Job) {
+        self._append_operations(c, job, &self.processing_times[job as usize]);
+    }
+
+    /// Acrescenta `job`, com os tempos de processamento `times`, ao escalonamento
+    /// representado por `c` (veja `_append_job`). Com sublotes, cada sublote é
+    /// acrescentado como uma tarefa, com a sua parte dos tempos.
+    #[inline]
+    fn _append_operations(&self, c: &mut [Time], job: Job, times: &[Time]) {
+        let release = self.release_date(job as usize);
+        let lots = self.sublots(job as usize);
+        for lot in 0..lots {
+            let time = |i: usize| sublot_time(times[i], lots, lot);
+            // Primeira máquina: término do sublote anterior (ou liberação da tarefa,
+            // se posterior) + processamento.
+            c[0] = self.earliest_start(0, max(c[0], release), time(0)) + time(0);
+            for i in 1..self.n_machines {
+                // A operação começa no máximo entre:
+                // - Chegada do mesmo sublote da máquina anterior (c[i - 1] mais o
+                //   transporte).
+                // - Término do sublote anterior na mesma máquina (c[i]).
+                // adiada, se necessário, para depois de uma manutenção da máquina.
+                let arrival = c[i - 1] + self.transfer_time(job as usize, i - 1);
+                c[i] = self.earliest_start(i, max(arrival, c[i]), time(i)) + time(i);
+            }
+        }
+    }
+}
+
+/// Tempo do sublote `lot` (a partir de 0) de uma operação de duração `time` dividida
+/// em `lots` sublotes iguais: as `time % lots` unidades restantes ficam com os
+/// primeiros sublotes, de modo que a soma dos sublotes é a duração da operação.
+#[inline]
+pub fn sublot_time(time: Time, lots: u32, lot: u32) -> Time {
+    if lots == 1 {
+        return time;
+    }
+    let (lots, lot) = (Time::from(lots), Time::from(lot));
+    time / lots + Time::from(lot < time % lots)
+}
+
+/// Acrescenta uma tarefa com tempos `times` ao escalonamento `c` de uma instância
+/// sem liberações, manutenções, sublotes nem tempos de transporte.
+#[inline]
+fn _append_plain(c: &mut [Time], times: &[Time]) {
+    let mut ready = 0;
+    for (completion, &time) in c.iter_mut().zip(times) {
+        ready = max(ready, *completion) + time;
+        *completion = ready;
+    }
+}
+
+/// Acrescenta quatro tarefas consecutivas, com tempos `times`, ao escalonamento `c`
+/// de uma instância sem liberações nem manutenções (ao menos três máquinas). Os
+/// cálculos das tarefas são defasados de uma máquina: enquanto a tarefa `k` avança
+/// para a máquina `i`, a tarefa `k + 1` é escalonada na máquina `i - 1`, que a
+/// anterior acabou de liberar. As quatro cadeias de dependência são independentes
+/// em cada passo, o que permite ao processador executá-las em paralelo; em
+/// instâncias grandes, a avaliação fica mais de duas vezes mais rápida que tarefa a
+/// tarefa.
+#[inline]
+fn _append_four(c: &mut [Time], times: [&[Time]; 4]) {
+    let m = c.len();
+    let [a, b, d, e] = times.map(|t| &t[..m]);
+    // `r[k]`: término da tarefa `k` na última máquina em que já foi escalonada.
+    // Início: a tarefa `k` ocupa as máquinas 0 a `2 - k`.
+    let mut r0 = c[0] + a[0];
+    let mut r1 = r0 + b[0];
+    r0 = max(r0, c[1]) + a[1];
+    let mut r2 = r1 + d[0];
+    r1 = max(r1, r0) + b[1];
+    r0 = max(r0, c[2]) + a[2];
+    let mut r3: Time = 0;
+    // Regime: no passo `s`, a tarefa `k` é escalonada na máquina `s - k`.
+    for s in 3..m {
+        r3 = max(r3, r2) + e[s - 3];
+        c[s - 3] = r3;
+        r2 = max(r2, r1) + d[s - 2];
+        r1 = max(r1, r0) + b[s - 1];
+        r0 = max(r0, c[s]) + a[s];
+    }
+    // Fim: as tarefas 1 a 3 concluem as últimas máquinas.
+    r3 = max(r3, r2) + e[m - 3];
+    c[m - 3] = r3;
+    r2 = max(r2, r1) + d[m - 2];
+    r1 = max(r1, r0) + b[m - 1];
+    r3 = max(r3, r2) + e[m - 2];
+    c[m - 2] = r3;
+    r2 = max(r2, r1) + d[m - 1];
+    c[m - 1] = max(r3, r2) + e[m - 1];
+}
+
+/// Frente de máquinas de uma sequência parcial: instante em que cada máquina
+/// termina a última tarefa do prefixo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MachineFront {
+    pub completion_times: Vec<Time>, // Término da última tarefa do prefixo em cada máquina.
+}
+
+impl MachineFront {
+    /// Frente vazia (nenhuma tarefa escalonada) para `n_machines` máquinas.
+    pub fn new(n_machines: usize) -> Self {
+        MachineFront {
+            completion_times: vec![0; n_machines],
+        }
+    }
+
+    /// Acrescenta `job` ao final do prefixo.
+    pub fn push(&mut self, instance: &FSSPInstance, job: Job) {
+        instance._append_job(&mut self.completion_times, job);
+    }
+
+    /// Frente resultante de acrescentar `job`, sem alterar a atual.
+    pub fn with_job(&self, instance: &FSSPInstance, job: Job) -> MachineFront {
+        let mut front = self.clone();
+        front.push(instance, job);
+        front
+    }
+
+    /// Makespan do prefixo (término na última máquina).
+    pub fn makespan(&self) -> Time {
+        self.completion_times.last().copied().unwrap_or(0)
+    }
+}
+
+thread_local! {
+    /// Vetor de trabalho reaproveitado pelas avaliações em cada thread.
+    static MAKESPAN_BUFFER: RefCell<Vec<Time>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Representa o escalonamento completo obtido a partir de uma sequência de tarefas.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub sequence: Vec<Job>,               // Sequência de tarefas decodificada.
+    pub start_times: Vec<Vec<Time>>,      // Instantes de início [tarefa][máquina].
+    pub completion_times: Vec<Vec<Time>>, // Instantes de conclusão [tarefa][máquina].
+    pub makespan: Time,                   // Tempo de conclusão da última tarefa na última máquina.
+}
+
+/// Posições consideradas ao inserir uma tarefa nova (ex: um pedido urgente) em um
+/// escalonamento com `Schedule::insert_job`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertionPolicy {
+    Best,            // Qualquer posição da sequência.
+    NotBefore(Time), // Após as tarefas já iniciadas na primeira máquina no instante informado.
+    Last,            // Final da sequência (ordem de chegada).
+}
+
+impl FSSPInstance {
+    /// Verifica se a sequência é uma permutação válida das tarefas da instância.
+    pub fn validate_sequence(&self, sequence: &[Job]) -> Result<()> {
+        if sequence.len() != self.n_jobs {
+            return Err(FsspError::InvalidSolution(format!(
+                "A sequência possui {} tarefas, mas a instância possui {}.",
+                sequence.len(),
+                self.n_jobs
+            )));
+        }
+        let mut seen = vec![false; self.n_jobs];
+        for &job in sequence {
+            if job as usize >= self.n_jobs {
+                return Err(FsspError::InvalidSolution(format!(
+                    "Tarefa {} fora do intervalo da instância.",
+                    job
+                )));
+            }
+            if seen[job as usize] {
+                return Err(FsspError::InvalidSolution(format!(
+                    "Tarefa {} aparece mais de uma vez na sequência.",
+                    job
+                )));
+            }
+            seen[job as usize] = true;
+        }
+        Ok(())
+    }
+
+    /// Decodifica uma sequência de tarefas em um escalonamento com os instantes de
+    /// início e conclusão de cada tarefa em cada máquina. Com sublotes, a operação
+    /// começa com o primeiro sublote e termina com o último.
+    pub fn decode_schedule(&self, sequence: &[Job]) -> Schedule {
+        let mut start_times = vec![vec![0; self.n_machines]; self.n_jobs];
+        let mut completion_times = vec![vec![0; self.n_machines]; self.n_jobs];
+        // Instante em que cada máquina fica livre.
+        let mut machine_free: Vec<Time> = vec![0; self.n_machines];
+
+        for &job in sequence {
+            let job = job as usize;
+            let lots = self.sublots(job);
+            for lot in 0..lots {
+                // Instante em que o sublote chega à máquina, vindo da anterior (na
+                // primeira máquina, a liberação da tarefa).
+                let mut job_ready = self.release_date(job);
+                for i in 0..self.n_machines {
+                    let duration = sublot_time(self.processing_times[job][i], lots, lot);
+                    let start = self.earliest_start(i, max(machine_free[i], job_ready), duration);
+                    let end = start + duration;
+                    if lot == 0 {
+                        start_times[job][i] = start;
+                    }
+                    completion_times[job][i] = end;
+                    machine_free[i] = end;
+                    if i + 1 < self.n_machines {
+                        job_ready = end + self.transfer_time(job, i);
+                    }
                 }
             }
         }
 
-        // O Makespan final é o tempo de conclusão da última tarefa na última máquina.
-        c[self.n_machines - 1][self.n_jobs - 1]
+        Schedule {
+            sequence: sequence.to_vec(),
+            start_times,
+            completion_times,
+            makespan: machine_free.last().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl Schedule {
+    /// Valida o escalonamento em relação à instância: a sequência deve ser uma permutação
+    /// válida, cada operação deve durar exatamente seu tempo de processamento, uma tarefa
+    /// só pode iniciar em uma máquina após terminar na anterior e ser transportada (e,
+    /// na primeira, após sua liberação), nenhuma máquina pode processar duas tarefas ao mesmo tempo e
+    /// nenhuma operação pode sobrepor uma manutenção. Com sublotes, cujos instantes
+    /// não são representados individualmente, o escalonamento deve coincidir com a
+    /// decodificação da sequência.
+    pub fn validate(&self, instance: &FSSPInstance) -> Result<()> {
+        instance.validate_sequence(&self.sequence)?;
+        if instance.sublots.is_some() {
+            let decoded = instance.decode_schedule(&self.sequence);
+            if decoded.start_times != self.start_times
+                || decoded.completion_times != self.completion_times
+                || decoded.makespan != self.makespan
+            {
+                return Err(FsspError::InvalidSolution(
+                    "Instantes do escalonamento diferem da decodificação dos sublotes.".to_string(),
+                ));
+            }
+            return Ok(());
+        }
+        if self.start_times.len() != instance.n_jobs
+            || self.completion_times.len() != instance.n_jobs
+        {
+            return Err(FsspError::InvalidSolution(
+                "Dimensões do escalonamento não correspondem à instância.".to_string(),
+            ));
+        }
+
+        for job in 0..instance.n_jobs {
+            if self.start_times[job].len() != instance.n_machines
+                || self.completion_times[job].len() != instance.n_machines
+            {
+                return Err(FsspError::InvalidSolution(format!(
+                    "Tarefa {} não possui tempos para todas as máquinas.",
+                    job
+                )));
+            }
+            for i in 0..instance.n_machines {
+                let start = self.start_times[job][i];
+                let end = self.completion_times[job][i];
+                // Duração da operação.
+                if end < start || end - start != instance.processing_times[job][i] {
+                    return Err(FsspError::InvalidSolution(format!(
+                        "Duração inválida da tarefa {} na máquina {}.",
+                        job, i
+                    )));
+                }
+                // A tarefa não pode iniciar antes de ser liberada.
+                if i == 0 && start < instance.release_date(job) {
+                    return Err(FsspError::InvalidSolution(format!(
+                        "Tarefa {} inicia antes de sua liberação.",
+                        job
+                    )));
+                }
+                // A operação não pode sobrepor uma manutenção da máquina.
+                if instance.unavailability[i]
+                    .iter()
+                    .any(|&(down_start, down_end)| start < down_end && down_start < end)
+                {
+                    return Err(FsspError::InvalidSolution(format!(
+                        "Tarefa {} sobrepõe uma indisponibilidade da máquina {}.",
+                        job, i
+                    )));
+                }
+                // Precedência entre máquinas consecutivas, com o transporte.
+                if i > 0
+                    && start
+                        < self.completion_times[job][i - 1] + instance.transfer_time(job, i - 1)
+                {
+                    return Err(FsspError::InvalidSolution(format!(
+                        "Tarefa {} inicia na máquina {} antes de terminar na máquina {}.",
+                        job,
+                        i,
+                        i - 1
+                    )));
+                }
+            }
+        }
+
+        // Não sobreposição: em cada máquina, as tarefas seguem a ordem da sequência.
+        for i in 0..instance.n_machines {
+            for pair in self.sequence.windows(2) {
+                let (prev, next) = (pair[0], pair[1]);
+                if self.start_times[next as usize][i] < self.completion_times[prev as usize][i] {
+                    return Err(FsspError::InvalidSolution(format!(
+                        "Sobreposição das tarefas {} e {} na máquina {}.",
+                        prev, next, i
+                    )));
+                }
+            }
+        }
+
+        let makespan = self
+            .sequence
+            .last()
+            .map(|&job| self.completion_times[job as usize][instance.n_machines - 1])
+            .unwrap_or(0);
+        if makespan != self.makespan {
+            return Err(FsspError::InvalidSolution(format!(
+                "Makespan informado ({}) difere do calculado ({}).",
+                self.makespan, makespan
+            )));
+        }
+        Ok(())
+    }
+
+    /// Insere uma tarefa nova com os tempos `processing_times` (ex: um pedido
+    /// urgente) na posição permitida por `policy` com o menor valor da função
+    /// objetivo, sem reotimizar as demais tarefas, e retorna o novo escalonamento. A
+    /// tarefa é acrescentada a `instance` com a data de entrega, o peso e os tempos
+    /// de transporte informados (veja `FSSPInstance::push_job`), liberada no instante
+    /// de `InsertionPolicy::NotBefore` ou em 0. Empates ficam com a primeira posição.
+    pub fn insert_job(
+        &self,
+        instance: &mut FSSPInstance,
+        processing_times: Vec<Time>,
+        due_date: Option<Time>,
+        weight: Option<Time>,
+        transfer_times: Option<Vec<Time>>,
+        policy: InsertionPolicy,
+    ) -> Result<Schedule> {
+        instance.validate_sequence(&self.sequence)?;
+        let (first, release) = match policy {
+            InsertionPolicy::Best => (0, 0),
+            InsertionPolicy::NotBefore(now) => (
+                self.sequence
+                    .iter()
+                    .take_while(|&&job| self.start_times[job as usize][0] < now)
+                    .count(),
+                now,
+            ),
+            InsertionPolicy::Last => (self.sequence.len(), 0),
+        };
+        let job = instance.push_job(processing_times, release, due_date, weight, transfer_times)?;
+
+        let mut sequence = self.sequence.clone();
+        let mut best = (first, Time::MAX);
+        for pos in first..=sequence.len() {
+            sequence.insert(pos, job);
+            let value = instance.evaluate(&sequence);
+            sequence.remove(pos);
+            if value < best.1 {
+                best = (pos, value);
+            }
+        }
+        sequence.insert(best.0, job);
+        Ok(instance.decode_schedule(&sequence))
+    }
+
+    /// Tarefas do caminho crítico, na ordem da sequência: partindo da última
+    /// operação da última máquina, segue para trás a operação que a precede
+    /// imediatamente sem folga (a mesma tarefa na máquina anterior, somado o tempo de
+    /// transporte de `instance`, ou a tarefa anterior na mesma máquina). O caminho
+    /// termina na primeira operação ou numa espera causada por liberação ou
+    /// manutenção. Reduzir o makespan exige alterar a posição de ao menos uma dessas
+    /// tarefas.
+    pub fn critical_path(&self, instance: &FSSPInstance) -> Vec<Job> {
+        let Some(n_machines) = self.start_times.first().map(Vec::len) else {
+            return Vec::new();
+        };
+        if self.sequence.is_empty() || n_machines == 0 {
+            return Vec::new();
+        }
+
+        let mut critical = Vec::new();
+        let (mut pos, mut machine) = (self.sequence.len() - 1, n_machines - 1);
+        loop {
+            let job = self.sequence[pos];
+            if critical.last() != Some(&job) {
+                critical.push(job);
+            }
+            let start = self.start_times[job as usize][machine];
+            if machine > 0
+                && self.completion_times[job as usize][machine - 1]
+                    + instance.transfer_time(job as usize, machine - 1)
+                    == start
+            {
+                machine -= 1;
+            } else if pos > 0
+                && self.completion_times[self.sequence[pos - 1] as usize][machine] == start
+            {
+                pos -= 1;
+            } else {
+                break;
+            }
+        }
+        critical.reverse();
+        critical
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_instance_rejects_zero_jobs() {
+        let error = parse_instance("0 3\n").unwrap_err();
+        assert!(matches!(error, FsspError::Format { line: 1, .. }));
+    }
+
+    #[test]
+    fn parse_instance_rejects_zero_machines() {
+        let error = parse_instance("2 0\n\n\n").unwrap_err();
+        assert!(matches!(error, FsspError::Format { line: 1, .. }));
+    }
+
+    #[test]
+    fn push_job_leaves_instance_unchanged_on_error() {
+        let mut instance = parse_instance("2 2\n3 4\n2 5\n").unwrap();
+        instance.due_dates = Some(vec![10, 12]);
+        let original = instance.clone();
+
+        assert!(instance.push_job(vec![1, 1], 5, None, None, None).is_err());
+        assert!(instance
+            .push_job(vec![Time::MAX, 1], 5, Some(20), None, None)
+            .is_err());
+        assert_eq!(instance.n_jobs, original.n_jobs);
+        assert_eq!(instance.processing_times, original.processing_times);
+        assert_eq!(instance.release_dates, original.release_dates);
+        assert_eq!(instance.due_dates, original.due_dates);
+
+        let job = instance
+            .push_job(vec![1, 1], 5, Some(20), None, None)
+            .unwrap();
+        assert_eq!(job, 2);
+        assert_eq!(instance.release_dates, Some(vec![0, 0, 5]));
+        assert_eq!(instance.due_dates, Some(vec![10, 12, 20]));
+        assert_eq!(instance.weights, None);
+    }
+
+    #[test]
+    fn push_job_requires_transfer_times_per_job() {
+        let mut instance = parse_instance("2 3\n3 4 2\n2 5 1\n").unwrap();
+        instance.transfer_times = Some(vec![vec![1, 2]; 2]);
+        assert_eq!(
+            instance
+                .push_job(vec![1, 1, 1], 0, None, None, None)
+                .unwrap(),
+            2
+        );
+        assert_eq!(instance.transfer_times.as_ref().unwrap()[2], vec![1, 2]);
+
+        instance.transfer_times = Some(vec![vec![1, 2], vec![3, 0], vec![1, 2]]);
+        assert!(instance
+            .push_job(vec![1, 1, 1], 0, None, None, None)
+            .is_err());
+        assert!(instance
+            .push_job(vec![1, 1, 1], 0, None, None, Some(vec![4]))
+            .is_err());
+        assert_eq!(instance.n_jobs, 3);
+        let job = instance
+            .push_job(vec![1, 1, 1], 0, None, None, Some(vec![4, 5]))
+            .unwrap();
+        assert_eq!(instance.transfer_time(job as usize, 1), 5);
+    }
+
+    #[test]
+    fn insert_job_follows_policy() {
+        let instance = parse_instance("3 2\n3 4\n2 5\n4 1\n").unwrap();
+        let schedule = instance.decode_schedule(&[1, 0, 2]);
+        let insert = |policy| {
+            let mut instance = instance.clone();
+            let updated = schedule
+                .insert_job(&mut instance, vec![1, 6], None, None, None, policy)
+                .unwrap();
+            (instance, updated)
+        };
+
+        // A tarefa nova (3) tem o menor tempo na primeira máquina: vai para o início.
+        let (_, updated) = insert(InsertionPolicy::Best);
+        assert_eq!(updated.sequence, vec![3, 1, 0, 2]);
+        assert_eq!(updated.makespan, 17);
+
+        // As tarefas 1 e 0 já iniciaram na primeira máquina no instante 3; entre as
+        // posições seguintes (empatadas), fica a primeira, liberada em 3.
+        let (instance_now, updated) = insert(InsertionPolicy::NotBefore(3));
+        assert_eq!(updated.sequence, vec![1, 0, 3, 2]);
+        assert_eq!(instance_now.release_dates, Some(vec![0, 0, 0, 3]));
+
+        let (_, updated) = insert(InsertionPolicy::Last);
+        assert_eq!(updated.sequence, vec![1, 0, 2, 3]);
+        assert_eq!(updated.makespan, 18);
+    }
+
+    #[test]
+    fn critical_path_follows_transfer_times() {
+        let mut instance = parse_instance("2 2\n3 1\n1 4\n").unwrap();
+        instance.transfer_times = Some(vec![vec![0], vec![5]]);
+        // A tarefa 1 termina na máquina 0 em 4 e chega à máquina 1 em 4 + 5 = 9: o
+        // caminho segue o transporte até a máquina 0, onde a tarefa 1 espera a 0.
+        let schedule = instance.decode_schedule(&[0, 1]);
+        assert_eq!(schedule.start_times[1][1], 9);
+        assert_eq!(schedule.critical_path(&instance), vec![0, 1]);
     }
 }
@@ -0,0 +1,316 @@
+//! Avaliação do makespan de populações inteiras na GPU (recurso `gpu`, via wgpu).
+//!
+//! Cada sequência é avaliada por uma invocação do kernel, com a recursão usual do
+//! makespan sobre a fronteira das máquinas; a população inteira é avaliada em um
+//! único lançamento (ou em poucos, se exceder os limites do dispositivo). Os tempos
+//! são calculados em 32 bits, de modo que apenas instâncias com horizonte até
+//! `u32::MAX` são aceitas. Instâncias não suportadas, populações pequenas e sistemas
+//! sem adaptador compatível usam a avaliação na CPU (veja
+//! `FSSPInstance::evaluate_population`).
+
+use crate::fssp_core::{FSSPInstance, Job, Objective, Time};
+use std::sync::OnceLock;
+use wgpu::util::DeviceExt;
+
+/// Menor população avaliada na GPU; abaixo dela, a transferência dos dados custa
+/// mais que a avaliação na CPU.
+pub const MIN_GPU_POPULATION: usize = 1024;
+
+/// Invocações por grupo de trabalho do kernel.
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Kernel do makespan. A fronteira das máquinas de cada sequência fica em
+/// `front[máquina * n_sequences + sequência]`, de modo que invocações vizinhas
+/// acessam posições vizinhas da memória.
+const SHADER: &str = r#"
+struct Params {
+    n_jobs: u32,
+    n_machines: u32,
+    n_sequences: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> times: array<u32>;
+@group(0) @binding(2) var<storage, read> sequences: array<u32>;
+@group(0) @binding(3) var<storage, read_write> front: array<u32>;
+@group(0) @binding(4) var<storage, read_write> makespans: array<u32>;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let s = id.x;
+    if (s >= params.n_sequences) {
+        return;
+    }
+    let m = params.n_machines;
+    let stride = params.n_sequences;
+    for (var i = 0u; i < m; i++) {
+        front[i * stride + s] = 0u;
+    }
+    for (var k = 0u; k < params.n_jobs; k++) {
+        let job = sequences[s * params.n_jobs + k];
+        var c = 0u;
+        for (var i = 0u; i < m; i++) {
+            c = max(c, front[i * stride + s]) + times[job * m + i];
+            front[i * stride + s] = c;
+        }
+    }
+    makespans[s] = front[(m - 1u) * stride + s];
+}
+"#;
+
+/// Avaliador de populações na GPU: o dispositivo e o kernel compilado.
+pub struct GpuEvaluator {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+}
+
+impl GpuEvaluator {
+    /// Inicializa o adaptador padrão do sistema. Retorna `None` se não houver GPU
+    /// (ou driver) com suporte a compute shaders.
+    pub fn new() -> Option<Self> {
+        pollster::block_on(Self::_new())
+    }
+
+    /// Avaliador compartilhado pelo processo, inicializado na primeira chamada.
+    pub fn shared() -> Option<&'static GpuEvaluator> {
+        static EVALUATOR: OnceLock<Option<GpuEvaluator>> = OnceLock::new();
+        EVALUATOR.get_or_init(Self::new).as_ref()
+    }
+
+    async fn _new() -> Option<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .ok()?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("fssp"),
+                required_limits: adapter.limits(),
+                ..Default::default()
+            })
+            .await
+            .ok()?;
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("makespan"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("makespan"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        Some(GpuEvaluator {
+            device,
+            queue,
+            pipeline,
+        })
+    }
+
+    /// Indica se a avaliação de `instance` pode ser feita na GPU: makespan sem
+    /// cenários de tempos, sem liberações, manutenções, sublotes nem tempos de
+    /// transporte, e com horizonte representável em 32 bits.
+    pub fn supports(instance: &FSSPInstance) -> bool {
+        instance.objective == Objective::Makespan
+            && instance.uncertainty.is_none()
+            && instance._is_plain()
+            && instance
+                .horizon()
+                .is_some_and(|horizon| horizon <= Time::from(u32::MAX))
+    }
+
+    /// Makespan de cada sequência, na mesma ordem, registrados no contador de
+    /// avaliações da instância. Retorna `None` se a instância não é suportada (veja
+    /// `supports`) ou se alguma sequência não contém todas as tarefas.
+    pub fn makespans<S: AsRef<[Job]>>(
+        &self,
+        instance: &FSSPInstance,
+        sequences: &[S],
+    ) -> Option<Vec<Time>> {
+        let (n_jobs, n_machines) = (instance.n_jobs, instance.n_machines);
+        if !Self::supports(instance) || sequences.iter().any(|s| s.as_ref().len() != n_jobs) {
+            return None;
+        }
+        let times: Vec<u32> = instance
+            .processing_times
+            .iter()
+            .flatten()
+            .map(|&t| t as u32)
+            .collect();
+        let times = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("times"),
+                contents: _bytes(&times),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+
+        // Sequências por lançamento, dentro dos limites de grupos e de buffers.
+        let limits = self.device.limits();
+        let per_sequence = 4 * n_jobs.max(n_machines) as u64;
+        let chunk = (limits.max_compute_workgroups_per_dimension as u64 * WORKGROUP_SIZE as u64)
+            .min(limits.max_storage_buffer_binding_size as u64 / per_sequence)
+            .max(1) as usize;
+        let mut makespans = Vec::with_capacity(sequences.len());
+        for block in sequences.chunks(chunk) {
+            makespans.extend(self._dispatch(&times, block, n_jobs, n_machines));
+        }
+        instance.evaluations.add(sequences.len() as u64);
+        Some(makespans)
+    }
+
+    /// Avalia um bloco de sequências em um único lançamento do kernel.
+    fn _dispatch<S: AsRef<[Job]>>(
+        &self,
+        times: &wgpu::Buffer,
+        sequences: &[S],
+        n_jobs: usize,
+        n_machines: usize,
+    ) -> Vec<Time> {
+        let n_sequences = sequences.len();
+        let flat: Vec<u32> = sequences
+            .iter()
+            .flat_map(|s| s.as_ref().iter().copied())
+            .collect();
+        let params = [n_jobs as u32, n_machines as u32, n_sequences as u32, 0];
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("params"),
+                contents: _bytes(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let sequences = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("sequences"),
+                contents: _bytes(&flat),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let front = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("front"),
+            size: (4 * n_sequences * n_machines) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        let output_size = (4 * n_sequences) as u64;
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("makespans"),
+            size: output_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: output_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("makespan"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                _entry(0, &params),
+                _entry(1, times),
+                _entry(2, &sequences),
+                _entry(3, &front),
+                _entry(4, &output),
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((n_sequences as u32).div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output, 0, &readback, 0, output_size);
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| {
+            result.expect("falha ao ler os makespans da GPU")
+        });
+        self.device
+            .poll(wgpu::PollType::Wait)
+            .expect("falha ao aguardar a GPU");
+        let data = slice.get_mapped_range();
+        data.chunks_exact(4)
+            .map(|bytes| Time::from(u32::from_ne_bytes(bytes.try_into().unwrap())))
+            .collect()
+    }
+}
+
+/// Entrada de um buffer inteiro no grupo de ligação do kernel.
+fn _entry(binding: u32, buffer: &wgpu::Buffer) -> wgpu::BindGroupEntry<'_> {
+    wgpu::BindGroupEntry {
+        binding,
+        resource: buffer.as_entire_binding(),
+    }
+}
+
+/// Bytes de um vetor de inteiros de 32 bits, na ordem nativa.
+fn _bytes(values: &[u32]) -> &[u8] {
+    // SAFETY: `u32` não tem bytes de preenchimento e todo padrão de bits é válido
+    // como `u8`; o alinhamento de `u8` é 1.
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), std::mem::size_of_val(values)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance_format::from_processing_times;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn gpu_makespans_match_evaluate() {
+        let Some(gpu) = GpuEvaluator::new() else {
+            eprintln!("sem adaptador compatível: avaliação na GPU não testada");
+            return;
+        };
+        let mut rng = StdRng::seed_from_u64(11);
+        let times: Vec<Vec<Time>> = (0..20)
+            .map(|_| (0..7).map(|_| rng.gen_range(1..100)).collect())
+            .collect();
+        let instance = from_processing_times(times).unwrap();
+        let population: Vec<Vec<Job>> = (0..MIN_GPU_POPULATION)
+            .map(|_| {
+                let mut sequence: Vec<Job> = (0..20).collect();
+                sequence.shuffle(&mut rng);
+                sequence
+            })
+            .collect();
+
+        let makespans = gpu.makespans(&instance, &population).unwrap();
+        let expected: Vec<Time> = population.iter().map(|s| instance.evaluate(s)).collect();
+        assert_eq!(makespans, expected);
+        assert_eq!(instance.evaluate_population(&population), expected);
+    }
+
+    #[test]
+    fn unsupported_instances_fall_back_to_cpu() {
+        let mut instance = from_processing_times(vec![vec![3, 4], vec![2, 5], vec![4, 1]]).unwrap();
+        instance.release_dates = Some(vec![0, 6, 2]);
+        assert!(!GpuEvaluator::supports(&instance));
+
+        let population: Vec<Vec<Job>> = vec![vec![0, 1, 2]; MIN_GPU_POPULATION];
+        let expected = instance.evaluate(&[0, 1, 2]);
+        assert!(instance
+            .evaluate_population(&population)
+            .iter()
+            .all(|&value| value == expected));
+    }
+}
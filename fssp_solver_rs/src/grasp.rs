@@ -0,0 +1,219 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::local_search::{self, SearchLimits};
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::Rng;
+use tracing::{debug, debug_span};
+
+/// Valores de alfa disponíveis para a construção gulosa aleatorizada.
+const ALPHAS: [f64; 6] = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5];
+/// Número de iterações entre atualizações das probabilidades de alfa.
+const REACTIVE_PERIOD: usize = 20;
+/// Expoente que amplifica as diferenças de qualidade entre os valores de alfa.
+const REACTIVE_DELTA: i32 = 10;
+
+/// GRASP (Greedy Randomized Adaptive Search Procedure) com alfa reativo.
+/// Cada iteração constrói uma solução no estilo NEH, escolhendo a posição de
+/// inserção de cada tarefa em uma lista restrita de candidatos (LRC), e a refina
+/// com busca local por inserção.
+pub struct Grasp {
+    instance: FSSPInstance,           // Instância do problema FSSP.
+    iterations: usize,                // Número máximo de iterações.
+    alpha_probs: Vec<f64>,            // Probabilidade de escolha de cada alfa.
+    alpha_sums: Vec<f64>,             // Soma dos makespans obtidos com cada alfa.
+    alpha_counts: Vec<usize>,         // Número de usos de cada alfa.
+    initial_solutions: Vec<Vec<Job>>, // Soluções fornecidas usadas como incumbentes iniciais.
+    pub best_sequence: Vec<Job>,      // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,          // O makespan da melhor sequência encontrada.
+}
+
+impl Grasp {
+    /// Cria uma nova instância do `Grasp`.
+    pub fn new(instance: FSSPInstance, iterations: usize) -> Self {
+        Grasp {
+            instance,
+            iterations,
+            alpha_probs: vec![1.0 / ALPHAS.len() as f64; ALPHAS.len()],
+            alpha_sums: vec![0.0; ALPHAS.len()],
+            alpha_counts: vec![0; ALPHAS.len()],
+            initial_solutions: Vec::new(),
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Define soluções (já validadas) que iniciam a busca como incumbentes: a melhor
+    /// delas é mantida enquanto as construções não a superarem.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.initial_solutions = solutions;
+        self
+    }
+
+    /// Sorteia o índice de um alfa conforme as probabilidades atuais.
+    fn _choose_alpha<R: Rng>(&self, rng: &mut R) -> usize {
+        let mut r = rng.gen::<f64>();
+        for (idx, &p) in self.alpha_probs.iter().enumerate() {
+            if r < p {
+                return idx;
+            }
+            r -= p;
+        }
+        self.alpha_probs.len() - 1
+    }
+
+    /// Constrói uma solução gulosa aleatorizada: as tarefas são consideradas em ordem
+    /// decrescente de tempo total (como no NEH) e cada uma é inserida em uma posição
+    /// sorteada entre as que resultam em makespan até `min + alpha * (max - min)`.
+    fn _construct<R: Rng>(&self, alpha: f64, rng: &mut R) -> Vec<Job> {
+        let mut order: Vec<Job> = (0..self.instance.n_jobs as Job).collect();
+        order.sort_by_key(|&job| {
+            std::cmp::Reverse(
+                self.instance.processing_times[job as usize]
+                    .iter()
+                    .sum::<Time>(),
+            )
+        });
+
+        let mut sequence = Vec::with_capacity(self.instance.n_jobs);
+        for job in order {
+            // Avalia todas as posições de inserção da tarefa.
+            let candidates: Vec<(usize, Time)> = (0..=sequence.len())
+                .map(|pos| {
+                    sequence.insert(pos, job);
+                    let makespan = self.instance.evaluate(&sequence);
+                    sequence.remove(pos);
+                    (pos, makespan)
+                })
+                .collect();
+
+            let min = candidates.iter().map(|&(_, m)| m).min().unwrap();
+            let max = candidates.iter().map(|&(_, m)| m).max().unwrap();
+            let threshold = min as f64 + alpha * (max - min) as f64;
+            let rcl: Vec<usize> = candidates
+                .iter()
+                .filter(|&&(_, m)| m as f64 <= threshold)
+                .map(|&(pos, _)| pos)
+                .collect();
+
+            let pos = rcl[rng.gen_range(0..rcl.len())];
+            sequence.insert(pos, job);
+        }
+        sequence
+    }
+
+    /// Atualiza as probabilidades dos alfas favorecendo os que geraram melhores
+    /// soluções em média (GRASP reativo).
+    fn _update_alpha_probs(&mut self) {
+        let best = self.best_makespan as f64;
+        let scores: Vec<f64> = self
+            .alpha_sums
+            .iter()
+            .zip(&self.alpha_counts)
+            .map(|(&sum, &count)| {
+                if count == 0 {
+                    // Alfas ainda não utilizados mantêm uma chance razoável.
+                    1.0
+                } else {
+                    (best / (sum / count as f64)).powi(REACTIVE_DELTA)
+                }
+            })
+            .collect();
+        let total: f64 = scores.iter().sum();
+        self.alpha_probs = scores.iter().map(|&q| q / total).collect();
+    }
+}
+
+impl Solver for Grasp {
+    fn name(&self) -> &'static str {
+        "grasp"
+    }
+
+    /// Executa o GRASP até o número máximo de iterações ou o esgotamento do
+    /// orçamento. A busca local de cada iteração também é interrompida no instante
+    /// limite ou ao atingir o máximo de avaliações.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let limits = SearchLimits {
+            deadline: budget.deadline,
+            max_evaluations: budget.max_evaluations,
+            ..SearchLimits::default()
+        };
+
+        // A melhor solução fornecida é a incumbente inicial.
+        if let Some((makespan, sequence)) = self
+            .initial_solutions
+            .iter()
+            .map(|seq| (self.instance.evaluate(seq), seq))
+            .min_by_key(|&(makespan, _)| makespan)
+        {
+            self.best_makespan = makespan;
+            self.best_sequence = sequence.clone();
+        }
+
+        for iter in 0..self.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            let alpha_idx = self._choose_alpha(&mut rng);
+            let mut sequence = self._construct(ALPHAS[alpha_idx], &mut rng);
+            let makespan =
+                local_search::insertion_descent_limited(&self.instance, &mut sequence, &limits);
+
+            self.alpha_sums[alpha_idx] += makespan as f64;
+            self.alpha_counts[alpha_idx] += 1;
+
+            if makespan < self.best_makespan {
+                self.best_makespan = makespan;
+                self.best_sequence = sequence;
+                debug!(makespan, alpha = ALPHAS[alpha_idx], "Nova melhor solução");
+                observer.on_new_best(&Solution {
+                    sequence: self.best_sequence.clone(),
+                    makespan,
+                });
+            }
+
+            if (iter + 1) % REACTIVE_PERIOD == 0 {
+                self._update_alpha_probs();
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_makespan,
+                generation_best: makespan,
+                mean_makespan: makespan as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -0,0 +1,291 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::local_search::best_insertion;
+use crate::observer::{GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
+use tracing::debug;
+
+/// Heurística construtiva NEH (Nawaz, Enscore e Ham): ordena as tarefas por tempo
+/// total de processamento decrescente e insere cada uma na posição da sequência
+/// parcial que resulta no menor makespan.
+pub fn neh(instance: &FSSPInstance) -> Vec<Job> {
+    let mut order: Vec<Job> = (0..instance.n_jobs as Job).collect();
+    order.sort_by_key(|&job| Reverse(instance.processing_times[job as usize].iter().sum::<Time>()));
+    neh_from_order(instance, &order)
+}
+
+/// Fase de inserção do NEH a partir de uma ordem de tarefas qualquer.
+pub fn neh_from_order(instance: &FSSPInstance, order: &[Job]) -> Vec<Job> {
+    let mut sequence = Vec::with_capacity(order.len());
+    for &job in order {
+        let (pos, _) = best_insertion(instance, &mut sequence, job);
+        sequence.insert(pos, job);
+    }
+    sequence
+}
+
+/// Critérios de desempate entre as posições de inserção de mesmo valor no NEH.
+/// Nas instâncias de Taillard os empates são frequentes, e o critério muda
+/// sensivelmente a qualidade da solução final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NehTieBreaking {
+    #[default]
+    First, // Primeira posição empatada (NEH original).
+    Last, // Última posição empatada.
+    Kk1,  // Kalczynski e Kamburowski: primeira ou última conforme o perfil da tarefa.
+    Idle, // Menor tempo ocioso total das máquinas na sequência parcial.
+}
+
+impl FromStr for NehTieBreaking {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "first" => Ok(NehTieBreaking::First),
+            "last" => Ok(NehTieBreaking::Last),
+            "kk1" => Ok(NehTieBreaking::Kk1),
+            "idle" => Ok(NehTieBreaking::Idle),
+            _ => Err(format!(
+                "Critério de desempate desconhecido '{}' (use first, last, kk1 ou idle)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for NehTieBreaking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NehTieBreaking::First => "first",
+            NehTieBreaking::Last => "last",
+            NehTieBreaking::Kk1 => "kk1",
+            NehTieBreaking::Idle => "idle",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// NEH com o critério de desempate `tie_breaking` entre as posições de inserção
+/// de mesmo valor. Com `NehTieBreaking::First`, equivale a `neh`.
+pub fn neh_with_tie_breaking(instance: &FSSPInstance, tie_breaking: NehTieBreaking) -> Vec<Job> {
+    if tie_breaking == NehTieBreaking::First {
+        return neh(instance);
+    }
+    let mut order: Vec<Job> = (0..instance.n_jobs as Job).collect();
+    order.sort_by_key(|&job| Reverse(instance.processing_times[job as usize].iter().sum::<Time>()));
+    let mut sequence = Vec::with_capacity(order.len());
+    for job in order {
+        let tied = _tied_insertions(instance, &mut sequence, job);
+        let pos = match tie_breaking {
+            NehTieBreaking::First => tied[0],
+            NehTieBreaking::Last => tied[tied.len() - 1],
+            NehTieBreaking::Kk1 => {
+                if _kk1_prefers_first(instance, job) {
+                    tied[0]
+                } else {
+                    tied[tied.len() - 1]
+                }
+            }
+            NehTieBreaking::Idle => tied
+                .iter()
+                .copied()
+                .min_by_key(|&pos| {
+                    sequence.insert(pos, job);
+                    let front = instance.calculate_makespan_prefix(&sequence, sequence.len());
+                    sequence.remove(pos);
+                    // A soma dos tempos de processamento não depende da posição,
+                    // logo o menor tempo ocioso total é a menor soma dos términos.
+                    front.completion_times.iter().sum::<Time>()
+                })
+                .unwrap(),
+        };
+        sequence.insert(pos, job);
+    }
+    sequence
+}
+
+/// Posições de inserção de `job` em `sequence` que resultam no menor valor da
+/// função objetivo, em ordem crescente.
+fn _tied_insertions(instance: &FSSPInstance, sequence: &mut Vec<Job>, job: Job) -> Vec<usize> {
+    let mut best = (Vec::new(), Time::MAX);
+    for pos in 0..=sequence.len() {
+        sequence.insert(pos, job);
+        let value = instance.evaluate(sequence);
+        sequence.remove(pos);
+        if value < best.1 {
+            best = (vec![pos], value);
+        } else if value == best.1 {
+            best.0.push(pos);
+        }
+    }
+    best.0
+}
+
+/// Desempate KK1 (Kalczynski e Kamburowski, 2008): com `c = (m - 1)(m - 2) / 2`,
+/// `a = sum((c + m - i) * p_ij)` e `b = sum((c + i - 1) * p_ij)` (máquinas `i` de
+/// 1 a `m`), a tarefa vai para a primeira posição empatada se `a <= b`, isto é,
+/// se seus tempos se concentram nas últimas máquinas, e para a última caso
+/// contrário.
+fn _kk1_prefers_first(instance: &FSSPInstance, job: Job) -> bool {
+    let m = instance.n_machines as u128;
+    let c = m.saturating_sub(1) * m.saturating_sub(2) / 2;
+    let (a, b) = instance.processing_times[job as usize]
+        .iter()
+        .enumerate()
+        .fold((0u128, 0u128), |(a, b), (i, &t)| {
+            let i = i as u128 + 1;
+            (a + (c + m - i) * t as u128, b + (c + i - 1) * t as u128)
+        });
+    a <= b
+}
+
+/// Regra de Johnson para o problema de duas máquinas com tempos `(a, b)` por
+/// tarefa: primeiro as tarefas com `a < b`, em ordem crescente de `a`; depois as
+/// demais, em ordem decrescente de `b`.
+fn _johnson(times: &[(Time, Time)]) -> Vec<Job> {
+    let (mut first, mut last): (Vec<Job>, Vec<Job>) =
+        (0..times.len() as Job).partition(|&job| times[job as usize].0 < times[job as usize].1);
+    first.sort_by_key(|&job| times[job as usize].0);
+    last.sort_by_key(|&job| Reverse(times[job as usize].1));
+    first.extend(last);
+    first
+}
+
+/// Heurística CDS (Campbell, Dudek e Smith): para cada `k` de 1 a `m - 1`, agrupa
+/// as `k` primeiras e as `k` últimas máquinas em duas máquinas virtuais, sequencia
+/// as tarefas pela regra de Johnson e retorna a melhor das `m - 1` sequências.
+pub fn cds(instance: &FSSPInstance) -> Vec<Job> {
+    let m = instance.n_machines;
+    let candidates = (1..m.max(2)).map(|k| {
+        let times: Vec<(Time, Time)> = instance
+            .processing_times
+            .iter()
+            .map(|times| {
+                (
+                    times[..k].iter().sum(),
+                    times[m.saturating_sub(k)..].iter().sum(),
+                )
+            })
+            .collect();
+        _johnson(&times)
+    });
+    candidates
+        .min_by_key(|sequence| instance.evaluate(sequence))
+        .unwrap_or_default()
+}
+
+/// Heurística de Palmer: ordena as tarefas pelo índice de inclinação
+/// `sum((2i - m - 1) * p_ij)` (máquinas `i` de 1 a `m`) em ordem decrescente,
+/// antecipando as tarefas cujos tempos crescem ao longo das máquinas.
+pub fn palmer(instance: &FSSPInstance) -> Vec<Job> {
+    let m = instance.n_machines as i128;
+    let slope = |job: Job| -> i128 {
+        instance.processing_times[job as usize]
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| (2 * (i as i128 + 1) - m - 1) * t as i128)
+            .sum()
+    };
+    let mut order: Vec<Job> = (0..instance.n_jobs as Job).collect();
+    order.sort_by_key(|&job| Reverse(slope(job)));
+    order
+}
+
+/// Heurísticas construtivas disponíveis como algoritmos independentes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstructiveHeuristic {
+    Neh(NehTieBreaking), // NEH com o critério de desempate informado.
+    Cds,                 // Campbell, Dudek e Smith.
+    Palmer,              // Índice de inclinação de Palmer.
+}
+
+impl ConstructiveHeuristic {
+    /// Constrói a sequência da heurística.
+    pub fn build(self, instance: &FSSPInstance) -> Vec<Job> {
+        match self {
+            ConstructiveHeuristic::Neh(tie_breaking) => {
+                neh_with_tie_breaking(instance, tie_breaking)
+            }
+            ConstructiveHeuristic::Cds => cds(instance),
+            ConstructiveHeuristic::Palmer => palmer(instance),
+        }
+    }
+}
+
+/// Executa uma heurística construtiva uma única vez, sem busca local, para obter
+/// uma solução instantânea (por exemplo, como referência em comparações).
+pub struct ConstructiveSolver {
+    instance: FSSPInstance,           // Instância do problema FSSP.
+    heuristic: ConstructiveHeuristic, // Heurística utilizada.
+    pub best_sequence: Vec<Job>,      // A sequência construída.
+    pub best_makespan: Time,          // O makespan da sequência construída.
+}
+
+impl ConstructiveSolver {
+    /// Cria uma nova instância do `ConstructiveSolver`.
+    pub fn new(instance: FSSPInstance, heuristic: ConstructiveHeuristic) -> Self {
+        ConstructiveSolver {
+            instance,
+            heuristic,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+}
+
+impl Solver for ConstructiveSolver {
+    fn name(&self) -> &'static str {
+        match self.heuristic {
+            ConstructiveHeuristic::Neh(_) => "neh",
+            ConstructiveHeuristic::Cds => "cds",
+            ConstructiveHeuristic::Palmer => "palmer",
+        }
+    }
+
+    /// Constrói a sequência e a avalia; o orçamento não se aplica.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        _budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        self.best_sequence = self.heuristic.build(&self.instance);
+        self.best_makespan = self.instance.evaluate(&self.best_sequence);
+        debug!(makespan = self.best_makespan, "Solução construída");
+        observer.on_new_best(&Solution {
+            sequence: self.best_sequence.clone(),
+            makespan: self.best_makespan,
+        });
+
+        let stats = GenerationStats {
+            generation: 1,
+            best_makespan: self.best_makespan,
+            generation_best: self.best_makespan,
+            mean_makespan: self.best_makespan as f64,
+            mutation_rate: 0.0,
+            diversity: None,
+            duplicate_rate: None,
+            operators: None,
+            evaluations: self.instance.evaluations.get(),
+            elapsed: start_time.elapsed(),
+        };
+        stats.trace();
+        observer.on_generation(&stats);
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -0,0 +1,274 @@
+use crate::aos::{AosStrategy, OperatorSelector};
+use crate::budget::Budget;
+use crate::config::SolverConfig;
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics;
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::registry;
+use crate::solver::{Algorithm, Solver};
+use crate::time::Instant;
+use tracing::{debug, debug_span, info};
+
+/// Parâmetros da hiper-heurística.
+#[derive(Debug, Clone)]
+pub struct HhParams {
+    pub slices: usize,            // Número de fatias (critério de parada).
+    pub slice_generations: usize, // Gerações (ou iterações) de cada fatia.
+    pub strategy: AosStrategy,    // Estratégia de escolha do algoritmo de cada fatia.
+}
+
+impl Default for HhParams {
+    fn default() -> Self {
+        HhParams {
+            slices: 100,
+            slice_generations: 10,
+            strategy: AosStrategy::Ucb,
+        }
+    }
+}
+
+/// Algoritmos de baixo nível usados quando nenhum é informado.
+pub const DEFAULT_ALGORITHMS: [Algorithm; 5] = [
+    Algorithm::Ig,
+    Algorithm::Sa,
+    Algorithm::Tabu,
+    Algorithm::Ils,
+    Algorithm::Memetic,
+];
+
+/// Desempenho de um algoritmo de baixo nível na hiper-heurística.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArmStats {
+    pub slices: usize,       // Fatias executadas pelo algoritmo.
+    pub improvements: usize, // Fatias que melhoraram a melhor solução global.
+    pub evaluations: u64,    // Avaliações consumidas pelo algoritmo.
+}
+
+/// Hiper-heurística de seleção: a execução é dividida em fatias curtas e, em cada
+/// uma, um algoritmo de baixo nível do registro é executado por
+/// `slice_generations` gerações a partir da melhor solução encontrada até então.
+/// O algoritmo de cada fatia é escolhido por um seletor adaptativo (UCB1 por
+/// padrão, o mesmo de `aos`) recompensado pela melhoria relativa da melhor solução
+/// por avaliação consumida, normalizada pela maior já observada. Assim, o orçamento
+/// restante se concentra nos algoritmos que mais progridem na instância.
+pub struct HyperHeuristic {
+    instance: FSSPInstance,           // Instância do problema FSSP.
+    config: SolverConfig,             // Parâmetros dos algoritmos de baixo nível.
+    algorithms: Vec<Algorithm>,       // Algoritmos de baixo nível disponíveis.
+    params: HhParams,                 // Parâmetros da hiper-heurística.
+    initial_solutions: Vec<Vec<Job>>, // Soluções fornecidas (incumbente inicial).
+    slice_evaluations: u64,           // Avaliações consumidas pelas fatias.
+    winner: Option<usize>,            // Algoritmo que encontrou a melhor solução.
+    pub arms: Vec<ArmStats>,          // Desempenho de cada algoritmo de baixo nível.
+    pub best_sequence: Vec<Job>,      // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,          // O makespan da melhor sequência encontrada.
+}
+
+impl HyperHeuristic {
+    /// Cria a hiper-heurística sobre os algoritmos informados, configurados por
+    /// `config`. Os algoritmos não podem ser vazios nem incluir a própria
+    /// hiper-heurística ou o portfólio, e cada um é construído uma vez para validar os parâmetros.
+    pub fn new(
+        instance: FSSPInstance,
+        config: SolverConfig,
+        algorithms: Vec<Algorithm>,
+        params: HhParams,
+    ) -> Result<Self> {
+        if algorithms.is_empty() {
+            return Err(FsspError::Config(
+                "a hiper-heurística exige ao menos um algoritmo".to_string(),
+            ));
+        }
+        if algorithms
+            .iter()
+            .any(|algorithm| matches!(algorithm, Algorithm::Hh | Algorithm::Auto))
+        {
+            return Err(FsspError::Config(
+                "a hiper-heurística não pode usar a si mesma nem o portfólio como algoritmo"
+                    .to_string(),
+            ));
+        }
+        for &algorithm in &algorithms {
+            registry::entry(algorithm).build(&instance, &config, Vec::new())?;
+        }
+        Ok(HyperHeuristic {
+            arms: vec![ArmStats::default(); algorithms.len()],
+            instance,
+            config,
+            algorithms,
+            params,
+            initial_solutions: Vec::new(),
+            slice_evaluations: 0,
+            winner: None,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        })
+    }
+
+    /// Define soluções (já validadas) que iniciam a busca: a melhor delas (ou a do
+    /// NEH, se for melhor) é o ponto de partida da primeira fatia.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.initial_solutions = solutions;
+        self
+    }
+
+    /// Uso de cada algoritmo (ex: `ig=12/5 sa=3/0`: fatias executadas/fatias que
+    /// melhoraram a melhor solução).
+    pub fn usage(&self) -> String {
+        self.algorithms
+            .iter()
+            .zip(&self.arms)
+            .map(|(algorithm, arm)| format!("{}={}/{}", algorithm, arm.slices, arm.improvements))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Observador das fatias: repassa ao observador da execução apenas as soluções
+/// que melhoram a melhor solução global.
+struct _SliceObserver<'a> {
+    inner: &'a mut dyn Observer, // Observador da execução.
+    best: Time,                  // Melhor makespan global conhecido.
+}
+
+impl Observer for _SliceObserver<'_> {
+    fn on_new_best(&mut self, solution: &Solution) {
+        if solution.makespan < self.best {
+            self.best = solution.makespan;
+            self.inner.on_new_best(solution);
+        }
+    }
+
+    fn incoming(&mut self) -> Option<Solution> {
+        self.inner.incoming()
+    }
+}
+
+impl Solver for HyperHeuristic {
+    fn name(&self) -> &'static str {
+        "hh"
+    }
+
+    /// Executa as fatias até o número máximo de fatias ou o esgotamento do
+    /// orçamento, que também limita cada fatia. A parada solicitada pelo observador
+    /// é verificada ao fim de cada fatia.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let mut selector = OperatorSelector::new(self.params.strategy, self.algorithms.len());
+        let config = SolverConfig {
+            max_generations: Some(self.params.slice_generations),
+            ..self.config.clone()
+        };
+
+        // Incumbente inicial: a melhor entre o NEH e as soluções fornecidas.
+        let neh = heuristics::neh(&self.instance);
+        let (makespan, sequence) = self
+            .initial_solutions
+            .iter()
+            .chain(std::iter::once(&neh))
+            .map(|seq| (self.instance.evaluate(seq), seq))
+            .min_by_key(|&(makespan, _)| makespan)
+            .unwrap();
+        self.best_makespan = makespan;
+        self.best_sequence = sequence.clone();
+
+        let mut state = 0;
+        let mut max_reward: f64 = 0.0;
+        for slice in 0..self.params.slices {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, slice, self.evaluations()) {
+                break;
+            }
+
+            let arm = selector.select(state, &mut rng);
+            let algorithm = self.algorithms[arm];
+            let _span = debug_span!("slice", slice = slice + 1, %algorithm).entered();
+            let mut solver = registry::entry(algorithm)
+                .build(&self.instance, &config, vec![self.best_sequence.clone()])
+                .expect("parâmetros validados na criação");
+            let before = self.best_makespan;
+            solver.run_with_observer(
+                start_time,
+                &budget.remaining(self.evaluations()),
+                &mut _SliceObserver {
+                    inner: observer,
+                    best: before,
+                },
+            );
+
+            let spent = solver.evaluations();
+            self.slice_evaluations += spent;
+            let stats = &mut self.arms[arm];
+            stats.slices += 1;
+            stats.evaluations += spent;
+            let after = solver.best_makespan();
+            let improved = after < before;
+            if improved {
+                stats.improvements += 1;
+                self.best_makespan = after;
+                self.best_sequence = solver.best_sequence().to_vec();
+                self.winner = Some(arm);
+                debug!(makespan = after, "Nova melhor solução");
+            }
+
+            // Recompensa: melhoria relativa por avaliação, normalizada pela maior.
+            let raw =
+                before.saturating_sub(after) as f64 / before.max(1) as f64 / spent.max(1) as f64;
+            max_reward = max_reward.max(raw);
+            let reward = if max_reward > 0.0 {
+                raw / max_reward
+            } else {
+                0.0
+            };
+            let next_state = usize::from(improved);
+            selector.update(state, arm, reward, next_state);
+            state = next_state;
+
+            let stats = GenerationStats {
+                generation: slice + 1,
+                best_makespan: self.best_makespan,
+                generation_best: after,
+                mean_makespan: after as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.evaluations(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+
+        info!(
+            usage = %self.usage(),
+            winner = self.winner().unwrap_or("-"),
+            "Hiper-heurística concluída"
+        );
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get() + self.slice_evaluations
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+
+    fn winner(&self) -> Option<&'static str> {
+        self.winner
+            .map(|arm| registry::entry(self.algorithms[arm]).name)
+    }
+}
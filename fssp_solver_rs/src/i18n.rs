@@ -0,0 +1,50 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Idioma dos rótulos exibidos no console e gravados nos arquivos de resultado.
+/// Os formatos de leitura automática (CSV e JSON) usam nomes de campos em inglês e
+/// não dependem do idioma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    Pt, // Português (padrão).
+    En, // Inglês.
+}
+
+impl Lang {
+    /// Escolhe o texto no idioma: `pt` em português, `en` em inglês.
+    pub fn pick(self, pt: &'static str, en: &'static str) -> &'static str {
+        match self {
+            Lang::Pt => pt,
+            Lang::En => en,
+        }
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    /// Aceita também identificadores de localidade, como `en_US.UTF-8` ou `pt-BR`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "pt" => Ok(Lang::Pt),
+            "en" => Ok(Lang::En),
+            _ => Err(format!("Idioma desconhecido '{}' (use pt ou en)", s)),
+        }
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Lang::Pt => "pt",
+            Lang::En => "en",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,375 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::local_search::{self, SearchLimits};
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+use tracing::{debug, debug_span};
+
+/// Critério de aceitação da solução obtida após perturbação e busca local.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acceptance {
+    Better,     // Aceita apenas soluções melhores ou iguais à atual.
+    RandomWalk, // Aceita sempre a nova solução.
+    Annealing,  // Aceita soluções piores com probabilidade de Metropolis (temperatura constante).
+}
+
+impl FromStr for Acceptance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "better" => Ok(Acceptance::Better),
+            "rw" => Ok(Acceptance::RandomWalk),
+            "sa" => Ok(Acceptance::Annealing),
+            _ => Err(format!(
+                "Critério de aceitação desconhecido '{}' (use better, rw ou sa)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Acceptance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Acceptance::Better => "better",
+            Acceptance::RandomWalk => "rw",
+            Acceptance::Annealing => "sa",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parâmetros da Busca Local Iterada.
+#[derive(Debug, Clone)]
+pub struct IlsParams {
+    pub iterations: usize, // Iterações (perturbação + busca local) por aplicação.
+    pub perturbation_strength: usize, // Número de inserções aleatórias na perturbação.
+    pub acceptance: Acceptance, // Critério de aceitação.
+    pub temperature: f64,  // Fator de temperatura do critério `sa`.
+    pub restart_after: Option<usize>, // Iterações sem melhoria antes de reiniciar.
+    pub adaptive_perturbation: bool, // Ajusta a força da perturbação (veja `AdaptiveStrength`).
+}
+
+impl Default for IlsParams {
+    fn default() -> Self {
+        IlsParams {
+            iterations: 20,
+            perturbation_strength: 2,
+            acceptance: Acceptance::Better,
+            temperature: 0.4,
+            restart_after: None,
+            adaptive_perturbation: false,
+        }
+    }
+}
+
+/// Fator de ajuste da força da perturbação após cada sucesso (veja `AdaptiveStrength`).
+const ADAPTATION_FACTOR: f64 = 1.5;
+
+/// Controle da força da perturbação (na ILS) ou do tamanho da destruição (no
+/// Iterated Greedy) pela regra de 1/5: cada candidato aceito que melhora a solução
+/// corrente multiplica a força por `ADAPTATION_FACTOR` e cada iteração sem melhoria
+/// a divide pela raiz quarta do fator, de modo que a força se estabiliza quando uma
+/// em cada cinco iterações melhora a solução corrente. Melhorias frequentes indicam
+/// que perturbações maiores ainda são produtivas; raras, que a busca deve voltar a
+/// explorar a vizinhança próxima. A força fica entre 1 e metade das tarefas.
+#[derive(Debug, Clone)]
+pub(crate) struct AdaptiveStrength {
+    strength: f64, // Força atual (contínua, arredondada ao ser usada).
+    max: f64,      // Maior força permitida.
+}
+
+impl AdaptiveStrength {
+    /// Inicia o controle com a força informada.
+    pub(crate) fn new(initial: usize, n_jobs: usize) -> Self {
+        let max = (n_jobs / 2).max(1) as f64;
+        AdaptiveStrength {
+            strength: (initial as f64).clamp(1.0, max),
+            max,
+        }
+    }
+
+    /// Força a usar na próxima perturbação.
+    pub(crate) fn current(&self) -> usize {
+        self.strength.round() as usize
+    }
+
+    /// Ajusta a força conforme a iteração melhorou ou não a solução corrente.
+    pub(crate) fn update(&mut self, success: bool) {
+        let factor = if success {
+            ADAPTATION_FACTOR
+        } else {
+            ADAPTATION_FACTOR.powf(-0.25)
+        };
+        self.strength = (self.strength * factor).clamp(1.0, self.max);
+    }
+}
+
+/// Perturba a sequência com `strength` movimentos de inserção aleatórios.
+pub(crate) fn perturb<R: Rng>(sequence: &mut Vec<Job>, strength: usize, rng: &mut R) {
+    let n = sequence.len();
+    if n < 2 {
+        return;
+    }
+    for _ in 0..strength {
+        let job = sequence.remove(rng.gen_range(0..n));
+        sequence.insert(rng.gen_range(0..n), job);
+    }
+}
+
+/// Temperatura constante de Osman e Potts: `T * soma(p) / (10 * n * m)`.
+pub(crate) fn temperature(instance: &FSSPInstance, factor: f64) -> f64 {
+    let total: Time = instance.processing_times.iter().flatten().sum();
+    factor * total as f64 / (10 * instance.n_jobs * instance.n_machines).max(1) as f64
+}
+
+/// Trajetória da ILS: solução corrente, melhor solução e contador de estagnação.
+struct Trajectory {
+    current: Vec<Job>,                  // Solução corrente.
+    current_makespan: Time,             // Makespan da solução corrente.
+    best: Vec<Job>,                     // Melhor solução da trajetória.
+    best_makespan: Time,                // Makespan da melhor solução.
+    since_improvement: usize,           // Iterações desde a última melhoria.
+    temperature: f64,                   // Temperatura do critério `sa`.
+    strength: Option<AdaptiveStrength>, // Força adaptativa da perturbação, se ativada.
+}
+
+impl Trajectory {
+    /// Inicia a trajetória aplicando a busca local à solução inicial.
+    fn new(
+        instance: &FSSPInstance,
+        mut start: Vec<Job>,
+        params: &IlsParams,
+        limits: &SearchLimits,
+    ) -> Self {
+        let makespan = local_search::insertion_descent_limited(instance, &mut start, limits);
+        Trajectory {
+            current: start.clone(),
+            current_makespan: makespan,
+            best: start,
+            best_makespan: makespan,
+            since_improvement: 0,
+            temperature: temperature(instance, params.temperature),
+            strength: params
+                .adaptive_perturbation
+                .then(|| AdaptiveStrength::new(params.perturbation_strength, instance.n_jobs)),
+        }
+    }
+
+    /// Executa uma iteração (reinício opcional, perturbação, busca local e aceitação).
+    /// Cada busca local é interrompida pelos limites de `limits`.
+    /// Retorna `true` se a melhor solução da trajetória foi melhorada.
+    fn step<R: Rng>(
+        &mut self,
+        instance: &FSSPInstance,
+        params: &IlsParams,
+        limits: &SearchLimits,
+        rng: &mut R,
+    ) -> bool {
+        // Reinicia a partir de uma permutação aleatória após estagnação.
+        if params
+            .restart_after
+            .is_some_and(|limit| self.since_improvement >= limit)
+        {
+            self.current.shuffle(rng);
+            self.current_makespan =
+                local_search::insertion_descent_limited(instance, &mut self.current, limits);
+            self.since_improvement = 0;
+        }
+
+        let mut candidate = self.current.clone();
+        let strength = self
+            .strength
+            .as_ref()
+            .map_or(params.perturbation_strength, AdaptiveStrength::current);
+        perturb(&mut candidate, strength, rng);
+        let makespan = local_search::insertion_descent_limited(instance, &mut candidate, limits);
+
+        let accept = match params.acceptance {
+            Acceptance::Better => makespan <= self.current_makespan,
+            Acceptance::RandomWalk => true,
+            Acceptance::Annealing => {
+                makespan <= self.current_makespan
+                    || rng.gen::<f64>()
+                        < (-((makespan - self.current_makespan) as f64) / self.temperature).exp()
+            }
+        };
+        if let Some(strength) = &mut self.strength {
+            strength.update(accept && makespan < self.current_makespan);
+        }
+        if accept {
+            self.current = candidate;
+            self.current_makespan = makespan;
+        }
+
+        if self.current_makespan < self.best_makespan {
+            self.best_makespan = self.current_makespan;
+            self.best = self.current.clone();
+            self.since_improvement = 0;
+            true
+        } else {
+            self.since_improvement += 1;
+            false
+        }
+    }
+
+    /// Adota uma solução externa como solução corrente e melhor da trajetória.
+    fn adopt(&mut self, solution: &Solution) {
+        self.current = solution.sequence.clone();
+        self.current_makespan = solution.makespan;
+        self.best = solution.sequence.clone();
+        self.best_makespan = solution.makespan;
+        self.since_improvement = 0;
+    }
+}
+
+/// Aplica a Busca Local Iterada a partir de `sequence`, substituindo-a pela melhor
+/// solução encontrada. Usada como mecanismo de melhoria dentro de outros algoritmos;
+/// as iterações são interrompidas quando o orçamento global de `limits` se esgota.
+/// Retorna o makespan final.
+pub fn improve<R: Rng>(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    params: &IlsParams,
+    limits: &SearchLimits,
+    rng: &mut R,
+) -> Time {
+    let mut trajectory = Trajectory::new(instance, sequence.clone(), params, limits);
+    for _ in 0..params.iterations {
+        if limits.budget_reached(instance) {
+            break;
+        }
+        trajectory.step(instance, params, limits, rng);
+    }
+    *sequence = trajectory.best;
+    trajectory.best_makespan
+}
+
+/// Busca Local Iterada (ILS) como algoritmo independente: parte da solução gulosa
+/// por tempo total de processamento e alterna perturbação e busca local por inserção.
+pub struct IteratedLocalSearch {
+    instance: FSSPInstance,             // Instância do problema FSSP.
+    params: IlsParams,                  // Parâmetros da busca (iterações = critério de parada).
+    initial_solution: Option<Vec<Job>>, // Solução inicial fornecida (substitui a gulosa).
+    pub best_sequence: Vec<Job>,        // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,            // O makespan da melhor sequência encontrada.
+}
+
+impl IteratedLocalSearch {
+    /// Cria uma nova instância do `IteratedLocalSearch`.
+    pub fn new(instance: FSSPInstance, params: IlsParams) -> Self {
+        IteratedLocalSearch {
+            instance,
+            params,
+            initial_solution: None,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Define a solução (já validada) a partir da qual a busca é iniciada.
+    pub fn with_initial_solution(mut self, solution: Vec<Job>) -> Self {
+        self.initial_solution = Some(solution);
+        self
+    }
+}
+
+impl Solver for IteratedLocalSearch {
+    fn name(&self) -> &'static str {
+        "ils"
+    }
+
+    /// Executa a ILS até o número máximo de iterações ou o esgotamento do orçamento.
+    /// Cada busca local também é interrompida no instante limite ou ao atingir o
+    /// máximo de avaliações.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let limits = SearchLimits {
+            deadline: budget.deadline,
+            max_evaluations: budget.max_evaluations,
+            ..SearchLimits::default()
+        };
+
+        // Solução inicial: a fornecida ou, na falta dela, as tarefas em ordem crescente
+        // de tempo total de processamento.
+        let initial = self.initial_solution.clone().unwrap_or_else(|| {
+            let mut initial: Vec<Job> = (0..self.instance.n_jobs as Job).collect();
+            initial.sort_by_key(|&job| {
+                self.instance.processing_times[job as usize]
+                    .iter()
+                    .sum::<Time>()
+            });
+            initial
+        });
+
+        let mut trajectory = Trajectory::new(&self.instance, initial, &self.params, &limits);
+        self.best_makespan = trajectory.best_makespan;
+        self.best_sequence = trajectory.best.clone();
+
+        for iter in 0..self.params.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            if trajectory.step(&self.instance, &self.params, &limits, &mut rng) {
+                self.best_makespan = trajectory.best_makespan;
+                self.best_sequence = trajectory.best.clone();
+                debug!(makespan = self.best_makespan, "Nova melhor solução");
+                observer.on_new_best(&Solution {
+                    sequence: self.best_sequence.clone(),
+                    makespan: self.best_makespan,
+                });
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_makespan,
+                generation_best: trajectory.current_makespan,
+                mean_makespan: trajectory.current_makespan as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+            // Uma solução externa melhor passa a ser a solução corrente.
+            if let Some(solution) = observer.incoming() {
+                if solution.makespan < self.best_makespan {
+                    trajectory.adopt(&solution);
+                    self.best_makespan = trajectory.best_makespan;
+                    self.best_sequence = trajectory.best.clone();
+                }
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -0,0 +1,271 @@
+use crate::fssp_core::{FSSPInstance, Time, TimeValue};
+use std::fmt;
+
+/// Estatísticas descritivas de um conjunto de tempos de processamento, na escala dos
+/// tempos armazenados (veja `Time`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeStats {
+    pub min: Time,    // Menor tempo.
+    pub max: Time,    // Maior tempo.
+    pub mean: f64,    // Média.
+    pub std_dev: f64, // Desvio padrão populacional.
+    pub median: f64,  // Mediana.
+    pub zeros: usize, // Quantidade de tempos nulos.
+}
+
+impl TimeStats {
+    /// Calcula as estatísticas dos valores (todos nulos se a lista for vazia).
+    pub fn new(values: &[Time]) -> Self {
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len().max(1) as f64;
+        let mean = sorted.iter().map(|&v| v as f64).sum::<f64>() / n;
+        let variance = sorted
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let median = match sorted.len() {
+            0 => 0.0,
+            len if len % 2 == 1 => sorted[len / 2] as f64,
+            len => (sorted[len / 2 - 1] as f64 + sorted[len / 2] as f64) / 2.0,
+        };
+        TimeStats {
+            min: sorted.first().copied().unwrap_or(0),
+            max: sorted.last().copied().unwrap_or(0),
+            mean,
+            std_dev: variance.sqrt(),
+            median,
+            zeros: sorted.iter().take_while(|&&v| v == 0).count(),
+        }
+    }
+
+    /// Coeficiente de variação (desvio padrão / média), 0 se a média for nula.
+    pub fn coefficient_of_variation(&self) -> f64 {
+        if self.mean > 0.0 {
+            self.std_dev / self.mean
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Resumo de uma instância para orientar a escolha de parâmetros: distribuição dos
+/// tempos, equilíbrio de carga entre máquinas, relações de dominância e
+/// limitantes inferiores triviais do makespan. A máquina `i` domina `k` quando o
+/// menor tempo de `i` não é inferior ao maior tempo de `k`; duas tarefas são
+/// comparáveis quando uma tem tempos menores ou iguais aos da outra em todas as
+/// máquinas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceReport {
+    pub n_jobs: usize,                  // Número de tarefas.
+    pub n_machines: usize,              // Número de máquinas.
+    pub times: TimeStats,               // Estatísticas de todos os tempos.
+    pub machine_loads: Vec<Time>,       // Soma dos tempos de cada máquina.
+    pub machine_times: Vec<TimeStats>,  // Estatísticas dos tempos de cada máquina.
+    pub job_totals: TimeStats,          // Estatísticas da soma dos tempos de cada tarefa.
+    pub dominant_machines: Vec<usize>,  // Máquinas que dominam todas as outras.
+    pub dominated_machine_pairs: usize, // Pares (i, k) em que a máquina i domina k.
+    pub dominated_job_pairs: usize,     // Pares de tarefas comparáveis por dominância.
+    pub machine_load_bound: Time,       // Maior carga de máquina.
+    pub job_bound: Time,                // Maior soma de tempos de uma tarefa.
+    pub taillard_bound: Time,           // Limitante inferior de Taillard.
+    pub sections: Vec<&'static str>,    // Seções opcionais presentes na instância.
+    pub time_decimals: u32,             // Casas decimais dos tempos, para exibição.
+}
+
+impl InstanceReport {
+    /// Analisa a instância.
+    pub fn new(instance: &FSSPInstance) -> Self {
+        let n_machines = instance.n_machines;
+        let all: Vec<Time> = instance
+            .processing_times
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        let columns: Vec<Vec<Time>> = (0..n_machines)
+            .map(|machine| {
+                instance
+                    .processing_times
+                    .iter()
+                    .map(|times| times[machine])
+                    .collect()
+            })
+            .collect();
+        let machine_times: Vec<TimeStats> = columns.iter().map(|c| TimeStats::new(c)).collect();
+        let machine_loads: Vec<Time> = columns.iter().map(|c| c.iter().sum()).collect();
+        let job_sums: Vec<Time> = instance
+            .processing_times
+            .iter()
+            .map(|times| times.iter().sum())
+            .collect();
+
+        // Dominância entre máquinas: o menor tempo de `i` não é inferior ao maior
+        // tempo de `k`.
+        let dominates = |i: usize, k: usize| i != k && machine_times[i].min >= machine_times[k].max;
+        let dominated_machine_pairs = (0..n_machines)
+            .flat_map(|i| (0..n_machines).map(move |k| (i, k)))
+            .filter(|&(i, k)| dominates(i, k))
+            .count();
+        let dominant_machines = (0..n_machines)
+            .filter(|&i| n_machines > 1 && (0..n_machines).all(|k| k == i || dominates(i, k)))
+            .collect();
+
+        // Dominância entre tarefas: tempos menores ou iguais em todas as máquinas.
+        let times = &instance.processing_times;
+        let dominated_job_pairs = (0..instance.n_jobs)
+            .flat_map(|a| (a + 1..instance.n_jobs).map(move |b| (a, b)))
+            .filter(|&(a, b)| {
+                let le = times[a].iter().zip(&times[b]).all(|(x, y)| x <= y);
+                let ge = times[a].iter().zip(&times[b]).all(|(x, y)| x >= y);
+                le || ge
+            })
+            .count();
+
+        let sections = [
+            ("release_dates", instance.release_dates.is_some()),
+            ("due_dates", instance.due_dates.is_some()),
+            ("weights", instance.weights.is_some()),
+            (
+                "unavailable",
+                instance.unavailability.iter().any(|i| !i.is_empty()),
+            ),
+        ]
+        .into_iter()
+        .filter(|&(_, present)| present)
+        .map(|(section, _)| section)
+        .collect();
+
+        InstanceReport {
+            n_jobs: instance.n_jobs,
+            n_machines,
+            times: TimeStats::new(&all),
+            machine_load_bound: machine_loads.iter().copied().max().unwrap_or(0),
+            machine_loads,
+            machine_times,
+            job_bound: job_sums.iter().copied().max().unwrap_or(0),
+            job_totals: TimeStats::new(&job_sums),
+            dominant_machines,
+            dominated_machine_pairs,
+            dominated_job_pairs,
+            taillard_bound: instance.lower_bound(),
+            sections,
+            time_decimals: instance.time_decimals,
+        }
+    }
+
+    /// Máquina de maior carga (gargalo).
+    pub fn bottleneck(&self) -> usize {
+        (0..self.n_machines)
+            .max_by_key(|&machine| (self.machine_loads[machine], usize::MAX - machine))
+            .unwrap_or(0)
+    }
+}
+
+impl fmt::Display for InstanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Tempos e médias nas unidades originais da instância.
+        let v = |value: Time| TimeValue::new(value, self.time_decimals);
+        let scale = 10f64.powi(self.time_decimals as i32);
+        writeln!(
+            f,
+            "Tarefas: {}  Maquinas: {}  Operacoes: {}",
+            self.n_jobs,
+            self.n_machines,
+            self.n_jobs * self.n_machines
+        )?;
+        if !self.sections.is_empty() {
+            writeln!(f, "Secoes: {}", self.sections.join(", "))?;
+        }
+
+        let t = &self.times;
+        writeln!(f, "\n--- Tempos de Processamento ---")?;
+        writeln!(
+            f,
+            "Min: {}  Max: {}  Media: {:.2}  Mediana: {:.1}  Desvio Padrao: {:.2}  CV: {:.3}",
+            v(t.min),
+            v(t.max),
+            t.mean / scale,
+            t.median / scale,
+            t.std_dev / scale,
+            t.coefficient_of_variation()
+        )?;
+        if t.zeros > 0 {
+            writeln!(f, "Tempos nulos: {}", t.zeros)?;
+        }
+        let j = &self.job_totals;
+        writeln!(
+            f,
+            "Soma por tarefa: Min: {}  Max: {}  Media: {:.2}  CV: {:.3}",
+            v(j.min),
+            v(j.max),
+            j.mean / scale,
+            j.coefficient_of_variation()
+        )?;
+
+        writeln!(f, "\n--- Carga das Maquinas ---")?;
+        writeln!(
+            f,
+            "{:>7} {:>10} {:>8} {:>6} {:>6} {:>9}",
+            "Maquina", "Carga", "% Max", "Min", "Max", "Media"
+        )?;
+        let max_load = self.machine_load_bound.max(1) as f64;
+        for (machine, (load, stats)) in self
+            .machine_loads
+            .iter()
+            .zip(&self.machine_times)
+            .enumerate()
+        {
+            writeln!(
+                f,
+                "{:>7} {:>10} {:>7.1}% {:>6} {:>6} {:>9.2}",
+                machine + 1,
+                v(*load),
+                100.0 * *load as f64 / max_load,
+                v(stats.min),
+                v(stats.max),
+                stats.mean / scale
+            )?;
+        }
+        let balance = TimeStats::new(&self.machine_loads);
+        writeln!(
+            f,
+            "Gargalo: maquina {}  Carga minima/maxima: {:.3}  CV das cargas: {:.3}",
+            self.bottleneck() + 1,
+            balance.min as f64 / max_load,
+            balance.coefficient_of_variation()
+        )?;
+
+        writeln!(f, "\n--- Dominancia ---")?;
+        let job_pairs = self.n_jobs * self.n_jobs.saturating_sub(1) / 2;
+        writeln!(
+            f,
+            "Pares de maquinas com dominancia: {} de {}",
+            self.dominated_machine_pairs,
+            self.n_machines * self.n_machines.saturating_sub(1)
+        )?;
+        if self.dominant_machines.is_empty() {
+            writeln!(f, "Maquina dominante: nenhuma")?;
+        } else {
+            let machines: Vec<String> = self
+                .dominant_machines
+                .iter()
+                .map(|m| (m + 1).to_string())
+                .collect();
+            writeln!(f, "Maquina dominante: {}", machines.join(", "))?;
+        }
+        writeln!(
+            f,
+            "Pares de tarefas comparaveis (uma domina a outra): {} de {} ({:.1}%)",
+            self.dominated_job_pairs,
+            job_pairs,
+            100.0 * self.dominated_job_pairs as f64 / job_pairs.max(1) as f64
+        )?;
+
+        writeln!(f, "\n--- Limitantes Inferiores do Makespan ---")?;
+        writeln!(f, "Maior carga de maquina: {}", v(self.machine_load_bound))?;
+        writeln!(f, "Maior soma de tarefa: {}", v(self.job_bound))?;
+        write!(f, "Taillard: {}", v(self.taillard_bound))
+    }
+}
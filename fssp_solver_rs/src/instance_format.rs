@@ -0,0 +1,590 @@
+use crate::error::{parse_token, tokens_with_columns, FsspError, Result};
+use crate::fssp_core::{
+    max_decimal_places, parse_instance, parse_time, EvaluationCounter, FSSPInstance, Objective,
+    Time, TimeValue,
+};
+use serde::Deserialize;
+use serde_json::Number;
+use std::fmt;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Formato de um arquivo de instância.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InstanceFormat {
+    #[default]
+    Custom, // Formato próprio (`N M` seguido da matriz), também aceita pares VRF.
+    Taillard, // Arquivos originais de Taillard: matriz por máquina após um cabeçalho.
+    Csv,      // Uma linha por tarefa, tempos separados por vírgula.
+    Json,     // Objeto `{"jobs": [[...], ...]}`.
+}
+
+impl FromStr for InstanceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "custom" | "txt" => Ok(InstanceFormat::Custom),
+            "taillard" => Ok(InstanceFormat::Taillard),
+            "csv" => Ok(InstanceFormat::Csv),
+            "json" => Ok(InstanceFormat::Json),
+            _ => Err(format!(
+                "Formato de instância desconhecido '{}' (use custom, taillard, csv ou json)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for InstanceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InstanceFormat::Custom => "custom",
+            InstanceFormat::Taillard => "taillard",
+            InstanceFormat::Csv => "csv",
+            InstanceFormat::Json => "json",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl InstanceFormat {
+    /// Deduz o formato pela extensão do arquivo (`.csv` ou `.json`); qualquer outra
+    /// extensão usa o formato próprio.
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| match ext.to_ascii_lowercase().as_str() {
+                "csv" => Some(InstanceFormat::Csv),
+                "json" => Some(InstanceFormat::Json),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Deduz o formato pela extensão e, para as extensões do formato próprio, pelo
+    /// conteúdo: arquivos iniciados pelo cabeçalho de Taillard (`number of jobs`)
+    /// são lidos nesse formato.
+    pub fn detect(path: &Path, text: &str) -> Self {
+        match InstanceFormat::from_path(path) {
+            InstanceFormat::Custom if text.trim_start().starts_with(TAILLARD_HEADER) => {
+                InstanceFormat::Taillard
+            }
+            format => format,
+        }
+    }
+
+    /// Interpreta o conteúdo de um arquivo neste formato.
+    pub fn parse(self, text: &str) -> Result<FSSPInstance> {
+        match self {
+            InstanceFormat::Custom => parse_instance(text),
+            InstanceFormat::Taillard => parse_taillard_instance(text),
+            InstanceFormat::Csv => parse_csv_instance(text),
+            InstanceFormat::Json => parse_json_instance(text),
+        }
+    }
+
+    /// Escreve a instância neste formato. Os dados que o formato não representa
+    /// (veja `unsupported_sections`) são omitidos.
+    pub fn write(self, instance: &FSSPInstance) -> String {
+        match self {
+            InstanceFormat::Custom => write_instance(instance),
+            InstanceFormat::Taillard => write_taillard_instance(instance),
+            InstanceFormat::Csv => write_csv_instance(instance),
+            InstanceFormat::Json => write_json_instance(instance),
+        }
+    }
+
+    /// Seções da instância, além dos tempos de processamento, que este formato não
+    /// representa e seriam perdidas ao escrevê-la.
+    pub fn unsupported_sections(self, instance: &FSSPInstance) -> Vec<&'static str> {
+        let present = [
+            ("release_dates", instance.release_dates.is_some()),
+            ("due_dates", instance.due_dates.is_some()),
+            ("weights", instance.weights.is_some()),
+            (
+                "unavailable",
+                instance.unavailability.iter().any(|i| !i.is_empty()),
+            ),
+            ("transfer", instance.transfer_times.is_some()),
+        ];
+        present
+            .into_iter()
+            .filter(|&(section, present)| {
+                present
+                    && match self {
+                        InstanceFormat::Custom => false,
+                        InstanceFormat::Json => matches!(section, "unavailable" | "transfer"),
+                        InstanceFormat::Csv => section != "weights",
+                        InstanceFormat::Taillard => true,
+                    }
+            })
+            .map(|(section, _)| section)
+            .collect()
+    }
+}
+
+/// Início do cabeçalho dos arquivos de Taillard.
+const TAILLARD_HEADER: &str = "number of jobs";
+
+/// Interpreta uma instância no formato original de Taillard:
+///
+/// ```text
+/// number of jobs, number of machines, initial seed, upper bound and lower bound :
+///           20           5   873654221        1278        1232
+/// processing times :
+///  54 83 15 71 77 36 53 38 27 87 76 91 14 29 12 77 32 87 68 94
+///  ...
+/// ```
+///
+/// A matriz tem uma linha por máquina e uma coluna por tarefa e pode ter casas
+/// decimais. Arquivos com várias instâncias (como os distribuídos por Taillard, dez
+/// por arquivo) fornecem apenas a primeira.
+pub fn parse_taillard_instance(text: &str) -> Result<FSSPInstance> {
+    let mut lines = text.lines().enumerate();
+    let error = |line: usize, message: &str| FsspError::Format {
+        line,
+        message: message.to_string(),
+    };
+
+    // Cabeçalho: N e M são os dois primeiros valores da linha seguinte.
+    lines
+        .by_ref()
+        .find(|(_, line)| line.trim_start().starts_with(TAILLARD_HEADER))
+        .ok_or_else(|| error(1, "cabeçalho 'number of jobs' não encontrado"))?;
+    let (idx, line) = lines
+        .next()
+        .ok_or_else(|| error(2, "esperados N e M após o cabeçalho"))?;
+    let header: Vec<usize> = tokens_with_columns(line)
+        .into_iter()
+        .take(2)
+        .map(|(column, token)| parse_token(idx + 1, column, token))
+        .collect::<Result<_>>()?;
+    let [n_jobs, n_machines] = header[..] else {
+        return Err(error(idx + 1, "esperados N e M após o cabeçalho"));
+    };
+    let n_times = n_jobs
+        .checked_mul(n_machines)
+        .ok_or_else(|| FsspError::Format {
+            line: idx + 1,
+            message: format!(
+                "dimensões da instância muito grandes: {} tarefas e {} máquinas",
+                n_jobs, n_machines
+            ),
+        })?;
+
+    // Matriz de tempos [máquina][tarefa], lida valor a valor.
+    let (idx, _) = lines
+        .by_ref()
+        .find(|(_, line)| line.trim_start().starts_with("processing times"))
+        .ok_or_else(|| error(idx + 2, "seção 'processing times' não encontrada"))?;
+    let mut last_line = idx + 1;
+    // O cabeçalho não é confiável: a reserva é limitada pelo tamanho do texto, que
+    // tem ao menos dois caracteres por valor.
+    let mut tokens = Vec::with_capacity(n_times.min(text.len() / 2 + 1));
+    for (idx, line) in lines {
+        if tokens.len() == n_times || line.trim_start().starts_with(TAILLARD_HEADER) {
+            break;
+        }
+        last_line = idx + 1;
+        for (column, token) in tokens_with_columns(line) {
+            tokens.push((idx + 1, column, token));
+        }
+    }
+    let decimals = max_decimal_places(tokens.iter().map(|&(_, _, token)| token));
+    let values: Vec<Time> = tokens
+        .iter()
+        .map(|&(line, column, token)| parse_time(line, column, token, decimals))
+        .collect::<Result<_>>()?;
+    if values.len() != n_times {
+        return Err(FsspError::Format {
+            line: last_line,
+            message: format!(
+                "esperados {} tempos de processamento ({} máquinas x {} tarefas), encontrados {}",
+                n_times,
+                n_machines,
+                n_jobs,
+                values.len()
+            ),
+        });
+    }
+
+    let mut instance = from_processing_times(
+        (0..n_jobs)
+            .map(|job| {
+                (0..n_machines)
+                    .map(|machine| values[machine * n_jobs + job])
+                    .collect()
+            })
+            .collect(),
+    )?;
+    instance.time_decimals = decimals;
+    Ok(instance)
+}
+
+/// Escreve a instância no formato próprio (veja `parse_instance`), incluindo as
+/// seções opcionais.
+pub fn write_instance(instance: &FSSPInstance) -> String {
+    let decimals = instance.time_decimals;
+    let mut text = format!("{} {}\n", instance.n_jobs, instance.n_machines);
+    for times in &instance.processing_times {
+        text += &_join(times, decimals, " ");
+        text.push('\n');
+    }
+    for (keyword, values, decimals) in [
+        ("release_dates", &instance.release_dates, decimals),
+        ("due_dates", &instance.due_dates, decimals),
+        ("weights", &instance.weights, 0),
+    ] {
+        if let Some(values) = values {
+            let _ = writeln!(text, "{} {}", keyword, _join(values, decimals, " "));
+        }
+    }
+    for (machine, intervals) in instance.unavailability.iter().enumerate() {
+        for &(start, end) in intervals {
+            let _ = writeln!(
+                text,
+                "unavailable {} {} {}",
+                machine,
+                instance.time_value(start),
+                instance.time_value(end)
+            );
+        }
+    }
+    // Uma linha para todas as tarefas se os tempos de transporte são iguais.
+    match instance.transfer_times.as_deref() {
+        Some([first, rest @ ..]) if rest.iter().all(|times| times == first) => {
+            let _ = writeln!(text, "transfer {}", _join(first, decimals, " "));
+        }
+        Some(transfer_times) => {
+            for (job, times) in transfer_times.iter().enumerate() {
+                let _ = writeln!(text, "transfer {} {}", job, _join(times, decimals, " "));
+            }
+        }
+        None => {}
+    }
+    text
+}
+
+/// Escreve a instância no formato original de Taillard (veja
+/// `parse_taillard_instance`). A semente e o limitante superior, desconhecidos,
+/// são gravados como 0; o limitante inferior é o de `FSSPInstance::lower_bound`.
+pub fn write_taillard_instance(instance: &FSSPInstance) -> String {
+    let mut text = format!(
+        "{}, number of machines, initial seed, upper bound and lower bound :\n",
+        TAILLARD_HEADER
+    );
+    let _ = writeln!(
+        text,
+        "{:>12}{:>12}{:>12}{:>12}{:>12}",
+        instance.n_jobs,
+        instance.n_machines,
+        0,
+        0,
+        instance.time_value(instance.lower_bound())
+    );
+    text += "processing times :\n";
+    for machine in 0..instance.n_machines {
+        let row: Vec<Time> = instance
+            .processing_times
+            .iter()
+            .map(|times| times[machine])
+            .collect();
+        let _ = writeln!(text, " {}", _join(&row, instance.time_decimals, " "));
+    }
+    text
+}
+
+/// Escreve a instância em CSV, com um cabeçalho `M1,M2,...` (seguido de `weight`,
+/// se a instância define pesos) e uma linha por tarefa.
+pub fn write_csv_instance(instance: &FSSPInstance) -> String {
+    let mut header: Vec<String> = (1..=instance.n_machines)
+        .map(|machine| format!("M{}", machine))
+        .collect();
+    if instance.weights.is_some() {
+        header.push("weight".to_string());
+    }
+    let mut text = header.join(",") + "\n";
+    for (job, times) in instance.processing_times.iter().enumerate() {
+        text += &_join(times, instance.time_decimals, ",");
+        if let Some(weights) = &instance.weights {
+            let _ = write!(text, ",{}", weights[job]);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Escreve a instância em JSON (veja `parse_json_instance`), com os tempos de uma
+/// tarefa por linha.
+pub fn write_json_instance(instance: &FSSPInstance) -> String {
+    let jobs: Vec<String> = instance
+        .processing_times
+        .iter()
+        .map(|times| format!("    [{}]", _join(times, instance.time_decimals, ", ")))
+        .collect();
+    let mut text = format!("{{\n  \"jobs\": [\n{}\n  ]", jobs.join(",\n"));
+    for (key, values, decimals) in [
+        (
+            "release_dates",
+            &instance.release_dates,
+            instance.time_decimals,
+        ),
+        ("due_dates", &instance.due_dates, instance.time_decimals),
+        ("weights", &instance.weights, 0),
+    ] {
+        if let Some(values) = values {
+            let _ = write!(
+                text,
+                ",\n  \"{}\": [{}]",
+                key,
+                _join(values, decimals, ", ")
+            );
+        }
+    }
+    text + "\n}\n"
+}
+
+/// Une os valores, com as casas decimais informadas, usando o separador.
+fn _join(values: &[Time], decimals: u32, separator: &str) -> String {
+    values
+        .iter()
+        .map(|&value| TimeValue::new(value, decimals).to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Interpreta uma instância em CSV: uma linha por tarefa com os tempos de cada
+/// máquina separados por vírgula (ou ponto e vírgula, como exportado por planilhas
+/// em português), com ponto como separador decimal. Uma primeira linha com valores
+/// não numéricos é tratada como cabeçalho e ignorada, assim como linhas vazias. Uma
+/// coluna de cabeçalho `weight` (ou `peso`) traz o peso inteiro de cada tarefa.
+pub fn parse_csv_instance(text: &str) -> Result<FSSPInstance> {
+    let mut rows: Vec<(usize, Vec<(usize, &str)>)> = Vec::new();
+    let mut header_checked = false;
+    let mut weight_column = None;
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_number = idx + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = _csv_fields(line);
+        if !header_checked {
+            header_checked = true;
+            if fields
+                .iter()
+                .any(|(_, field)| field.parse::<f64>().is_err())
+            {
+                weight_column = fields.iter().position(|(_, field)| {
+                    matches!(
+                        field.to_ascii_lowercase().as_str(),
+                        "weight" | "weights" | "peso" | "pesos"
+                    )
+                });
+                continue;
+            }
+        }
+        rows.push((line_number, fields));
+    }
+
+    // Separa a coluna de pesos dos tempos de processamento.
+    let mut weights = Vec::new();
+    if let Some(index) = weight_column {
+        for (line_number, fields) in &mut rows {
+            if index >= fields.len() {
+                return Err(FsspError::Format {
+                    line: *line_number,
+                    message: "coluna de pesos ausente".to_string(),
+                });
+            }
+            let (column, field) = fields.remove(index);
+            weights.push(parse_token(*line_number, column, field)?);
+        }
+    }
+
+    let decimals = max_decimal_places(
+        rows.iter()
+            .flat_map(|(_, fields)| fields.iter().map(|&(_, field)| field)),
+    );
+    let mut processing_times: Vec<Vec<Time>> = Vec::with_capacity(rows.len());
+    for (line_number, fields) in rows {
+        let row: Vec<Time> = fields
+            .into_iter()
+            .map(|(column, field)| parse_time(line_number, column, field, decimals))
+            .collect::<Result<_>>()?;
+        if let Some(first) = processing_times.first() {
+            if row.len() != first.len() {
+                return Err(FsspError::Format {
+                    line: line_number,
+                    message: format!(
+                        "esperados {} tempos de processamento (um por máquina), encontrados {}",
+                        first.len(),
+                        row.len()
+                    ),
+                });
+            }
+        }
+        processing_times.push(row);
+    }
+
+    let mut instance = from_processing_times(processing_times)?;
+    instance.time_decimals = decimals;
+    instance.weights = weight_column.map(|_| weights);
+    Ok(instance)
+}
+
+/// Divide uma linha de CSV nos campos separados por `,` ou `;`, sem os espaços ao
+/// redor, retornando a coluna (base 1) de início de cada campo.
+fn _csv_fields(line: &str) -> Vec<(usize, &str)> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    for (idx, ch) in line.char_indices().chain([(line.len(), ',')]) {
+        if ch == ',' || ch == ';' {
+            let raw = &line[start..idx];
+            let leading = raw.len() - raw.trim_start().len();
+            let column = line[..start + leading].chars().count() + 1;
+            fields.push((column, raw.trim()));
+            start = idx + ch.len_utf8();
+        }
+    }
+    fields
+}
+
+/// Estrutura de uma instância em JSON.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonInstance {
+    jobs: Vec<Vec<Number>>, // Tempos de processamento [tarefa][máquina].
+    release_dates: Option<Vec<Number>>, // Instante de liberação de cada tarefa.
+    due_dates: Option<Vec<Number>>, // Data de entrega de cada tarefa.
+    weights: Option<Vec<Time>>, // Peso de cada tarefa nos objetivos ponderados.
+}
+
+/// Interpreta uma instância em JSON no formato `{"jobs": [[p00, p01, ...], ...]}`,
+/// com uma lista de tempos por tarefa. As listas opcionais `release_dates`,
+/// `due_dates` e `weights` têm o mesmo significado das seções do formato próprio;
+/// tempos e instantes podem ter casas decimais.
+pub fn parse_json_instance(text: &str) -> Result<FSSPInstance> {
+    let json: JsonInstance = serde_json::from_str(text).map_err(|e| FsspError::Format {
+        line: e.line(),
+        message: e.to_string(),
+    })?;
+    let tokens = |values: &[Number]| values.iter().map(Number::to_string).collect::<Vec<_>>();
+    let jobs: Vec<Vec<String>> = json.jobs.iter().map(|job| tokens(job)).collect();
+    let release_dates = json.release_dates.as_deref().map(tokens);
+    let due_dates = json.due_dates.as_deref().map(tokens);
+    let decimals = max_decimal_places(
+        jobs.iter()
+            .chain(&release_dates)
+            .chain(&due_dates)
+            .flatten()
+            .map(String::as_str),
+    );
+    let times = |name: &str, values: &[String]| -> Result<Vec<Time>> {
+        values
+            .iter()
+            .map(|value| {
+                parse_time(0, 0, value, decimals).map_err(|_| {
+                    FsspError::Instance(format!("valor inválido '{}' em '{}'", value, name))
+                })
+            })
+            .collect()
+    };
+
+    let mut instance = from_processing_times(
+        jobs.iter()
+            .map(|job| times("jobs", job))
+            .collect::<Result<_>>()?,
+    )?;
+    instance.time_decimals = decimals;
+    let lengths = [
+        ("release_dates", release_dates.as_ref().map(Vec::len)),
+        ("due_dates", due_dates.as_ref().map(Vec::len)),
+        ("weights", json.weights.as_ref().map(Vec::len)),
+    ];
+    for (name, len) in lengths {
+        if let Some(len) = len {
+            if len != instance.n_jobs {
+                return Err(FsspError::Instance(format!(
+                    "esperados {} valores em '{}' (um por tarefa), encontrados {}",
+                    instance.n_jobs, name, len
+                )));
+            }
+        }
+    }
+    instance.release_dates = release_dates
+        .map(|values| times("release_dates", &values))
+        .transpose()?;
+    instance.due_dates = due_dates
+        .map(|values| times("due_dates", &values))
+        .transpose()?;
+    instance.weights = json.weights;
+    instance.check_horizon()?;
+    Ok(instance)
+}
+
+/// Cria uma instância a partir da matriz de tempos [tarefa][máquina], que deve ter
+/// ao menos uma tarefa e uma máquina e o mesmo número de máquinas em cada tarefa.
+/// Os tempos são inteiros (`time_decimals` 0).
+pub fn from_processing_times(processing_times: Vec<Vec<Time>>) -> Result<FSSPInstance> {
+    let n_jobs = processing_times.len();
+    let n_machines = processing_times.first().map_or(0, Vec::len);
+    if n_jobs == 0 || n_machines == 0 {
+        return Err(FsspError::Instance(
+            "a instância deve ter ao menos uma tarefa e uma máquina".to_string(),
+        ));
+    }
+    if let Some(job) = processing_times
+        .iter()
+        .position(|row| row.len() != n_machines)
+    {
+        return Err(FsspError::Instance(format!(
+            "a tarefa {} tem {} tempos de processamento, esperados {}",
+            job,
+            processing_times[job].len(),
+            n_machines
+        )));
+    }
+    let instance = FSSPInstance {
+        n_jobs,
+        n_machines,
+        processing_times,
+        release_dates: None,
+        due_dates: None,
+        weights: None,
+        unavailability: vec![Vec::new(); n_machines],
+        objective: Objective::Makespan,
+        evaluations: EvaluationCounter::default(),
+        time_decimals: 0,
+        uncertainty: None,
+        sublots: None,
+        transfer_times: None,
+    };
+    instance.check_horizon()?;
+    Ok(instance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taillard_header_dimensions_are_checked() {
+        let text = |header: &str| {
+            format!(
+                "number of jobs, number of machines, initial seed, upper bound and lower bound :\n\
+                 {}\nprocessing times :\n 1 2\n 3 4\n",
+                header
+            )
+        };
+        let overflow = parse_taillard_instance(&text("18446744073709551615 2 0 0 0"));
+        assert!(matches!(overflow, Err(FsspError::Format { line: 2, .. })));
+        // Dimensões enormes não reservam memória antes da contagem dos valores.
+        let huge = parse_taillard_instance(&text("4000000000 4000000000 0 0 0"));
+        assert!(matches!(huge, Err(FsspError::Format { line: 5, .. })));
+        let instance = parse_taillard_instance(&text("2 2 0 0 0")).unwrap();
+        assert_eq!(instance.processing_times, vec![vec![1, 3], vec![2, 4]]);
+    }
+}
@@ -0,0 +1,295 @@
+use crate::budget::Budget;
+use crate::fssp_core::{Job, Time};
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::shared_best::{SharedBest, SharedBestHandle};
+use crate::solver::{MemeticAlgorithm, Solver};
+use crate::time::Instant;
+use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use tracing::{debug, debug_span};
+
+/// Topologia de migração entre as ilhas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    Ring,     // Cada ilha envia migrantes para a ilha seguinte.
+    Complete, // Cada ilha envia migrantes para todas as outras.
+    Random,   // Cada ilha envia migrantes para uma ilha sorteada.
+}
+
+impl FromStr for Topology {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ring" => Ok(Topology::Ring),
+            "complete" => Ok(Topology::Complete),
+            "random" => Ok(Topology::Random),
+            _ => Err(format!(
+                "Topologia desconhecida '{}' (use ring, complete ou random)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Topology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Topology::Ring => "ring",
+            Topology::Complete => "complete",
+            Topology::Random => "random",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Modelo de ilhas: várias subpopulações do Algoritmo Memético evoluem em paralelo
+/// e trocam seus melhores indivíduos a cada `migration_interval` gerações. Com o
+/// compartilhamento ativado, cada nova melhor solução de uma ilha também é enviada
+/// às demais assim que encontrada, e entra no lugar do pior indivíduo de cada ilha
+/// ao final da sua geração corrente.
+pub struct IslandModel {
+    islands: Vec<MemeticAlgorithm>, // Subpopulações independentes.
+    migration_interval: usize,      // Gerações entre migrações.
+    migration_rate: f64,            // Fração da população enviada em cada migração.
+    topology: Topology,             // Topologia de migração.
+    sharing: bool,                  // Compartilha a melhor solução global entre migrações.
+    pub best_sequence: Vec<Job>,    // A melhor sequência encontrada entre todas as ilhas.
+    pub best_makespan: Time,        // O makespan da melhor sequência encontrada.
+}
+
+impl IslandModel {
+    /// Cria um novo modelo de ilhas a partir de algoritmos já configurados.
+    pub fn new(
+        islands: Vec<MemeticAlgorithm>,
+        migration_interval: usize,
+        migration_rate: f64,
+        topology: Topology,
+    ) -> Self {
+        IslandModel {
+            islands,
+            migration_interval: migration_interval.max(1),
+            migration_rate,
+            topology,
+            sharing: true,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Ativa ou desativa o compartilhamento da melhor solução global entre as
+    /// ilhas (ativado por padrão). Desativado, as ilhas trocam soluções apenas nas
+    /// migrações.
+    pub fn with_sharing(mut self, sharing: bool) -> Self {
+        self.sharing = sharing;
+        self
+    }
+
+    /// Atualiza a melhor solução global a partir das melhores soluções das ilhas.
+    /// Retorna `true` se houve melhoria.
+    fn _update_best(&mut self) -> bool {
+        let mut improved = false;
+        for island in &self.islands {
+            if island.best_makespan < self.best_makespan {
+                self.best_makespan = island.best_makespan;
+                self.best_sequence = island.best_sequence.clone();
+                improved = true;
+            }
+        }
+        improved
+    }
+
+    /// Envia os melhores indivíduos de cada ilha para as ilhas vizinhas conforme a topologia.
+    fn _migrate(&mut self) {
+        let n = self.islands.len();
+        if n < 2 {
+            return;
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut incoming: Vec<Vec<Vec<Job>>> = vec![Vec::new(); n];
+
+        for (source, island) in self.islands.iter_mut().enumerate() {
+            let count =
+                ((island.population_size() as f64 * self.migration_rate).round() as usize).max(1);
+            let migrants = island.best_individuals(count);
+
+            let targets: Vec<usize> = match self.topology {
+                Topology::Ring => vec![(source + 1) % n],
+                Topology::Complete => (0..n).filter(|&t| t != source).collect(),
+                Topology::Random => {
+                    // Sorteia uma ilha diferente da origem.
+                    let offset = rng.gen_range(1..n);
+                    vec![(source + offset) % n]
+                }
+            };
+            for target in targets {
+                incoming[target].extend(migrants.iter().cloned());
+            }
+        }
+
+        maybe_par_iter_mut!(self.islands)
+            .zip(incoming)
+            .for_each(|(island, migrants)| island.replace_worst(migrants));
+    }
+}
+
+/// Publica a melhor solução da ilha, se ela melhorou na última geração, e adota a
+/// melhor solução recebida das demais ilhas, se for melhor que a da ilha.
+fn _share(island: &mut MemeticAlgorithm, handle: &SharedBestHandle, improved: bool) {
+    if improved {
+        handle.publish(&Solution {
+            sequence: island.best_sequence.clone(),
+            makespan: island.best_makespan,
+        });
+    }
+    if let Some(solution) = handle.receive() {
+        if solution.makespan < island.best_makespan {
+            island.replace_worst(vec![solution.sequence]);
+        }
+    }
+}
+
+impl Solver for IslandModel {
+    fn name(&self) -> &'static str {
+        "islands"
+    }
+
+    /// Executa o modelo de ilhas até o número máximo de gerações ou o esgotamento do
+    /// orçamento. O tempo é compartilhado por todas as ilhas, e o máximo de
+    /// avaliações é dividido igualmente entre elas. O observador é notificado a cada
+    /// migração.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let generations = self
+            .islands
+            .iter()
+            .map(|island| island.generations())
+            .max()
+            .unwrap_or(0);
+        let island_budget = Budget {
+            max_evaluations: budget
+                .max_evaluations
+                .map(|max| max / self.islands.len().max(1) as u64),
+            ..*budget
+        };
+        maybe_par_iter_mut!(self.islands).for_each(|island| {
+            island.set_budget(&island_budget);
+            island.initialize();
+        });
+        let shared = SharedBest::new();
+        let mut handles: Vec<Option<SharedBestHandle>> = self
+            .islands
+            .iter()
+            .map(|_| self.sharing.then(|| shared.subscribe()))
+            .collect();
+
+        let mut gen = 0;
+        while gen < generations {
+            if budget.should_stop(start_time, gen, self.evaluations()) {
+                break;
+            }
+
+            let _span = debug_span!("epoch", gen).entered();
+
+            // Cada ilha evolui de forma independente até a próxima migração.
+            let epoch = self
+                .migration_interval
+                .min(generations - gen)
+                .min(budget.max_generations.map_or(usize::MAX, |max| max - gen));
+            maybe_par_iter_mut!(self.islands)
+                .zip(maybe_par_iter_mut!(handles))
+                .for_each(|(island, handle)| {
+                    for _ in 0..epoch {
+                        if island.budget_exhausted() {
+                            break;
+                        }
+                        let improved = island.evolve_generation();
+                        if let Some(handle) = handle {
+                            _share(island, handle, improved);
+                        }
+                    }
+                    island.sync_best();
+                });
+            gen += epoch;
+
+            if self._update_best() {
+                debug!(makespan = self.best_makespan, "Nova melhor solução");
+                observer.on_new_best(&Solution {
+                    sequence: self.best_sequence.clone(),
+                    makespan: self.best_makespan,
+                });
+            }
+
+            let island_bests: Vec<Time> = self.islands.iter().map(|i| i.best_makespan).collect();
+            // Operadores de todas as ilhas desde a última migração.
+            let operators = self
+                .islands
+                .iter_mut()
+                .filter_map(|island| island.take_operator_stats())
+                .reduce(|mut total, stats| {
+                    total.merge(&stats);
+                    total
+                });
+            let stats = GenerationStats {
+                generation: gen,
+                best_makespan: self.best_makespan,
+                generation_best: self.best_makespan,
+                mean_makespan: island_bests.iter().map(|&m| m as f64).sum::<f64>()
+                    / island_bests.len().max(1) as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators,
+                evaluations: self.islands.iter().map(|island| island.evaluations()).sum(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+
+            if gen < generations {
+                self._migrate();
+            }
+            // Uma solução externa melhor entra no lugar do pior indivíduo de cada ilha.
+            if let Some(solution) = observer.incoming() {
+                if solution.makespan < self.best_makespan {
+                    for island in &mut self.islands {
+                        island.replace_worst(vec![solution.sequence.clone()]);
+                    }
+                }
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.islands.iter().map(|island| island.evaluations()).sum()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+
+    /// União das populações de todas as ilhas.
+    fn population(&self) -> Vec<(Vec<Job>, Time)> {
+        let mut population: Vec<(Vec<Job>, Time)> = self
+            .islands
+            .iter()
+            .flat_map(|island| island.population())
+            .collect();
+        population.sort_by_key(|&(_, value)| value);
+        population
+    }
+}
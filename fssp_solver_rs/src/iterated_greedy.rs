@@ -0,0 +1,187 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics;
+use crate::ils;
+use crate::local_search::{self, SearchLimits};
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::Rng;
+use tracing::{debug, debug_span};
+
+/// Parâmetros do Iterated Greedy. Cada iteração consiste em destruição, reconstrução
+/// e busca local por inserção.
+#[derive(Debug, Clone)]
+pub struct IgParams {
+    pub iterations: usize,           // Número de iterações (critério de parada).
+    pub destruction_size: usize,     // Tarefas removidas em cada destruição.
+    pub temperature: f64,            // Fator de temperatura do critério de aceitação.
+    pub adaptive_perturbation: bool, // Ajusta o tamanho da destruição (regra de 1/5).
+}
+
+impl Default for IgParams {
+    fn default() -> Self {
+        IgParams {
+            iterations: 100,
+            destruction_size: 4,
+            temperature: 0.4,
+            adaptive_perturbation: false,
+        }
+    }
+}
+
+/// Iterated Greedy de Ruiz e Stützle (2007): parte da solução do NEH e, a cada
+/// iteração, remove `destruction_size` tarefas sorteadas, reinsere cada uma na
+/// melhor posição (como no NEH) e aplica a busca local por inserção. Soluções piores
+/// são aceitas com a probabilidade de Metropolis sob temperatura constante. Com
+/// `adaptive_perturbation`, o número de tarefas removidas parte de
+/// `destruction_size` e é ajustado pela regra de 1/5 (veja `ils::AdaptiveStrength`).
+pub struct IteratedGreedy {
+    instance: FSSPInstance,             // Instância do problema FSSP.
+    params: IgParams,                   // Parâmetros da busca (iterações = critério de parada).
+    initial_solution: Option<Vec<Job>>, // Solução inicial fornecida (substitui o NEH).
+    pub best_sequence: Vec<Job>,        // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,            // O makespan da melhor sequência encontrada.
+}
+
+impl IteratedGreedy {
+    /// Cria uma nova instância do `IteratedGreedy`, que parte da solução do NEH.
+    pub fn new(instance: FSSPInstance, params: IgParams) -> Self {
+        IteratedGreedy {
+            instance,
+            params,
+            initial_solution: None,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Define a solução (já validada) a partir da qual a busca é iniciada, no lugar
+    /// da solução do NEH.
+    pub fn with_initial_solution(mut self, solution: Vec<Job>) -> Self {
+        self.initial_solution = Some(solution);
+        self
+    }
+
+    /// Remove `size` tarefas sorteadas e reinsere cada uma, na ordem da remoção, na
+    /// posição de menor makespan.
+    fn _destruct_construct<R: Rng>(&self, sequence: &mut Vec<Job>, size: usize, rng: &mut R) {
+        let size = size.min(sequence.len());
+        let removed: Vec<Job> = (0..size)
+            .map(|_| sequence.remove(rng.gen_range(0..sequence.len())))
+            .collect();
+        for job in removed {
+            let (pos, _) = local_search::best_insertion(&self.instance, sequence, job);
+            sequence.insert(pos, job);
+        }
+    }
+}
+
+impl Solver for IteratedGreedy {
+    fn name(&self) -> &'static str {
+        "ig"
+    }
+
+    /// Executa o Iterated Greedy até o número máximo de iterações ou o esgotamento do
+    /// orçamento. Cada busca local também é interrompida no instante limite ou ao
+    /// atingir o máximo de avaliações.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let limits = SearchLimits {
+            deadline: budget.deadline,
+            max_evaluations: budget.max_evaluations,
+            ..SearchLimits::default()
+        };
+        let temperature = ils::temperature(&self.instance, self.params.temperature);
+        let mut destruction = self.params.adaptive_perturbation.then(|| {
+            ils::AdaptiveStrength::new(self.params.destruction_size, self.instance.n_jobs)
+        });
+
+        let mut current = self
+            .initial_solution
+            .clone()
+            .unwrap_or_else(|| heuristics::neh(&self.instance));
+        let mut current_makespan =
+            local_search::insertion_descent_limited(&self.instance, &mut current, &limits);
+        self.best_sequence = current.clone();
+        self.best_makespan = current_makespan;
+
+        for iter in 0..self.params.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            let mut candidate = current.clone();
+            let size = destruction
+                .as_ref()
+                .map_or(self.params.destruction_size, ils::AdaptiveStrength::current);
+            self._destruct_construct(&mut candidate, size, &mut rng);
+            let makespan =
+                local_search::insertion_descent_limited(&self.instance, &mut candidate, &limits);
+            if let Some(destruction) = &mut destruction {
+                destruction.update(makespan < current_makespan);
+            }
+
+            if makespan <= current_makespan
+                || rng.gen::<f64>() < (-((makespan - current_makespan) as f64) / temperature).exp()
+            {
+                current = candidate;
+                current_makespan = makespan;
+                if makespan < self.best_makespan {
+                    self.best_makespan = makespan;
+                    self.best_sequence = current.clone();
+                    debug!(makespan, "Nova melhor solução");
+                    observer.on_new_best(&Solution {
+                        sequence: self.best_sequence.clone(),
+                        makespan,
+                    });
+                }
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_makespan,
+                generation_best: current_makespan,
+                mean_makespan: current_makespan as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+            // Uma solução externa melhor passa a ser a solução corrente.
+            if let Some(solution) = observer.incoming() {
+                if solution.makespan < self.best_makespan {
+                    current = solution.sequence;
+                    current_makespan = solution.makespan;
+                    self.best_sequence = current.clone();
+                    self.best_makespan = current_makespan;
+                }
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -0,0 +1,224 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics;
+use crate::local_search::SearchLimits;
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::random_key;
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::rngs::ThreadRng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::{debug, debug_span};
+
+/// Estado comum às metaheurísticas populacionais sobre chaves aleatórias
+/// (evolução diferencial, BRKGA e PSO): a população de vetores de chaves, o valor
+/// de cada um e a melhor solução encontrada.
+pub struct KeyPopulation {
+    pub instance: FSSPInstance,           // Instância do problema FSSP.
+    pub size: usize,                      // Tamanho da população.
+    pub generations: usize,               // Número máximo de gerações.
+    pub initial_solutions: Vec<Vec<Job>>, // Soluções fornecidas que iniciam a população.
+    pub keys: Vec<Vec<f64>>,              // Chaves de cada indivíduo.
+    pub fitness: Vec<Time>,               // Valor da função objetivo de cada indivíduo.
+    pub best_sequence: Vec<Job>,          // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,              // O makespan da melhor sequência encontrada.
+}
+
+impl KeyPopulation {
+    /// Cria uma população vazia de `size` indivíduos, preenchida por `initialize`.
+    pub fn new(instance: FSSPInstance, size: usize, generations: usize) -> Self {
+        KeyPopulation {
+            instance,
+            size,
+            generations,
+            initial_solutions: Vec::new(),
+            keys: Vec::new(),
+            fitness: Vec::new(),
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// População inicial: as soluções fornecidas, a solução NEH e chaves aleatórias.
+    pub fn initialize(&mut self, rng: &mut ThreadRng) {
+        let n = self.instance.n_jobs;
+        let mut keys: Vec<Vec<f64>> = self
+            .initial_solutions
+            .iter()
+            .take(self.size)
+            .map(|seq| random_key::encode(seq))
+            .collect();
+        if keys.len() < self.size {
+            keys.push(random_key::encode(&heuristics::neh(&self.instance)));
+        }
+        while keys.len() < self.size {
+            keys.push(random_key::random_keys(n, rng));
+        }
+        self.fitness = self.evaluate(&keys);
+        self.keys = keys;
+    }
+
+    /// Decodifica e avalia os vetores de chaves (em paralelo, com o recurso
+    /// `parallel`).
+    pub fn evaluate(&self, keys: &[Vec<f64>]) -> Vec<Time> {
+        maybe_par_iter!(keys)
+            .map(|keys| self.instance.evaluate(&random_key::decode(keys)))
+            .collect()
+    }
+
+    /// Índice do melhor indivíduo da população (o primeiro, em caso de empate).
+    pub fn best_index(&self) -> usize {
+        (0..self.fitness.len())
+            .min_by_key(|&idx| self.fitness[idx])
+            .unwrap()
+    }
+
+    /// Refina o melhor indivíduo por busca local por inserção, atualizando suas
+    /// chaves. Retorna o índice do indivíduo refinado.
+    fn _improve_best(&mut self, limits: &SearchLimits) -> usize {
+        let best = self.best_index();
+        let value = random_key::improve(&self.instance, &mut self.keys[best], limits);
+        self.fitness[best] = value.min(self.fitness[best]);
+        best
+    }
+
+    /// Atualiza a melhor solução global a partir do indivíduo `idx`, notificando o
+    /// observador se houve melhoria.
+    fn _update_best(&mut self, idx: usize, observer: &mut dyn Observer) {
+        if self.fitness[idx] >= self.best_makespan {
+            return;
+        }
+        self.best_makespan = self.fitness[idx];
+        self.best_sequence = random_key::decode(&self.keys[idx]);
+        debug!(makespan = self.best_makespan, "Nova melhor solução");
+        observer.on_new_best(&Solution {
+            sequence: self.best_sequence.clone(),
+            makespan: self.best_makespan,
+        });
+    }
+}
+
+/// Metaheurística populacional sobre chaves aleatórias. O algoritmo define apenas
+/// como a população evolui em uma geração; o laço de gerações, o orçamento, o
+/// refinamento da melhor solução e a notificação do observador são comuns, e todo
+/// `KeyAlgorithm` é um `Solver`.
+pub trait KeyAlgorithm: Send {
+    /// Nome curto do algoritmo (ex: "de").
+    const NAME: &'static str;
+
+    /// Estado comum da população.
+    fn state(&self) -> &KeyPopulation;
+
+    /// Estado comum da população, mutável.
+    fn state_mut(&mut self) -> &mut KeyPopulation;
+
+    /// Gera a população inicial.
+    fn initialize(&mut self, rng: &mut ThreadRng) {
+        self.state_mut().initialize(rng);
+    }
+
+    /// Executa uma geração. O refinamento da melhor solução é feito em seguida.
+    fn step(&mut self, rng: &mut ThreadRng, budget: &Budget);
+
+    /// Taxa de mutação informada nas estatísticas de cada geração.
+    fn mutation_rate(&self) -> f64 {
+        0.0
+    }
+}
+
+impl<A: KeyAlgorithm> Solver for A {
+    fn name(&self) -> &'static str {
+        A::NAME
+    }
+
+    /// Executa o algoritmo até o número máximo de gerações ou o esgotamento do
+    /// orçamento, verificado a cada geração. A cada geração em que a melhor solução
+    /// da população muda, ela é refinada por busca local por inserção e suas chaves
+    /// são reordenadas para a sequência melhorada (aprendizado lamarckiano).
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let limits = SearchLimits {
+            deadline: budget.deadline,
+            max_evaluations: budget.max_evaluations,
+            ..SearchLimits::default()
+        };
+        self.initialize(&mut rng);
+        let mut polished: Option<(usize, Time)> = None;
+
+        for gen in 0..self.state().generations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, gen, self.evaluations()) {
+                break;
+            }
+
+            let _span = debug_span!("generation", gen = gen + 1).entered();
+            self.step(&mut rng, budget);
+
+            // Refina a melhor solução da população apenas quando ela muda.
+            let state = self.state_mut();
+            let best = state.best_index();
+            let best = if polished != Some((best, state.fitness[best])) {
+                let best = state._improve_best(&limits);
+                polished = Some((best, state.fitness[best]));
+                best
+            } else {
+                best
+            };
+            state._update_best(best, observer);
+
+            let state = self.state();
+            let stats = GenerationStats {
+                generation: gen + 1,
+                best_makespan: state.best_makespan,
+                generation_best: state.fitness[best],
+                mean_makespan: state.fitness.iter().map(|&f| f as f64).sum::<f64>()
+                    / state.fitness.len() as f64,
+                mutation_rate: self.mutation_rate(),
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: state.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+
+        // Sem gerações completas, a melhor solução é a da população inicial.
+        let state = self.state_mut();
+        let best = state.best_index();
+        state._update_best(best, observer);
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.state().instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.state().best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.state().best_sequence
+    }
+
+    fn population(&self) -> Vec<(Vec<Job>, Time)> {
+        let state = self.state();
+        let mut population: Vec<(Vec<Job>, Time)> = state
+            .keys
+            .iter()
+            .zip(&state.fitness)
+            .map(|(keys, &value)| (random_key::decode(keys), value))
+            .collect();
+        population.sort_by_key(|&(_, value)| value);
+        population
+    }
+}
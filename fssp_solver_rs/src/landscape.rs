@@ -0,0 +1,228 @@
+use crate::fssp_core::{FSSPInstance, Job, Time, TimeValue};
+use crate::inspect::TimeStats;
+use crate::local_search;
+use crate::solver::kendall_tau_distance;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Defasagens em que a autocorrelação do passeio aleatório é reportada.
+const LAGS: [usize; 4] = [1, 2, 5, 10];
+
+/// Parâmetros da análise da paisagem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LandscapeParams {
+    pub walk_length: usize, // Passos do passeio aleatório.
+    pub samples: usize,     // Ótimos locais amostrados.
+}
+
+impl Default for LandscapeParams {
+    fn default() -> Self {
+        LandscapeParams {
+            walk_length: 1000,
+            samples: 50,
+        }
+    }
+}
+
+/// Caracterização da paisagem de aptidão de uma instância na vizinhança de
+/// inserção, a partir da função objetivo da instância:
+/// - passeio aleatório: a cada passo, uma tarefa sorteada é reinserida em outra
+///   posição sorteada; a autocorrelação dos valores ao longo do passeio mede a
+///   rugosidade da paisagem (próxima de 1 indica uma paisagem suave), e o
+///   comprimento de correlação `-1 / ln|r(1)|` estima quantos passos separam
+///   soluções pouco correlacionadas;
+/// - amostragem de ótimos locais: permutações aleatórias são levadas a ótimos
+///   locais pela busca local por inserção, e a correlação entre o valor de cada
+///   ótimo e sua distância de Kendall-tau ao melhor ótimo amostrado (FDC) indica
+///   uma estrutura de "grande vale" quando positiva.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandscapeReport {
+    pub walk_length: usize,                        // Passos do passeio aleatório.
+    pub walk: TimeStats,                           // Estatísticas dos valores do passeio.
+    pub autocorrelation: Vec<(usize, f64)>,        // Autocorrelação em cada defasagem.
+    pub correlation_length: Option<f64>,           // Comprimento de correlação.
+    pub samples: usize,                            // Ótimos locais amostrados.
+    pub optima: TimeStats,                         // Estatísticas dos ótimos locais.
+    pub distinct_optima: usize,                    // Ótimos locais distintos amostrados.
+    pub best_optimum: Vec<Job>,                    // Melhor ótimo local amostrado.
+    pub mean_distance: f64,                        // Distância média ao melhor ótimo (normalizada).
+    pub fitness_distance_correlation: Option<f64>, // FDC dos ótimos locais.
+    pub evaluations: u64,                          // Avaliações da função objetivo na análise.
+    pub time_decimals: u32,                        // Casas decimais dos tempos, para exibição.
+}
+
+impl LandscapeReport {
+    /// Analisa a paisagem da instância.
+    pub fn new<R: Rng>(instance: &FSSPInstance, params: LandscapeParams, rng: &mut R) -> Self {
+        let n = instance.n_jobs;
+        let start_evaluations = instance.evaluations.get();
+
+        // Passeio aleatório na vizinhança de inserção.
+        let mut sequence: Vec<Job> = (0..n as Job).collect();
+        sequence.shuffle(rng);
+        let mut walk = Vec::with_capacity(params.walk_length + 1);
+        walk.push(instance.evaluate(&sequence));
+        if n >= 2 {
+            for _ in 0..params.walk_length {
+                let from = rng.gen_range(0..n);
+                let job = sequence.remove(from);
+                let to = (from + rng.gen_range(1..n)) % n;
+                sequence.insert(to, job);
+                walk.push(instance.evaluate(&sequence));
+            }
+        }
+        let autocorrelation: Vec<(usize, f64)> = LAGS
+            .iter()
+            .filter(|&&lag| lag < walk.len())
+            .filter_map(|&lag| _autocorrelation(&walk, lag).map(|r| (lag, r)))
+            .collect();
+        let correlation_length = autocorrelation
+            .first()
+            .filter(|&&(lag, r)| lag == 1 && r.abs() > 0.0 && r.abs() < 1.0)
+            .map(|&(_, r)| -1.0 / r.abs().ln());
+
+        // Amostragem de ótimos locais da busca por inserção.
+        let optima: Vec<(Vec<Job>, Time)> = (0..params.samples.max(1))
+            .map(|_| {
+                let mut sequence: Vec<Job> = (0..n as Job).collect();
+                sequence.shuffle(rng);
+                let value = local_search::insertion_descent(instance, &mut sequence);
+                (sequence, value)
+            })
+            .collect();
+        let (best_optimum, _) = optima.iter().min_by_key(|(_, value)| *value).unwrap();
+        let max_distance = (n * n.saturating_sub(1) / 2).max(1) as f64;
+        let distances: Vec<f64> = optima
+            .iter()
+            .map(|(seq, _)| kendall_tau_distance(seq, best_optimum) as f64 / max_distance)
+            .collect();
+        let values: Vec<f64> = optima.iter().map(|&(_, value)| value as f64).collect();
+        let distinct_optima = optima
+            .iter()
+            .map(|(seq, _)| seq)
+            .collect::<HashSet<_>>()
+            .len();
+
+        LandscapeReport {
+            walk_length: walk.len() - 1,
+            walk: TimeStats::new(&walk),
+            autocorrelation,
+            correlation_length,
+            samples: optima.len(),
+            optima: TimeStats::new(&optima.iter().map(|&(_, v)| v).collect::<Vec<_>>()),
+            distinct_optima,
+            best_optimum: best_optimum.clone(),
+            mean_distance: distances.iter().sum::<f64>() / distances.len() as f64,
+            fitness_distance_correlation: _pearson(&values, &distances),
+            evaluations: instance.evaluations.get() - start_evaluations,
+            time_decimals: instance.time_decimals,
+        }
+    }
+}
+
+/// Autocorrelação da série na defasagem `lag` (nula se a série for constante).
+fn _autocorrelation(series: &[Time], lag: usize) -> Option<f64> {
+    let n = series.len() as f64;
+    let mean = series.iter().map(|&v| v as f64).sum::<f64>() / n;
+    let variance = series
+        .iter()
+        .map(|&v| (v as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    if variance == 0.0 {
+        return None;
+    }
+    let pairs = series.len() - lag;
+    let covariance = series
+        .iter()
+        .zip(&series[lag..])
+        .map(|(&a, &b)| (a as f64 - mean) * (b as f64 - mean))
+        .sum::<f64>()
+        / pairs as f64;
+    Some(covariance / variance)
+}
+
+/// Coeficiente de correlação de Pearson (nulo se uma das séries for constante).
+fn _pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    let n = x.len() as f64;
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (&a, &b) in x.iter().zip(y) {
+        cov += (a - mean_x) * (b - mean_y);
+        var_x += (a - mean_x).powi(2);
+        var_y += (b - mean_y).powi(2);
+    }
+    (var_x > 0.0 && var_y > 0.0).then(|| cov / (var_x * var_y).sqrt())
+}
+
+impl fmt::Display for LandscapeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = |value: Time| TimeValue::new(value, self.time_decimals);
+        let scale = 10f64.powi(self.time_decimals as i32);
+        let optional = |value: Option<f64>| match value {
+            Some(value) => format!("{:.3}", value),
+            None => "indefinida".to_string(),
+        };
+
+        writeln!(f, "--- Passeio Aleatorio (insercao) ---")?;
+        writeln!(
+            f,
+            "Passos: {}  Min: {}  Max: {}  Media: {:.2}  Desvio Padrao: {:.2}",
+            self.walk_length,
+            v(self.walk.min),
+            v(self.walk.max),
+            self.walk.mean / scale,
+            self.walk.std_dev / scale
+        )?;
+        let lags: Vec<String> = self
+            .autocorrelation
+            .iter()
+            .map(|(lag, r)| format!("r({})={:.3}", lag, r))
+            .collect();
+        writeln!(
+            f,
+            "Autocorrelacao: {}",
+            if lags.is_empty() {
+                "indefinida".to_string()
+            } else {
+                lags.join("  ")
+            }
+        )?;
+        writeln!(
+            f,
+            "Comprimento de correlacao: {}",
+            optional(self.correlation_length)
+        )?;
+
+        writeln!(f, "\n--- Otimos Locais (busca por insercao) ---")?;
+        writeln!(
+            f,
+            "Amostras: {}  Distintos: {}  Min: {}  Max: {}  Media: {:.2}  Desvio Padrao: {:.2}",
+            self.samples,
+            self.distinct_optima,
+            v(self.optima.min),
+            v(self.optima.max),
+            self.optima.mean / scale,
+            self.optima.std_dev / scale
+        )?;
+        writeln!(
+            f,
+            "Distancia media ao melhor otimo (Kendall-tau normalizada): {:.3}",
+            self.mean_distance
+        )?;
+        writeln!(
+            f,
+            "Correlacao aptidao-distancia (FDC): {}",
+            optional(self.fitness_distance_correlation)
+        )?;
+        let best: Vec<String> = self
+            .best_optimum
+            .iter()
+            .map(|job| (job + 1).to_string())
+            .collect();
+        writeln!(f, "Melhor otimo: {}", best.join(" "))?;
+        write!(f, "Avaliacoes: {}", self.evaluations)
+    }
+}
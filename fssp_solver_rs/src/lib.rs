@@ -1,2 +1,82 @@
+/// Itera mutavelmente em paralelo (rayon) com o recurso `parallel` e
+/// sequencialmente sem ele. O módulo que a usa deve importar `rayon::prelude::*`
+/// quando `parallel` estiver ativo.
+macro_rules! maybe_par_iter_mut {
+    ($collection:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = $collection.par_iter_mut();
+        #[cfg(not(feature = "parallel"))]
+        let iter = $collection.iter_mut();
+        iter
+    }};
+}
+
+/// Itera em paralelo (rayon) com o recurso `parallel` e sequencialmente sem ele.
+macro_rules! maybe_par_iter {
+    ($collection:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = $collection.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = $collection.iter();
+        iter
+    }};
+}
+
+pub mod ant_colony;
+pub mod aos;
+pub mod best_known;
+pub mod branch_and_bound;
+pub mod brkga;
+pub mod budget;
+pub mod c_api;
+pub mod cancel;
+pub mod config;
+pub mod crossover;
+pub mod csv_record;
+#[cfg(feature = "dashboard")]
+pub mod dashboard;
+pub mod differential_evolution;
+pub mod energy;
+pub mod error;
+pub mod fitness_cache;
+pub mod fixed_prefix;
 pub mod fssp_core;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod grasp;
+pub mod heuristics;
+pub mod hyper_heuristic;
+pub mod i18n;
+pub mod ils;
+pub mod inspect;
+pub mod instance_format;
+pub mod island;
+pub mod iterated_greedy;
+pub mod key_population;
+pub mod landscape;
+pub mod local_search;
+pub mod metrics;
+pub mod milp;
+pub mod mutation;
+pub mod non_permutation;
+pub mod objective;
+pub mod observer;
+pub mod path_relinking;
+pub mod population;
+pub mod portfolio;
+pub mod progress;
+pub mod pso;
+pub mod random_key;
+pub mod registry;
+pub mod reschedule;
+pub mod robust;
+pub mod selection;
+pub mod shared_best;
+pub mod simulated_annealing;
 pub mod solver;
+pub mod stats;
+pub mod stochastic;
+pub mod tabu_search;
+pub mod time;
+pub mod tuning;
+pub mod web;
@@ -0,0 +1,707 @@
+use crate::fssp_core::{max, EvaluationCounter, FSSPInstance, Job, MachineFront, Objective, Time};
+use crate::time::Instant;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Maior tamanho de bloco considerado na vizinhança de movimento de blocos.
+const MAX_BLOCK_SIZE: usize = 3;
+/// Número de vizinhanças usadas pela VND/VNS (troca, inserção e blocos).
+const NEIGHBORHOODS: usize = 3;
+
+/// Mecanismo de melhoria local aplicado aos indivíduos do Algoritmo Memético.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalSearch {
+    Swap,     // Descida por trocas de pares de tarefas (2-opt).
+    Ils,      // Busca Local Iterada com busca por inserção.
+    Vnd,      // Descida em Vizinhança Variável (troca, inserção e movimento de blocos).
+    Vns,      // Busca em Vizinhança Variável: perturbação na vizinhança k seguida de VND.
+    Critical, // Trocas e inserções restritas às tarefas do caminho crítico.
+}
+
+impl FromStr for LocalSearch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "swap" => Ok(LocalSearch::Swap),
+            "ils" => Ok(LocalSearch::Ils),
+            "vnd" => Ok(LocalSearch::Vnd),
+            "vns" => Ok(LocalSearch::Vns),
+            "critical" => Ok(LocalSearch::Critical),
+            _ => Err(format!(
+                "Busca local desconhecida '{}' (use swap, ils, vnd, vns ou critical)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LocalSearch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LocalSearch::Swap => "swap",
+            LocalSearch::Ils => "ils",
+            LocalSearch::Vnd => "vnd",
+            LocalSearch::Vns => "vns",
+            LocalSearch::Critical => "critical",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl LocalSearch {
+    /// Todos os mecanismos de busca local.
+    pub const ALL: [LocalSearch; 5] = [
+        LocalSearch::Swap,
+        LocalSearch::Ils,
+        LocalSearch::Vnd,
+        LocalSearch::Vns,
+        LocalSearch::Critical,
+    ];
+}
+
+/// Estratégia de aceitação de movimentos da busca local por trocas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImprovementStrategy {
+    #[default]
+    First, // Aplica cada troca que melhora a solução assim que é encontrada.
+    Best, // Varre toda a vizinhança e aplica a troca de maior melhoria.
+}
+
+impl FromStr for ImprovementStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "first" => Ok(ImprovementStrategy::First),
+            "best" => Ok(ImprovementStrategy::Best),
+            _ => Err(format!(
+                "Estratégia de busca local desconhecida '{}' (use first ou best)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ImprovementStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ImprovementStrategy::First => "first",
+            ImprovementStrategy::Best => "best",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Limites de esforço de uma aplicação de busca local.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchLimits {
+    pub max_moves: Option<usize>, // Máximo de movimentos avaliados por aplicação.
+    pub max_time: Option<Duration>, // Tempo máximo por aplicação.
+    pub deadline: Option<Instant>, // Instante limite global da execução.
+    pub max_evaluations: Option<u64>, // Total de avaliações da instância em que a busca para.
+    pub first_improvement: bool,  // Reinicia a varredura após cada melhoria.
+    pub strategy: ImprovementStrategy, // Aceitação de movimentos da busca por trocas.
+    pub random_order: bool,       // Varre os pares de posições em ordem aleatória.
+    pub dont_look_bits: bool,     // Pula pares de posições inalteradas desde a última varredura.
+}
+
+impl SearchLimits {
+    /// Indica se o instante limite global ou o total de avaliações da instância
+    /// (orçamento da execução) já foi atingido.
+    pub fn budget_reached(&self, instance: &FSSPInstance) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+            || self
+                .max_evaluations
+                .is_some_and(|max| instance.evaluations.get() >= max)
+    }
+
+    /// Inicia a contagem de esforço de uma aplicação da busca local.
+    fn start<'a>(&self, instance: &'a FSSPInstance) -> LimitTracker<'a> {
+        let local_deadline = self.max_time.map(|t| Instant::now() + t);
+        let stop_at = match (local_deadline, self.deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        LimitTracker {
+            moves: 0,
+            max_moves: self.max_moves,
+            stop_at,
+            evaluations: &instance.evaluations,
+            max_evaluations: self.max_evaluations,
+        }
+    }
+}
+
+/// Contador de esforço de uma aplicação da busca local.
+struct LimitTracker<'a> {
+    moves: usize,                       // Movimentos avaliados até o momento.
+    max_moves: Option<usize>,           // Máximo de movimentos permitidos.
+    stop_at: Option<Instant>,           // Instante em que a busca deve parar.
+    evaluations: &'a EvaluationCounter, // Avaliações feitas com a instância.
+    max_evaluations: Option<u64>,       // Total de avaliações em que a busca para.
+}
+
+impl LimitTracker<'_> {
+    /// Registra a avaliação de um movimento e informa se o esforço se esgotou.
+    fn exhausted(&mut self) -> bool {
+        self.moves += 1;
+        self.max_moves.is_some_and(|max| self.moves > max)
+            || self.stop_at.is_some_and(|t| Instant::now() >= t)
+            || self
+                .max_evaluations
+                .is_some_and(|max| self.evaluations.get() >= max)
+    }
+}
+
+/// Limitantes inferiores incrementais do makespan de movimentos que reorganizam um
+/// trecho contíguo `lo..=hi` da sequência (trocas, inserções e movimentos de blocos),
+/// usados para descartar, sem avaliá-los, movimentos que não podem melhorar a
+/// solução. O prefixo antes do trecho e a ordem do sufixo depois dele não mudam, e
+/// o trecho mantém as mesmas tarefas: em cada máquina `k`, o trecho termina após a
+/// frente do prefixo mais a sua carga e após o término da sua última tarefa na
+/// máquina anterior mais o tempo dela em `k`, e o sufixo ainda precisa da sua cauda
+/// (tempo mínimo de `k` em diante). Cada limitante custa O(m), contra O(n·m) de uma
+/// avaliação, e a estrutura é recalculada em O(n·m) após cada movimento aplicado.
+struct _SegmentBound {
+    sequence: Vec<Job>,    // Sequência para a qual os dados foram calculados.
+    heads: Vec<Vec<Time>>, // heads[p][k]: frente das p primeiras posições na máquina k.
+    loads: Vec<Vec<Time>>, // loads[p][k]: tempos das p primeiras posições na máquina k.
+    tails: Vec<Vec<Time>>, // tails[p][k]: cauda das posições p.. a partir da máquina k.
+}
+
+impl _SegmentBound {
+    /// Calcula os dados da sequência. Retorna `None` se a função objetivo não é o
+    /// makespan dos tempos da instância (outros objetivos, cenários de tempos ou
+    /// tarefas divididas em sublotes), caso em que os limitantes não se aplicam.
+    fn new(instance: &FSSPInstance, sequence: &[Job]) -> Option<Self> {
+        if instance.objective != Objective::Makespan
+            || instance.uncertainty.is_some()
+            || instance.sublots.is_some()
+        {
+            return None;
+        }
+        let mut bound = _SegmentBound {
+            sequence: Vec::new(),
+            heads: Vec::new(),
+            loads: Vec::new(),
+            tails: Vec::new(),
+        };
+        bound.update(instance, sequence);
+        Some(bound)
+    }
+
+    /// Recalcula os dados após uma alteração da sequência.
+    fn update(&mut self, instance: &FSSPInstance, sequence: &[Job]) {
+        let (n, m) = (sequence.len(), instance.n_machines);
+        self.sequence.clear();
+        self.sequence.extend_from_slice(sequence);
+        let mut front = MachineFront::new(m);
+        self.heads.resize(n + 1, Vec::new());
+        self.loads.resize(n + 1, Vec::new());
+        self.tails.resize(n + 1, Vec::new());
+        self.heads[0] = front.completion_times.clone();
+        self.loads[0] = vec![0; m];
+        for (p, &job) in sequence.iter().enumerate() {
+            front.push(instance, job);
+            self.heads[p + 1].clone_from(&front.completion_times);
+            let times = &instance.processing_times[job as usize];
+            let load: Vec<Time> = self.loads[p]
+                .iter()
+                .zip(times)
+                .map(|(l, t)| l + t)
+                .collect();
+            self.loads[p + 1] = load;
+        }
+        self.tails[n] = vec![0; m];
+        for p in (0..n).rev() {
+            let times = &instance.processing_times[sequence[p] as usize];
+            let mut tail = vec![0; m];
+            let mut later = 0; // Cauda da mesma posição na máquina seguinte.
+            for k in (0..m).rev() {
+                later = max(later, self.tails[p + 1][k]) + times[k];
+                tail[k] = later;
+            }
+            self.tails[p] = tail;
+        }
+    }
+
+    /// Limitante do makespan após reorganizar o trecho `lo..=hi` de modo que ele
+    /// termine com a tarefa `last`.
+    fn bound(&self, instance: &FSSPInstance, lo: usize, hi: usize, last: Job) -> Time {
+        let times = &instance.processing_times[last as usize];
+        let (head, tail) = (&self.heads[lo], &self.tails[hi + 1]);
+        let mut previous = 0; // Limitante do término do trecho na máquina anterior.
+        let mut value = 0;
+        for k in 0..instance.n_machines {
+            let load = self.loads[hi + 1][k] - self.loads[lo][k];
+            previous = max(head[k] + load, previous + times[k]);
+            value = max(value, previous + tail[k]);
+        }
+        value
+    }
+
+    /// Limitante da troca das posições `i` e `j`.
+    fn swap_bound(&self, instance: &FSSPInstance, i: usize, j: usize) -> Time {
+        let (lo, hi) = (i.min(j), i.max(j));
+        let last = self.sequence[lo];
+        self.bound(instance, lo, hi, last)
+    }
+
+    /// Limitante da remoção do bloco de `size` tarefas iniciado em `from` e da sua
+    /// reinserção na posição `to` da sequência sem o bloco.
+    fn move_bound(&self, instance: &FSSPInstance, from: usize, to: usize, size: usize) -> Time {
+        if to < from {
+            let last = self.sequence[from - 1];
+            self.bound(instance, to, from + size - 1, last)
+        } else {
+            let last = self.sequence[from + size - 1];
+            self.bound(instance, from, to + size - 1, last)
+        }
+    }
+}
+
+/// Realiza uma busca local 2-opt (trocas de pares de tarefas) até não haver melhoria.
+/// Retorna o makespan da sequência resultante.
+pub fn swap_descent(instance: &FSSPInstance, sequence: &mut [Job]) -> Time {
+    swap_descent_limited(
+        instance,
+        sequence,
+        &SearchLimits::default(),
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Busca local 2-opt sujeita a limites de movimentos e de tempo. Na estratégia
+/// `First`, cada troca que melhora o makespan é aplicada imediatamente; com
+/// `first_improvement`, a varredura recomeça do início após a troca, caso
+/// contrário continua a partir do par seguinte. Na estratégia `Best`, cada
+/// varredura avalia todos os pares e aplica apenas a melhor troca. Com
+/// `random_order`, as posições são embaralhadas (com `rng`) a cada varredura.
+///
+/// Com `dont_look_bits`, cada varredura após a primeira avalia apenas os pares com
+/// ao menos uma posição alterada por uma troca desde o início da varredura
+/// anterior; trocas entre posições inalteradas já foram avaliadas sem melhoria.
+/// Como uma troca altera os tempos de conclusão de todas as tarefas seguintes,
+/// a poda é heurística e pode deixar de encontrar algumas melhorias, em troca de
+/// muito menos avaliações em instâncias grandes.
+///
+/// Com o objetivo makespan, trocas cujo limitante inferior (veja `_SegmentBound`)
+/// não é menor que o valor a superar não são avaliadas; essa poda é exata.
+/// Retorna o makespan da sequência resultante.
+pub fn swap_descent_limited<R: Rng>(
+    instance: &FSSPInstance,
+    sequence: &mut [Job],
+    limits: &SearchLimits,
+    rng: &mut R,
+) -> Time {
+    let mut tracker = limits.start(instance);
+    let mut current_makespan = instance.evaluate(sequence);
+    let mut bounds = _SegmentBound::new(instance, sequence);
+    // Ordem de varredura das posições: cada par (order[a], order[b]), a < b, é
+    // visitado uma vez por varredura.
+    let mut order: Vec<usize> = (0..sequence.len()).collect();
+    // Posições cujos pares devem ser avaliados na varredura atual e posições
+    // alteradas desde o início dela (don't-look bits).
+    let mut look = vec![true; sequence.len()];
+    let mut changed = vec![false; sequence.len()];
+    let mut improved = true;
+
+    'search: while improved {
+        improved = false;
+        if limits.random_order {
+            order.shuffle(rng);
+        }
+        // Melhor troca da varredura (estratégia `Best`).
+        let mut best_move: Option<(usize, usize, Time)> = None;
+        let mut exhausted = false;
+        'scan: for a in 0..order.len() {
+            for b in (a + 1)..order.len() {
+                let (i, j) = (order[a], order[b]);
+                if limits.dont_look_bits && !look[i] && !look[j] {
+                    continue;
+                }
+                // Descarta a troca se o limitante mostra que ela não melhora o
+                // makespan atual (ou a melhor troca da varredura).
+                let threshold = best_move.map_or(current_makespan, |(_, _, v)| v);
+                if bounds
+                    .as_ref()
+                    .is_some_and(|b| b.swap_bound(instance, i, j) >= threshold)
+                {
+                    continue;
+                }
+                if tracker.exhausted() {
+                    exhausted = true;
+                    break 'scan;
+                }
+                sequence.swap(i, j); // Tenta uma troca.
+                let new_makespan = instance.evaluate(sequence);
+                match limits.strategy {
+                    ImprovementStrategy::First if new_makespan < current_makespan => {
+                        current_makespan = new_makespan;
+                        improved = true;
+                        (look[i], look[j]) = (true, true);
+                        (changed[i], changed[j]) = (true, true);
+                        if let Some(bounds) = &mut bounds {
+                            bounds.update(instance, sequence);
+                        }
+                        if limits.first_improvement {
+                            continue 'search;
+                        }
+                    }
+                    ImprovementStrategy::Best => {
+                        sequence.swap(i, j);
+                        let best_value = best_move.map_or(current_makespan, |(_, _, v)| v);
+                        if new_makespan < best_value {
+                            best_move = Some((i, j, new_makespan));
+                        }
+                    }
+                    ImprovementStrategy::First => {
+                        sequence.swap(i, j); // Desfaz a troca se não houver melhoria.
+                    }
+                }
+            }
+        }
+        if let Some((i, j, makespan)) = best_move {
+            sequence.swap(i, j);
+            current_makespan = makespan;
+            improved = true;
+            (changed[i], changed[j]) = (true, true);
+            if let Some(bounds) = &mut bounds {
+                bounds.update(instance, sequence);
+            }
+        }
+        if exhausted {
+            break;
+        }
+        std::mem::swap(&mut look, &mut changed);
+        changed.fill(false);
+    }
+    current_makespan
+}
+
+/// Realiza uma busca local por inserção: cada tarefa é removida e reinserida na
+/// melhor posição, repetindo enquanto houver melhoria. Retorna o makespan final.
+pub fn insertion_descent(instance: &FSSPInstance, sequence: &mut Vec<Job>) -> Time {
+    insertion_descent_limited(instance, sequence, &SearchLimits::default())
+}
+
+/// Busca local por inserção sujeita a limites de movimentos e de tempo (cada
+/// reinserção avaliada conta como um movimento). Com o objetivo makespan, as
+/// posições cujo limitante inferior não é menor que o melhor valor já encontrado
+/// não são avaliadas (veja `_SegmentBound`). Retorna o makespan final.
+pub fn insertion_descent_limited(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    limits: &SearchLimits,
+) -> Time {
+    let mut tracker = limits.start(instance);
+    let mut current_makespan = instance.evaluate(sequence);
+    let mut bounds = _SegmentBound::new(instance, sequence);
+    let mut improved = true;
+
+    while improved {
+        improved = false;
+        for from in 0..sequence.len() {
+            if tracker.exhausted() {
+                return current_makespan;
+            }
+            let job = sequence.remove(from);
+            let (best_pos, best_makespan) = match &bounds {
+                Some(bounds) => {
+                    _bounded_insertion(instance, sequence, job, from, current_makespan, bounds)
+                }
+                None => best_insertion(instance, sequence, job),
+            };
+            if best_makespan < current_makespan {
+                sequence.insert(best_pos, job);
+                current_makespan = best_makespan;
+                improved = true;
+                if let Some(bounds) = &mut bounds {
+                    bounds.update(instance, sequence);
+                }
+            } else {
+                sequence.insert(from, job); // Desfaz a remoção se não houver melhoria.
+            }
+        }
+    }
+    current_makespan
+}
+
+/// Busca local guiada pelo caminho crítico: apenas as tarefas do caminho crítico
+/// do escalonamento atual são movidas, por inserção em outra posição ou por troca
+/// com outra tarefa, já que movimentos que não alteram a posição de nenhuma
+/// tarefa crítica não podem reduzir o makespan. O primeiro movimento de melhoria é
+/// aplicado e o caminho crítico é recalculado, até que nenhum movimento melhore a
+/// função objetivo ou os limites se esgotem. Com objetivos diferentes do makespan,
+/// o caminho crítico continua sendo o do makespan e serve apenas como heurística.
+/// Retorna o valor da função objetivo da sequência resultante.
+pub fn critical_descent_limited(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    limits: &SearchLimits,
+) -> Time {
+    let mut tracker = limits.start(instance);
+    let mut current = instance.evaluate(sequence);
+    let n = sequence.len();
+
+    'search: loop {
+        let critical = instance.decode_schedule(sequence).critical_path(instance);
+        for job in critical {
+            let from = sequence.iter().position(|&j| j == job).unwrap();
+
+            // Inserção da tarefa crítica em outra posição.
+            sequence.remove(from);
+            for to in (0..n).filter(|&to| to != from) {
+                if tracker.exhausted() {
+                    sequence.insert(from, job);
+                    break 'search;
+                }
+                sequence.insert(to, job);
+                let value = instance.evaluate(sequence);
+                if value < current {
+                    current = value;
+                    continue 'search;
+                }
+                sequence.remove(to);
+            }
+            sequence.insert(from, job);
+
+            // Troca da tarefa crítica com outra tarefa.
+            for other in (0..n).filter(|&other| other != from) {
+                if tracker.exhausted() {
+                    break 'search;
+                }
+                sequence.swap(from, other);
+                let value = instance.evaluate(sequence);
+                if value < current {
+                    current = value;
+                    continue 'search;
+                }
+                sequence.swap(from, other);
+            }
+        }
+        break;
+    }
+    current
+}
+
+/// Encontra a posição de inserção de `job` em `sequence` com menor makespan.
+/// Retorna a posição e o makespan resultante.
+pub fn best_insertion(instance: &FSSPInstance, sequence: &mut Vec<Job>, job: Job) -> (usize, Time) {
+    let mut best = (0, Time::MAX);
+    for pos in 0..=sequence.len() {
+        sequence.insert(pos, job);
+        let makespan = instance.evaluate(sequence);
+        sequence.remove(pos);
+        if makespan < best.1 {
+            best = (pos, makespan);
+        }
+    }
+    best
+}
+
+/// Melhor posição de reinserção de `job`, removida da posição `from`, entre as que
+/// melhoram `current`, avaliando apenas as posições cujo limitante é menor que o
+/// melhor valor encontrado. Retorna `(from, current)` se nenhuma melhorar.
+fn _bounded_insertion(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    job: Job,
+    from: usize,
+    current: Time,
+    bounds: &_SegmentBound,
+) -> (usize, Time) {
+    let mut best = (from, current);
+    for pos in (0..=sequence.len()).filter(|&pos| pos != from) {
+        if bounds.move_bound(instance, from, pos, 1) >= best.1 {
+            continue;
+        }
+        sequence.insert(pos, job);
+        let makespan = instance.evaluate(sequence);
+        sequence.remove(pos);
+        if makespan < best.1 {
+            best = (pos, makespan);
+        }
+    }
+    best
+}
+
+/// Aplica o primeiro movimento de troca que melhora o makespan, se existir.
+fn first_improving_swap(
+    instance: &FSSPInstance,
+    sequence: &mut [Job],
+    current: Time,
+    bounds: Option<&_SegmentBound>,
+) -> Option<Time> {
+    for i in 0..sequence.len() {
+        for j in (i + 1)..sequence.len() {
+            if bounds.is_some_and(|b| b.swap_bound(instance, i, j) >= current) {
+                continue;
+            }
+            sequence.swap(i, j);
+            let makespan = instance.evaluate(sequence);
+            if makespan < current {
+                return Some(makespan);
+            }
+            sequence.swap(i, j);
+        }
+    }
+    None
+}
+
+/// Aplica o primeiro movimento de bloco (remoção de `size` tarefas consecutivas e
+/// reinserção em outra posição) que melhora o makespan, se existir. Com `size = 1`
+/// corresponde à vizinhança de inserção.
+fn first_improving_block_move(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    current: Time,
+    size: usize,
+    bounds: Option<&_SegmentBound>,
+) -> Option<Time> {
+    let n = sequence.len();
+    if size >= n {
+        return None;
+    }
+    for from in 0..=(n - size) {
+        let block: Vec<Job> = sequence.drain(from..from + size).collect();
+        for to in (0..=sequence.len()).filter(|&to| to != from) {
+            if bounds.is_some_and(|b| b.move_bound(instance, from, to, size) >= current) {
+                continue;
+            }
+            sequence.splice(to..to, block.iter().copied());
+            let makespan = instance.evaluate(sequence);
+            if makespan < current {
+                return Some(makespan);
+            }
+            sequence.drain(to..to + size);
+        }
+        sequence.splice(from..from, block);
+    }
+    None
+}
+
+/// Busca o primeiro movimento de melhoria na vizinhança `k` (0: troca, 1: inserção,
+/// 2: movimento de blocos de 2 a `MAX_BLOCK_SIZE` tarefas), sem avaliar movimentos
+/// descartados pelos limitantes de `_SegmentBound`.
+fn improve_in_neighborhood(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    current: Time,
+    k: usize,
+) -> Option<Time> {
+    let bounds = _SegmentBound::new(instance, sequence);
+    let bounds = bounds.as_ref();
+    match k {
+        0 => first_improving_swap(instance, sequence, current, bounds),
+        1 => first_improving_block_move(instance, sequence, current, 1, bounds),
+        _ => (2..=MAX_BLOCK_SIZE)
+            .find_map(|size| first_improving_block_move(instance, sequence, current, size, bounds)),
+    }
+}
+
+/// Descida em Vizinhança Variável (VND): explora as vizinhanças de troca, inserção e
+/// movimento de blocos em ordem, voltando à primeira sempre que há melhoria.
+/// Retorna o makespan final.
+pub fn vnd(instance: &FSSPInstance, sequence: &mut Vec<Job>) -> Time {
+    vnd_limited(instance, sequence, &SearchLimits::default())
+}
+
+/// VND sujeita a limites de movimentos e de tempo (cada vizinhança explorada conta
+/// como um movimento). Retorna o makespan final.
+pub fn vnd_limited(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    limits: &SearchLimits,
+) -> Time {
+    let mut tracker = limits.start(instance);
+    let mut current = instance.evaluate(sequence);
+    let mut k = 0;
+    while k < NEIGHBORHOODS {
+        if tracker.exhausted() {
+            break;
+        }
+        match improve_in_neighborhood(instance, sequence, current, k) {
+            Some(makespan) => {
+                current = makespan;
+                k = 0;
+            }
+            None => k += 1,
+        }
+    }
+    current
+}
+
+/// Busca em Vizinhança Variável (VNS básica): a cada iteração perturba a melhor
+/// solução com um movimento aleatório da vizinhança `k`, aplica a VND e aceita o
+/// resultado se houver melhoria (voltando a `k = 0`); caso contrário avança `k`.
+/// Retorna o makespan final.
+pub fn vns<R: Rng>(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    iterations: usize,
+    rng: &mut R,
+) -> Time {
+    vns_limited(
+        instance,
+        sequence,
+        iterations,
+        &SearchLimits::default(),
+        rng,
+    )
+}
+
+/// VNS em que cada VND respeita `limits` e as iterações são interrompidas quando o
+/// orçamento global (instante limite ou total de avaliações) se esgota. Retorna o makespan final.
+pub fn vns_limited<R: Rng>(
+    instance: &FSSPInstance,
+    sequence: &mut Vec<Job>,
+    iterations: usize,
+    limits: &SearchLimits,
+    rng: &mut R,
+) -> Time {
+    let mut best = vnd_limited(instance, sequence, limits);
+    let n = sequence.len();
+    if n < 2 {
+        return best;
+    }
+
+    let mut k = 0;
+    for _ in 0..iterations {
+        if limits.budget_reached(instance) {
+            break;
+        }
+        let mut candidate = sequence.clone();
+        match k {
+            0 => candidate.swap(rng.gen_range(0..n), rng.gen_range(0..n)),
+            _ => {
+                // Movimento aleatório de inserção (k = 1) ou de bloco (k = 2).
+                let size = if k == 1 {
+                    1
+                } else {
+                    rng.gen_range(2..=MAX_BLOCK_SIZE)
+                }
+                .min(n - 1);
+                let from = rng.gen_range(0..=n - size);
+                let block: Vec<Job> = candidate.drain(from..from + size).collect();
+                let to = rng.gen_range(0..=candidate.len());
+                candidate.splice(to..to, block);
+            }
+        }
+
+        let makespan = vnd_limited(instance, &mut candidate, limits);
+        if makespan < best {
+            best = makespan;
+            *sequence = candidate;
+            k = 0;
+        } else {
+            k = (k + 1) % NEIGHBORHOODS;
+        }
+    }
+    best
+}
@@ -1,19 +1,378 @@
-use clap::Parser;
-use fssp_solver_rs::fssp_core::load_instance;
-use fssp_solver_rs::solver::MemeticAlgorithm;
-use std::fs::File;
-use std::io::Write;
+use clap::parser::ValueSource;
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use fssp_solver_rs::ant_colony::AcoParams;
+use fssp_solver_rs::aos::AosStrategy;
+use fssp_solver_rs::best_known::{relative_percentage_deviation, BestKnown};
+use fssp_solver_rs::budget::Budget;
+use fssp_solver_rs::cancel::{CancellableObserver, CancellationToken};
+use fssp_solver_rs::config::SolverConfig;
+use fssp_solver_rs::crossover::Crossover;
+use fssp_solver_rs::csv_record;
+#[cfg(feature = "dashboard")]
+use fssp_solver_rs::dashboard::Dashboard;
+use fssp_solver_rs::energy::{EnergyPoint, EnergySearch, ParetoFront, SpeedProfile};
+use fssp_solver_rs::error::{FsspError, Result};
+use fssp_solver_rs::fitness_cache;
+use fssp_solver_rs::fixed_prefix::FixedPrefix;
+use fssp_solver_rs::fssp_core::{
+    load_instance, load_sequences, FSSPInstance, Job, Objective, Time, TimeValue,
+};
+use fssp_solver_rs::heuristics::NehTieBreaking;
+use fssp_solver_rs::i18n::Lang;
+use fssp_solver_rs::ils::Acceptance;
+use fssp_solver_rs::inspect::InstanceReport;
+use fssp_solver_rs::instance_format::InstanceFormat;
+use fssp_solver_rs::island::Topology;
+use fssp_solver_rs::iterated_greedy::IgParams;
+use fssp_solver_rs::landscape::{LandscapeParams, LandscapeReport};
+use fssp_solver_rs::local_search::{ImprovementStrategy, LocalSearch};
+use fssp_solver_rs::metrics::ScheduleMetrics;
+use fssp_solver_rs::milp::{self, Formulation, ModelFormat};
+use fssp_solver_rs::mutation::MutationMix;
+use fssp_solver_rs::non_permutation::{NonPermutationSchedule, NonPermutationSearch, ScheduleType};
+use fssp_solver_rs::observer::{HistoryObserver, MultiObserver, NoopObserver, Observer};
+use fssp_solver_rs::portfolio::{self, InstanceFeatures, PortfolioModel};
+use fssp_solver_rs::progress::{ProgressObserver, ReportObserver};
+use fssp_solver_rs::registry;
+use fssp_solver_rs::reschedule::{Breakdown, Rescheduling};
+use fssp_solver_rs::robust::{self, RobustCriterion};
+use fssp_solver_rs::selection::Selection;
+use fssp_solver_rs::shared_best::SharedBest;
+use fssp_solver_rs::simulated_annealing::SaParams;
+use fssp_solver_rs::solver::{Algorithm, LocalSearchPolicy, PhaseSchedule, Replacement};
+use fssp_solver_rs::stats;
+use fssp_solver_rs::stochastic::{self, AdaptiveSampling, Estimate, NoiseModel};
+use fssp_solver_rs::tabu_search::TabuParams;
+use fssp_solver_rs::tuning::{self, TuningMethod};
+use indicatif::MultiProgress;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
+use tracing::{error, info, info_span, warn, Level};
 
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+/// Formato das mensagens de log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogFormat {
+    Text, // Texto legível, uma mensagem por linha.
+    Json, // Um objeto JSON por linha, para processamento automático.
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!(
+                "Formato de log desconhecido '{}' (use text ou json)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Campos aceitos no modelo de nome dos arquivos de resultado.
+const TEMPLATE_FIELDS: [&str; 4] = ["instance", "algo", "run", "timestamp"];
+
+/// Modelo do nome dos arquivos de resultado (`--output-template`), relativo a
+/// `--output-dir`. Os campos `{instance}`, `{algo}`, `{run}` e `{timestamp}` são
+/// substituídos pelo nome da instância, pelo algoritmo, pelo número da execução e
+/// pelo instante da gravação (segundos desde a época Unix); separadores de
+/// diretório criam subdiretórios.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OutputTemplate(String);
+
+impl OutputTemplate {
+    /// Caminho do arquivo de resultado da instância `instance`. `run` é o número da
+    /// execução no multi-start (`None` para o resultado único ou consolidado, em
+    /// que `{run}` vale `best`). Sem o campo `{run}` no modelo, os arquivos de cada
+    /// execução recebem o sufixo `_run<k>`, para não sobrescreverem o consolidado.
+    fn render(&self, instance: &str, algorithm: Algorithm, run: Option<u32>) -> PathBuf {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let rendered = self
+            .0
+            .replace("{instance}", instance)
+            .replace("{algo}", &algorithm.to_string())
+            .replace(
+                "{run}",
+                &run.map_or_else(|| "best".to_string(), |run| run.to_string()),
+            )
+            .replace("{timestamp}", &timestamp.to_string());
+        let path = PathBuf::from(rendered);
+        match run {
+            Some(run) if !self.0.contains("{run}") => {
+                let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+                let file_name = match path.extension().and_then(|e| e.to_str()) {
+                    Some(ext) => format!("{}_run{}.{}", stem, run, ext),
+                    None => format!("{}_run{}", stem, run),
+                };
+                path.with_file_name(file_name)
+            }
+            _ => path,
+        }
+    }
+}
+
+impl FromStr for OutputTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut rest = s;
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                return Err(format!("Modelo de nome com '{{' sem fechamento: '{}'", s));
+            };
+            let field = &rest[start + 1..start + end];
+            if !TEMPLATE_FIELDS.contains(&field) {
+                return Err(format!(
+                    "Campo desconhecido '{{{}}}' no modelo de nome (use {})",
+                    field,
+                    TEMPLATE_FIELDS.map(|f| format!("{{{}}}", f)).join(", ")
+                ));
+            }
+            rest = &rest[start + end + 1..];
+        }
+        if s.is_empty() || s.ends_with('/') {
+            return Err(format!("Modelo de nome sem nome de arquivo: '{}'", s));
+        }
+        Ok(OutputTemplate(s.to_string()))
+    }
+}
+
+impl fmt::Display for OutputTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Resolve instâncias do Problema de Escalonamento Flow Shop (FSSP) com o algoritmo
+/// memético e outras metaheurísticas, heurísticas construtivas e métodos exatos.
+#[derive(Parser, Debug, Clone)]
+#[command(version, about, long_about = None, subcommand_negates_reqs = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// O caminho para o arquivo da instância FSSP.
-    #[arg(required = true)]
-    instance_path: PathBuf,
+    #[arg(required_unless_present_any = ["batch", "list_algorithms"])]
+    instance_path: Option<PathBuf>,
+
+    /// Resolve todas as instâncias de um diretório e gera um resumo consolidado.
+    #[arg(long, conflicts_with = "instance_path")]
+    batch: Option<PathBuf>,
+
+    /// Resolve as instâncias do modo em lote em paralelo.
+    #[arg(long, requires = "batch")]
+    parallel: bool,
+
+    /// Número de execuções independentes da instância, realizadas em paralelo, com
+    /// estatísticas agregadas do valor da função objetivo.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "batch")]
+    runs: u32,
+
+    /// Desativa o compartilhamento da melhor solução global entre as execuções
+    /// simultâneas de --runs e entre as ilhas de --ma.islands, que passam a ser
+    /// independentes (as ilhas ainda trocam soluções nas migrações).
+    #[arg(long)]
+    no_sharing: bool,
+
+    /// Arquivo de resumo do modo em lote (formato CSV ou JSON, conforme a extensão).
+    #[arg(long, default_value = "batch_summary.csv", requires = "batch")]
+    summary: PathBuf,
+
+    /// Acrescenta uma linha por execução a este arquivo CSV compartilhado (instante,
+    /// instância, algoritmo, parâmetros, melhor valor e tempo), com bloqueio do
+    /// arquivo para que várias invocações simultâneas possam gravar nele.
+    #[arg(long, value_name = "PATH")]
+    append_summary: Option<PathBuf>,
+
+    /// Arquivo TOML com os parâmetros do algoritmo. Opções informadas na linha de
+    /// comando têm precedência sobre os valores do arquivo.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Arquivo com uma ou mais sequências iniciais (uma por linha, tarefas a partir de 0),
+    /// inseridas na população inicial. Aceita também um arquivo de resultado anterior ou
+    /// de população (`--save-population`). Não pode ser combinado com --batch, cujas
+    /// instâncias têm dimensões próprias.
+    #[arg(long, conflicts_with = "batch")]
+    initial_solution: Option<PathBuf>,
+
+    /// Fixa as primeiras posições da sequência com estas tarefas (a partir de 0, ex:
+    /// "3 7 1"), já iniciadas na linha de produção; apenas as demais são otimizadas.
+    #[arg(
+        long,
+        value_name = "TAREFAS",
+        value_delimiter = ' ',
+        conflicts_with = "batch"
+    )]
+    fixed_prefix: Option<Vec<Job>>,
+
+    /// Reescalona o escalonamento em execução deste arquivo (uma sequência, tarefas a
+    /// partir de 0, como em --initial-solution) no instante `--now`: as tarefas já
+    /// iniciadas ficam congeladas e as demais são reotimizadas. Tarefas ausentes do
+    /// arquivo (ex: pedidos urgentes) entram na reotimização.
+    #[arg(
+        long,
+        value_name = "ARQUIVO",
+        requires = "now",
+        conflicts_with_all = ["batch", "fixed_prefix"]
+    )]
+    reschedule: Option<PathBuf>,
+
+    /// Instante atual do reescalonamento, nas unidades da instância.
+    #[arg(long, value_name = "INSTANTE", requires = "reschedule")]
+    now: Option<String>,
+
+    /// Máquina parada no instante do reescalonamento e o instante previsto para o fim
+    /// do reparo, no formato `máquina:instante` (máquina a partir de 0, ex: 2:140).
+    /// Pode ser repetida. Operações interrompidas recomeçam após o reparo.
+    #[arg(long, value_name = "MAQ:INSTANTE", requires = "reschedule")]
+    machine_down: Vec<String>,
+
+    /// Grava a população final (valor da função objetivo e sequência de cada
+    /// indivíduo) neste arquivo, que pode ser usado como `--initial-solution` de uma
+    /// execução seguinte com outros parâmetros.
+    #[arg(long, conflicts_with_all = ["batch", "runs"])]
+    save_population: Option<PathBuf>,
+
+    /// Grava o histórico da execução neste arquivo CSV, uma linha por geração: melhor
+    /// valor global e da geração, média, taxa de mutação, diversidade, fração de
+    /// duplicados, tempo decorrido e, com `--ma.operator-stats`, as estatísticas dos
+    /// operadores.
+    #[arg(long, conflicts_with_all = ["batch", "runs"])]
+    history: Option<PathBuf>,
+
+    /// Arquivo com os melhores makespans conhecidos (`nome makespan` por linha).
+    #[arg(long)]
+    best_known: Option<PathBuf>,
+
+    /// Função objetivo minimizada: makespan, flowtime (tempo de fluxo total), tt
+    /// (atraso total), tmax (atraso máximo), twt (atraso ponderado total), twft (tempo
+    /// de fluxo ponderado total) ou weighted:w1,w2,w3 (soma ponderada de makespan,
+    /// tempo de fluxo total e atraso máximo). Os atrasos exigem datas de entrega na
+    /// instância; twt e twft usam os pesos das tarefas (1 se ausentes).
+    #[arg(long, default_value_t = Objective::Makespan)]
+    objective: Objective,
+
+    /// Tipo de escalonamento (permutation ou non-permutation). No modo não
+    /// permutacional, a melhor sequência do algoritmo escolhido é refinada permitindo
+    /// ordens diferentes em cada máquina.
+    #[arg(long, default_value_t = ScheduleType::Permutation)]
+    schedule_type: ScheduleType,
+
+    /// Divide cada tarefa neste número de sublotes iguais (lot streaming): cada
+    /// sublote segue para a máquina seguinte assim que termina, sobrepondo o
+    /// processamento da tarefa em máquinas consecutivas.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    sublots: Option<u32>,
+
+    /// Velocidades de processamento disponíveis para cada operação, no formato
+    /// `fator:potência,...` (ex: 1:1,1.25:1.5625,1.5:2.25). Ativa a otimização do
+    /// makespan e da energia: a melhor sequência do algoritmo escolhido é refinada
+    /// escolhendo a velocidade de cada operação.
+    #[arg(long)]
+    speeds: Option<SpeedProfile>,
+
+    /// Peso da energia (entre 0 e 1) na soma ponderada com o makespan, ambos
+    /// normalizados pelos valores na velocidade nominal.
+    #[arg(long, default_value_t = 0.5, requires = "speeds")]
+    energy_weight: f64,
+
+    /// Arquivos de instância com cenários alternativos dos tempos de processamento
+    /// (mesmas dimensões da instância, que é o primeiro cenário). Ativa a otimização
+    /// robusta segundo `--robust`.
+    #[arg(long, num_args = 1.., conflicts_with_all = ["batch", "time_upper"])]
+    scenarios: Vec<PathBuf>,
+
+    /// Arquivo de instância com o tempo máximo de cada operação: os tempos da
+    /// instância são os mínimos, e `--scenario-samples` cenários são sorteados nos
+    /// intervalos para a otimização robusta.
+    #[arg(long, conflicts_with = "batch")]
+    time_upper: Option<PathBuf>,
+
+    /// Número de cenários sorteados nos intervalos de `--time-upper`, incluindo os
+    /// cenários dos tempos mínimos e máximos.
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u64).range(2..))]
+    scenario_samples: u64,
+
+    /// Semente do sorteio dos cenários de `--time-upper` e das replicações de
+    /// `--stochastic`.
+    #[arg(long)]
+    scenario_seed: Option<u64>,
+
+    /// Critério da otimização robusta com cenários (worst, o pior cenário, ou
+    /// expected, a média dos cenários).
+    #[arg(long, default_value_t = RobustCriterion::WorstCase)]
+    robust: RobustCriterion,
+
+    /// Distribuição dos tempos de processamento para a avaliação por simulação de
+    /// Monte Carlo, no formato `família:cv` (normal, uniform, lognormal ou
+    /// exponential; ex: normal:0.1), onde `cv` é o coeficiente de variação. A média
+    /// de cada operação é o seu tempo na instância, e a função objetivo é a média
+    /// estimada nas replicações.
+    #[arg(long, conflicts_with_all = ["batch", "scenarios", "time_upper"])]
+    stochastic: Option<NoiseModel>,
+
+    /// Arquivo de instância com o desvio padrão do tempo de cada operação na
+    /// simulação, no lugar do coeficiente de variação de `--stochastic`.
+    #[arg(long, requires = "stochastic")]
+    time_stddev: Option<PathBuf>,
+
+    /// Número de replicações sorteadas para a simulação: o máximo usado em cada
+    /// avaliação.
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u64).range(2..))]
+    replications: u64,
+
+    /// Replicações da estimativa inicial de cada sequência e de cada reamostragem.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(2..))]
+    initial_replications: u64,
+
+    /// Tolerância, em erros padrão, para reamostrar uma sequência: ela recebe mais
+    /// replicações enquanto a média menos `z` erros padrão não superar a melhor
+    /// média conhecida.
+    #[arg(long, default_value_t = 2.0)]
+    resample_z: f64,
+
+    /// Grava a fronteira de Pareto (makespan x energia) das soluções avaliadas
+    /// neste arquivo CSV.
+    #[arg(long, requires = "speeds", conflicts_with_all = ["batch", "runs"])]
+    pareto_front: Option<PathBuf>,
+
+    /// Algoritmo utilizado (memetic, ig, sa, tabu, ils, aco, grasp, bb, de, brkga,
+    /// pso, neh, cds, palmer, hh ou auto; veja `--list-algorithms`).
+    #[arg(long, default_value_t = Algorithm::Memetic)]
+    algorithm: Algorithm,
 
-    /// Número máximo de gerações que o algoritmo irá executar.
+    /// Lista os algoritmos disponíveis, com a descrição e as opções de cada um, e
+    /// encerra.
+    #[arg(long)]
+    list_algorithms: bool,
+
+    /// Número máximo de gerações (ou iterações, no GRASP e na ILS) que o algoritmo irá executar.
     #[arg(long, default_value_t = 100)]
     max_generations: usize,
 
@@ -21,99 +380,3025 @@ struct Cli {
     #[arg(long)]
     max_duration: Option<u64>,
 
-    /// Diretório para salvar o arquivo de resultado.
+    /// Número máximo de avaliações da função objetivo, inclusive as da busca local.
+    /// Permite comparar algoritmos com o mesmo esforço computacional.
+    #[arg(long)]
+    max_evaluations: Option<u64>,
+
+    /// Aumenta o detalhamento do log (-v: depuração, -vv: estatísticas de cada geração).
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Formato do log emitido na saída de erro (text ou json).
+    #[arg(long, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+
+    /// Idioma dos resultados no console e nos arquivos de resultado (pt ou en). Os
+    /// arquivos CSV e JSON não dependem do idioma.
+    #[arg(long, env = "FSSP_LANG", default_value_t = Lang::Pt)]
+    lang: Lang,
+
+    /// Desativa a barra de progresso (útil em execuções por scripts).
+    #[arg(long, short)]
+    quiet: bool,
+
+    /// Imprime o melhor valor, o tempo decorrido e as avaliações na saída padrão a
+    /// cada N gerações (0 desativa).
+    #[arg(long, value_name = "N", default_value_t = 20)]
+    report_every: usize,
+
+    /// Inicia um painel web de acompanhamento no endereço informado (ex: 127.0.0.1:8090).
+    #[cfg(feature = "dashboard")]
+    #[arg(long, value_name = "ADDR")]
+    dashboard: Option<String>,
+
+    /// Diretório para salvar o arquivo de resultado (criado se não existir).
     #[arg(long, default_value = ".")]
     output_dir: PathBuf,
 
-    // --- Parâmetros do Algoritmo ---
+    /// Modelo do nome do arquivo de resultado, relativo a --output-dir, com os campos
+    /// {instance}, {algo}, {run} e {timestamp} (ex: "{instance}/{algo}_{run}.txt").
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        default_value = "resultado_{instance}.txt"
+    )]
+    output_template: OutputTemplate,
+
+    // --- Parâmetros dos Algoritmos ---
+    // Cada algoritmo tem as suas opções, prefixadas pelo nome da sua seção no
+    // arquivo de configuração (ex: `--ma.population-size`, `--sa.initial-temp`).
+    #[command(flatten)]
+    ma: MemeticArgs,
+
+    #[command(flatten)]
+    ils: IlsArgs,
+
+    #[command(flatten)]
+    ig: IgArgs,
+
+    #[command(flatten)]
+    sa: SaArgs,
+
+    #[command(flatten)]
+    tabu: TabuArgs,
+
+    #[command(flatten)]
+    aco: AcoArgs,
+
+    #[command(flatten)]
+    de: DeArgs,
+
+    #[command(flatten)]
+    brkga: BrkgaArgs,
+
+    #[command(flatten)]
+    pso: PsoArgs,
+
+    #[command(flatten)]
+    neh: NehArgs,
+
+    #[command(flatten)]
+    hh: HhArgs,
+
+    #[command(flatten)]
+    portfolio: PortfolioArgs,
+}
+
+/// Opções do algoritmo memético e do modelo de ilhas (`--ma.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Algoritmo memético (--ma.*)")]
+struct MemeticArgs {
     /// Tamanho da população.
-    #[arg(long, default_value_t = 100)]
+    #[arg(
+        long = "ma.population-size",
+        id = "ma.population_size",
+        value_name = "POPULATION_SIZE",
+        default_value_t = 100
+    )]
     population_size: usize,
 
     /// Taxa de mutação (probabilidade de um indivíduo sofrer mutação).
-    #[arg(long, default_value_t = 0.3)]
+    #[arg(
+        long = "ma.mutation-rate",
+        id = "ma.mutation_rate",
+        value_name = "MUTATION_RATE",
+        default_value_t = 0.3
+    )]
     mutation_rate: f64,
 
     /// Taxa de busca local (probabilidade de um indivíduo passar por busca local).
-    #[arg(long, default_value_t = 0.6)]
+    #[arg(
+        long = "ma.local-search-rate",
+        id = "ma.local_search_rate",
+        value_name = "LOCAL_SEARCH_RATE",
+        default_value_t = 0.6
+    )]
     local_search_rate: f64,
+
+    /// Seleção dos pais (tournament[:k], rank ou roulette).
+    #[arg(
+        long = "ma.selection",
+        id = "ma.selection",
+        value_name = "SELECTION",
+        default_value_t = Selection::default()
+    )]
+    selection: Selection,
+
+    /// Operador de cruzamento (ox, pmx, tp ou sbx).
+    #[arg(
+        long = "ma.crossover",
+        id = "ma.crossover",
+        value_name = "CROSSOVER",
+        default_value_t = Crossover::Ox
+    )]
+    crossover: Crossover,
+
+    /// Operadores de mutação, com pesos opcionais (swap, insert, inversion ou
+    /// scramble; ex: swap:0.5,insert:0.3,inversion:0.2).
+    #[arg(
+        long = "ma.mutation",
+        id = "ma.mutation",
+        value_name = "MUTATION",
+        default_value_t = MutationMix::default()
+    )]
+    mutation: MutationMix,
+
+    /// Busca local aplicada pelo algoritmo memético (swap, ils, vnd, vns ou critical).
+    #[arg(
+        long = "ma.local-search",
+        id = "ma.local_search",
+        value_name = "LOCAL_SEARCH",
+        default_value_t = LocalSearch::Swap
+    )]
+    local_search: LocalSearch,
+
+    /// Estratégia de substituição da população (generational, steady-state ou crowding).
+    #[arg(
+        long = "ma.replacement",
+        id = "ma.replacement",
+        value_name = "REPLACEMENT",
+        default_value_t = Replacement::Generational
+    )]
+    replacement: Replacement,
+
+    /// Número de melhores indivíduos preservados em cada geração (modo geracional).
+    #[arg(
+        long = "ma.elite-count",
+        id = "ma.elite_count",
+        value_name = "ELITE_COUNT",
+        default_value_t = 1
+    )]
+    elite_count: usize,
+
+    /// Capacidade do cache de avaliações do algoritmo memético (número de sequências; 0 desativa).
+    #[arg(
+        long = "ma.fitness-cache",
+        id = "ma.fitness_cache",
+        value_name = "FITNESS_CACHE",
+        default_value_t = fitness_cache::DEFAULT_CAPACITY
+    )]
+    fitness_cache: usize,
+
+    /// Adapta a taxa de mutação dinamicamente quando a diversidade da população colapsa.
+    #[arg(long = "ma.adaptive", id = "ma.adaptive")]
+    adaptive: bool,
+
+    /// Registra, a cada geração do algoritmo memético, as aplicações de cruzamento,
+    /// mutação e busca local, quantas melhoraram a solução e a melhoria média
+    /// (exibidas com `-v` e gravadas em `--history`). Consome avaliações adicionais.
+    #[arg(long = "ma.operator-stats", id = "ma.operator_stats")]
+    operator_stats: bool,
+
+    /// Seleção adaptativa de operadores: a cada geração, o cruzamento e a busca local
+    /// do algoritmo memético são escolhidos conforme as melhorias que produziram,
+    /// pela estratégia informada (pm, ucb ou qlearning; qlearning se omitida).
+    #[arg(
+        long = "ma.aos",
+        id = "ma.aos",
+        value_name = "AOS",
+        num_args = 0..=1,
+        default_missing_value = "qlearning"
+    )]
+    aos: Option<AosStrategy>,
+
+    /// Cruzamentos disponíveis para a seleção adaptativa, separados por vírgula.
+    #[arg(
+        long = "ma.aos-crossovers",
+        id = "ma.aos_crossovers",
+        value_name = "AOS_CROSSOVERS",
+        value_delimiter = ',',
+        default_value = "ox,pmx,tp,sbx",
+        requires = "ma.aos"
+    )]
+    aos_crossovers: Vec<Crossover>,
+
+    /// Buscas locais disponíveis para a seleção adaptativa, separadas por vírgula.
+    #[arg(
+        long = "ma.aos-local-searches",
+        id = "ma.aos_local_searches",
+        value_name = "AOS_LOCAL_SEARCHES",
+        value_delimiter = ',',
+        default_value = "swap,ils,vnd,vns,critical",
+        requires = "ma.aos"
+    )]
+    aos_local_searches: Vec<LocalSearch>,
+
+    /// Máximo de movimentos avaliados por aplicação da busca local (swap, vnd ou critical).
+    #[arg(
+        long = "ma.ls-max-moves",
+        id = "ma.ls_max_moves",
+        value_name = "LS_MAX_MOVES"
+    )]
+    ls_max_moves: Option<usize>,
+
+    /// Tempo máximo, em milissegundos, de cada aplicação da busca local (swap, vnd ou critical).
+    #[arg(
+        long = "ma.ls-max-millis",
+        id = "ma.ls_max_millis",
+        value_name = "LS_MAX_MILLIS"
+    )]
+    ls_max_millis: Option<u64>,
+
+    /// Recomeça a varredura da busca local por trocas após cada melhoria.
+    #[arg(long = "ma.ls-first-improvement", id = "ma.ls_first_improvement")]
+    ls_first_improvement: bool,
+
+    /// Estratégia da busca local por trocas (first ou best).
+    #[arg(
+        long = "ma.ls-strategy",
+        id = "ma.ls_strategy",
+        value_name = "LS_STRATEGY",
+        default_value_t = ImprovementStrategy::First
+    )]
+    ls_strategy: ImprovementStrategy,
+
+    /// Varre os pares de posições da busca local por trocas em ordem aleatória.
+    #[arg(long = "ma.ls-random-order", id = "ma.ls_random_order")]
+    ls_random_order: bool,
+
+    /// Pula, na busca local por trocas, pares de posições inalteradas desde a varredura anterior.
+    #[arg(long = "ma.ls-dont-look-bits", id = "ma.ls_dont_look_bits")]
+    ls_dont_look_bits: bool,
+
+    /// Indivíduos que recebem a busca local do algoritmo memético: sorteados com a
+    /// taxa de busca local (random), os que estariam entre a fração q dos melhores
+    /// (elite[:q]) ou os ainda não avaliados (unique).
+    #[arg(
+        long = "ma.ls-policy",
+        id = "ma.ls_policy",
+        value_name = "LS_POLICY",
+        default_value_t = LocalSearchPolicy::default()
+    )]
+    ls_policy: LocalSearchPolicy,
+
+    /// Reinicia a população após N gerações sem melhoria, preservando os melhores indivíduos.
+    #[arg(
+        long = "ma.restart-after",
+        id = "ma.restart_after",
+        value_name = "RESTART_AFTER"
+    )]
+    restart_after: Option<usize>,
+
+    /// Número de melhores indivíduos preservados em cada reinício da população.
+    #[arg(
+        long = "ma.restart-elite",
+        id = "ma.restart_elite",
+        value_name = "RESTART_ELITE",
+        default_value_t = 2
+    )]
+    restart_elite: usize,
+
+    /// Religa caminhos entre a melhor solução e elites da população a cada N gerações.
+    #[arg(
+        long = "ma.path-relinking-every",
+        id = "ma.path_relinking_every",
+        value_name = "PATH_RELINKING_EVERY"
+    )]
+    path_relinking_every: Option<usize>,
+
+    /// Número de elites religadas à melhor solução em cada aplicação da religação de caminhos.
+    #[arg(
+        long = "ma.path-relinking-elites",
+        id = "ma.path_relinking_elites",
+        value_name = "PATH_RELINKING_ELITES",
+        default_value_t = 2
+    )]
+    path_relinking_elites: usize,
+
+    /// Cronograma em duas fases: exploração (mutação intensa e pouca busca local) até
+    /// a fração informada do orçamento e, depois, busca local apenas nas elites.
+    #[arg(long = "ma.two-phase", id = "ma.two_phase", value_name = "FRACAO")]
+    two_phase: Option<f64>,
+
+    /// Taxa de mutação da fase de exploração do cronograma em duas fases.
+    #[arg(
+        long = "ma.exploration-mutation-rate",
+        id = "ma.exploration_mutation_rate",
+        value_name = "EXPLORATION_MUTATION_RATE",
+        default_value_t = PhaseSchedule::default().exploration_mutation_rate
+    )]
+    exploration_mutation_rate: f64,
+
+    /// Taxa de busca local da fase de exploração do cronograma em duas fases.
+    #[arg(
+        long = "ma.exploration-local-search-rate",
+        id = "ma.exploration_local_search_rate",
+        value_name = "EXPLORATION_LOCAL_SEARCH_RATE",
+        default_value_t = PhaseSchedule::default().exploration_local_search_rate
+    )]
+    exploration_local_search_rate: f64,
+
+    /// Fração da população tratada como elite na fase de intensificação.
+    #[arg(
+        long = "ma.intensification-elite",
+        id = "ma.intensification_elite",
+        value_name = "INTENSIFICATION_ELITE",
+        default_value_t = PhaseSchedule::default().elite_fraction
+    )]
+    intensification_elite: f64,
+
+    /// Iterações da VNS em cada aplicação como busca local do algoritmo memético.
+    #[arg(
+        long = "ma.vns-iterations",
+        id = "ma.vns_iterations",
+        value_name = "VNS_ITERATIONS",
+        default_value_t = 10
+    )]
+    vns_iterations: usize,
+
+    /// Número de ilhas (subpopulações evoluindo em paralelo). Valores menores que 2 desativam o modelo.
+    #[arg(
+        long = "ma.islands",
+        id = "ma.islands",
+        value_name = "ISLANDS",
+        default_value_t = 1
+    )]
+    islands: usize,
+
+    /// Número de gerações entre migrações no modelo de ilhas.
+    #[arg(
+        long = "ma.migration-interval",
+        id = "ma.migration_interval",
+        value_name = "MIGRATION_INTERVAL",
+        default_value_t = 10
+    )]
+    migration_interval: usize,
+
+    /// Fração da população de cada ilha enviada em cada migração.
+    #[arg(
+        long = "ma.migration-rate",
+        id = "ma.migration_rate",
+        value_name = "MIGRATION_RATE",
+        default_value_t = 0.05
+    )]
+    migration_rate: f64,
+
+    /// Topologia de migração entre ilhas (ring, complete ou random).
+    #[arg(
+        long = "ma.topology",
+        id = "ma.topology",
+        value_name = "TOPOLOGY",
+        default_value_t = Topology::Ring
+    )]
+    topology: Topology,
 }
 
-fn main() -> std::io::Result<()> {
-    let cli = Cli::parse();
+/// Opções da busca local iterada (`--ils.*`), usadas pelo algoritmo `ils` e pela
+/// busca local `ils` do algoritmo memético.
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Busca local iterada (--ils.*)")]
+struct IlsArgs {
+    /// Iterações da ILS em cada aplicação como busca local do algoritmo memético.
+    #[arg(
+        long = "ils.iterations",
+        id = "ils.iterations",
+        value_name = "ITERATIONS",
+        default_value_t = 20
+    )]
+    iterations: usize,
 
-    let instance =
-        load_instance(cli.instance_path.to_str().unwrap()).expect("Falha ao carregar instância.");
+    /// Força da perturbação da ILS (número de inserções aleatórias).
+    #[arg(
+        long = "ils.strength",
+        id = "ils.strength",
+        value_name = "STRENGTH",
+        default_value_t = 2
+    )]
+    strength: usize,
 
-    let start_time = Instant::now();
-    let max_duration = cli.max_duration.map(Duration::from_secs);
+    /// Critério de aceitação da ILS (better, rw ou sa).
+    #[arg(
+        long = "ils.acceptance",
+        id = "ils.acceptance",
+        value_name = "ACCEPTANCE",
+        default_value_t = Acceptance::Better
+    )]
+    acceptance: Acceptance,
 
-    let mut solver = MemeticAlgorithm::new(
-        instance,
-        cli.population_size,
-        cli.max_generations,
-        cli.mutation_rate,
-        cli.local_search_rate,
+    /// Fator de temperatura do critério de aceitação `sa` da ILS.
+    #[arg(
+        long = "ils.temperature",
+        id = "ils.temperature",
+        value_name = "TEMPERATURE",
+        default_value_t = 0.4
+    )]
+    temperature: f64,
+
+    /// Reinicia a ILS a partir de uma solução aleatória após N iterações sem melhoria.
+    #[arg(
+        long = "ils.restart-after",
+        id = "ils.restart_after",
+        value_name = "RESTART_AFTER"
+    )]
+    restart_after: Option<usize>,
+
+    /// Ajusta a força da perturbação da ILS durante a busca pela regra de 1/5,
+    /// partindo de --ils.strength.
+    #[arg(long = "ils.adaptive-perturbation", id = "ils.adaptive_perturbation")]
+    adaptive_perturbation: bool,
+}
+
+/// Opções do Iterated Greedy (`--ig.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Iterated Greedy (--ig.*)")]
+struct IgArgs {
+    /// Número de tarefas removidas e reinseridas a cada iteração do Iterated Greedy.
+    #[arg(
+        long = "ig.destruction-size",
+        id = "ig.destruction_size",
+        value_name = "DESTRUCTION_SIZE",
+        default_value_t = IgParams::default().destruction_size
+    )]
+    destruction_size: usize,
+
+    /// Fator de temperatura do critério de aceitação do Iterated Greedy.
+    #[arg(
+        long = "ig.temperature",
+        id = "ig.temperature",
+        value_name = "TEMPERATURE",
+        default_value_t = IgParams::default().temperature
+    )]
+    temperature: f64,
+
+    /// Ajusta o número de tarefas removidas durante a busca pela regra de 1/5,
+    /// partindo de --ig.destruction-size.
+    #[arg(long = "ig.adaptive-perturbation", id = "ig.adaptive_perturbation")]
+    adaptive_perturbation: bool,
+}
+
+/// Opções do recozimento simulado (`--sa.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Recozimento simulado (--sa.*)")]
+struct SaArgs {
+    /// Fator da temperatura inicial do recozimento simulado, multiplicado por
+    /// soma(p) / (10 n m).
+    #[arg(
+        long = "sa.initial-temp",
+        id = "sa.initial_temp",
+        value_name = "INITIAL_TEMP",
+        default_value_t = SaParams::default().initial_temperature
+    )]
+    initial_temp: f64,
+
+    /// Fator de resfriamento geométrico aplicado a cada patamar do recozimento simulado.
+    #[arg(
+        long = "sa.cooling-rate",
+        id = "sa.cooling_rate",
+        value_name = "COOLING_RATE",
+        default_value_t = SaParams::default().cooling_rate
+    )]
+    cooling_rate: f64,
+}
+
+/// Opções da busca tabu (`--tabu.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Busca tabu (--tabu.*)")]
+struct TabuArgs {
+    /// Número de iterações em que uma tarefa movida permanece tabu.
+    #[arg(
+        long = "tabu.tenure",
+        id = "tabu.tenure",
+        value_name = "TENURE",
+        default_value_t = TabuParams::default().tenure
+    )]
+    tenure: usize,
+
+    /// Tarefas sorteadas para a lista de candidatos de cada iteração da busca tabu.
+    #[arg(
+        long = "tabu.candidates",
+        id = "tabu.candidates",
+        value_name = "CANDIDATES",
+        default_value_t = TabuParams::default().candidates
+    )]
+    candidates: usize,
+}
+
+/// Opções da colônia de formigas (`--aco.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Colônia de formigas (--aco.*)")]
+struct AcoArgs {
+    /// Número de formigas (soluções construídas) por iteração da colônia.
+    #[arg(
+        long = "aco.ants",
+        id = "aco.ants",
+        value_name = "ANTS",
+        default_value_t = AcoParams::default().ants
+    )]
+    ants: usize,
+
+    /// Taxa de evaporação do feromônio da colônia de formigas.
+    #[arg(
+        long = "aco.evaporation",
+        id = "aco.evaporation",
+        value_name = "EVAPORATION",
+        default_value_t = AcoParams::default().evaporation
+    )]
+    evaporation: f64,
+
+    /// Probabilidade de uma formiga escolher a tarefa de maior feromônio em vez de
+    /// sortear proporcionalmente ao feromônio.
+    #[arg(
+        long = "aco.exploitation",
+        id = "aco.exploitation",
+        value_name = "EXPLOITATION",
+        default_value_t = AcoParams::default().exploitation
+    )]
+    exploitation: f64,
+}
+
+/// Opções da evolução diferencial (`--de.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Evolução diferencial (--de.*)")]
+struct DeArgs {
+    /// Tamanho da população da evolução diferencial.
+    #[arg(
+        long = "de.population-size",
+        id = "de.population_size",
+        value_name = "POPULATION_SIZE",
+        default_value_t = 100
+    )]
+    population_size: usize,
+
+    /// Fator de escala F da diferença de vetores na evolução diferencial.
+    #[arg(
+        long = "de.scale",
+        id = "de.scale",
+        value_name = "SCALE",
+        default_value_t = 0.5
+    )]
+    scale: f64,
+
+    /// Taxa CR do cruzamento binomial da evolução diferencial.
+    #[arg(
+        long = "de.crossover-rate",
+        id = "de.crossover_rate",
+        value_name = "CROSSOVER_RATE",
+        default_value_t = 0.9
+    )]
+    crossover_rate: f64,
+}
+
+/// Opções do BRKGA (`--brkga.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "BRKGA (--brkga.*)")]
+struct BrkgaArgs {
+    /// Tamanho da população do BRKGA.
+    #[arg(
+        long = "brkga.population-size",
+        id = "brkga.population_size",
+        value_name = "POPULATION_SIZE",
+        default_value_t = 100
+    )]
+    population_size: usize,
+
+    /// Fração da população na partição de elite do BRKGA.
+    #[arg(
+        long = "brkga.elite",
+        id = "brkga.elite",
+        value_name = "ELITE",
+        default_value_t = 0.2
+    )]
+    elite: f64,
+
+    /// Fração da população substituída por mutantes (chaves aleatórias) a cada
+    /// geração do BRKGA.
+    #[arg(
+        long = "brkga.mutants",
+        id = "brkga.mutants",
+        value_name = "MUTANTS",
+        default_value_t = 0.15
+    )]
+    mutants: f64,
+
+    /// Probabilidade de o filho herdar cada chave do pai de elite no BRKGA.
+    #[arg(
+        long = "brkga.bias",
+        id = "brkga.bias",
+        value_name = "BIAS",
+        default_value_t = 0.7
+    )]
+    bias: f64,
+}
+
+/// Opções do enxame de partículas (`--pso.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Enxame de partículas (--pso.*)")]
+struct PsoArgs {
+    /// Tamanho da população (número de partículas) do PSO.
+    #[arg(
+        long = "pso.population-size",
+        id = "pso.population_size",
+        value_name = "POPULATION_SIZE",
+        default_value_t = 100
+    )]
+    population_size: usize,
+
+    /// Inércia da velocidade das partículas no PSO.
+    #[arg(
+        long = "pso.inertia",
+        id = "pso.inertia",
+        value_name = "INERTIA",
+        default_value_t = 0.7
+    )]
+    inertia: f64,
+
+    /// Coeficiente de atração de cada partícula pela sua melhor posição no PSO.
+    #[arg(
+        long = "pso.cognitive",
+        id = "pso.cognitive",
+        value_name = "COGNITIVE",
+        default_value_t = 1.5
+    )]
+    cognitive: f64,
+
+    /// Coeficiente de atração das partículas pela melhor posição do enxame no PSO.
+    #[arg(
+        long = "pso.social",
+        id = "pso.social",
+        value_name = "SOCIAL",
+        default_value_t = 1.5
+    )]
+    social: f64,
+}
+
+/// Opções da heurística NEH (`--neh.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Heurística NEH (--neh.*)")]
+struct NehArgs {
+    /// Critério de desempate entre posições de inserção de mesmo valor no NEH
+    /// (`--algorithm neh` e reinícios do algoritmo memético): first, last, kk1 ou
+    /// idle.
+    #[arg(
+        long = "neh.tie-breaking",
+        id = "neh.tie_breaking",
+        value_name = "TIE_BREAKING",
+        default_value_t = NehTieBreaking::First
+    )]
+    tie_breaking: NehTieBreaking,
+}
+
+/// Opções da hiper-heurística (`--hh.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Hiper-heurística (--hh.*)")]
+struct HhArgs {
+    /// Algoritmos de baixo nível da hiper-heurística (`--algorithm hh`), separados
+    /// por vírgula. Cada um usa os parâmetros da sua própria seção.
+    #[arg(
+        long = "hh.algorithms",
+        id = "hh.algorithms",
+        value_name = "ALGORITHMS",
+        value_delimiter = ',',
+        default_value = "ig,sa,tabu,ils,memetic"
+    )]
+    algorithms: Vec<Algorithm>,
+
+    /// Gerações (ou iterações) de cada fatia da hiper-heurística. O número de
+    /// fatias é dado por `--max-generations`.
+    #[arg(
+        long = "hh.slice-generations",
+        id = "hh.slice_generations",
+        value_name = "SLICE_GENERATIONS",
+        default_value_t = 10
+    )]
+    slice_generations: usize,
+
+    /// Estratégia de escolha do algoritmo de cada fatia: ucb, pm ou qlearning.
+    #[arg(
+        long = "hh.strategy",
+        id = "hh.strategy",
+        value_name = "STRATEGY",
+        default_value_t = AosStrategy::Ucb
+    )]
+    strategy: AosStrategy,
+}
+
+/// Opções do portfólio (`--portfolio.*`).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Portfólio (--portfolio.*)")]
+struct PortfolioArgs {
+    /// Modelo do portfólio (`--algorithm auto`), gerado pelo subcomando
+    /// `train-portfolio` (padrão: modelo embutido).
+    #[arg(long = "portfolio.model", id = "portfolio.model", value_name = "MODEL")]
+    model: Option<PathBuf>,
+}
+
+/// Subcomandos adicionais. Sem subcomando, o programa resolve a instância informada
+/// (ou o diretório de `--batch`).
+#[derive(Subcommand, Debug, Clone)]
+enum Command {
+    /// Ajusta os parâmetros do algoritmo memético em um conjunto de instâncias de
+    /// treinamento e grava a melhor configuração em TOML (utilizável com --config).
+    /// Critérios de parada e demais opções informadas antes do subcomando valem para
+    /// todas as execuções.
+    Tune(TuneArgs),
+
+    /// Compara configurações a partir do CSV de execuções do binário `experiment`,
+    /// com os testes de Friedman e de postos sinalizados de Wilcoxon.
+    Compare(CompareArgs),
+
+    /// Exporta o modelo MILP de uma instância (objetivo makespan) para solvers
+    /// externos como CPLEX, Gurobi ou HiGHS.
+    ExportMilp(ExportMilpArgs),
+
+    /// Converte uma instância entre os formatos suportados (custom, taillard, csv e
+    /// json), verificando se a conversão preserva os dados.
+    Convert(ConvertArgs),
+
+    /// Exibe estatísticas de uma instância (tempos, carga das máquinas, dominância
+    /// e limitantes inferiores) para orientar a escolha dos parâmetros.
+    Inspect(InspectArgs),
+
+    /// Caracteriza a paisagem de aptidão de uma instância (autocorrelação de
+    /// passeios aleatórios, amostragem de ótimos locais e correlação
+    /// aptidão-distância) para avaliar sua dificuldade.
+    Analyze(AnalyzeArgs),
+
+    /// Treina o modelo do portfólio (`--algorithm auto`): executa cada algoritmo
+    /// candidato nas instâncias de treinamento e grava, para cada instância, as
+    /// suas características e o algoritmo vencedor. Critérios de parada e demais
+    /// opções informadas antes do subcomando valem para todas as execuções.
+    TrainPortfolio(TrainPortfolioArgs),
+}
+
+/// Opções do subcomando `train-portfolio`.
+#[derive(Args, Debug, Clone)]
+struct TrainPortfolioArgs {
+    /// Instâncias de treinamento (arquivos ou diretórios).
+    #[arg(required = true)]
+    instances: Vec<PathBuf>,
+
+    /// Algoritmos candidatos, separados por vírgula.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "ig,sa,tabu,ils,aco,memetic"
+    )]
+    candidates: Vec<Algorithm>,
+
+    /// Arquivo TOML em que o modelo é gravado (utilizável com --portfolio.model).
+    #[arg(long, default_value = "portfolio.toml")]
+    output: PathBuf,
+}
+
+/// Opções do subcomando `analyze`.
+#[derive(Args, Debug, Clone)]
+struct AnalyzeArgs {
+    /// Arquivo da instância FSSP.
+    instance: PathBuf,
+
+    /// Passos do passeio aleatório na vizinhança de inserção.
+    #[arg(long, default_value_t = 1000)]
+    walk_length: usize,
+
+    /// Número de ótimos locais amostrados (busca por inserção a partir de
+    /// permutações aleatórias).
+    #[arg(long, default_value_t = 50)]
+    samples: usize,
+
+    /// Semente do passeio e das permutações iniciais.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+/// Opções do subcomando `inspect`.
+#[derive(Args, Debug, Clone)]
+struct InspectArgs {
+    /// Arquivo da instância FSSP.
+    instance: PathBuf,
+}
+
+/// Opções do subcomando `convert`.
+#[derive(Args, Debug, Clone)]
+struct ConvertArgs {
+    /// Arquivo da instância de entrada.
+    input: PathBuf,
+
+    /// Arquivo de saída.
+    output: PathBuf,
+
+    /// Formato de entrada (padrão: deduzido da extensão e do conteúdo).
+    #[arg(long)]
+    from: Option<InstanceFormat>,
+
+    /// Formato de saída: custom, taillard, csv ou json (padrão: deduzido da extensão).
+    #[arg(long)]
+    to: Option<InstanceFormat>,
+}
+
+/// Opções do subcomando `export-milp`.
+#[derive(Args, Debug, Clone)]
+struct ExportMilpArgs {
+    /// Arquivo da instância FSSP.
+    instance: PathBuf,
+
+    /// Formulação do modelo (positional ou disjunctive).
+    #[arg(long, default_value_t = Formulation::Positional)]
+    formulation: Formulation,
+
+    /// Formato do arquivo (lp ou mps).
+    #[arg(long, default_value_t = ModelFormat::Lp)]
+    format: ModelFormat,
+
+    /// Arquivo de saída (padrão: nome da instância com a extensão do formato).
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+/// Opções do subcomando `compare`.
+#[derive(Args, Debug, Clone)]
+struct CompareArgs {
+    /// Arquivo CSV de execuções em formato longo (uma linha por execução).
+    runs: PathBuf,
+
+    /// Coluna comparada (menor é melhor).
+    #[arg(long, default_value = "makespan")]
+    metric: String,
+
+    /// Nível de significância dos testes.
+    #[arg(long, default_value_t = 0.05)]
+    alpha: f64,
+}
+
+/// Opções do subcomando `tune`.
+#[derive(Args, Debug, Clone)]
+struct TuneArgs {
+    /// Instâncias de treinamento (arquivos ou diretórios).
+    #[arg(required = true)]
+    instances: Vec<PathBuf>,
+
+    /// Procedimento de ajuste (race ou random).
+    #[arg(long, default_value_t = TuningMethod::Race)]
+    method: TuningMethod,
+
+    /// Número de configurações candidatas sorteadas.
+    #[arg(long, default_value_t = 20)]
+    candidates: usize,
+
+    /// Arquivo TOML em que a melhor configuração é gravada.
+    #[arg(long, default_value = "tuned.toml")]
+    output: PathBuf,
+
+    /// Semente do sorteio das configurações candidatas.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+/// Resumo da solução de uma instância no modo em lote.
+#[derive(Debug, Serialize)]
+struct BatchRecord {
+    instance: String,                      // Nome da instância (sem extensão).
+    n_jobs: usize,                         // Número de tarefas.
+    n_machines: usize,                     // Número de máquinas.
+    best_makespan: TimeValue,              // Makespan da melhor sequência encontrada.
+    best_known: Option<TimeValue>,         // Melhor makespan conhecido, se disponível.
+    gap_percent: Option<f64>,              // Desvio percentual em relação ao melhor conhecido.
+    runtime_seconds: f64,                  // Tempo de execução em segundos.
+    weighted_tardiness: Option<TimeValue>, // Atraso ponderado total (objetivo twt).
+    evaluations: u64,                      // Avaliações da função objetivo.
+    winner: Option<&'static str>,          // Algoritmo vencedor (hiper-heurística).
+    total_idle_time: TimeValue,            // Tempo ocioso total das máquinas.
+    mean_utilization: f64,                 // Utilização média das máquinas (0 a 1).
+    total_waiting_time: TimeValue,         // Tempo de espera total das tarefas.
+}
+
+/// Resultado da execução do solver sobre uma instância.
+struct SolveOutcome {
+    value: Time,                              // Valor da função objetivo.
+    makespan: Time,                           // Makespan da melhor solução.
+    sequence: Vec<Job>,                       // Melhor sequência permutacional.
+    schedule: Option<NonPermutationSchedule>, // Ordens por máquina (modo não permutacional).
+    evaluations: u64,                         // Avaliações da função objetivo.
+    population: Vec<(Vec<Job>, Time)>,        // População final (com `--save-population`).
+    time_decimals: u32,                       // Casas decimais dos tempos da instância.
+    energy: Option<EnergyOutcome>,            // Velocidades e energia (com `--speeds`).
+    robust: Option<(RobustCriterion, usize)>, // Critério e número de cenários, se robusto.
+    simulation: Option<Estimate>,             // Média com todas as replicações, se simulado.
+    winner: Option<&'static str>,             // Algoritmo vencedor (hiper-heurística).
+    metrics: ScheduleMetrics,                 // Ociosidade, utilização e esperas da solução.
+}
+
+/// Resultado da otimização com velocidades ajustáveis.
+struct EnergyOutcome {
+    best: EnergyPoint,     // Melhor solução segundo a soma ponderada.
+    value: f64,            // Soma ponderada normalizada da melhor solução.
+    profile: SpeedProfile, // Velocidades disponíveis.
+    front: ParetoFront,    // Fronteira de Pareto das soluções avaliadas.
+}
+
+impl SolveOutcome {
+    /// Valor de tempo nas unidades originais da instância.
+    fn time_value(&self, value: Time) -> TimeValue {
+        TimeValue::new(value, self.time_decimals)
+    }
+}
+
+/// Token acionado por Ctrl-C (SIGINT): a execução em andamento é encerrada e a
+/// melhor solução encontrada até então é exibida e gravada normalmente.
+static INTERRUPT: OnceLock<CancellationToken> = OnceLock::new();
+
+/// Painel web de acompanhamento, iniciado com `--dashboard`.
+#[cfg(feature = "dashboard")]
+static DASHBOARD: OnceLock<Dashboard> = OnceLock::new();
+
+fn main() {
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    init_logging(cli.verbose, cli.log_format);
+
+    let result = match &cli.config {
+        Some(path) => SolverConfig::load(path).map(|config| {
+            apply_config(&mut cli, config, |id| {
+                matches.value_source(id) == Some(ValueSource::CommandLine)
+            })
+        }),
+        None => Ok(()),
+    }
+    .and_then(|_| run(&cli));
+
+    if let Err(e) = result {
+        error!("Erro: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Token de interrupção do programa.
+fn interrupt_token() -> &'static CancellationToken {
+    INTERRUPT.get_or_init(CancellationToken::new)
+}
+
+/// Instala o tratador de Ctrl-C (SIGINT no Unix, evento de console no Windows). A
+/// primeira interrupção cancela o token de interrupção; a segunda encerra o
+/// programa imediatamente.
+fn install_interrupt_handler() {
+    let token = interrupt_token();
+    let installed = ctrlc::set_handler(move || {
+        if token.is_cancelled() {
+            std::process::exit(130);
+        }
+        token.cancel();
+    });
+    if let Err(e) = installed {
+        warn!("Não foi possível instalar o tratador de Ctrl-C: {}", e);
+    }
+}
+
+/// Configura o log na saída de erro, mantendo a saída padrão para os resultados.
+fn init_logging(verbose: u8, format: LogFormat) {
+    let level = match verbose {
+        0 => Level::INFO,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_target(false)
+        .with_writer(std::io::stderr);
+    match format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
+}
+
+/// Aplica os valores do arquivo de configuração às opções, exceto àquelas para as
+/// quais `from_command_line` indica que o valor foi informado na linha de comando.
+/// As opções de um algoritmo são identificadas por `seção.campo` (ex:
+/// `ma.population_size`).
+fn apply_config(cli: &mut Cli, config: SolverConfig, from_command_line: impl Fn(&str) -> bool) {
+    // Opções com valor padrão ou sinalizadores.
+    macro_rules! merge {
+        ($section:ident: $($field:ident),* $(,)?) => {
+            $(
+                if let Some(value) = config.$section.$field {
+                    if !from_command_line(concat!(stringify!($section), ".", stringify!($field))) {
+                        cli.$section.$field = value;
+                    }
+                }
+            )*
+        };
+        ($($field:ident),* $(,)?) => {
+            $(
+                if let Some(value) = config.$field {
+                    if !from_command_line(stringify!($field)) {
+                        cli.$field = value;
+                    }
+                }
+            )*
+        };
+    }
+    // Opções sem valor padrão.
+    macro_rules! merge_optional {
+        ($section:ident: $($field:ident),* $(,)?) => {
+            $(
+                if config.$section.$field.is_some()
+                    && !from_command_line(concat!(stringify!($section), ".", stringify!($field)))
+                {
+                    cli.$section.$field = config.$section.$field;
+                }
+            )*
+        };
+        ($($field:ident),* $(,)?) => {
+            $(
+                if config.$field.is_some() && !from_command_line(stringify!($field)) {
+                    cli.$field = config.$field;
+                }
+            )*
+        };
+    }
+
+    merge!(
+        objective,
+        schedule_type,
+        energy_weight,
+        robust,
+        scenario_samples,
+        replications,
+        initial_replications,
+        resample_z,
+        algorithm,
+        max_generations,
+        output_dir,
+        no_sharing,
+    );
+    merge!(
+        ma: population_size,
+        mutation_rate,
+        local_search_rate,
+        selection,
+        crossover,
+        mutation,
+        local_search,
+        replacement,
+        elite_count,
+        fitness_cache,
+        adaptive,
+        operator_stats,
+        aos_crossovers,
+        aos_local_searches,
+        ls_first_improvement,
+        ls_strategy,
+        ls_random_order,
+        ls_dont_look_bits,
+        ls_policy,
+        restart_elite,
+        path_relinking_elites,
+        exploration_mutation_rate,
+        exploration_local_search_rate,
+        intensification_elite,
+        vns_iterations,
+        islands,
+        migration_interval,
+        migration_rate,
+        topology,
+    );
+    merge!(ils: iterations, strength, acceptance, temperature, adaptive_perturbation);
+    merge!(ig: destruction_size, temperature, adaptive_perturbation);
+    merge!(sa: initial_temp, cooling_rate);
+    merge!(tabu: tenure, candidates);
+    merge!(aco: ants, evaporation, exploitation);
+    merge!(de: population_size, scale, crossover_rate);
+    merge!(brkga: population_size, elite, mutants, bias);
+    merge!(pso: population_size, inertia, cognitive, social);
+    merge!(neh: tie_breaking);
+    merge!(hh: algorithms, slice_generations, strategy);
+    merge_optional!(
+        speeds,
+        stochastic,
+        max_duration,
+        max_evaluations,
+        best_known,
+    );
+    merge_optional!(
+        ma: aos,
+        ls_max_moves,
+        ls_max_millis,
+        restart_after,
+        path_relinking_every,
+        two_phase,
+    );
+    merge_optional!(ils: restart_after);
+    merge_optional!(portfolio: model);
+}
+
+/// Configuração do solver equivalente às opções da linha de comando, usada para
+/// construir o solver pelo registro de algoritmos.
+fn solver_config(cli: &Cli) -> SolverConfig {
+    let mut config = SolverConfig::default();
+    // Opções com valor padrão ou sinalizadores.
+    macro_rules! set {
+        ($section:ident: $($field:ident),* $(,)?) => {
+            $(config.$section.$field = Some(cli.$section.$field.clone());)*
+        };
+        ($($field:ident),* $(,)?) => {
+            $(config.$field = Some(cli.$field.clone());)*
+        };
+    }
+    // Opções sem valor padrão.
+    macro_rules! set_optional {
+        ($section:ident: $($field:ident),* $(,)?) => {
+            $(config.$section.$field = cli.$section.$field.clone();)*
+        };
+        ($($field:ident),* $(,)?) => {
+            $(config.$field = cli.$field.clone();)*
+        };
+    }
+
+    set!(
+        objective,
+        schedule_type,
+        energy_weight,
+        robust,
+        scenario_samples,
+        replications,
+        initial_replications,
+        resample_z,
+        algorithm,
+        max_generations,
+        output_dir,
+        no_sharing,
+    );
+    set!(
+        ma: population_size,
+        mutation_rate,
+        local_search_rate,
+        selection,
+        crossover,
+        mutation,
+        local_search,
+        replacement,
+        elite_count,
+        fitness_cache,
+        adaptive,
+        operator_stats,
+        aos_crossovers,
+        aos_local_searches,
+        ls_first_improvement,
+        ls_strategy,
+        ls_random_order,
+        ls_dont_look_bits,
+        ls_policy,
+        restart_elite,
+        path_relinking_elites,
+        exploration_mutation_rate,
+        exploration_local_search_rate,
+        intensification_elite,
+        vns_iterations,
+        islands,
+        migration_interval,
+        migration_rate,
+        topology,
     );
+    set!(ils: iterations, strength, acceptance, temperature, adaptive_perturbation);
+    set!(ig: destruction_size, temperature, adaptive_perturbation);
+    set!(sa: initial_temp, cooling_rate);
+    set!(tabu: tenure, candidates);
+    set!(aco: ants, evaporation, exploitation);
+    set!(de: population_size, scale, crossover_rate);
+    set!(brkga: population_size, elite, mutants, bias);
+    set!(pso: population_size, inertia, cognitive, social);
+    set!(neh: tie_breaking);
+    set!(hh: algorithms, slice_generations, strategy);
+    set_optional!(
+        speeds,
+        stochastic,
+        max_duration,
+        max_evaluations,
+        best_known,
+    );
+    set_optional!(
+        ma: aos,
+        ls_max_moves,
+        ls_max_millis,
+        restart_after,
+        path_relinking_every,
+        two_phase,
+    );
+    set_optional!(ils: restart_after);
+    set_optional!(portfolio: model);
+    config
+}
+
+/// Lista os algoritmos do registro, com a descrição e as opções de cada um.
+fn print_algorithms() {
+    const WIDTH: usize = 80;
+    for entry in &registry::ALGORITHMS {
+        println!("{:<8} {}", entry.name, entry.description);
+        if entry.params.is_empty() {
+            continue;
+        }
+        let mut line = format!("{:<8} opções:", "");
+        for param in entry.params {
+            if line.chars().count() + param.len() + 1 > WIDTH {
+                println!("{}", line);
+                line = " ".repeat(16);
+            }
+            line.push(' ');
+            line.push_str(param);
+        }
+        println!("{}", line);
+    }
+    println!(
+        "\nOpções comuns: --max-generations (iterações), --max-duration, --max-evaluations e --initial-solution."
+    );
+}
 
-    // Executa o solver com os limites de tempo e geração.
-    solver.run(start_time, max_duration);
+/// Executa o modo selecionado pela linha de comando.
+fn run(cli: &Cli) -> Result<()> {
+    if cli.list_algorithms {
+        print_algorithms();
+        return Ok(());
+    }
 
+    // Tabela embutida (Taillard), complementada pelo arquivo informado.
+    let mut best_known = BestKnown::builtin();
+    if let Some(path) = &cli.best_known {
+        best_known.extend(BestKnown::load(path)?);
+    }
+
+    match &cli.command {
+        Some(Command::Tune(args)) => return run_tune(cli, args),
+        Some(Command::Compare(args)) => return run_compare(args),
+        Some(Command::ExportMilp(args)) => return run_export_milp(args),
+        Some(Command::Convert(args)) => return run_convert(args),
+        Some(Command::Inspect(args)) => return run_inspect(args, &best_known),
+        Some(Command::Analyze(args)) => return run_analyze(args),
+        Some(Command::TrainPortfolio(args)) => return run_train_portfolio(cli, args),
+        None => {}
+    }
+
+    install_interrupt_handler();
+    #[cfg(feature = "dashboard")]
+    if let Some(addr) = &cli.dashboard {
+        let _ = DASHBOARD.set(Dashboard::start(addr)?);
+    }
+    match (&cli.batch, &cli.instance_path) {
+        (Some(dir), _) => run_batch(cli, dir, &best_known),
+        (None, Some(instance_path)) if cli.runs > 1 => {
+            run_multi_start(cli, instance_path, &best_known)
+        }
+        (None, Some(instance_path)) => run_single(cli, instance_path, &best_known),
+        (None, None) => unreachable!("clap exige o caminho da instância ou --batch"),
+    }
+}
+
+/// Resolve uma única instância, exibindo e salvando o resultado.
+fn run_single(cli: &Cli, instance_path: &Path, best_known: &BestKnown) -> Result<()> {
+    let mut instance = load_instance(instance_path.to_str().unwrap())?;
+    apply_uncertainty(cli, &mut instance)?;
+    // Desvio em relação ao melhor conhecido, quando a instância é reconhecida. Os
+    // valores conhecidos referem-se ao makespan.
+    let bks = best_known_for(cli, best_known, &instance_name(instance_path), &instance);
+
+    let progress = MultiProgress::new();
+    let start_time = Instant::now();
+    let outcome = solve(
+        cli,
+        instance,
+        &instance_name(instance_path),
+        start_time,
+        (!cli.quiet).then_some(&progress),
+        None,
+    )?;
     let execution_time = start_time.elapsed();
+    let best_makespan = outcome.makespan;
 
     // --- Exibição dos resultados no console ---
-    println!("\n--- Resultados Finais ---");
-    println!("Melhor Makespan: {}", solver.best_makespan);
+    let lang = cli.lang;
+    println!(
+        "\n--- {} ---",
+        lang.pick("Resultados Finais", "Final Results")
+    );
+    if let Some(line) = objective_line(&outcome, cli.objective, lang) {
+        println!("{}", line);
+    }
+    println!(
+        "{}: {}",
+        lang.pick("Melhor Makespan", "Best Makespan"),
+        outcome.time_value(best_makespan)
+    );
+    if let Some(bks) = bks {
+        println!(
+            "{}: {} (RPD = {:.2}%)",
+            lang.pick("Melhor Conhecido", "Best Known"),
+            outcome.time_value(bks),
+            relative_percentage_deviation(best_makespan, bks)
+        );
+    }
 
-    let sequence_str_display: Vec<String> = solver
-        .best_sequence
+    let sequence_str_display: Vec<String> = outcome
+        .sequence
         .iter()
         .map(|&x| (x + 1).to_string()) // +1 para visualização (base 1)
         .collect();
-    println!("Melhor Sequencia: {}", sequence_str_display.join(" "));
     println!(
-        "Tempo de Execucao (segundos): {:.4}",
+        "{}: {}",
+        lang.pick("Melhor Sequencia", "Best Sequence"),
+        sequence_str_display.join(" ")
+    );
+    if let Some(schedule) = &outcome.schedule {
+        for (machine, order) in schedule.orders.iter().enumerate() {
+            let order_str: Vec<String> = order.iter().map(|&x| (x + 1).to_string()).collect();
+            println!(
+                "{} {}: {}",
+                lang.pick("Ordem na Maquina", "Order on Machine"),
+                machine + 1,
+                order_str.join(" ")
+            );
+        }
+    }
+    for line in energy_lines(&outcome, 1, lang) {
+        println!("{}", line);
+    }
+    for line in metrics_lines(&outcome, lang) {
+        println!("{}", line);
+    }
+    println!(
+        "{}: {:.4}",
+        lang.pick("Tempo de Execucao (segundos)", "Runtime (seconds)"),
         execution_time.as_secs_f64()
     );
+    println!(
+        "{}: {}",
+        lang.pick("Avaliacoes", "Evaluations"),
+        outcome.evaluations
+    );
+    if let Some(winner) = outcome.winner {
+        println!(
+            "{}: {}",
+            lang.pick("Algoritmo Vencedor", "Winning Algorithm"),
+            winner
+        );
+    }
 
     // --- Geração do arquivo de resultado ---
-    write_results_to_file(&cli, &solver, execution_time.as_secs_f64())?;
+    write_results_to_file(
+        &result_path(cli, &instance_name(instance_path), None),
+        &outcome,
+        cli.objective,
+        cli.lang,
+        execution_time.as_secs_f64(),
+        bks,
+    )?;
+    if let Some(path) = &cli.append_summary {
+        append_summary(
+            path,
+            cli,
+            &instance_name(instance_path),
+            None,
+            &outcome,
+            execution_time.as_secs_f64(),
+            bks,
+        )?;
+    }
+    if let Some(path) = &cli.save_population {
+        write_population_file(path, &instance_name(instance_path), &outcome)?;
+    }
+    if let (Some(path), Some(energy)) = (&cli.pareto_front, &outcome.energy) {
+        write_pareto_front(path, energy, outcome.time_decimals)?;
+    }
 
     Ok(())
 }
 
-fn write_results_to_file(
+/// Executa `--runs` execuções independentes da mesma instância em paralelo (cada
+/// uma com seu próprio gerador aleatório), grava o resultado de cada execução em
+/// `resultado_<instância>_run<k>.txt` e, em `resultado_<instância>.txt`, a melhor
+/// solução seguida das estatísticas agregadas (nomes segundo `--output-template`).
+fn run_multi_start(cli: &Cli, instance_path: &Path, best_known: &BestKnown) -> Result<()> {
+    let mut instance = load_instance(instance_path.to_str().unwrap())?;
+    apply_uncertainty(cli, &mut instance)?;
+    let name = instance_name(instance_path);
+    let bks = best_known_for(cli, best_known, &name, &instance);
+    info!(
+        "Multi-start: {} execuções independentes de {}.",
+        cli.runs, name
+    );
+
+    let progress = MultiProgress::new();
+    let start_time = Instant::now();
+    // Melhor solução global compartilhada entre as execuções simultâneas.
+    let shared = (!cli.no_sharing).then(SharedBest::new);
+    let solve_run = |run: u32| -> Result<Option<(u32, SolveOutcome)>> {
+        // Após uma interrupção, as execuções ainda não iniciadas são ignoradas.
+        if interrupt_token().is_cancelled() {
+            return Ok(None);
+        }
+        let label = format!("{}#{}", name, run);
+        let _span = info_span!("run", run).entered();
+        let run_start = Instant::now();
+        let outcome = solve(
+            cli,
+            instance.clone(),
+            &label,
+            run_start,
+            (!cli.quiet).then_some(&progress),
+            shared.as_ref(),
+        )?;
+        let runtime_seconds = run_start.elapsed().as_secs_f64();
+        write_results_to_file(
+            &result_path(cli, &name, Some(run)),
+            &outcome,
+            cli.objective,
+            cli.lang,
+            runtime_seconds,
+            bks,
+        )?;
+        if let Some(path) = &cli.append_summary {
+            append_summary(path, cli, &name, Some(run), &outcome, runtime_seconds, bks)?;
+        }
+        Ok(Some((run, outcome)))
+    };
+    let results: Vec<Option<(u32, SolveOutcome)>> = (1..=cli.runs)
+        .into_par_iter()
+        .map(solve_run)
+        .collect::<Result<_>>()?;
+    let results: Vec<(u32, SolveOutcome)> = results.into_iter().flatten().collect();
+    if results.len() < cli.runs as usize {
+        warn!(
+            skipped = cli.runs as usize - results.len(),
+            "Multi-start interrompido: execuções não iniciadas foram ignoradas."
+        );
+    }
+    let Some((best_run, best)) = results.iter().min_by_key(|(_, outcome)| outcome.value) else {
+        return Ok(());
+    };
+    let execution_time = start_time.elapsed().as_secs_f64();
+
+    // Estatísticas do valor da função objetivo (desvio padrão amostral), nas
+    // unidades originais da instância.
+    let values: Vec<f64> = results
+        .iter()
+        .map(|(_, o)| o.time_value(o.value).as_f64())
+        .collect();
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let std_dev = if values.len() > 1 {
+        (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+    } else {
+        0.0
+    };
+    let worst = results
+        .iter()
+        .map(|(_, o)| o.value)
+        .max()
+        .unwrap_or(best.value);
+    let lang = cli.lang;
+    let label = objective_label(cli.objective, lang);
+    let mut summary = vec![
+        format!("{}: {}", lang.pick("Execucoes", "Runs"), results.len()),
+        format!("{}: {}", lang.pick("Melhor Execucao", "Best Run"), best_run),
+        match lang {
+            Lang::Pt => format!("{} Medio: {:.2}", label, mean),
+            Lang::En => format!("Mean {}: {:.2}", label, mean),
+        },
+        format!(
+            "{}: {:.2}",
+            lang.pick("Desvio Padrao", "Standard Deviation"),
+            std_dev
+        ),
+        match lang {
+            Lang::Pt => format!("Pior {}: {}", label, best.time_value(worst)),
+            Lang::En => format!("Worst {}: {}", label, best.time_value(worst)),
+        },
+        format!(
+            "{}: {:.0}",
+            lang.pick("Avaliacoes Medias", "Mean Evaluations"),
+            results
+                .iter()
+                .map(|(_, o)| o.evaluations as f64)
+                .sum::<f64>()
+                / n
+        ),
+    ];
+    if let Some(bks) = bks {
+        let mean_rpd = results
+            .iter()
+            .map(|(_, o)| relative_percentage_deviation(o.makespan, bks))
+            .sum::<f64>()
+            / n;
+        summary.push(format!(
+            "{}: {:.4}",
+            lang.pick("Desvio Relativo Medio (%)", "Mean Relative Deviation (%)"),
+            mean_rpd
+        ));
+    }
+
+    // --- Exibição dos resultados no console ---
+    match lang {
+        Lang::Pt => println!("\n--- Resultados de {} Execuções ---", results.len()),
+        Lang::En => println!("\n--- Results of {} Runs ---", results.len()),
+    }
+    if let Some(line) = objective_line(best, cli.objective, lang) {
+        println!("{}", line);
+    }
+    println!(
+        "{}: {}",
+        lang.pick("Melhor Makespan", "Best Makespan"),
+        best.time_value(best.makespan)
+    );
+    for line in &summary {
+        println!("{}", line);
+    }
+    if let Some(bks) = bks {
+        println!(
+            "{}: {} (RPD = {:.2}%)",
+            lang.pick("Melhor Conhecido", "Best Known"),
+            best.time_value(bks),
+            relative_percentage_deviation(best.makespan, bks)
+        );
+    }
+    let sequence_str_display: Vec<String> =
+        best.sequence.iter().map(|&x| (x + 1).to_string()).collect();
+    println!(
+        "{}: {}",
+        lang.pick("Melhor Sequencia", "Best Sequence"),
+        sequence_str_display.join(" ")
+    );
+    for line in energy_lines(best, 1, lang) {
+        println!("{}", line);
+    }
+    println!(
+        "{}: {:.4}",
+        lang.pick("Tempo de Execucao (segundos)", "Runtime (seconds)"),
+        execution_time
+    );
+
+    // --- Geração do arquivo de resultado: melhor solução e estatísticas ---
+    let output_path = result_path(cli, &name, None);
+    write_results_to_file(
+        &output_path,
+        best,
+        cli.objective,
+        cli.lang,
+        execution_time,
+        bks,
+    )?;
+    let mut file = fs::OpenOptions::new().append(true).open(output_path)?;
+    for line in &summary {
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Define os cenários de tempos de processamento da otimização robusta (`--scenarios`
+/// ou `--time-upper`) ou as replicações da simulação (`--stochastic`), se informados.
+fn apply_uncertainty(cli: &Cli, instance: &mut FSSPInstance) -> Result<()> {
+    let mut rng = match cli.scenario_seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    if let Some(model) = cli.stochastic {
+        if !(cli.resample_z.is_finite() && cli.resample_z >= 0.0) {
+            return Err(FsspError::Config(
+                "--resample-z deve ser um número não negativo".to_string(),
+            ));
+        }
+        let std_devs = cli
+            .time_stddev
+            .as_ref()
+            .map(|path| load_instance(path.to_str().unwrap()))
+            .transpose()?;
+        let uncertainty = stochastic::simulation_uncertainty(
+            instance,
+            model,
+            std_devs,
+            cli.replications as usize,
+            AdaptiveSampling::new(cli.initial_replications as usize, cli.resample_z),
+            &mut rng,
+        )?;
+        info!(
+            "Simulação ({}) com {} replicações.",
+            model, cli.replications
+        );
+        return instance.set_uncertainty(uncertainty);
+    }
+    let uncertainty = if !cli.scenarios.is_empty() {
+        let scenarios = cli
+            .scenarios
+            .iter()
+            .map(|path| load_instance(path.to_str().unwrap()))
+            .collect::<Result<_>>()?;
+        robust::scenario_uncertainty(instance, scenarios, cli.robust)?
+    } else if let Some(path) = &cli.time_upper {
+        let upper = load_instance(path.to_str().unwrap())?;
+        robust::interval_uncertainty(
+            instance,
+            upper,
+            cli.scenario_samples as usize,
+            cli.robust,
+            &mut rng,
+        )?
+    } else {
+        return Ok(());
+    };
+    info!(
+        "Otimização robusta ({}) com {} cenários.",
+        cli.robust,
+        uncertainty.scenarios.len()
+    );
+    instance.set_uncertainty(uncertainty)
+}
+
+/// Melhor makespan conhecido da instância, na escala dos tempos armazenados (veja
+/// `FSSPInstance::time_scale`). Só se aplica ao objetivo makespan.
+fn best_known_for(
     cli: &Cli,
-    solver: &MemeticAlgorithm,
-    exec_time: f64,
+    best_known: &BestKnown,
+    name: &str,
+    instance: &FSSPInstance,
+) -> Option<Time> {
+    match cli.objective {
+        Objective::Makespan => best_known
+            .get(name)
+            .map(|bks| bks.saturating_mul(instance.time_scale())),
+        _ => None,
+    }
+}
+
+/// Resolve todas as instâncias de um diretório e grava um resumo consolidado.
+fn run_batch(cli: &Cli, dir: &Path, best_known: &BestKnown) -> Result<()> {
+    let instances = load_instances(&[dir.to_path_buf()])?;
+    info!("Modo em lote: {} instâncias encontradas.", instances.len());
+    // Sem o nome da instância, os resultados de todas iriam para o mesmo arquivo.
+    if instances.len() > 1 && !cli.output_template.0.contains("{instance}") {
+        return Err(FsspError::Config(format!(
+            "--output-template '{}' deve conter {{instance}} no modo em lote com mais de uma instância",
+            cli.output_template
+        )));
+    }
+
+    // Barras de progresso compartilhadas pelas instâncias resolvidas em paralelo.
+    let progress = MultiProgress::new();
+
+    let solve_one = |(path, instance): &(PathBuf, FSSPInstance)| -> Result<Option<BatchRecord>> {
+        let name = instance_name(path);
+        // Após uma interrupção, as instâncias ainda não iniciadas são ignoradas.
+        if interrupt_token().is_cancelled() {
+            return Ok(None);
+        }
+        let _span = info_span!("batch", instance = %name).entered();
+
+        let start_time = Instant::now();
+        let outcome = solve(
+            cli,
+            instance.clone(),
+            &name,
+            start_time,
+            (!cli.quiet).then_some(&progress),
+            None,
+        )?;
+        let runtime_seconds = start_time.elapsed().as_secs_f64();
+        let best_makespan = outcome.makespan;
+        let weighted_tardiness = (cli.objective == Objective::WeightedTardiness)
+            .then_some(outcome.time_value(outcome.value));
+        let bks = best_known_for(cli, best_known, &name, instance);
+
+        write_results_to_file(
+            &result_path(cli, &name, None),
+            &outcome,
+            cli.objective,
+            cli.lang,
+            runtime_seconds,
+            bks,
+        )?;
+        if let Some(path) = &cli.append_summary {
+            append_summary(path, cli, &name, None, &outcome, runtime_seconds, bks)?;
+        }
+
+        Ok(Some(BatchRecord {
+            instance: name,
+            n_jobs: instance.n_jobs,
+            n_machines: instance.n_machines,
+            best_makespan: outcome.time_value(best_makespan),
+            best_known: bks.map(|bks| outcome.time_value(bks)),
+            gap_percent: bks.map(|bks| relative_percentage_deviation(best_makespan, bks)),
+            runtime_seconds,
+            weighted_tardiness,
+            evaluations: outcome.evaluations,
+            winner: outcome.winner,
+            total_idle_time: outcome.time_value(outcome.metrics.total_idle),
+            mean_utilization: outcome.metrics.mean_utilization(),
+            total_waiting_time: outcome.time_value(outcome.metrics.total_waiting),
+        }))
+    };
+
+    let records: Vec<Option<BatchRecord>> = if cli.parallel {
+        instances.par_iter().map(solve_one).collect::<Result<_>>()?
+    } else {
+        instances.iter().map(solve_one).collect::<Result<_>>()?
+    };
+    let records: Vec<BatchRecord> = records.into_iter().flatten().collect();
+    if records.len() < instances.len() {
+        warn!(
+            skipped = instances.len() - records.len(),
+            "Lote interrompido: instâncias não iniciadas foram ignoradas."
+        );
+    }
+
+    write_batch_summary(&cli.summary, &records)?;
+    info!("Resumo do lote salvo em: {}", cli.summary.display());
+    Ok(())
+}
+
+/// Ajusta os parâmetros nas instâncias de treinamento e grava a melhor configuração.
+fn run_tune(cli: &Cli, args: &TuneArgs) -> Result<()> {
+    let instances = load_instances(&args.instances)?;
+    if instances.is_empty() {
+        return Err(FsspError::Config(
+            "nenhuma instância de treinamento encontrada".to_string(),
+        ));
+    }
+
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let candidates: Vec<SolverConfig> = (0..args.candidates.max(1))
+        .map(|_| tuning::sample_config(&mut rng))
+        .collect();
+    info!(
+        "Ajuste ({}): {} configurações em {} instâncias.",
+        args.method,
+        candidates.len(),
+        instances.len()
+    );
+
+    let tuned = tuning::tune(candidates, instances.len(), args.method, |config, idx| {
+        let mut candidate_cli = cli.clone();
+        apply_config(&mut candidate_cli, config.clone(), |_| false);
+        candidate_cli.initial_solution = None;
+        candidate_cli.fixed_prefix = None;
+        candidate_cli.reschedule = None;
+        let result = solve(
+            &candidate_cli,
+            instances[idx].1.clone(),
+            "",
+            Instant::now(),
+            None,
+            None,
+        )?;
+        Ok(result.value)
+    })?;
+
+    let toml = tuned.config.to_toml()?;
+    let header = format!(
+        "# Configuração obtida pelo subcomando `tune` ({}, {} execuções, desvio médio de {:.2}%).\n",
+        args.method, tuned.evaluations, tuned.mean_deviation
+    );
+    fs::write(&args.output, header + &toml)?;
+
+    println!("\n--- Melhor Configuração ---\n{}", toml);
+    info!("Configuração salva em: {}", args.output.display());
+    Ok(())
+}
+
+/// Treina o modelo do portfólio nas instâncias informadas e grava o modelo.
+fn run_train_portfolio(cli: &Cli, args: &TrainPortfolioArgs) -> Result<()> {
+    let instances = load_instances(&args.instances)?;
+    if instances.is_empty() {
+        return Err(FsspError::Config(
+            "nenhuma instância de treinamento encontrada".to_string(),
+        ));
+    }
+    if let Some(algorithm) = args.candidates.iter().find(|&&a| a == Algorithm::Auto) {
+        return Err(FsspError::Config(format!(
+            "o portfólio não pode usar o algoritmo {} como candidato",
+            algorithm
+        )));
+    }
+    info!(
+        "Treinamento do portfólio: {} algoritmos em {} instâncias.",
+        args.candidates.len(),
+        instances.len()
+    );
+
+    let features: Vec<(String, InstanceFeatures)> = instances
+        .iter()
+        .map(|(path, instance)| (instance_name(path), InstanceFeatures::new(instance)))
+        .collect();
+    let model = portfolio::train(&features, &args.candidates, |algorithm, idx| {
+        let mut candidate_cli = cli.clone();
+        candidate_cli.algorithm = algorithm;
+        candidate_cli.initial_solution = None;
+        candidate_cli.fixed_prefix = None;
+        candidate_cli.reschedule = None;
+        let result = solve(
+            &candidate_cli,
+            instances[idx].1.clone(),
+            "",
+            Instant::now(),
+            None,
+            None,
+        )?;
+        Ok(result.value)
+    })?;
+
+    let header = format!(
+        "# Modelo obtido pelo subcomando `train-portfolio` ({} em {} instâncias).\n",
+        args.candidates
+            .iter()
+            .map(Algorithm::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        instances.len()
+    );
+    fs::write(&args.output, header + &model.to_toml()?)?;
+
+    for preset in &model.presets {
+        println!(
+            "{}: {}",
+            preset.name,
+            preset.config.algorithm.unwrap_or(Algorithm::Memetic)
+        );
+    }
+    info!("Modelo salvo em: {}", args.output.display());
+    Ok(())
+}
+
+/// Grava o modelo MILP da instância no formato solicitado.
+fn run_export_milp(args: &ExportMilpArgs) -> Result<()> {
+    let instance = load_instance(args.instance.to_str().unwrap())?;
+    if instance
+        .unavailability
+        .iter()
+        .any(|intervals| !intervals.is_empty())
+    {
+        warn!("As manutenções das máquinas não são representadas no modelo MILP.");
+    }
+    if instance.transfer_times.is_some() {
+        warn!("Os tempos de transporte entre máquinas não são representados no modelo MILP.");
+    }
+
+    let output = args.output.clone().unwrap_or_else(|| {
+        PathBuf::from(format!("{}.{}", instance_name(&args.instance), args.format))
+    });
+    let mut file = std::io::BufWriter::new(File::create(&output)?);
+    milp::write_model(&instance, args.formulation, args.format, &mut file)?;
+    file.flush()?;
+
+    info!(
+        "Modelo {} ({}) salvo em: {}",
+        args.formulation,
+        args.format,
+        output.display()
+    );
+    Ok(())
+}
+
+/// Converte uma instância para outro formato. Avisa sobre as seções que o formato
+/// de saída não representa e verifica, relendo o texto gerado, se os tempos de
+/// processamento e as demais seções foram preservados.
+fn run_convert(args: &ConvertArgs) -> Result<()> {
+    let text = fs::read_to_string(&args.input)?;
+    let from = args
+        .from
+        .unwrap_or_else(|| InstanceFormat::detect(&args.input, &text));
+    let to = args
+        .to
+        .unwrap_or_else(|| InstanceFormat::from_path(&args.output));
+    let instance = from.parse(&text)?;
+
+    let lost = to.unsupported_sections(&instance);
+    if !lost.is_empty() {
+        warn!(
+            "O formato {} não representa as seções {}, que serão omitidas.",
+            to,
+            lost.join(", ")
+        );
+    }
+    if let Some(job) = instance
+        .processing_times
+        .iter()
+        .position(|times| times.iter().all(|&p| p == 0))
+    {
+        warn!("A tarefa {} tem tempo nulo em todas as máquinas.", job);
+    }
+
+    let output = to.write(&instance);
+    let converted = to.parse(&output)?;
+    if converted.processing_times != instance.processing_times || to.write(&converted) != output {
+        return Err(FsspError::Instance(format!(
+            "a instância convertida para {} difere da original",
+            to
+        )));
+    }
+    fs::write(&args.output, output)?;
+
+    info!(
+        "Instância {}x{} convertida de {} para {}: {}",
+        instance.n_jobs,
+        instance.n_machines,
+        from,
+        to,
+        args.output.display()
+    );
+    Ok(())
+}
+
+/// Exibe o resumo da instância e, se disponível, o melhor makespan conhecido com a
+/// distância até o limitante de Taillard.
+fn run_inspect(args: &InspectArgs, best_known: &BestKnown) -> Result<()> {
+    let instance = load_instance(args.instance.to_str().unwrap())?;
+    let report = InstanceReport::new(&instance);
+    let name = instance_name(&args.instance);
+
+    println!("--- Instancia {} ---", name);
+    println!("{}", report);
+    let features = InstanceFeatures::new(&instance);
+    println!(
+        "Portfolio: variacao dos tempos {:.3}, correlacao entre maquinas {:.3} (preset embutido: {})",
+        features.time_cv,
+        features.machine_correlation,
+        PortfolioModel::builtin().select(&features).name
+    );
+    if let Some(best) = best_known.get(&name) {
+        let scaled = best.saturating_mul(instance.time_scale());
+        println!(
+            "Melhor conhecido: {} ({:.2}% acima do limitante de Taillard)",
+            best,
+            relative_percentage_deviation(scaled, report.taillard_bound.max(1))
+        );
+    }
+    Ok(())
+}
+
+/// Executa o subcomando `analyze`: caracteriza a paisagem de aptidão da instância.
+fn run_analyze(args: &AnalyzeArgs) -> Result<()> {
+    let instance = load_instance(args.instance.to_str().unwrap())?;
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let params = LandscapeParams {
+        walk_length: args.walk_length,
+        samples: args.samples,
+    };
+    let report = LandscapeReport::new(&instance, params, &mut rng);
+
+    println!(
+        "--- Paisagem da Instancia {} ---",
+        instance_name(&args.instance)
+    );
+    println!("{}", report);
+    Ok(())
+}
+
+/// Colunas do CSV de execuções que não identificam a configuração.
+const RUN_COLUMNS: [&str; 7] = [
+    "instance",
+    "run",
+    "seed",
+    "makespan",
+    "runtime_seconds",
+    "generations_completed",
+    "evaluations",
+];
+
+/// Compara as configurações de um CSV de execuções. Cada par (instância, execução)
+/// presente em todas as configurações forma um bloco dos testes pareados.
+fn run_compare(args: &CompareArgs) -> Result<()> {
+    // Os campos podem estar entre aspas, como os nomes de instância gravados pelo
+    // binário `experiment`.
+    let mut records = csv_record::records(BufReader::new(File::open(&args.runs)?));
+    let (_, header_record) = records.next().ok_or_else(|| FsspError::Format {
+        line: 1,
+        message: "arquivo de execuções vazio".to_string(),
+    })??;
+    let header: Vec<&str> = header_record.iter().map(|c| c.trim()).collect();
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|&c| c == name)
+            .ok_or_else(|| FsspError::Format {
+                line: 1,
+                message: format!("coluna '{}' não encontrada", name),
+            })
+    };
+    let (instance_col, run_col, metric_col) =
+        (column("instance")?, column("run")?, column(&args.metric)?);
+    let config_cols: Vec<usize> = (0..header.len())
+        .filter(|&c| !RUN_COLUMNS.contains(&header[c]) && c != metric_col)
+        .collect();
+
+    // Valores por configuração, indexados por (instância, execução).
+    let mut labels: Vec<String> = Vec::new();
+    let mut values: Vec<HashMap<(String, String), f64>> = Vec::new();
+    for record in records {
+        let (line_number, record) = record?;
+        let fields: Vec<&str> = record.iter().map(|f| f.trim()).collect();
+        if fields.len() != header.len() {
+            return Err(FsspError::Format {
+                line: line_number,
+                message: format!(
+                    "esperadas {} colunas, encontradas {}",
+                    header.len(),
+                    fields.len()
+                ),
+            });
+        }
+        let value: f64 = fields[metric_col].parse().map_err(|_| FsspError::Format {
+            line: line_number,
+            message: format!(
+                "valor inválido '{}' na coluna '{}'",
+                fields[metric_col], args.metric
+            ),
+        })?;
+        let label = config_cols
+            .iter()
+            .map(|&c| format!("{}={}", header[c], fields[c]))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let config = match labels.iter().position(|l| *l == label) {
+            Some(config) => config,
+            None => {
+                labels.push(label);
+                values.push(HashMap::new());
+                labels.len() - 1
+            }
+        };
+        let block = (
+            fields[instance_col].to_string(),
+            fields[run_col].to_string(),
+        );
+        values[config].insert(block, value);
+    }
+
+    if labels.len() < 2 {
+        return Err(FsspError::Config(
+            "são necessárias ao menos duas configurações para a comparação".to_string(),
+        ));
+    }
+
+    // Blocos completos: presentes em todas as configurações.
+    let blocks: Vec<&(String, String)> = values[0]
+        .keys()
+        .filter(|block| values.iter().all(|v| v.contains_key(*block)))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    if blocks.is_empty() {
+        return Err(FsspError::Config(
+            "nenhum par (instância, execução) é comum a todas as configurações".to_string(),
+        ));
+    }
+    let samples: Vec<Vec<f64>> = values
+        .iter()
+        .map(|v| blocks.iter().map(|block| v[*block]).collect())
+        .collect();
+    let block_rows: Vec<Vec<f64>> = (0..blocks.len())
+        .map(|b| samples.iter().map(|s| s[b]).collect())
+        .collect();
+
+    let friedman = stats::friedman(&block_rows);
+    let mut ranking: Vec<usize> = (0..labels.len()).collect();
+    ranking.sort_by(|&a, &b| friedman.mean_ranks[a].total_cmp(&friedman.mean_ranks[b]));
+
+    println!(
+        "--- Comparação de {} configurações em {} blocos (métrica: {}) ---",
+        labels.len(),
+        blocks.len(),
+        args.metric
+    );
+    println!(
+        "{:>5} {:>12} {:>12}  Configuração",
+        "Posto", "Posto Médio", "Média"
+    );
+    for (position, &config) in ranking.iter().enumerate() {
+        let mean = samples[config].iter().sum::<f64>() / blocks.len() as f64;
+        println!(
+            "{:>5} {:>12.3} {:>12.2}  {}",
+            position + 1,
+            friedman.mean_ranks[config],
+            mean,
+            labels[config]
+        );
+    }
+
+    println!(
+        "\nTeste de Friedman: Q = {:.4}, gl = {}, p = {:.4}{}",
+        friedman.statistic,
+        friedman.df,
+        friedman.p_value,
+        if friedman.p_value < args.alpha {
+            " (diferença significativa)"
+        } else {
+            ""
+        }
+    );
+
+    // Wilcoxon pareado entre a melhor configuração e as demais, com ajuste de Holm.
+    let best = ranking[0];
+    let others = &ranking[1..];
+    let tests: Vec<stats::WilcoxonResult> = others
+        .iter()
+        .map(|&config| stats::wilcoxon_signed_rank(&samples[best], &samples[config]))
+        .collect();
+    let adjusted = stats::holm(&tests.iter().map(|t| t.p_value).collect::<Vec<_>>());
+
+    println!("\nWilcoxon pareado (melhor configuração vs. demais, p ajustado por Holm):");
+    println!(
+        "{:>5} {:>10} {:>10} {:>5} {:>10} {:>10}",
+        "Posto", "W+", "W-", "n", "p", "p (Holm)"
+    );
+    for (position, (test, p_holm)) in tests.iter().zip(&adjusted).enumerate() {
+        println!(
+            "{:>5} {:>10.1} {:>10.1} {:>5} {:>10.4} {:>10.4}{}",
+            position + 2,
+            test.w_plus,
+            test.w_minus,
+            test.n,
+            test.p_value,
+            p_holm,
+            if *p_holm < args.alpha { " *" } else { "" }
+        );
+    }
+    Ok(())
+}
+
+/// Carrega as instâncias dos caminhos informados; diretórios são expandidos para os
+/// arquivos que contêm. Arquivos que não estão no formato esperado são ignorados.
+fn load_instances(paths: &[PathBuf]) -> Result<Vec<(PathBuf, FSSPInstance)>> {
+    let mut instance_paths = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            let mut entries: Vec<PathBuf> = fs::read_dir(path)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.is_file())
+                .collect();
+            entries.sort();
+            instance_paths.extend(entries);
+        } else {
+            instance_paths.push(path.clone());
+        }
+    }
+
+    Ok(instance_paths
+        .into_iter()
+        .filter_map(|path| match load_instance(path.to_str().unwrap()) {
+            Ok(instance) => Some((path, instance)),
+            Err(e) => {
+                warn!("Ignorando {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Executa o solver configurado pela linha de comando sobre uma instância, exibindo
+/// uma barra de progresso em `progress` quando informado. Com `--fixed-prefix` (ou
+/// as tarefas já iniciadas de `--reschedule`), o solver otimiza a instância reduzida
+/// às tarefas fora do prefixo, e o resultado é expandido e reavaliado na instância
+/// completa.
+fn solve(
+    cli: &Cli,
+    mut instance: FSSPInstance,
+    name: &str,
+    start_time: Instant,
+    progress: Option<&MultiProgress>,
+    shared: Option<&Arc<SharedBest>>,
+) -> Result<SolveOutcome> {
+    instance.set_objective(cli.objective)?;
+    if let Some(sublots) = cli.sublots {
+        if cli.speeds.is_some() || cli.schedule_type == ScheduleType::NonPermutation {
+            return Err(FsspError::Config(
+                "--sublots não é compatível com --speeds nem com o escalonamento não \
+                 permutacional"
+                    .to_string(),
+            ));
+        }
+        instance.set_sublots(vec![sublots; instance.n_jobs])?;
+    }
+
+    // Soluções iniciais fornecidas, validadas contra a instância.
+    let initial_solutions = match &cli.initial_solution {
+        Some(path) => {
+            let sequences = load_sequences(path.to_str().unwrap())?;
+            for sequence in &sequences {
+                instance.validate_sequence(sequence)?;
+            }
+            sequences
+        }
+        None => Vec::new(),
+    };
+
+    if cli.fixed_prefix.is_none() && cli.reschedule.is_none() {
+        return solve_instance(
+            cli,
+            instance,
+            initial_solutions,
+            name,
+            start_time,
+            progress,
+            shared,
+        );
+    }
+    if instance.uncertainty.is_some() || cli.speeds.is_some() {
+        return Err(FsspError::Config(
+            "--fixed-prefix e --reschedule não são compatíveis com --speeds nem com a \
+             otimização robusta"
+                .to_string(),
+        ));
+    }
+    let mut initial_solutions = initial_solutions;
+    let prefix = match (&cli.fixed_prefix, &cli.reschedule) {
+        (Some(prefix), _) => FixedPrefix::new(&instance, prefix.clone())?,
+        (None, Some(path)) => {
+            let rescheduling = rescheduling(cli, &instance, path)?;
+            info!(
+                "Reescalonamento de {}: {} tarefas congeladas, {} a reotimizar.",
+                name,
+                rescheduling.prefix.prefix().len(),
+                instance.n_jobs - rescheduling.prefix.prefix().len()
+            );
+            instance = rescheduling.instance;
+            initial_solutions.push(rescheduling.current);
+            rescheduling.prefix
+        }
+        (None, None) => unreachable!(),
+    };
+    let initial_solutions = initial_solutions
+        .iter()
+        .map(|sequence| prefix.restrict(sequence))
+        .collect::<Result<_>>()?;
+    let outcome = solve_instance(
+        cli,
+        prefix.reduce(&instance),
+        initial_solutions,
+        name,
+        start_time,
+        progress,
+        shared,
+    )?;
+
+    // Os valores da instância reduzida não incluem os custos das tarefas do prefixo.
+    let sequence = prefix.expand(&outcome.sequence);
+    let (value, makespan, metrics, schedule) = match &outcome.schedule {
+        Some(schedule) if cli.schedule_type == ScheduleType::NonPermutation => {
+            let schedule = prefix.expand_schedule(schedule);
+            (
+                schedule.evaluate(&instance),
+                schedule.makespan(&instance),
+                ScheduleMetrics::new(&instance, &schedule.decode_schedule(&instance)),
+                Some(schedule),
+            )
+        }
+        _ => (
+            instance.evaluate(&sequence),
+            instance.calculate_makespan(&sequence),
+            ScheduleMetrics::new(&instance, &instance.decode_schedule(&sequence)),
+            None,
+        ),
+    };
+    let population = outcome
+        .population
+        .iter()
+        .map(|(sequence, _)| {
+            let sequence = prefix.expand(sequence);
+            let value = instance.evaluate(&sequence);
+            (sequence, value)
+        })
+        .collect();
+    Ok(SolveOutcome {
+        value,
+        makespan,
+        sequence,
+        schedule,
+        population,
+        metrics,
+        ..outcome
+    })
+}
+
+/// Prepara o reescalonamento de `--reschedule` no instante `--now`, com as paradas de
+/// `--machine-down`. O escalonamento atual é a primeira sequência do arquivo.
+fn rescheduling(cli: &Cli, instance: &FSSPInstance, path: &Path) -> Result<Rescheduling> {
+    let schedule = load_sequences(path.to_str().unwrap())?.swap_remove(0);
+    let now = instance.parse_time_value(cli.now.as_deref().unwrap_or("0"))?;
+    let breakdowns = cli
+        .machine_down
+        .iter()
+        .map(|text| Breakdown::parse(text, instance))
+        .collect::<Result<Vec<_>>>()?;
+    Rescheduling::new(instance, &schedule, now, &breakdowns)
+}
+
+/// Executa o solver sobre uma instância já validada. No modo não permutacional,
+/// metade do tempo disponível é reservada ao refinamento das ordens por máquina e,
+/// com `--speeds`, à escolha das velocidades.
+fn solve_instance(
+    cli: &Cli,
+    instance: FSSPInstance,
+    initial_solutions: Vec<Vec<Job>>,
+    name: &str,
+    start_time: Instant,
+    progress: Option<&MultiProgress>,
+    shared: Option<&Arc<SharedBest>>,
+) -> Result<SolveOutcome> {
+    if cli.speeds.is_some()
+        && (cli.schedule_type == ScheduleType::NonPermutation
+            || cli.objective != Objective::Makespan)
+    {
+        return Err(FsspError::Config(
+            "--speeds exige o escalonamento permutacional e o objetivo makespan".to_string(),
+        ));
+    }
+    if let Some(profile) = &cli.speeds {
+        // Verifica as durações antes da fase permutacional.
+        profile.durations(&instance)?;
+    }
+    if instance.uncertainty.is_some()
+        && (cli.schedule_type == ScheduleType::NonPermutation
+            || cli.speeds.is_some()
+            || cli.algorithm == Algorithm::BranchAndBound)
+    {
+        return Err(FsspError::Config(
+            "a otimização robusta exige o escalonamento permutacional, sem --speeds, e um algoritmo heurístico"
+                .to_string(),
+        ));
+    }
+    let max_duration = cli.max_duration.map(Duration::from_secs);
+    let refined = cli.schedule_type == ScheduleType::NonPermutation || cli.speeds.is_some();
+    let (permutation_duration, permutation_evaluations) = if refined {
+        (
+            max_duration.map(|d| d / 2),
+            cli.max_evaluations.map(|n| n / 2),
+        )
+    } else {
+        (max_duration, cli.max_evaluations)
+    };
+
+    let mut solver = solver_config(cli).build_solver_with(&instance, initial_solutions)?;
+
+    // Executa o solver com os limites de tempo e geração, até uma eventual interrupção.
+    let token = interrupt_token().clone();
+    let mut progress_observer = progress
+        .map(|multi| ProgressObserver::new(multi, name, cli.max_generations, permutation_duration));
+    #[cfg(feature = "dashboard")]
+    let mut dashboard_observer = DASHBOARD.get().map(|d| d.observer(name, &instance));
+    let mut report_observer = (cli.report_every > 0).then(|| {
+        ReportObserver::new(progress, name, cli.report_every, instance.time_decimals)
+            .with_lang(cli.lang)
+    });
+    let mut history_observer = cli.history.as_ref().map(|_| HistoryObserver::new());
+    let mut shared_observer = shared.map(|shared| shared.subscribe());
+    let mut observers = MultiObserver::new();
+    if let Some(observer) = progress_observer.as_mut() {
+        observers.push(observer);
+    }
+    if let Some(observer) = report_observer.as_mut() {
+        observers.push(observer);
+    }
+    if let Some(observer) = history_observer.as_mut() {
+        observers.push(observer);
+    }
+    if let Some(observer) = shared_observer.as_mut() {
+        observers.push(observer);
+    }
+    #[cfg(feature = "dashboard")]
+    if let Some(observer) = dashboard_observer.as_mut() {
+        observers.push(observer);
+    }
+    solver.run_with_observer(
+        start_time,
+        &Budget::with_duration(start_time, permutation_duration)
+            .with_max_evaluations(permutation_evaluations),
+        &mut CancellableObserver::new(&mut observers, token.clone()),
+    );
+    if let Some(observer) = &progress_observer {
+        observer.finish();
+    }
+    if let (Some(path), Some(history)) = (&cli.history, &history_observer) {
+        history.write_csv(BufWriter::new(File::create(path)?), instance.time_decimals)?;
+        info!("Histórico salvo em: {}", path.display());
+    }
+    let sequence = solver.best_sequence().to_vec();
+    let population = if cli.save_population.is_some() {
+        solver.population()
+    } else {
+        Vec::new()
+    };
+    if token.is_cancelled() {
+        warn!("Execução interrompida: usando a melhor solução encontrada até o momento.");
+    }
+
+    // A busca das velocidades (e a não permutacional) usa o tempo e as avaliações
+    // restantes.
+    let budget = Budget::with_duration(start_time, max_duration)
+        .with_max_evaluations(cli.max_evaluations)
+        .remaining(solver.evaluations());
+
+    if let (Some(profile), false) = (&cli.speeds, token.is_cancelled()) {
+        let mut search = EnergySearch::new(
+            instance.clone(),
+            profile.clone(),
+            cli.energy_weight,
+            &sequence,
+            cli.max_generations,
+            cli.ils.strength,
+        )?;
+        run_refinement(
+            name,
+            "speeds",
+            cli,
+            progress,
+            max_duration,
+            token,
+            |observer| search.run_with_observer(start_time, &budget, observer),
+        );
+        return Ok(SolveOutcome {
+            value: search.best.makespan,
+            makespan: search.best.makespan,
+            sequence: search.best.schedule.sequence.clone(),
+            schedule: None,
+            evaluations: solver.evaluations() + search.evaluations(),
+            winner: solver.winner(),
+            population,
+            time_decimals: instance.time_decimals,
+            robust: _robust_summary(&instance),
+            simulation: None,
+            metrics: ScheduleMetrics::new(
+                &instance,
+                &search.decode_schedule(&search.best.schedule),
+            ),
+            energy: Some(EnergyOutcome {
+                value: search.best_value,
+                profile: search.profile().clone(),
+                best: search.best,
+                front: search.front,
+            }),
+        });
+    }
+
+    if cli.schedule_type == ScheduleType::Permutation || token.is_cancelled() {
+        let schedule = (cli.schedule_type == ScheduleType::NonPermutation)
+            .then(|| NonPermutationSchedule::from_permutation(&sequence, instance.n_machines));
+        // Na simulação, o valor reportado é estimado com todas as replicações.
+        let simulation = stochastic::full_estimate(&instance, &sequence);
+        return Ok(SolveOutcome {
+            value: simulation.map_or(solver.best_makespan(), |e| e.mean.round() as Time),
+            makespan: instance.calculate_makespan(&sequence),
+            metrics: ScheduleMetrics::new(&instance, &instance.decode_schedule(&sequence)),
+            sequence,
+            schedule,
+            evaluations: solver.evaluations(),
+            winner: solver.winner(),
+            population,
+            time_decimals: instance.time_decimals,
+            robust: _robust_summary(&instance),
+            simulation,
+            energy: None,
+        });
+    }
+
+    // Refina a melhor sequência permitindo ordens diferentes em cada máquina.
+    let mut search = NonPermutationSearch::new(
+        instance.clone(),
+        &sequence,
+        cli.max_generations,
+        cli.ils.strength,
+    );
+    run_refinement(
+        name,
+        "non-permutation",
+        cli,
+        progress,
+        max_duration,
+        token,
+        |observer| search.run_with_observer(start_time, &budget, observer),
+    );
+    Ok(SolveOutcome {
+        value: search.best_value,
+        makespan: search.best_schedule.makespan(&instance),
+        sequence,
+        evaluations: solver.evaluations() + search.evaluations(),
+        winner: solver.winner(),
+        metrics: ScheduleMetrics::new(&instance, &search.best_schedule.decode_schedule(&instance)),
+        schedule: Some(search.best_schedule),
+        population,
+        time_decimals: instance.time_decimals,
+        robust: _robust_summary(&instance),
+        simulation: None,
+        energy: None,
+    })
+}
+
+/// Executa uma busca de refinamento após o algoritmo principal, com uma barra de
+/// progresso própria (rotulada por `phase`) e interrompível por Ctrl-C.
+fn run_refinement(
+    name: &str,
+    phase: &str,
+    cli: &Cli,
+    progress: Option<&MultiProgress>,
+    max_duration: Option<Duration>,
+    token: CancellationToken,
+    run: impl FnOnce(&mut dyn Observer),
+) {
+    match progress {
+        Some(multi) => {
+            let label = format!("{} ({})", name, phase);
+            let mut observer =
+                ProgressObserver::new(multi, &label, cli.max_generations, max_duration);
+            run(&mut CancellableObserver::new(&mut observer, token));
+            observer.finish();
+        }
+        None => run(&mut CancellableObserver::new(&mut NoopObserver, token)),
+    }
+}
+
+/// Linha do resultado com o valor da função objetivo quando ele difere do makespan
+/// da sequência: os objetivos diferentes do makespan e, na otimização robusta, o
+/// valor agregado nos cenários.
+fn objective_line(outcome: &SolveOutcome, objective: Objective, lang: Lang) -> Option<String> {
+    let label = objective_label(objective, lang);
+    let value = outcome.time_value(outcome.value);
+    if let Some(estimate) = &outcome.simulation {
+        let scale = 10f64.powi(outcome.time_decimals as i32);
+        let (mean, std_error) = (estimate.mean / scale, estimate.std_error() / scale);
+        return Some(match lang {
+            Lang::Pt => format!(
+                "Melhor {} Esperado (simulacao, {} replicacoes): {:.2} (erro padrao {:.2})",
+                label, estimate.replications, mean, std_error
+            ),
+            Lang::En => format!(
+                "Best Expected {} (simulation, {} replications): {:.2} (standard error {:.2})",
+                label, estimate.replications, mean, std_error
+            ),
+        });
+    }
+    match (outcome.robust, lang) {
+        (Some((RobustCriterion::WorstCase, scenarios)), Lang::Pt) => Some(format!(
+            "Melhor {} no Pior Cenario ({} cenarios): {}",
+            label, scenarios, value
+        )),
+        (Some((RobustCriterion::WorstCase, scenarios)), Lang::En) => Some(format!(
+            "Best Worst-Case {} ({} scenarios): {}",
+            label, scenarios, value
+        )),
+        (Some((RobustCriterion::Expected, scenarios)), Lang::Pt) => Some(format!(
+            "Melhor {} Esperado ({} cenarios): {}",
+            label, scenarios, value
+        )),
+        (Some((RobustCriterion::Expected, scenarios)), Lang::En) => Some(format!(
+            "Best Expected {} ({} scenarios): {}",
+            label, scenarios, value
+        )),
+        (None, _) if objective != Objective::Makespan => Some(format!(
+            "{} {}: {}",
+            lang.pick("Melhor", "Best"),
+            label,
+            value
+        )),
+        (None, _) => None,
+    }
+}
+
+/// Nome da função objetivo nos rótulos do resultado.
+fn objective_label(objective: Objective, lang: Lang) -> &'static str {
+    match objective {
+        Objective::Makespan => "Makespan",
+        Objective::TotalFlowtime => lang.pick("Tempo de Fluxo Total", "Total Flowtime"),
+        Objective::TotalTardiness => lang.pick("Atraso Total", "Total Tardiness"),
+        Objective::MaxTardiness => lang.pick("Atraso Maximo", "Maximum Tardiness"),
+        Objective::WeightedTardiness => {
+            lang.pick("Atraso Ponderado Total", "Total Weighted Tardiness")
+        }
+        Objective::WeightedFlowtime => {
+            lang.pick("Tempo de Fluxo Ponderado Total", "Total Weighted Flowtime")
+        }
+        Objective::Scalarized(_) => lang.pick("Objetivo Escalarizado", "Scalarized Objective"),
+    }
+}
+
+/// Critério e número de cenários da otimização robusta da instância, se houver.
+fn _robust_summary(instance: &FSSPInstance) -> Option<(RobustCriterion, usize)> {
+    instance
+        .uncertainty
+        .as_ref()
+        .map(|u| (u.criterion, u.scenarios.len()))
+}
+
+/// Linhas do resultado com velocidades (`--speeds`): energia total, soma ponderada
+/// e a velocidade de cada operação, com as tarefas numeradas a partir de `base`.
+fn energy_lines(outcome: &SolveOutcome, base: usize, lang: Lang) -> Vec<String> {
+    let Some(energy) = &outcome.energy else {
+        return Vec::new();
+    };
+    let scale = 10f64.powi(outcome.time_decimals as i32);
+    let mut lines = vec![
+        format!(
+            "{}: {:.2}",
+            lang.pick("Energia Total", "Total Energy"),
+            energy.best.energy / scale
+        ),
+        format!(
+            "{}: {:.4}",
+            lang.pick("Objetivo Ponderado", "Weighted Objective"),
+            energy.value
+        ),
+    ];
+    for (job, speeds) in energy.best.schedule.speeds.iter().enumerate() {
+        lines.push(format!(
+            "{} {}: {}",
+            lang.pick("Velocidades da Tarefa", "Speeds of Job"),
+            job + base,
+            _speed_factors(&energy.profile, speeds)
+        ));
+    }
+    lines
+}
+
+/// Linhas do resultado com os indicadores do escalonamento: tempo ocioso total,
+/// utilização média e de cada máquina, tempos de espera das tarefas (na ordem dos
+/// índices das tarefas) e, se a instância define pesos, o tempo de fluxo e o atraso
+/// ponderados.
+fn metrics_lines(outcome: &SolveOutcome, lang: Lang) -> Vec<String> {
+    let metrics = &outcome.metrics;
+    let join = |values: Vec<String>| values.join(" ");
+    let mut lines = vec![
+        format!(
+            "{}: {}",
+            lang.pick("Tempo Ocioso Total", "Total Idle Time"),
+            outcome.time_value(metrics.total_idle)
+        ),
+        format!(
+            "{}: {:.2}",
+            lang.pick("Utilizacao Media (%)", "Mean Utilization (%)"),
+            100.0 * metrics.mean_utilization()
+        ),
+        format!(
+            "{}: {}",
+            lang.pick("Utilizacao por Maquina (%)", "Utilization per Machine (%)"),
+            join(
+                metrics
+                    .machines
+                    .iter()
+                    .map(|m| format!("{:.2}", 100.0 * m.utilization))
+                    .collect()
+            )
+        ),
+        format!(
+            "{}: {}",
+            lang.pick("Tempo de Espera Total", "Total Waiting Time"),
+            outcome.time_value(metrics.total_waiting)
+        ),
+        format!(
+            "{}: {}",
+            lang.pick("Tempos de Espera por Tarefa", "Waiting Time per Job"),
+            join(
+                metrics
+                    .waiting_times
+                    .iter()
+                    .map(|&w| outcome.time_value(w).to_string())
+                    .collect()
+            )
+        ),
+    ];
+    if let Some(value) = metrics.weighted_flowtime {
+        lines.push(format!(
+            "{}: {}",
+            lang.pick("Tempo de Fluxo Ponderado Total", "Total Weighted Flowtime"),
+            outcome.time_value(value)
+        ));
+    }
+    if let Some(value) = metrics.weighted_tardiness {
+        lines.push(format!(
+            "{}: {}",
+            lang.pick("Atraso Ponderado Total", "Total Weighted Tardiness"),
+            outcome.time_value(value)
+        ));
+    }
+    lines
+}
+
+/// Fatores de velocidade dos níveis informados, separados por espaço.
+fn _speed_factors(profile: &SpeedProfile, levels: &[usize]) -> String {
+    levels
+        .iter()
+        .map(|&level| profile.levels[level].factor.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Grava a fronteira de Pareto em CSV, um ponto por linha em ordem crescente de
+/// makespan: makespan, energia, sequência (tarefas a partir de 0) e os fatores de
+/// velocidade de cada tarefa, separados por `|`.
+fn write_pareto_front(
+    path: &Path,
+    energy: &EnergyOutcome,
+    time_decimals: u32,
 ) -> std::io::Result<()> {
-    // Extrai o nome do arquivo da instância, ex: "fssp_instance_05"
-    let instance_stem = Path::new(&cli.instance_path)
+    let mut file = File::create(path)?;
+    let scale = 10f64.powi(time_decimals as i32);
+    writeln!(file, "makespan,energy,sequence,speeds")?;
+    for point in &energy.front.points {
+        let sequence: Vec<String> = point
+            .schedule
+            .sequence
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        let speeds: Vec<String> = point
+            .schedule
+            .speeds
+            .iter()
+            .map(|levels| _speed_factors(&energy.profile, levels))
+            .collect();
+        writeln!(
+            file,
+            "{},{:.2},{},{}",
+            TimeValue::new(point.makespan, time_decimals),
+            point.energy / scale,
+            sequence.join(" "),
+            speeds.join("|")
+        )?;
+    }
+    info!(
+        "Fronteira de Pareto ({} pontos) salva em: {}",
+        energy.front.points.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Grava a população final, um indivíduo por linha no formato `valor: sequência`
+/// (tarefas a partir de 0), do melhor para o pior. O arquivo é aceito por
+/// `--initial-solution`.
+fn write_population_file(path: &Path, name: &str, outcome: &SolveOutcome) -> std::io::Result<()> {
+    let population = &outcome.population;
+    let mut file = File::create(path)?;
+    writeln!(
+        file,
+        "# Populacao final de {} ({} individuos): valor da funcao objetivo: sequencia",
+        name,
+        population.len()
+    )?;
+    for (sequence, value) in population {
+        let sequence_str: Vec<String> = sequence.iter().map(|&x| x.to_string()).collect();
+        writeln!(
+            file,
+            "{}: {}",
+            outcome.time_value(*value),
+            sequence_str.join(" ")
+        )?;
+    }
+    info!("População salva em: {}", path.display());
+    Ok(())
+}
+
+/// Extrai o nome da instância a partir do caminho, ex: "fssp_instance_05".
+fn instance_name(instance_path: &Path) -> String {
+    instance_path
         .file_stem()
         .and_then(|s| s.to_str())
-        .unwrap_or("resultado_desconhecido");
+        .unwrap_or("resultado_desconhecido")
+        .to_string()
+}
 
-    // Monta o nome do arquivo de saída
-    let output_filename = format!("resultado_{}.txt", instance_stem);
-    let output_path = cli.output_dir.join(output_filename);
+/// Grava o resumo do lote em CSV ou, se a extensão for `.json`, em JSON.
+fn write_batch_summary(path: &Path, records: &[BatchRecord]) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
 
-    println!("\nSalvando resultados em: {}", output_path.display());
+    if path.extension().and_then(|e| e.to_str()) == Some("json") {
+        serde_json::to_writer_pretty(&mut file, records)?;
+        writeln!(file)?;
+        return Ok(());
+    }
 
-    // Cria e abre o arquivo para escrita
-    let mut file = File::create(&output_path)?;
+    writeln!(
+        file,
+        "instance,n_jobs,n_machines,best_makespan,best_known,gap_percent,runtime_seconds,weighted_tardiness,evaluations,winner,total_idle_time,mean_utilization,total_waiting_time"
+    )?;
+    for r in records {
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{:.4},{},{},{},{},{:.4},{}",
+            csv_record::field(&r.instance),
+            r.n_jobs,
+            r.n_machines,
+            r.best_makespan,
+            r.best_known.map(|v| v.to_string()).unwrap_or_default(),
+            r.gap_percent
+                .map(|g| format!("{:.4}", g))
+                .unwrap_or_default(),
+            r.runtime_seconds,
+            r.weighted_tardiness
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            r.evaluations,
+            r.winner.unwrap_or_default(),
+            r.total_idle_time,
+            r.mean_utilization,
+            r.total_waiting_time
+        )?;
+    }
+    Ok(())
+}
+
+/// Cabeçalho do CSV de `--append-summary`.
+const APPEND_SUMMARY_HEADER: &str = "timestamp,instance,algorithm,objective,run,best_makespan,value,best_known,gap_percent,runtime_seconds,evaluations,params";
+
+/// Acrescenta a linha de uma execução ao CSV de `--append-summary`, gravando o
+/// cabeçalho se o arquivo estiver vazio. O arquivo fica bloqueado (bloqueio
+/// exclusivo do sistema operacional) durante a gravação, de modo que invocações
+/// simultâneas, por exemplo de um escalonador de tarefas, não intercalam linhas.
+/// A coluna `params` traz os argumentos da linha de comando, que reproduzem a
+/// execução.
+fn append_summary(
+    path: &Path,
+    cli: &Cli,
+    name: &str,
+    run: Option<u32>,
+    outcome: &SolveOutcome,
+    runtime_seconds: f64,
+    best_known: Option<Time>,
+) -> std::io::Result<()> {
+    let params: Vec<String> = std::env::args().skip(1).collect();
+    let row = format!(
+        "{},{},{},{},{},{},{},{},{},{:.4},{},{}\n",
+        _utc_timestamp(),
+        csv_record::field(name),
+        cli.algorithm,
+        cli.objective,
+        run.map(|run| run.to_string()).unwrap_or_default(),
+        outcome.time_value(outcome.makespan),
+        outcome.time_value(outcome.value),
+        best_known
+            .map(|bks| outcome.time_value(bks).to_string())
+            .unwrap_or_default(),
+        best_known
+            .map(|bks| format!(
+                "{:.4}",
+                relative_percentage_deviation(outcome.makespan, bks)
+            ))
+            .unwrap_or_default(),
+        runtime_seconds,
+        outcome.evaluations,
+        csv_record::field(&params.join(" "))
+    );
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.lock()?;
+    // O tamanho é consultado após o bloqueio: apenas a primeira gravação escreve
+    // o cabeçalho.
+    let mut text = String::new();
+    if file.metadata()?.len() == 0 {
+        text.push_str(APPEND_SUMMARY_HEADER);
+        text.push('\n');
+    }
+    text.push_str(&row);
+    file.write_all(text.as_bytes())?;
+    file.unlock()
+}
+
+/// Instante atual em UTC no formato ISO 8601 (ex: `2024-05-01T12:30:00Z`).
+fn _utc_timestamp() -> String {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let (days, time) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+    // Conversão de dias desde 1970-01-01 para data civil (algoritmo de H. Hinnant).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Caminho do arquivo de resultado da instância `name` (e da execução `run` do
+/// multi-start), montado a partir de `--output-dir` e `--output-template`.
+fn result_path(cli: &Cli, name: &str, run: Option<u32>) -> PathBuf {
+    cli.output_dir
+        .join(cli.output_template.render(name, cli.algorithm, run))
+}
+
+fn write_results_to_file(
+    output_path: &Path,
+    outcome: &SolveOutcome,
+    objective: Objective,
+    lang: Lang,
+    exec_time: f64,
+    best_known: Option<Time>,
+) -> std::io::Result<()> {
+    info!("Salvando resultados em: {}", output_path.display());
+
+    // Cria os diretórios necessários e abre o arquivo para escrita
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(output_path)?;
 
     // Formata a sequência para o arquivo (base 0, como nos dados)
-    let sequence_str_file: Vec<String> = solver
-        .best_sequence
-        .iter()
-        .map(|&x| x.to_string())
-        .collect();
+    let sequence_str_file: Vec<String> = outcome.sequence.iter().map(|&x| x.to_string()).collect();
 
     // Escreve os resultados no arquivo
-    writeln!(file, "Melhor Makespan: {}", solver.best_makespan)?;
-    writeln!(file, "Melhor Sequencia: {}", sequence_str_file.join(" "))?;
-    writeln!(file, "Tempo de Execucao (segundos): {:.4}", exec_time)?;
+    if let Some(line) = objective_line(outcome, objective, lang) {
+        writeln!(file, "{}", line)?;
+    }
+    writeln!(
+        file,
+        "{}: {}",
+        lang.pick("Melhor Makespan", "Best Makespan"),
+        outcome.time_value(outcome.makespan)
+    )?;
+    writeln!(
+        file,
+        "{}: {}",
+        lang.pick("Melhor Sequencia", "Best Sequence"),
+        sequence_str_file.join(" ")
+    )?;
+    if let Some(schedule) = &outcome.schedule {
+        for (machine, order) in schedule.orders.iter().enumerate() {
+            let order_str: Vec<String> = order.iter().map(|&x| x.to_string()).collect();
+            writeln!(
+                file,
+                "{} {}: {}",
+                lang.pick("Ordem na Maquina", "Order on Machine"),
+                machine + 1,
+                order_str.join(" ")
+            )?;
+        }
+    }
+    for line in energy_lines(outcome, 0, lang) {
+        writeln!(file, "{}", line)?;
+    }
+    for line in metrics_lines(outcome, lang) {
+        writeln!(file, "{}", line)?;
+    }
+    writeln!(
+        file,
+        "{}: {:.4}",
+        lang.pick("Tempo de Execucao (segundos)", "Runtime (seconds)"),
+        exec_time
+    )?;
+    writeln!(
+        file,
+        "{}: {}",
+        lang.pick("Avaliacoes", "Evaluations"),
+        outcome.evaluations
+    )?;
+    if let Some(winner) = outcome.winner {
+        writeln!(
+            file,
+            "{}: {}",
+            lang.pick("Algoritmo Vencedor", "Winning Algorithm"),
+            winner
+        )?;
+    }
+    if let Some(bks) = best_known {
+        writeln!(
+            file,
+            "{}: {}",
+            lang.pick("Melhor Conhecido", "Best Known"),
+            outcome.time_value(bks)
+        )?;
+        writeln!(
+            file,
+            "{}: {:.4}",
+            lang.pick("Desvio Relativo (%)", "Relative Deviation (%)"),
+            relative_percentage_deviation(outcome.makespan, bks)
+        )?;
+    }
 
-    println!("Resultados salvos com sucesso.");
+    info!("Resultados salvos com sucesso.");
     Ok(())
 }
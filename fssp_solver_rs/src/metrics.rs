@@ -0,0 +1,109 @@
+//! Indicadores de um escalonamento além do makespan: tempo ocioso e utilização de
+//! cada máquina, tempo de espera de cada tarefa e, com pesos na instância, tempo de
+//! fluxo e atraso ponderados, calculados a partir dos instantes de início e término
+//! das operações (`Schedule`).
+
+use crate::fssp_core::{FSSPInstance, Schedule, Time};
+use serde::Serialize;
+
+/// Uso de uma máquina no horizonte [0, makespan].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MachineMetrics {
+    pub busy: Time,       // Tempo em processamento.
+    pub idle: Time,       // Tempo ocioso (makespan - busy), incluindo manutenções.
+    pub utilization: f64, // Fração do horizonte em processamento (busy / makespan).
+}
+
+/// Indicadores de um escalonamento, na escala dos tempos armazenados (veja `Time`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduleMetrics {
+    pub machines: Vec<MachineMetrics>, // Uso de cada máquina.
+    pub waiting_times: Vec<Time>,      // Tempo de espera de cada tarefa [tarefa].
+    pub total_idle: Time,              // Soma dos tempos ociosos das máquinas.
+    pub total_waiting: Time,           // Soma dos tempos de espera das tarefas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weighted_flowtime: Option<Time>, // Soma de w_j * C_j, se a instância define pesos.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weighted_tardiness: Option<Time>, // Soma de w_j * T_j, com pesos e datas de entrega.
+}
+
+impl ScheduleMetrics {
+    /// Calcula os indicadores do escalonamento. O tempo de espera de uma tarefa é o
+    /// tempo em que ela está disponível sem ser processada: na primeira máquina,
+    /// desde a sua liberação e, nas demais, desde a chegada da máquina anterior. Os
+    /// valores ponderados usam o término de cada tarefa na última máquina.
+    pub fn new(instance: &FSSPInstance, schedule: &Schedule) -> Self {
+        let makespan = schedule.makespan;
+        let machines: Vec<MachineMetrics> = (0..instance.n_machines)
+            .map(|machine| {
+                // Com sublotes, a operação pode ser interrompida entre sublotes, e
+                // apenas o tempo de processamento ocupa a máquina.
+                let busy: Time = (0..instance.n_jobs)
+                    .map(|job| match instance.sublots {
+                        Some(_) => instance.processing_times[job][machine],
+                        None => {
+                            schedule.completion_times[job][machine]
+                                - schedule.start_times[job][machine]
+                        }
+                    })
+                    .sum();
+                MachineMetrics {
+                    busy,
+                    idle: makespan.saturating_sub(busy),
+                    utilization: if makespan > 0 {
+                        busy as f64 / makespan as f64
+                    } else {
+                        0.0
+                    },
+                }
+            })
+            .collect();
+        let waiting_times: Vec<Time> = (0..instance.n_jobs)
+            .map(|job| {
+                let mut ready = instance.release_date(job);
+                let mut waiting = 0;
+                for machine in 0..instance.n_machines {
+                    waiting += schedule.start_times[job][machine].saturating_sub(ready);
+                    ready = schedule.completion_times[job][machine];
+                    if machine + 1 < instance.n_machines {
+                        ready += instance.transfer_time(job, machine);
+                    }
+                }
+                waiting
+            })
+            .collect();
+        // Soma de w_j * custo(C_j) sobre as tarefas, onde C_j é o término na última
+        // máquina.
+        let weighted = |cost: &dyn Fn(usize, Time) -> Time| -> Time {
+            (0..instance.n_jobs)
+                .map(|job| {
+                    let completion = schedule.completion_times[job][instance.n_machines - 1];
+                    instance.weight(job).saturating_mul(cost(job, completion))
+                })
+                .fold(0, Time::saturating_add)
+        };
+        let weighted_flowtime = instance
+            .weights
+            .as_ref()
+            .map(|_| weighted(&|_, completion| completion));
+        let weighted_tardiness = instance.weights.as_ref().and_then(|_| {
+            let due_dates = instance.due_dates.as_ref()?;
+            Some(weighted(&|job, completion| {
+                completion.saturating_sub(due_dates[job])
+            }))
+        });
+        ScheduleMetrics {
+            total_idle: machines.iter().map(|m| m.idle).sum(),
+            total_waiting: waiting_times.iter().sum(),
+            machines,
+            waiting_times,
+            weighted_flowtime,
+            weighted_tardiness,
+        }
+    }
+
+    /// Utilização média das máquinas.
+    pub fn mean_utilization(&self) -> f64 {
+        self.machines.iter().map(|m| m.utilization).sum::<f64>() / self.machines.len().max(1) as f64
+    }
+}
@@ -0,0 +1,393 @@
+use crate::fssp_core::FSSPInstance;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Número máximo de termos por linha no formato LP (o formato limita o tamanho das linhas).
+const LP_TERMS_PER_LINE: usize = 8;
+
+/// Formulação MILP do FSSP de permutação com objetivo makespan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Formulation {
+    Positional,  // Baseada em posições (Wagner): x[j,k] = 1 se a tarefa j ocupa a posição k.
+    Disjunctive, // Disjuntiva (Manne): y[j,l] = 1 se a tarefa j precede l, com big-M.
+}
+
+impl FromStr for Formulation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "positional" => Ok(Formulation::Positional),
+            "disjunctive" => Ok(Formulation::Disjunctive),
+            _ => Err(format!(
+                "Formulação desconhecida '{}' (use positional ou disjunctive)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Formulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Formulation::Positional => "positional",
+            Formulation::Disjunctive => "disjunctive",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Formato do arquivo do modelo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelFormat {
+    Lp,  // Formato LP (CPLEX), legível.
+    Mps, // Formato MPS livre, aceito pela maioria dos solvers.
+}
+
+impl FromStr for ModelFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "lp" => Ok(ModelFormat::Lp),
+            "mps" => Ok(ModelFormat::Mps),
+            _ => Err(format!(
+                "Formato de modelo desconhecido '{}' (use lp ou mps)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ModelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ModelFormat::Lp => "lp",
+            ModelFormat::Mps => "mps",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Sentido de uma restrição.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sense {
+    Equal,
+    GreaterEqual,
+}
+
+/// Restrição linear `soma(coef * var) <sentido> rhs`.
+struct Constraint {
+    name: String,
+    terms: Vec<(f64, String)>,
+    sense: Sense,
+    rhs: f64,
+}
+
+/// Modelo MILP de minimização: variáveis contínuas não negativas e binárias.
+#[derive(Default)]
+struct Model {
+    objective: Vec<(f64, String)>, // Termos da função objetivo.
+    continuous: Vec<String>,       // Variáveis contínuas (>= 0), na ordem de criação.
+    binary: Vec<String>,           // Variáveis binárias, na ordem de criação.
+    constraints: Vec<Constraint>,  // Restrições, na ordem de criação.
+}
+
+impl Model {
+    fn add(&mut self, name: String, terms: Vec<(f64, String)>, sense: Sense, rhs: f64) {
+        self.constraints.push(Constraint {
+            name,
+            terms,
+            sense,
+            rhs,
+        });
+    }
+}
+
+/// Grava o modelo MILP da instância (objetivo makespan, com instantes de liberação,
+/// se houver) na formulação e no formato indicados.
+pub fn write_model<W: Write>(
+    instance: &FSSPInstance,
+    formulation: Formulation,
+    format: ModelFormat,
+    writer: &mut W,
+) -> io::Result<()> {
+    let model = match formulation {
+        Formulation::Positional => _positional(instance),
+        Formulation::Disjunctive => _disjunctive(instance),
+    };
+    // Comentário em ASCII, pois alguns leitores de LP/MPS não aceitam acentos.
+    let comment = format!(
+        "FSSP de permutacao: {} tarefas, {} maquinas, formulacao {}",
+        instance.n_jobs, instance.n_machines, formulation
+    );
+    match format {
+        ModelFormat::Lp => _write_lp(&model, &comment, writer),
+        ModelFormat::Mps => _write_mps(&model, &comment, writer),
+    }
+}
+
+/// Formulação baseada em posições. `C_k_i` é o término da tarefa na posição `k` na
+/// máquina `i`; `x_j_k` indica que a tarefa `j` ocupa a posição `k`.
+fn _positional(instance: &FSSPInstance) -> Model {
+    let (n, m) = (instance.n_jobs, instance.n_machines);
+    let p = &instance.processing_times;
+    let x = |j: usize, k: usize| format!("x_{}_{}", j, k);
+    let c = |k: usize, i: usize| format!("C_{}_{}", k, i);
+
+    let mut model = Model {
+        objective: vec![(1.0, "Cmax".to_string())],
+        ..Model::default()
+    };
+    model.continuous.push("Cmax".to_string());
+    for k in 0..n {
+        for i in 0..m {
+            model.continuous.push(c(k, i));
+        }
+    }
+    for j in 0..n {
+        for k in 0..n {
+            model.binary.push(x(j, k));
+        }
+    }
+
+    // Cada tarefa ocupa exatamente uma posição e cada posição recebe uma tarefa.
+    for j in 0..n {
+        let terms = (0..n).map(|k| (1.0, x(j, k))).collect();
+        model.add(format!("job_{}", j), terms, Sense::Equal, 1.0);
+    }
+    for k in 0..n {
+        let terms = (0..n).map(|j| (1.0, x(j, k))).collect();
+        model.add(format!("pos_{}", k), terms, Sense::Equal, 1.0);
+    }
+
+    for k in 0..n {
+        for i in 0..m {
+            // Processamento da tarefa na posição k, máquina i.
+            let processing = (0..n).map(|j| (-(p[j][i] as f64), x(j, k)));
+            // Precedência entre máquinas (na primeira máquina, a liberação da tarefa).
+            let mut terms = vec![(1.0, c(k, i))];
+            if i == 0 {
+                terms.extend(
+                    (0..n).map(|j| (-((p[j][0] + instance.release_date(j)) as f64), x(j, k))),
+                );
+            } else {
+                terms.push((-1.0, c(k, i - 1)));
+                terms.extend(processing.clone());
+            }
+            model.add(format!("mach_{}_{}", k, i), terms, Sense::GreaterEqual, 0.0);
+            // Sequenciamento na máquina: a posição k começa após a posição k - 1.
+            if k > 0 {
+                let mut terms = vec![(1.0, c(k, i)), (-1.0, c(k - 1, i))];
+                terms.extend(processing);
+                model.add(format!("seq_{}_{}", k, i), terms, Sense::GreaterEqual, 0.0);
+            }
+        }
+    }
+
+    if n > 0 && m > 0 {
+        model.add(
+            "makespan".to_string(),
+            vec![(1.0, "Cmax".to_string()), (-1.0, c(n - 1, m - 1))],
+            Sense::GreaterEqual,
+            0.0,
+        );
+    }
+    model
+}
+
+/// Formulação disjuntiva. `C_j_i` é o término da tarefa `j` na máquina `i`; `y_j_l`
+/// (j < l) indica que `j` precede `l`, na mesma ordem em todas as máquinas.
+fn _disjunctive(instance: &FSSPInstance) -> Model {
+    let (n, m) = (instance.n_jobs, instance.n_machines);
+    let p = &instance.processing_times;
+    let y = |j: usize, l: usize| format!("y_{}_{}", j, l);
+    let c = |j: usize, i: usize| format!("C_{}_{}", j, i);
+
+    // Constante big-M: limitante superior do makespan de qualquer sequência.
+    let big_m = p.iter().flatten().map(|&t| t as f64).sum::<f64>()
+        + (0..n).map(|j| instance.release_date(j)).max().unwrap_or(0) as f64;
+
+    let mut model = Model {
+        objective: vec![(1.0, "Cmax".to_string())],
+        ..Model::default()
+    };
+    model.continuous.push("Cmax".to_string());
+    for j in 0..n {
+        for i in 0..m {
+            model.continuous.push(c(j, i));
+        }
+    }
+    for j in 0..n {
+        for l in j + 1..n {
+            model.binary.push(y(j, l));
+        }
+    }
+
+    for (j, times) in p.iter().enumerate() {
+        for (i, &time) in times.iter().enumerate() {
+            // Precedência entre máquinas (na primeira máquina, a liberação da tarefa).
+            if i == 0 {
+                model.add(
+                    format!("mach_{}_0", j),
+                    vec![(1.0, c(j, 0))],
+                    Sense::GreaterEqual,
+                    (time + instance.release_date(j)) as f64,
+                );
+            } else {
+                model.add(
+                    format!("mach_{}_{}", j, i),
+                    vec![(1.0, c(j, i)), (-1.0, c(j, i - 1))],
+                    Sense::GreaterEqual,
+                    time as f64,
+                );
+            }
+        }
+        model.add(
+            format!("makespan_{}", j),
+            vec![(1.0, "Cmax".to_string()), (-1.0, c(j, m - 1))],
+            Sense::GreaterEqual,
+            0.0,
+        );
+    }
+
+    // Disjunções: se y = 1, l começa após j terminar; caso contrário, o inverso.
+    for j in 0..n {
+        for l in j + 1..n {
+            for (i, (&time_j, &time_l)) in p[j].iter().zip(&p[l]).enumerate() {
+                model.add(
+                    format!("before_{}_{}_{}", j, l, i),
+                    vec![(1.0, c(l, i)), (-1.0, c(j, i)), (-big_m, y(j, l))],
+                    Sense::GreaterEqual,
+                    time_l as f64 - big_m,
+                );
+                model.add(
+                    format!("after_{}_{}_{}", j, l, i),
+                    vec![(1.0, c(j, i)), (-1.0, c(l, i)), (big_m, y(j, l))],
+                    Sense::GreaterEqual,
+                    time_j as f64,
+                );
+            }
+        }
+    }
+    model
+}
+
+/// Formata um coeficiente sem casas decimais desnecessárias.
+fn _number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Grava os termos de uma expressão linear no formato LP, quebrando as linhas longas.
+fn _write_lp_terms<W: Write>(terms: &[(f64, String)], writer: &mut W) -> io::Result<()> {
+    for (idx, (coef, var)) in terms.iter().enumerate() {
+        if idx > 0 && idx % LP_TERMS_PER_LINE == 0 {
+            write!(writer, "\n   ")?;
+        }
+        let sign = if *coef < 0.0 { "-" } else { "+" };
+        write!(writer, " {} {} {}", sign, _number(coef.abs()), var)?;
+    }
+    Ok(())
+}
+
+/// Grava o modelo no formato LP.
+fn _write_lp<W: Write>(model: &Model, comment: &str, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "\\ {}", comment)?;
+    writeln!(writer, "Minimize")?;
+    write!(writer, " obj:")?;
+    _write_lp_terms(&model.objective, writer)?;
+    writeln!(writer)?;
+
+    writeln!(writer, "Subject To")?;
+    for constraint in &model.constraints {
+        write!(writer, " {}:", constraint.name)?;
+        _write_lp_terms(&constraint.terms, writer)?;
+        let sense = match constraint.sense {
+            Sense::Equal => "=",
+            Sense::GreaterEqual => ">=",
+        };
+        writeln!(writer, " {} {}", sense, _number(constraint.rhs))?;
+    }
+
+    if !model.binary.is_empty() {
+        writeln!(writer, "Binary")?;
+        for chunk in model.binary.chunks(LP_TERMS_PER_LINE) {
+            writeln!(writer, " {}", chunk.join(" "))?;
+        }
+    }
+    writeln!(writer, "End")
+}
+
+/// Grava o modelo no formato MPS livre (campos separados por espaços).
+fn _write_mps<W: Write>(model: &Model, comment: &str, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "* {}", comment)?;
+    writeln!(writer, "NAME fssp")?;
+
+    writeln!(writer, "ROWS")?;
+    writeln!(writer, " N obj")?;
+    for constraint in &model.constraints {
+        let sense = match constraint.sense {
+            Sense::Equal => "E",
+            Sense::GreaterEqual => "G",
+        };
+        writeln!(writer, " {} {}", sense, constraint.name)?;
+    }
+
+    // O formato MPS é orientado a colunas: agrupa os coeficientes por variável.
+    let mut columns: BTreeMap<&str, Vec<(&str, f64)>> = BTreeMap::new();
+    for (coef, var) in &model.objective {
+        columns.entry(var).or_default().push(("obj", *coef));
+    }
+    for constraint in &model.constraints {
+        for (coef, var) in &constraint.terms {
+            columns
+                .entry(var)
+                .or_default()
+                .push((&constraint.name, *coef));
+        }
+    }
+
+    writeln!(writer, "COLUMNS")?;
+    let write_column = |var: &str, writer: &mut W| -> io::Result<()> {
+        for (row, coef) in columns.get(var).map(Vec::as_slice).unwrap_or_default() {
+            writeln!(writer, " {} {} {}", var, row, _number(*coef))?;
+        }
+        Ok(())
+    };
+    for var in &model.continuous {
+        write_column(var, writer)?;
+    }
+    if !model.binary.is_empty() {
+        writeln!(writer, " MARKER 'MARKER' 'INTORG'")?;
+        for var in &model.binary {
+            write_column(var, writer)?;
+        }
+        writeln!(writer, " MARKER 'MARKER' 'INTEND'")?;
+    }
+
+    writeln!(writer, "RHS")?;
+    for constraint in &model.constraints {
+        if constraint.rhs != 0.0 {
+            writeln!(
+                writer,
+                " RHS {} {}",
+                constraint.name,
+                _number(constraint.rhs)
+            )?;
+        }
+    }
+
+    if !model.binary.is_empty() {
+        writeln!(writer, "BOUNDS")?;
+        for var in &model.binary {
+            writeln!(writer, " BV BND {}", var)?;
+        }
+    }
+    writeln!(writer, "ENDATA")
+}
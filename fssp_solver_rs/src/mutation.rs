@@ -0,0 +1,163 @@
+use crate::fssp_core::Job;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Operadores de mutação disponíveis para permutações de tarefas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutation {
+    Swap,      // Troca duas tarefas de posição.
+    Insert,    // Remove uma tarefa e a reinsere em outra posição (shift).
+    Inversion, // Inverte a ordem de uma subsequência.
+    Scramble,  // Embaralha uma subsequência.
+}
+
+impl FromStr for Mutation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "swap" => Ok(Mutation::Swap),
+            "insert" | "shift" => Ok(Mutation::Insert),
+            "inversion" => Ok(Mutation::Inversion),
+            "scramble" => Ok(Mutation::Scramble),
+            _ => Err(format!(
+                "Mutação desconhecida '{}' (use swap, insert, inversion ou scramble)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Mutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Mutation::Swap => "swap",
+            Mutation::Insert => "insert",
+            Mutation::Inversion => "inversion",
+            Mutation::Scramble => "scramble",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Mutation {
+    /// Aplica o operador à sequência, sorteando as duas posições envolvidas.
+    pub fn apply<R: Rng>(&self, sequence: &mut [Job], rng: &mut R) {
+        let n_jobs = sequence.len();
+        if n_jobs < 2 {
+            return;
+        }
+        let i = rng.gen_range(0..n_jobs);
+        let j = rng.gen_range(0..n_jobs);
+        let (start, end) = (i.min(j), i.max(j));
+        match self {
+            Mutation::Swap => sequence.swap(i, j),
+            // Mover a tarefa de `i` para `j` equivale a rotacionar o trecho entre elas.
+            Mutation::Insert if i < j => sequence[i..=j].rotate_left(1),
+            Mutation::Insert => sequence[j..=i].rotate_right(1),
+            Mutation::Inversion => sequence[start..=end].reverse(),
+            Mutation::Scramble => sequence[start..=end].shuffle(rng),
+        }
+    }
+}
+
+/// Combinação ponderada de operadores de mutação: a cada mutação, um operador é
+/// sorteado com probabilidade proporcional ao seu peso. Representada por texto como
+/// `swap:0.5,insert:0.3,inversion:0.2` (peso 1 quando omitido).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutationMix {
+    operators: Vec<(Mutation, f64)>, // Operadores e seus pesos (positivos).
+}
+
+impl Default for MutationMix {
+    /// Apenas a mutação por troca.
+    fn default() -> Self {
+        MutationMix::single(Mutation::Swap)
+    }
+}
+
+impl MutationMix {
+    /// Combinação com um único operador.
+    pub fn single(mutation: Mutation) -> Self {
+        MutationMix {
+            operators: vec![(mutation, 1.0)],
+        }
+    }
+
+    /// Operadores e pesos da combinação.
+    pub fn operators(&self) -> &[(Mutation, f64)] {
+        &self.operators
+    }
+
+    /// Sorteia um operador conforme os pesos e o aplica à sequência. Com um único
+    /// operador, nenhum sorteio adicional é feito.
+    pub fn apply<R: Rng>(&self, sequence: &mut [Job], rng: &mut R) -> Mutation {
+        let mutation = match self.operators.as_slice() {
+            [(mutation, _)] => *mutation,
+            operators => {
+                let total: f64 = operators.iter().map(|(_, weight)| weight).sum();
+                let mut target = rng.gen::<f64>() * total;
+                operators
+                    .iter()
+                    .find(|(_, weight)| {
+                        target -= weight;
+                        target < 0.0
+                    })
+                    .unwrap_or(&operators[operators.len() - 1])
+                    .0
+            }
+        };
+        mutation.apply(sequence, rng);
+        mutation
+    }
+}
+
+impl FromStr for MutationMix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut operators: Vec<(Mutation, f64)> = Vec::new();
+        for item in s.split(',').map(str::trim) {
+            let (name, weight) = match item.split_once(':') {
+                Some((name, weight)) => (name, Some(weight)),
+                None => (item, None),
+            };
+            let mutation: Mutation = name.parse()?;
+            let weight = match weight {
+                None => 1.0,
+                Some(weight) => match weight.parse::<f64>() {
+                    Ok(w) if w.is_finite() && w > 0.0 => w,
+                    _ => {
+                        return Err(format!(
+                            "Peso de mutação inválido '{}' (use um número positivo, ex: swap:0.5)",
+                            weight
+                        ))
+                    }
+                },
+            };
+            if operators.iter().any(|(m, _)| *m == mutation) {
+                return Err(format!("Mutação '{}' repetida em '{}'", mutation, s));
+            }
+            operators.push((mutation, weight));
+        }
+        Ok(MutationMix { operators })
+    }
+}
+
+impl fmt::Display for MutationMix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (mutation, weight)) in self.operators.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            if *weight == 1.0 {
+                write!(f, "{}", mutation)?;
+            } else {
+                write!(f, "{}:{}", mutation, weight)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+use crate::budget::Budget;
+use crate::fssp_core::{max, FSSPInstance, Job, Schedule, Time};
+use crate::observer::{Control, GenerationStats, Observer};
+use crate::time::Instant;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+use tracing::{debug, debug_span};
+
+/// Tipo de escalonamento produzido pelo solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScheduleType {
+    #[default]
+    Permutation, // A mesma ordem de tarefas em todas as máquinas.
+    NonPermutation, // Uma ordem de tarefas por máquina.
+}
+
+impl FromStr for ScheduleType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "permutation" => Ok(ScheduleType::Permutation),
+            "non-permutation" => Ok(ScheduleType::NonPermutation),
+            _ => Err(format!(
+                "Tipo de escalonamento desconhecido '{}' (use permutation ou non-permutation)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ScheduleType::Permutation => "permutation",
+            ScheduleType::NonPermutation => "non-permutation",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Escalonamento não permutacional: `orders[i]` é a ordem das tarefas na máquina `i`.
+/// Como todas as tarefas visitam as máquinas na mesma rota, qualquer combinação de
+/// ordens é factível.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonPermutationSchedule {
+    pub orders: Vec<Vec<Job>>, // Ordem das tarefas em cada máquina.
+}
+
+impl NonPermutationSchedule {
+    /// Escalonamento que usa a mesma sequência em todas as máquinas.
+    pub fn from_permutation(sequence: &[Job], n_machines: usize) -> Self {
+        NonPermutationSchedule {
+            orders: vec![sequence.to_vec(); n_machines],
+        }
+    }
+
+    /// Decodifica o escalonamento: `completion[i][j]` é o término da tarefa `j` na
+    /// máquina `i`. Cada operação começa assim que a máquina fica livre e a tarefa
+    /// chega da máquina anterior (ou é liberada, na primeira máquina).
+    pub fn completion_times(&self, instance: &FSSPInstance) -> Vec<Vec<Time>> {
+        let mut completion = vec![vec![0; instance.n_jobs]; instance.n_machines];
+        self._decode_from(instance, &mut completion, 0);
+        completion
+    }
+
+    /// Decodifica o escalonamento em instantes de início e término de cada operação
+    /// ([tarefa][máquina]). A sequência do resultado é a ordem na última máquina.
+    pub fn decode_schedule(&self, instance: &FSSPInstance) -> Schedule {
+        let completion = self.completion_times(instance);
+        let completion_times: Vec<Vec<Time>> = (0..instance.n_jobs)
+            .map(|job| completion.iter().map(|row| row[job]).collect())
+            .collect();
+        let start_times = completion_times
+            .iter()
+            .zip(&instance.processing_times)
+            .map(|(ends, times)| ends.iter().zip(times).map(|(&end, &p)| end - p).collect())
+            .collect();
+        Schedule {
+            sequence: self.orders.last().cloned().unwrap_or_default(),
+            start_times,
+            completion_times,
+            makespan: _makespan(&completion),
+        }
+    }
+
+    /// Makespan do escalonamento.
+    pub fn makespan(&self, instance: &FSSPInstance) -> Time {
+        _makespan(&self.completion_times(instance))
+    }
+
+    /// Avalia o escalonamento segundo a função objetivo da instância.
+    pub fn evaluate(&self, instance: &FSSPInstance) -> Time {
+        _objective(instance, &self.completion_times(instance))
+    }
+
+    /// Recalcula os términos a partir da máquina `from`, reaproveitando as máquinas
+    /// anteriores de `completion`.
+    fn _decode_from(&self, instance: &FSSPInstance, completion: &mut [Vec<Time>], from: usize) {
+        for machine in from..instance.n_machines {
+            let mut machine_free = 0;
+            for &job in &self.orders[machine] {
+                let job = job as usize;
+                let ready = if machine == 0 {
+                    instance.release_date(job)
+                } else {
+                    completion[machine - 1][job] + instance.transfer_time(job, machine - 1)
+                };
+                let duration = instance.processing_times[job][machine];
+                machine_free =
+                    instance.earliest_start(machine, max(machine_free, ready), duration) + duration;
+                completion[machine][job] = machine_free;
+            }
+        }
+    }
+}
+
+/// Makespan a partir da matriz de términos.
+fn _makespan(completion: &[Vec<Time>]) -> Time {
+    completion
+        .last()
+        .and_then(|last| last.iter().copied().max())
+        .unwrap_or(0)
+}
+
+/// Valor da função objetivo a partir da matriz de términos, registrado como uma
+/// avaliação no contador da instância.
+fn _objective(instance: &FSSPInstance, completion: &[Vec<Time>]) -> Time {
+    instance.evaluations.add(1);
+    completion.last().map_or(0, |last| {
+        instance.objective.function().evaluate_completions(
+            instance,
+            &mut last.iter().enumerate().map(|(job, &c)| (job as Job, c)),
+        )
+    })
+}
+
+/// Cruzamento por máquina: cada máquina do filho herda a ordem de um dos pais,
+/// escolhido com igual probabilidade.
+pub fn crossover<R: Rng>(
+    parent1: &NonPermutationSchedule,
+    parent2: &NonPermutationSchedule,
+    rng: &mut R,
+) -> NonPermutationSchedule {
+    NonPermutationSchedule {
+        orders: parent1
+            .orders
+            .iter()
+            .zip(&parent2.orders)
+            .map(|(a, b)| {
+                if rng.gen_bool(0.5) {
+                    a.clone()
+                } else {
+                    b.clone()
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Mutação: move uma tarefa aleatória para outra posição na ordem de uma máquina
+/// aleatória.
+pub fn mutate<R: Rng>(schedule: &mut NonPermutationSchedule, rng: &mut R) {
+    let machine = rng.gen_range(0..schedule.orders.len());
+    let order = &mut schedule.orders[machine];
+    let n = order.len();
+    if n < 2 {
+        return;
+    }
+    let job = order.remove(rng.gen_range(0..n));
+    order.insert(rng.gen_range(0..n), job);
+}
+
+/// Busca local por inserção em cada máquina: retira cada tarefa da ordem de uma
+/// máquina e a reinsere na melhor posição, repetindo enquanto houver melhoria ou até
+/// o esgotamento de `budget` (tempo ou avaliações registradas na instância). Apenas
+/// as máquinas a partir da alterada são redecodificadas.
+/// Retorna o valor final da função objetivo.
+pub fn insertion_descent(
+    instance: &FSSPInstance,
+    schedule: &mut NonPermutationSchedule,
+    budget: &Budget,
+) -> Time {
+    let mut completion = schedule.completion_times(instance);
+    let mut current = _objective(instance, &completion);
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for machine in 0..instance.n_machines {
+            for idx in 0..instance.n_jobs {
+                if budget.exhausted(instance.evaluations.get()) {
+                    return current;
+                }
+                let job = schedule.orders[machine].remove(idx);
+                let mut best = (idx, current);
+                for pos in 0..instance.n_jobs {
+                    if pos == idx {
+                        continue;
+                    }
+                    schedule.orders[machine].insert(pos, job);
+                    schedule._decode_from(instance, &mut completion, machine);
+                    let value = _objective(instance, &completion);
+                    if value < best.1 {
+                        best = (pos, value);
+                    }
+                    schedule.orders[machine].remove(pos);
+                }
+                schedule.orders[machine].insert(best.0, job);
+                schedule._decode_from(instance, &mut completion, machine);
+                if best.1 < current {
+                    current = best.1;
+                    improved = true;
+                }
+            }
+        }
+    }
+    current
+}
+
+/// Busca local iterada sobre escalonamentos não permutacionais. Parte de uma solução
+/// permutacional (normalmente a melhor encontrada pelo algoritmo escolhido) e alterna
+/// perturbações (cruzamento por máquina da solução corrente com a melhor, seguido de
+/// mutações) e busca local por inserção, aceitando soluções não piores.
+pub struct NonPermutationSearch {
+    instance: FSSPInstance,                    // Instância do problema FSSP.
+    iterations: usize,                         // Número de iterações (critério de parada).
+    perturbation_strength: usize,              // Mutações aplicadas em cada perturbação.
+    pub best_schedule: NonPermutationSchedule, // O melhor escalonamento encontrado.
+    pub best_value: Time,                      // Valor da função objetivo do melhor escalonamento.
+}
+
+impl NonPermutationSearch {
+    /// Cria a busca a partir da sequência permutacional `initial`.
+    pub fn new(
+        instance: FSSPInstance,
+        initial: &[Job],
+        iterations: usize,
+        perturbation_strength: usize,
+    ) -> Self {
+        let best_schedule = NonPermutationSchedule::from_permutation(initial, instance.n_machines);
+        let best_value = best_schedule.evaluate(&instance);
+        NonPermutationSearch {
+            instance,
+            iterations,
+            perturbation_strength,
+            best_schedule,
+            best_value,
+        }
+    }
+
+    /// Executa a busca até o número de iterações ou o esgotamento do orçamento. O
+    /// resultado nunca é pior que a solução permutacional de partida.
+    pub fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+
+        let mut current = self.best_schedule.clone();
+        let mut current_value = insertion_descent(&self.instance, &mut current, budget);
+        if current_value < self.best_value {
+            self.best_value = current_value;
+            self.best_schedule = current.clone();
+        }
+
+        for iter in 0..self.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            // A solução corrente pode ter se afastado da melhor por movimentos de
+            // mesmo valor: o cruzamento recombina as ordens das duas máquina a máquina.
+            let mut candidate = crossover(&current, &self.best_schedule, &mut rng);
+            for _ in 0..self.perturbation_strength {
+                mutate(&mut candidate, &mut rng);
+            }
+            let value = insertion_descent(&self.instance, &mut candidate, budget);
+            if value <= current_value {
+                current = candidate;
+                current_value = value;
+            }
+            if current_value < self.best_value {
+                self.best_value = current_value;
+                self.best_schedule = current.clone();
+                debug!(value = self.best_value, "Nova melhor solução");
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_value,
+                generation_best: current_value,
+                mean_makespan: current_value as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+    }
+
+    /// Avaliações da função objetivo realizadas pela busca.
+    pub fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+}
@@ -0,0 +1,287 @@
+//! Funções objetivo. Cada objetivo implementa `ObjectiveFunction` a partir do
+//! término de cada tarefa na última máquina, e `FSSPInstance::evaluate` o aplica
+//! à sequência: os algoritmos avaliam soluções apenas pela instância, de modo que um
+//! novo objetivo exige somente a sua implementação e uma variante em `Objective`.
+
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{FSSPInstance, Job, Time};
+
+/// Função objetivo regular (não decrescente nos términos das tarefas), minimizada
+/// pelos algoritmos. O valor de uma sequência combina, com `combine`, o custo de
+/// cada tarefa (`job_cost`) ao terminar a última máquina.
+pub trait ObjectiveFunction: Send + Sync {
+    /// Nome usado em `--objective`.
+    fn name(&self) -> &'static str;
+
+    /// Verifica se a instância possui os dados exigidos pelo objetivo.
+    fn check(&self, _instance: &FSSPInstance) -> Result<()> {
+        Ok(())
+    }
+
+    /// Custo de `job` ao terminar a última máquina no instante `completion`.
+    fn job_cost(&self, instance: &FSSPInstance, job: Job, completion: Time) -> Time;
+
+    /// Acrescenta o custo de uma tarefa ao valor acumulado (soma, por padrão).
+    fn combine(&self, total: Time, cost: Time) -> Time {
+        total.saturating_add(cost)
+    }
+
+    /// Indica se o valor é a combinação dos custos das tarefas (`job_cost` e
+    /// `combine`), como exigem os limitantes do branch-and-bound. Objetivos não
+    /// separáveis definem `evaluate_completions` e `evaluate_with`.
+    fn separable(&self) -> bool {
+        true
+    }
+
+    /// Valor a partir dos pares (tarefa, término na última máquina).
+    fn evaluate_completions(
+        &self,
+        instance: &FSSPInstance,
+        completions: &mut dyn Iterator<Item = (Job, Time)>,
+    ) -> Time {
+        completions.fold(0, |total, (job, completion)| {
+            self.combine(total, self.job_cost(instance, job, completion))
+        })
+    }
+
+    /// Valor da sequência com os tempos de processamento informados (ex: os de um
+    /// cenário). Sequências parciais são aceitas.
+    fn evaluate_with(
+        &self,
+        instance: &FSSPInstance,
+        processing_times: &[Vec<Time>],
+        sequence: &[Job],
+    ) -> Time {
+        let mut total = 0;
+        instance.for_each_completion(processing_times, sequence, |job, completion| {
+            total = self.combine(total, self.job_cost(instance, job, completion));
+        });
+        total
+    }
+
+    /// Valor da sequência com os tempos de processamento da instância.
+    fn evaluate(&self, instance: &FSSPInstance, sequence: &[Job]) -> Time {
+        self.evaluate_with(instance, &instance.processing_times, sequence)
+    }
+}
+
+/// Tempo de conclusão da última tarefa (C_max).
+pub struct Makespan;
+
+impl ObjectiveFunction for Makespan {
+    fn name(&self) -> &'static str {
+        "makespan"
+    }
+
+    fn job_cost(&self, _instance: &FSSPInstance, _job: Job, completion: Time) -> Time {
+        completion
+    }
+
+    fn combine(&self, total: Time, cost: Time) -> Time {
+        total.max(cost)
+    }
+
+    fn evaluate(&self, instance: &FSSPInstance, sequence: &[Job]) -> Time {
+        instance.calculate_makespan(sequence)
+    }
+}
+
+/// Tempo de fluxo total: soma dos términos das tarefas (soma de C_j).
+pub struct TotalFlowtime;
+
+impl ObjectiveFunction for TotalFlowtime {
+    fn name(&self) -> &'static str {
+        "flowtime"
+    }
+
+    fn job_cost(&self, _instance: &FSSPInstance, _job: Job, completion: Time) -> Time {
+        completion
+    }
+}
+
+/// Atraso total: soma de max(0, C_j - d_j).
+pub struct TotalTardiness;
+
+impl ObjectiveFunction for TotalTardiness {
+    fn name(&self) -> &'static str {
+        "tt"
+    }
+
+    fn check(&self, instance: &FSSPInstance) -> Result<()> {
+        _require_due_dates(instance, self.name())
+    }
+
+    fn job_cost(&self, instance: &FSSPInstance, job: Job, completion: Time) -> Time {
+        instance.due_dates.as_ref().map_or(0, |due_dates| {
+            completion.saturating_sub(due_dates[job as usize])
+        })
+    }
+}
+
+/// Atraso máximo: maior max(0, C_j - d_j).
+pub struct MaxTardiness;
+
+impl ObjectiveFunction for MaxTardiness {
+    fn name(&self) -> &'static str {
+        "tmax"
+    }
+
+    fn check(&self, instance: &FSSPInstance) -> Result<()> {
+        _require_due_dates(instance, self.name())
+    }
+
+    fn job_cost(&self, instance: &FSSPInstance, job: Job, completion: Time) -> Time {
+        TotalTardiness.job_cost(instance, job, completion)
+    }
+
+    fn combine(&self, total: Time, cost: Time) -> Time {
+        total.max(cost)
+    }
+}
+
+/// Atraso ponderado total: soma de w_j * max(0, C_j - d_j).
+pub struct WeightedTardiness;
+
+impl ObjectiveFunction for WeightedTardiness {
+    fn name(&self) -> &'static str {
+        "twt"
+    }
+
+    fn check(&self, instance: &FSSPInstance) -> Result<()> {
+        _require_due_dates(instance, self.name())
+    }
+
+    fn job_cost(&self, instance: &FSSPInstance, job: Job, completion: Time) -> Time {
+        instance
+            .weight(job as usize)
+            .saturating_mul(TotalTardiness.job_cost(instance, job, completion))
+    }
+
+    fn evaluate(&self, instance: &FSSPInstance, sequence: &[Job]) -> Time {
+        instance.calculate_total_weighted_tardiness(sequence)
+    }
+}
+
+/// Tempo de fluxo ponderado total: soma de w_j * C_j. Sem pesos na instância,
+/// equivale ao tempo de fluxo total.
+pub struct WeightedFlowtime;
+
+impl ObjectiveFunction for WeightedFlowtime {
+    fn name(&self) -> &'static str {
+        "twft"
+    }
+
+    fn job_cost(&self, instance: &FSSPInstance, job: Job, completion: Time) -> Time {
+        instance.weight(job as usize).saturating_mul(completion)
+    }
+}
+
+/// Critérios combinados pelo objetivo escalarizado, na ordem dos pesos.
+const SCALARIZED_CRITERIA: [&dyn ObjectiveFunction; 3] = [&Makespan, &TotalFlowtime, &MaxTardiness];
+
+/// Objetivo escalarizado: soma ponderada w_1 * C_max + w_2 * soma de C_j + w_3 *
+/// T_max, arredondada para o inteiro mais próximo, para obter uma única solução de
+/// compromisso entre os critérios. Os pesos não são normalizados. Cada critério é
+/// acumulado com o seu `job_cost` e `combine` em uma única passagem pela sequência.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scalarized {
+    pub weights: [f64; 3], // Pesos do makespan, do tempo de fluxo total e do atraso máximo.
+}
+
+impl Scalarized {
+    /// Cria o objetivo com os pesos informados, que devem ser finitos, não negativos
+    /// e não todos nulos.
+    pub fn new(weights: [f64; 3]) -> std::result::Result<Self, String> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(
+                "os pesos do objetivo ponderado devem ser números não negativos".to_string(),
+            );
+        }
+        if weights.iter().all(|&w| w == 0.0) {
+            return Err("ao menos um peso do objetivo ponderado deve ser positivo".to_string());
+        }
+        Ok(Scalarized { weights })
+    }
+
+    /// Acrescenta o término de `job` aos valores acumulados de cada critério.
+    fn _accumulate(
+        &self,
+        instance: &FSSPInstance,
+        totals: &mut [Time; 3],
+        job: Job,
+        completion: Time,
+    ) {
+        for (total, criterion) in totals.iter_mut().zip(SCALARIZED_CRITERIA) {
+            *total = criterion.combine(*total, criterion.job_cost(instance, job, completion));
+        }
+    }
+
+    /// Soma ponderada dos valores dos critérios.
+    fn _scalarize(&self, totals: &[Time; 3]) -> Time {
+        let value: f64 = totals
+            .iter()
+            .zip(&self.weights)
+            .map(|(&total, &weight)| total as f64 * weight)
+            .sum();
+        value.round().min(Time::MAX as f64) as Time
+    }
+}
+
+impl ObjectiveFunction for Scalarized {
+    fn name(&self) -> &'static str {
+        "weighted"
+    }
+
+    /// O atraso máximo exige datas de entrega quando o seu peso é positivo.
+    fn check(&self, instance: &FSSPInstance) -> Result<()> {
+        if self.weights[2] > 0.0 {
+            _require_due_dates(instance, self.name())?;
+        }
+        Ok(())
+    }
+
+    /// Não usado: o objetivo não é separável (veja `separable`).
+    fn job_cost(&self, _instance: &FSSPInstance, _job: Job, completion: Time) -> Time {
+        completion
+    }
+
+    fn separable(&self) -> bool {
+        false
+    }
+
+    fn evaluate_completions(
+        &self,
+        instance: &FSSPInstance,
+        completions: &mut dyn Iterator<Item = (Job, Time)>,
+    ) -> Time {
+        let mut totals = [0; 3];
+        for (job, completion) in completions {
+            self._accumulate(instance, &mut totals, job, completion);
+        }
+        self._scalarize(&totals)
+    }
+
+    fn evaluate_with(
+        &self,
+        instance: &FSSPInstance,
+        processing_times: &[Vec<Time>],
+        sequence: &[Job],
+    ) -> Time {
+        let mut totals = [0; 3];
+        instance.for_each_completion(processing_times, sequence, |job, completion| {
+            self._accumulate(instance, &mut totals, job, completion);
+        });
+        self._scalarize(&totals)
+    }
+}
+
+/// Erro se a instância não define datas de entrega.
+fn _require_due_dates(instance: &FSSPInstance, name: &str) -> Result<()> {
+    if instance.due_dates.is_none() {
+        return Err(FsspError::Config(format!(
+            "o objetivo '{}' exige datas de entrega (seção `due_dates` da instância)",
+            name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,264 @@
+use crate::fssp_core::{Job, Time, TimeValue};
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Duration;
+use tracing::trace;
+
+/// Estatísticas de uma geração, repassadas aos observadores durante a execução.
+#[derive(Debug, Clone)]
+pub struct GenerationStats {
+    pub generation: usize,                // Número da geração (a partir de 1).
+    pub best_makespan: Time,              // Melhor makespan encontrado até esta geração.
+    pub generation_best: Time,            // Melhor makespan da população desta geração.
+    pub mean_makespan: f64,               // Makespan médio da população desta geração.
+    pub mutation_rate: f64,               // Taxa de mutação usada nesta geração.
+    pub diversity: Option<f64>,           // Diversidade da população (modo adaptativo).
+    pub duplicate_rate: Option<f64>,      // Fração de filhos duplicados (algoritmo memético).
+    pub operators: Option<OperatorStats>, // Aplicações e melhorias de cada operador.
+    pub evaluations: u64,                 // Avaliações da função objetivo até esta geração.
+    pub elapsed: Duration,                // Tempo decorrido desde o início da execução.
+}
+
+impl GenerationStats {
+    /// Registra as estatísticas no nível `trace` do log.
+    pub(crate) fn trace(&self) {
+        trace!(
+            best_makespan = self.best_makespan,
+            generation_best = self.generation_best,
+            mean_makespan = self.mean_makespan,
+            mutation_rate = self.mutation_rate,
+            diversity = ?self.diversity,
+            duplicate_rate = ?self.duplicate_rate,
+            operators = ?self.operators,
+            evaluations = self.evaluations,
+            elapsed_seconds = self.elapsed.as_secs_f64(),
+            "Geração concluída"
+        );
+    }
+}
+
+/// Aplicações de um operador em uma geração e as melhorias que produziram.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OperatorCounts {
+    pub applications: u64, // Número de aplicações do operador.
+    pub improvements: u64, // Aplicações que melhoraram a solução de referência.
+    pub gain: f64,         // Soma das melhorias relativas obtidas.
+}
+
+impl OperatorCounts {
+    /// Registra uma aplicação que levou a solução de referência de `before` para
+    /// `after`.
+    pub fn record(&mut self, before: Time, after: Time) {
+        self.applications += 1;
+        if after < before {
+            self.improvements += 1;
+            self.gain += (before - after) as f64 / before as f64;
+        }
+    }
+
+    /// Melhoria relativa média das aplicações que melhoraram (0 se nenhuma).
+    pub fn mean_gain(&self) -> f64 {
+        if self.improvements > 0 {
+            self.gain / self.improvements as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Acumula as contagens de outro registro (por exemplo, de outra ilha).
+    pub fn merge(&mut self, other: &OperatorCounts) {
+        self.applications += other.applications;
+        self.improvements += other.improvements;
+        self.gain += other.gain;
+    }
+}
+
+impl fmt::Display for OperatorCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} ({:.2}%)",
+            self.improvements,
+            self.applications,
+            100.0 * self.mean_gain()
+        )
+    }
+}
+
+/// Estatísticas dos operadores do algoritmo memético em uma geração. O cruzamento
+/// melhora quando o filho é melhor que o melhor dos pais; a mutação e a busca
+/// local, quando o indivíduo fica melhor do que antes da sua aplicação.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OperatorStats {
+    pub crossover: OperatorCounts, // Cruzamentos (um registro por filho).
+    pub mutation: OperatorCounts,  // Mutações aplicadas.
+    pub local_search: OperatorCounts, // Aplicações da busca local.
+}
+
+impl OperatorStats {
+    /// Acumula as estatísticas de outro registro.
+    pub fn merge(&mut self, other: &OperatorStats) {
+        self.crossover.merge(&other.crossover);
+        self.mutation.merge(&other.mutation);
+        self.local_search.merge(&other.local_search);
+    }
+}
+
+/// Uma solução do problema: sequência de tarefas e seu makespan.
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub sequence: Vec<Job>, // Sequência de tarefas.
+    pub makespan: Time,     // Makespan da sequência.
+}
+
+/// Decisão do observador sobre a continuidade da execução.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Continue, // Segue para a próxima geração.
+    Stop,     // Encerra a execução antecipadamente.
+}
+
+/// Interface para acompanhar a execução de um algoritmo (registro personalizado,
+/// gráficos em tempo real, parada antecipada etc.) sem alterar o laço principal.
+pub trait Observer {
+    /// Chamado ao final de cada geração. Retornar `Control::Stop` encerra a execução.
+    fn on_generation(&mut self, _stats: &GenerationStats) -> Control {
+        Control::Continue
+    }
+
+    /// Chamado sempre que uma nova melhor solução global é encontrada.
+    fn on_new_best(&mut self, _solution: &Solution) {}
+
+    /// Solução externa oferecida ao algoritmo (por exemplo, a melhor solução global
+    /// de execuções paralelas), consultada ao final de cada geração. O algoritmo a
+    /// adota se ela for melhor que a sua melhor solução.
+    fn incoming(&mut self) -> Option<Solution> {
+        None
+    }
+}
+
+/// Observador que não realiza nenhuma ação.
+pub struct NoopObserver;
+
+impl Observer for NoopObserver {}
+
+/// Observador que repassa os eventos a vários observadores. A execução é encerrada
+/// se qualquer um deles solicitar a parada.
+#[derive(Default)]
+pub struct MultiObserver<'a> {
+    observers: Vec<&'a mut dyn Observer>, // Observadores notificados, em ordem.
+}
+
+impl<'a> MultiObserver<'a> {
+    /// Cria um observador sem destinatários.
+    pub fn new() -> Self {
+        MultiObserver::default()
+    }
+
+    /// Acrescenta um observador.
+    pub fn push(&mut self, observer: &'a mut dyn Observer) {
+        self.observers.push(observer);
+    }
+}
+
+impl Observer for MultiObserver<'_> {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        let mut control = Control::Continue;
+        for observer in &mut self.observers {
+            if observer.on_generation(stats) == Control::Stop {
+                control = Control::Stop;
+            }
+        }
+        control
+    }
+
+    fn on_new_best(&mut self, solution: &Solution) {
+        for observer in &mut self.observers {
+            observer.on_new_best(solution);
+        }
+    }
+
+    /// A melhor entre as soluções oferecidas pelos observadores.
+    fn incoming(&mut self) -> Option<Solution> {
+        self.observers
+            .iter_mut()
+            .filter_map(|observer| observer.incoming())
+            .min_by_key(|solution| solution.makespan)
+    }
+}
+
+/// Observador que guarda as estatísticas de cada geração para gravá-las em CSV
+/// ao final da execução (histórico de convergência).
+#[derive(Debug, Default)]
+pub struct HistoryObserver {
+    pub generations: Vec<GenerationStats>, // Estatísticas de cada geração, em ordem.
+}
+
+impl HistoryObserver {
+    pub fn new() -> Self {
+        HistoryObserver::default()
+    }
+
+    /// Grava o histórico em CSV, uma linha por geração, com os valores nas
+    /// unidades originais da instância (`time_decimals` casas decimais). As colunas
+    /// opcionais ficam vazias quando o algoritmo não as fornece; as dos operadores
+    /// trazem aplicações, melhorias e a melhoria relativa média de cada um.
+    pub fn write_csv<W: Write>(&self, mut writer: W, time_decimals: u32) -> io::Result<()> {
+        let scale = 10f64.powi(time_decimals as i32);
+        let optional = |value: Option<f64>| value.map(|v| format!("{:.4}", v)).unwrap_or_default();
+        write!(
+            writer,
+            "generation,best,generation_best,mean,mutation_rate,diversity,duplicate_rate,elapsed_seconds,evaluations"
+        )?;
+        for operator in ["crossover", "mutation", "local_search"] {
+            write!(
+                writer,
+                ",{0}_applications,{0}_improvements,{0}_mean_gain",
+                operator
+            )?;
+        }
+        writeln!(writer)?;
+        for stats in &self.generations {
+            write!(
+                writer,
+                "{},{},{},{:.2},{:.4},{},{},{:.4},{}",
+                stats.generation,
+                TimeValue::new(stats.best_makespan, time_decimals),
+                TimeValue::new(stats.generation_best, time_decimals),
+                stats.mean_makespan / scale,
+                stats.mutation_rate,
+                optional(stats.diversity),
+                optional(stats.duplicate_rate),
+                stats.elapsed.as_secs_f64(),
+                stats.evaluations
+            )?;
+            match &stats.operators {
+                Some(operators) => {
+                    for counts in [
+                        &operators.crossover,
+                        &operators.mutation,
+                        &operators.local_search,
+                    ] {
+                        write!(
+                            writer,
+                            ",{},{},{:.6}",
+                            counts.applications,
+                            counts.improvements,
+                            counts.mean_gain()
+                        )?;
+                    }
+                }
+                None => write!(writer, ",,,,,,,,,")?,
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Observer for HistoryObserver {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        self.generations.push(stats.clone());
+        Control::Continue
+    }
+}
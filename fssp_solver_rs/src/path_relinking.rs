@@ -0,0 +1,53 @@
+use crate::fssp_core::{FSSPInstance, Job, Time};
+
+/// Religação de caminhos (path relinking) gulosa de `from` até `to`: a cada passo,
+/// entre as trocas que colocam em sua posição final uma tarefa ainda fora do lugar
+/// em relação a `to`, aplica a de menor valor da função objetivo. Retorna a melhor
+/// solução intermediária do caminho (excluindo os extremos) e seu valor, ou `None`
+/// se as sequências diferem em menos de três posições (não há intermediárias
+/// distintas dos extremos).
+///
+/// Cada passo avalia até `n` trocas, de modo que o caminho completo custa O(n²)
+/// avaliações.
+pub fn relink(instance: &FSSPInstance, from: &[Job], to: &[Job]) -> Option<(Vec<Job>, Time)> {
+    let mut current = from.to_vec();
+    // Posição de cada tarefa na sequência corrente.
+    let mut position = vec![0; current.len()];
+    for (idx, &job) in current.iter().enumerate() {
+        position[job as usize] = idx;
+    }
+
+    let mut best: Option<(Vec<Job>, Time)> = None;
+    loop {
+        let mismatched: Vec<usize> = (0..current.len())
+            .filter(|&idx| current[idx] != to[idx])
+            .collect();
+        // Com duas posições fora do lugar, o próximo passo leva ao destino.
+        if mismatched.len() <= 2 {
+            break;
+        }
+
+        let mut step: Option<(usize, usize, Time)> = None;
+        for &idx in &mismatched {
+            let other = position[to[idx] as usize];
+            current.swap(idx, other);
+            let value = instance.evaluate(&current);
+            current.swap(idx, other);
+            if step.is_none_or(|(_, _, best_value)| value < best_value) {
+                step = Some((idx, other, value));
+            }
+        }
+
+        let (idx, other, value) = step.unwrap();
+        current.swap(idx, other);
+        position[current[idx] as usize] = idx;
+        position[current[other] as usize] = other;
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_value)| value < *best_value)
+        {
+            best = Some((current.clone(), value));
+        }
+    }
+    best
+}
@@ -0,0 +1,121 @@
+use crate::fssp_core::Job;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, ChunksExactMut};
+
+/// População de sequências de tarefas armazenada em um único vetor contíguo, com
+/// uma linha de `n_jobs` tarefas por indivíduo. Os operadores recebem cada
+/// indivíduo como uma fatia da linha (`&population[i]`), sem alocações por
+/// indivíduo, e o mesmo armazenamento é reaproveitado de uma geração para outra.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Population {
+    genes: Vec<Job>, // Sequências concatenadas, `n_jobs` tarefas cada.
+    n_jobs: usize,   // Tamanho de cada sequência (largura da linha).
+}
+
+impl Population {
+    /// Cria uma população vazia de sequências com `n_jobs` tarefas.
+    pub fn new(n_jobs: usize) -> Self {
+        Population {
+            genes: Vec::new(),
+            n_jobs,
+        }
+    }
+
+    /// Cria uma população vazia com espaço reservado para `individuals` sequências.
+    pub fn with_capacity(n_jobs: usize, individuals: usize) -> Self {
+        Population {
+            genes: Vec::with_capacity(n_jobs * individuals),
+            n_jobs,
+        }
+    }
+
+    /// Cria uma população com cópias das sequências fornecidas, que devem ter
+    /// `n_jobs` tarefas cada.
+    pub fn from_sequences<S: AsRef<[Job]>>(n_jobs: usize, sequences: &[S]) -> Self {
+        let mut population = Population::with_capacity(n_jobs, sequences.len());
+        for sequence in sequences {
+            population.push(sequence.as_ref());
+        }
+        population
+    }
+
+    /// Número de tarefas de cada sequência.
+    pub fn n_jobs(&self) -> usize {
+        self.n_jobs
+    }
+
+    /// Número de indivíduos.
+    pub fn len(&self) -> usize {
+        self.genes.len() / self.n_jobs.max(1)
+    }
+
+    /// Indica se a população está vazia.
+    pub fn is_empty(&self) -> bool {
+        self.genes.is_empty()
+    }
+
+    /// Remove todos os indivíduos, mantendo o espaço alocado.
+    pub fn clear(&mut self) {
+        self.genes.clear();
+    }
+
+    /// Acrescenta uma cópia da sequência, que deve ter `n_jobs` tarefas.
+    pub fn push(&mut self, sequence: &[Job]) {
+        assert_eq!(
+            sequence.len(),
+            self.n_jobs,
+            "sequência com tamanho diferente das demais da população"
+        );
+        self.genes.extend_from_slice(sequence);
+    }
+
+    /// Acrescenta cópias dos `count` primeiros indivíduos de `other`.
+    pub fn extend_from(&mut self, other: &Population, count: usize) {
+        let count = count.min(other.len());
+        self.genes
+            .extend_from_slice(&other.genes[..count * other.n_jobs]);
+    }
+
+    /// Indica se a população contém a sequência.
+    pub fn contains(&self, sequence: &[Job]) -> bool {
+        self.iter().any(|individual| individual == sequence)
+    }
+
+    /// Percorre os indivíduos em ordem.
+    pub fn iter(&self) -> ChunksExact<'_, Job> {
+        self.genes.chunks_exact(self.n_jobs.max(1))
+    }
+
+    /// Percorre os indivíduos em ordem, permitindo alterá-los.
+    pub fn iter_mut(&mut self) -> ChunksExactMut<'_, Job> {
+        self.genes.chunks_exact_mut(self.n_jobs.max(1))
+    }
+
+    /// Cópias das sequências, uma por indivíduo.
+    pub fn to_sequences(&self) -> Vec<Vec<Job>> {
+        self.iter().map(<[Job]>::to_vec).collect()
+    }
+}
+
+impl Index<usize> for Population {
+    type Output = [Job];
+
+    fn index(&self, idx: usize) -> &[Job] {
+        &self.genes[idx * self.n_jobs..(idx + 1) * self.n_jobs]
+    }
+}
+
+impl IndexMut<usize> for Population {
+    fn index_mut(&mut self, idx: usize) -> &mut [Job] {
+        &mut self.genes[idx * self.n_jobs..(idx + 1) * self.n_jobs]
+    }
+}
+
+impl<'a> IntoIterator for &'a Population {
+    type Item = &'a [Job];
+    type IntoIter = ChunksExact<'a, Job>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
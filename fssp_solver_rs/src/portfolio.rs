@@ -0,0 +1,319 @@
+//! Portfólio de algoritmos: `--algorithm auto` extrai características da instância
+//! (dimensões, variação dos tempos e correlação entre máquinas) e usa o preset
+//! (algoritmo e, se definidos, parâmetros) da instância de referência mais próxima
+//! do modelo. O modelo é um arquivo TOML, obtido pelo subcomando `train-portfolio`
+//! (que escolhe apenas o algoritmo de cada referência) ou escrito à mão; sem modelo,
+//! é usado o modelo embutido (`portfolio.toml`).
+
+use crate::budget::Budget;
+use crate::config::SolverConfig;
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::inspect::TimeStats;
+use crate::observer::Observer;
+use crate::registry;
+use crate::solver::{Algorithm, Solver};
+use crate::time::Instant;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "fs")]
+use std::path::Path;
+use tracing::info;
+
+/// Modelo embutido, usado quando nenhum modelo é informado.
+const BUILTIN_MODEL: &str = include_str!("portfolio.toml");
+
+/// Características de uma instância usadas na escolha do algoritmo.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstanceFeatures {
+    pub n_jobs: usize,            // Número de tarefas.
+    pub n_machines: usize,        // Número de máquinas.
+    pub time_cv: f64,             // Coeficiente de variação de todos os tempos.
+    pub machine_correlation: f64, // Correlação média dos tempos entre máquinas consecutivas.
+}
+
+impl InstanceFeatures {
+    /// Extrai as características da instância. A correlação é a média dos
+    /// coeficientes de Pearson entre os tempos das tarefas em cada par de máquinas
+    /// consecutivas: próxima de 1 quando os tempos de cada tarefa são semelhantes
+    /// em todas as máquinas e de 0 quando são independentes.
+    pub fn new(instance: &FSSPInstance) -> Self {
+        let all: Vec<Time> = instance
+            .processing_times
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        let column = |machine: usize| -> Vec<f64> {
+            instance
+                .processing_times
+                .iter()
+                .map(|times| times[machine] as f64)
+                .collect()
+        };
+        let pairs = instance.n_machines.saturating_sub(1);
+        let machine_correlation = if pairs == 0 {
+            0.0
+        } else {
+            (0..pairs)
+                .map(|machine| _pearson(&column(machine), &column(machine + 1)))
+                .sum::<f64>()
+                / pairs as f64
+        };
+        InstanceFeatures {
+            n_jobs: instance.n_jobs,
+            n_machines: instance.n_machines,
+            time_cv: TimeStats::new(&all).coefficient_of_variation(),
+            machine_correlation,
+        }
+    }
+
+    /// Distância entre as características de duas instâncias. As dimensões são
+    /// comparadas em escala logarítmica (dobrar o número de tarefas vale 1), o
+    /// coeficiente de variação em passos de 0.25 e a correlação em passos de 0.5.
+    pub fn distance(&self, other: &InstanceFeatures) -> f64 {
+        let log_ratio = |a: usize, b: usize| (a.max(1) as f64 / b.max(1) as f64).log2();
+        (log_ratio(self.n_jobs, other.n_jobs).powi(2)
+            + log_ratio(self.n_machines, other.n_machines).powi(2)
+            + ((self.time_cv - other.time_cv) / 0.25).powi(2)
+            + ((self.machine_correlation - other.machine_correlation) / 0.5).powi(2))
+        .sqrt()
+    }
+}
+
+/// Coeficiente de correlação de Pearson (0 se alguma das séries for constante).
+fn _pearson(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len().max(1) as f64;
+    let (mean_a, mean_b) = (a.iter().sum::<f64>() / n, b.iter().sum::<f64>() / n);
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (&x, &y) in a.iter().zip(b) {
+        cov += (x - mean_a) * (y - mean_b);
+        var_a += (x - mean_a).powi(2);
+        var_b += (y - mean_b).powi(2);
+    }
+    if var_a > 0.0 && var_b > 0.0 {
+        cov / (var_a * var_b).sqrt()
+    } else {
+        0.0
+    }
+}
+
+/// Instância de referência do modelo e o preset usado nas instâncias próximas.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PortfolioPreset {
+    pub name: String,               // Nome da instância de referência.
+    pub features: InstanceFeatures, // Características da instância de referência.
+    pub config: SolverConfig,       // Algoritmo e parâmetros do preset.
+}
+
+/// Modelo do portfólio: instâncias de referência com os respectivos presets. A
+/// escolha é pelo vizinho mais próximo (veja `InstanceFeatures::distance`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PortfolioModel {
+    #[serde(default)]
+    pub presets: Vec<PortfolioPreset>,
+}
+
+impl PortfolioModel {
+    /// Modelo embutido.
+    pub fn builtin() -> Self {
+        PortfolioModel::from_toml(BUILTIN_MODEL).expect("o modelo embutido é válido")
+    }
+
+    /// Carrega o modelo de um arquivo TOML.
+    #[cfg(feature = "fs")]
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        PortfolioModel::from_toml(&text)
+    }
+
+    /// Interpreta o modelo a partir de um texto TOML. O modelo deve ter ao menos um
+    /// preset, e nenhum preset pode usar `auto`.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let model: PortfolioModel =
+            toml::from_str(text).map_err(|e| FsspError::Config(e.to_string()))?;
+        if model.presets.is_empty() {
+            return Err(FsspError::Config(
+                "o modelo do portfólio não possui presets".to_string(),
+            ));
+        }
+        if let Some(preset) = model
+            .presets
+            .iter()
+            .find(|preset| preset.config.algorithm == Some(Algorithm::Auto))
+        {
+            return Err(FsspError::Config(format!(
+                "o preset '{}' do portfólio não pode usar o algoritmo auto",
+                preset.name
+            )));
+        }
+        Ok(model)
+    }
+
+    /// Serializa o modelo em TOML.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).map_err(|e| FsspError::Config(e.to_string()))
+    }
+
+    /// Preset da instância de referência mais próxima.
+    pub fn select(&self, features: &InstanceFeatures) -> &PortfolioPreset {
+        self.presets
+            .iter()
+            .min_by(|a, b| {
+                a.features
+                    .distance(features)
+                    .total_cmp(&b.features.distance(features))
+            })
+            .expect("o modelo possui ao menos um preset")
+    }
+}
+
+/// Treina o modelo nas instâncias informadas (nome e características):
+/// `evaluate(algorithm, instance)` executa o algoritmo na instância e retorna o
+/// valor obtido, e cada instância vira um preset com o algoritmo de menor valor.
+/// Os presets treinados não definem parâmetros: as execuções usam os da
+/// configuração, e parâmetros por preset podem ser acrescentados ao arquivo à mão.
+/// Os candidatos de cada instância são avaliados em paralelo; o primeiro erro de
+/// uma execução encerra o treinamento e é retornado.
+pub fn train<F>(
+    instances: &[(String, InstanceFeatures)],
+    candidates: &[Algorithm],
+    evaluate: F,
+) -> Result<PortfolioModel>
+where
+    F: Fn(Algorithm, usize) -> Result<Time> + Sync,
+{
+    assert!(!candidates.is_empty(), "nenhum algoritmo candidato");
+    let presets = instances
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, features))| {
+            let values: Vec<Time> = maybe_par_iter!(candidates)
+                .map(|&algorithm| evaluate(algorithm, idx))
+                .collect::<Result<_>>()?;
+            let (best, value) = candidates
+                .iter()
+                .zip(&values)
+                .min_by_key(|&(_, &value)| value)
+                .unwrap();
+            info!(instance = %name, algorithm = %best, value, "Preset do portfólio");
+            Ok(PortfolioPreset {
+                name: name.clone(),
+                features: *features,
+                config: SolverConfig {
+                    algorithm: Some(*best),
+                    ..SolverConfig::default()
+                },
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(PortfolioModel { presets })
+}
+
+/// Sobrepõe os parâmetros definidos no preset aos da configuração. As seções de
+/// algoritmo são combinadas campo a campo.
+pub fn apply_preset(config: &SolverConfig, preset: &SolverConfig) -> Result<SolverConfig> {
+    fn merge(base: &mut toml::Table, overlay: toml::Table) {
+        for (key, value) in overlay {
+            match (base.get_mut(&key), value) {
+                (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                    merge(base, overlay)
+                }
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
+        }
+    }
+    let to_table = |config: &SolverConfig| {
+        toml::Table::try_from(config).map_err(|e| FsspError::Config(e.to_string()))
+    };
+    let mut table = to_table(config)?;
+    merge(&mut table, to_table(preset)?);
+    table
+        .try_into()
+        .map_err(|e: toml::de::Error| FsspError::Config(e.to_string()))
+}
+
+/// Solver do portfólio: executa o algoritmo do preset escolhido para a instância.
+pub struct PortfolioSolver {
+    preset: String,          // Nome do preset escolhido.
+    algorithm: &'static str, // Algoritmo do preset.
+    inner: Box<dyn Solver>,  // Solver do algoritmo do preset.
+}
+
+impl PortfolioSolver {
+    /// Escolhe o preset de `model` mais próximo da instância e constrói o solver do
+    /// seu algoritmo, com os parâmetros do preset sobrepostos aos de `config`.
+    pub fn new(
+        instance: &FSSPInstance,
+        config: &SolverConfig,
+        model: &PortfolioModel,
+        initial_solutions: Vec<Vec<Job>>,
+    ) -> Result<Self> {
+        let features = InstanceFeatures::new(instance);
+        let preset = model.select(&features);
+        let config = apply_preset(config, &preset.config)?;
+        let entry = registry::entry(config.algorithm.unwrap_or(Algorithm::Memetic));
+        info!(
+            preset = %preset.name,
+            algorithm = entry.name,
+            n_jobs = features.n_jobs,
+            n_machines = features.n_machines,
+            time_cv = features.time_cv,
+            machine_correlation = features.machine_correlation,
+            "Portfólio: preset selecionado"
+        );
+        Ok(PortfolioSolver {
+            preset: preset.name.clone(),
+            algorithm: entry.name,
+            inner: entry.build(instance, &config, initial_solutions)?,
+        })
+    }
+
+    /// Nome do preset escolhido.
+    pub fn preset(&self) -> &str {
+        &self.preset
+    }
+}
+
+impl Solver for PortfolioSolver {
+    fn name(&self) -> &'static str {
+        "auto"
+    }
+
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        self.inner.run_with_observer(start_time, budget, observer);
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.inner.evaluations()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.inner.best_makespan()
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        self.inner.best_sequence()
+    }
+
+    fn population(&self) -> Vec<(Vec<Job>, Time)> {
+        self.inner.population()
+    }
+
+    /// O vencedor do algoritmo escolhido (ex: na hiper-heurística) ou o próprio
+    /// algoritmo escolhido.
+    fn winner(&self) -> Option<&'static str> {
+        self.inner.winner().or(Some(self.algorithm))
+    }
+}
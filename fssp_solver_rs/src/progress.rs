@@ -0,0 +1,151 @@
+use crate::fssp_core::{Time, TimeValue};
+use crate::i18n::Lang;
+use crate::observer::{Control, GenerationStats, Observer};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// Modelo da barra de progresso: geração atual, tempo decorrido, estimativa de término
+/// e a mensagem com o melhor makespan e a taxa de melhoria.
+const TEMPLATE: &str =
+    "{prefix} [{elapsed_precise}] {bar:30.cyan/blue} {pos}/{len} (ETA {eta}) {msg}";
+
+/// Observador que exibe o progresso da execução em uma barra no terminal, substituindo
+/// as mensagens periódicas dos solvers.
+pub struct ProgressObserver {
+    bar: ProgressBar,               // Barra de progresso no terminal.
+    max_duration: Option<Duration>, // Limite de tempo da execução, se houver.
+    initial_best: Option<Time>,     // Melhor makespan da primeira geração.
+}
+
+impl ProgressObserver {
+    /// Cria a barra de progresso para `total` gerações (ou iterações), adicionada a
+    /// `multi` para que várias execuções simultâneas compartilhem o terminal.
+    pub fn new(
+        multi: &MultiProgress,
+        name: &str,
+        total: usize,
+        max_duration: Option<Duration>,
+    ) -> Self {
+        let bar = multi.add(ProgressBar::new(total as u64));
+        bar.set_style(
+            ProgressStyle::with_template(TEMPLATE)
+                .expect("modelo da barra de progresso válido")
+                .progress_chars("=> "),
+        );
+        bar.set_prefix(name.to_string());
+        ProgressObserver {
+            bar,
+            max_duration,
+            initial_best: None,
+        }
+    }
+
+    /// Encerra a barra, mantendo a última mensagem visível.
+    pub fn finish(&self) {
+        self.bar.finish();
+    }
+}
+
+impl Observer for ProgressObserver {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        let initial = *self.initial_best.get_or_insert(stats.best_makespan);
+        let improvement = initial.saturating_sub(stats.best_makespan);
+        let seconds = stats.elapsed.as_secs_f64().max(1e-9);
+
+        let mut message = format!(
+            "melhor = {} | melhoria = {:.2}% ({:.1}/s)",
+            stats.best_makespan,
+            100.0 * improvement as f64 / initial.max(1) as f64,
+            improvement as f64 / seconds
+        );
+        if let Some(duration) = self.max_duration {
+            let remaining = duration.saturating_sub(stats.elapsed);
+            message.push_str(&format!(" | restante = {:.1}s", remaining.as_secs_f64()));
+        }
+        if let Some(diversity) = stats.diversity {
+            message.push_str(&format!(
+                " | diversidade = {:.3} | mutação = {:.3}",
+                diversity, stats.mutation_rate
+            ));
+        }
+
+        if let Some(rate) = stats.duplicate_rate {
+            message.push_str(&format!(" | duplicados = {:.1}%", 100.0 * rate));
+        }
+
+        self.bar.set_position(stats.generation as u64);
+        self.bar.set_message(message);
+        Control::Continue
+    }
+}
+
+/// Observador que imprime uma linha de progresso na saída padrão a cada `every`
+/// gerações, com o melhor valor, o tempo decorrido e as avaliações realizadas. A
+/// saída é descarregada a cada linha, para que o progresso apareça imediatamente
+/// quando redirecionada (por exemplo, para `tee`). Com uma barra de progresso
+/// ativa, a barra é suspensa enquanto a linha é impressa.
+pub struct ReportObserver {
+    multi: Option<MultiProgress>, // Barras de progresso a suspender, se houver.
+    name: String,                 // Nome da instância, prefixado a cada linha.
+    every: usize,                 // Intervalo de gerações entre as linhas.
+    time_decimals: u32,           // Casas decimais dos tempos, para exibição.
+    lang: Lang,                   // Idioma da linha impressa.
+}
+
+impl ReportObserver {
+    /// Cria o observador para a instância `name`, imprimindo a cada `every`
+    /// gerações (ao menos 1).
+    pub fn new(
+        multi: Option<&MultiProgress>,
+        name: &str,
+        every: usize,
+        time_decimals: u32,
+    ) -> Self {
+        ReportObserver {
+            multi: multi.cloned(),
+            name: name.to_string(),
+            every: every.max(1),
+            time_decimals,
+            lang: Lang::default(),
+        }
+    }
+
+    /// Define o idioma da linha impressa.
+    pub fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+}
+
+impl Observer for ReportObserver {
+    fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+        if !stats.generation.is_multiple_of(self.every) {
+            return Control::Continue;
+        }
+        let (best, elapsed) = (
+            TimeValue::new(stats.best_makespan, self.time_decimals),
+            stats.elapsed.as_secs_f64(),
+        );
+        let line = match self.lang {
+            Lang::Pt => format!(
+                "[{}] Geração {}: Melhor Makespan = {} | tempo = {:.2}s | avaliações = {}",
+                self.name, stats.generation, best, elapsed, stats.evaluations
+            ),
+            Lang::En => format!(
+                "[{}] Generation {}: Best Makespan = {} | time = {:.2}s | evaluations = {}",
+                self.name, stats.generation, best, elapsed, stats.evaluations
+            ),
+        };
+        let print = || {
+            let mut stdout = io::stdout().lock();
+            // Falhas de escrita (por exemplo, um pipe fechado) não interrompem a execução.
+            let _ = writeln!(stdout, "{}", line).and_then(|_| stdout.flush());
+        };
+        match &self.multi {
+            Some(multi) => multi.suspend(print),
+            None => print(),
+        }
+        Control::Continue
+    }
+}
@@ -0,0 +1,126 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job};
+use crate::key_population::{KeyAlgorithm, KeyPopulation};
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+/// Velocidade máxima de cada chave por iteração, em unidades do intervalo [0, 1)
+/// das chaves iniciais.
+const MAX_VELOCITY: f64 = 0.2;
+
+/// Parâmetros da otimização por enxame de partículas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PsoParams {
+    pub inertia: f64,   // Peso da velocidade anterior.
+    pub cognitive: f64, // Atração pela melhor posição da própria partícula.
+    pub social: f64,    // Atração pela melhor posição do enxame.
+}
+
+impl Default for PsoParams {
+    fn default() -> Self {
+        PsoParams {
+            inertia: 0.7,
+            cognitive: 1.5,
+            social: 1.5,
+        }
+    }
+}
+
+/// Otimização por enxame de partículas (PSO) sobre chaves aleatórias. A posição de
+/// cada partícula é um vetor de chaves decodificado em uma sequência pela regra LOV
+/// (veja `random_key`), e a velocidade é atualizada pela regra contínua clássica,
+/// `w * v + c1 * r1 * (pbest - x) + c2 * r2 * (gbest - x)`, limitada a
+/// `MAX_VELOCITY` por chave. As novas posições são decodificadas e avaliadas em
+/// paralelo (com o recurso `parallel`). Como na evolução diferencial, a melhor
+/// posição do enxame é refinada por busca local por inserção sempre que muda.
+pub struct ParticleSwarm {
+    state: KeyPopulation,      // Melhores posições das partículas e seus valores.
+    params: PsoParams,         // Inércia e coeficientes de atração.
+    positions: Vec<Vec<f64>>,  // Chaves da posição atual de cada partícula.
+    velocities: Vec<Vec<f64>>, // Velocidade de cada partícula.
+}
+
+impl ParticleSwarm {
+    /// Cria uma nova instância do `ParticleSwarm` (com ao menos duas partículas).
+    pub fn new(instance: FSSPInstance, swarm_size: usize, generations: usize) -> Self {
+        ParticleSwarm {
+            state: KeyPopulation::new(instance, swarm_size.max(2), generations),
+            params: PsoParams::default(),
+            positions: Vec::new(),
+            velocities: Vec::new(),
+        }
+    }
+
+    /// Define a inércia e os coeficientes de atração.
+    pub fn with_params(mut self, params: PsoParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Define soluções (já validadas) inseridas no enxame inicial antes da solução
+    /// NEH e das chaves aleatórias.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.state.initial_solutions = solutions;
+        self
+    }
+}
+
+impl KeyAlgorithm for ParticleSwarm {
+    const NAME: &'static str = "pso";
+
+    fn state(&self) -> &KeyPopulation {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut KeyPopulation {
+        &mut self.state
+    }
+
+    /// Enxame inicial: as soluções fornecidas, a solução NEH e chaves aleatórias,
+    /// com velocidades aleatórias.
+    fn initialize(&mut self, rng: &mut ThreadRng) {
+        self.state.initialize(rng);
+        let n = self.state.instance.n_jobs;
+        self.velocities = (0..self.state.size)
+            .map(|_| {
+                (0..n)
+                    .map(|_| rng.gen_range(-MAX_VELOCITY..=MAX_VELOCITY))
+                    .collect()
+            })
+            .collect();
+        self.positions = self.state.keys.clone();
+    }
+
+    /// Move as partículas em direção à melhor posição do enxame e atualiza suas
+    /// melhores posições. Apenas as novas posições são avaliadas.
+    fn step(&mut self, rng: &mut ThreadRng, _budget: &Budget) {
+        let PsoParams {
+            inertia,
+            cognitive,
+            social,
+        } = self.params;
+        let leader = self.state.keys[self.state.best_index()].clone();
+        for ((position, velocity), personal) in self
+            .positions
+            .iter_mut()
+            .zip(&mut self.velocities)
+            .zip(&self.state.keys)
+        {
+            for k in 0..position.len() {
+                let (r1, r2) = (rng.gen::<f64>(), rng.gen::<f64>());
+                velocity[k] = (inertia * velocity[k]
+                    + cognitive * r1 * (personal[k] - position[k])
+                    + social * r2 * (leader[k] - position[k]))
+                    .clamp(-MAX_VELOCITY, MAX_VELOCITY);
+                position[k] += velocity[k];
+            }
+        }
+        let values = self.state.evaluate(&self.positions);
+        for (idx, value) in values.into_iter().enumerate() {
+            if value <= self.state.fitness[idx] {
+                self.state.keys[idx].clone_from(&self.positions[idx]);
+                self.state.fitness[idx] = value;
+            }
+        }
+    }
+}
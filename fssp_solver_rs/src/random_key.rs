@@ -0,0 +1,52 @@
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::local_search::{self, SearchLimits};
+use rand::Rng;
+
+/// Representação por chaves aleatórias: cada tarefa recebe uma chave real, e a
+/// sequência é obtida ordenando as tarefas pela chave em ordem decrescente (regra
+/// LOV, largest order value). Qualquer vetor de chaves decodifica em uma
+/// permutação válida, o que permite usar operadores contínuos (diferença de
+/// vetores, cruzamento uniforme, velocidades) sobre sequências de tarefas.
+pub fn decode(keys: &[f64]) -> Vec<Job> {
+    let mut sequence: Vec<Job> = (0..keys.len() as Job).collect();
+    // Ordenação estável: chaves iguais mantêm a ordem dos índices das tarefas.
+    sequence.sort_by(|&a, &b| keys[b as usize].total_cmp(&keys[a as usize]));
+    sequence
+}
+
+/// Chaves em [0, 1) que decodificam em `sequence`: a tarefa na posição `p` de uma
+/// sequência de `n` tarefas recebe a chave `1 - (p + 1) / (n + 1)`.
+pub fn encode(sequence: &[Job]) -> Vec<f64> {
+    let n = sequence.len();
+    let mut keys = vec![0.0; n];
+    for (position, &job) in sequence.iter().enumerate() {
+        keys[job as usize] = 1.0 - (position + 1) as f64 / (n + 1) as f64;
+    }
+    keys
+}
+
+/// Reatribui as chaves existentes para que decodifiquem em `sequence` (por exemplo,
+/// após uma busca local na sequência decodificada), preservando o conjunto de
+/// valores: a maior chave vai para a primeira tarefa, e assim por diante.
+pub fn reorder(keys: &mut [f64], sequence: &[Job]) {
+    let mut values = keys.to_vec();
+    values.sort_by(|a, b| b.total_cmp(a));
+    for (&job, value) in sequence.iter().zip(values) {
+        keys[job as usize] = value;
+    }
+}
+
+/// Refina a sequência decodificada das chaves por busca local por inserção e
+/// reordena as chaves para a sequência melhorada (aprendizado lamarckiano).
+/// Retorna o valor da função objetivo da sequência resultante.
+pub fn improve(instance: &FSSPInstance, keys: &mut [f64], limits: &SearchLimits) -> Time {
+    let mut sequence = decode(keys);
+    let value = local_search::insertion_descent_limited(instance, &mut sequence, limits);
+    reorder(keys, &sequence);
+    value
+}
+
+/// Vetor de `n` chaves uniformes em [0, 1).
+pub fn random_keys<R: Rng>(n: usize, rng: &mut R) -> Vec<f64> {
+    (0..n).map(|_| rng.gen::<f64>()).collect()
+}
@@ -0,0 +1,625 @@
+//! Registro dos algoritmos disponíveis: cada entrada associa o nome usado em
+//! `--algorithm` (e nos arquivos de configuração) à fábrica que constrói o solver,
+//! à sua descrição e às opções que ele utiliza. A linha de comando, a interface C, o
+//! serviço HTTP e a interface WebAssembly constroem os solvers por este registro.
+
+use crate::ant_colony::{AcoParams, AntColony};
+use crate::aos::AdaptiveOperators;
+use crate::branch_and_bound::BranchAndBound;
+use crate::brkga::{Brkga, BrkgaParams};
+use crate::config::SolverConfig;
+use crate::crossover::Crossover;
+use crate::differential_evolution::DifferentialEvolution;
+use crate::error::{FsspError, Result};
+use crate::fitness_cache;
+use crate::fssp_core::{FSSPInstance, Job};
+use crate::grasp::Grasp;
+use crate::heuristics::{ConstructiveHeuristic, ConstructiveSolver};
+use crate::hyper_heuristic::{self, HhParams, HyperHeuristic};
+use crate::ils::{IlsParams, IteratedLocalSearch};
+use crate::island::{IslandModel, Topology};
+use crate::iterated_greedy::{IgParams, IteratedGreedy};
+use crate::local_search::{LocalSearch, SearchLimits};
+use crate::portfolio::{PortfolioModel, PortfolioSolver};
+use crate::pso::{ParticleSwarm, PsoParams};
+use crate::simulated_annealing::{SaParams, SimulatedAnnealing};
+use crate::solver::{Algorithm, MemeticAlgorithm, MemeticParams, Solver};
+use crate::tabu_search::{TabuParams, TabuSearch};
+use std::time::Duration;
+
+/// Constrói o solver de um algoritmo para a instância, com os parâmetros da
+/// configuração (padrões da linha de comando nos campos ausentes) e as soluções
+/// iniciais fornecidas, já validadas contra a instância.
+pub type SolverFactory = fn(&FSSPInstance, &SolverConfig, Vec<Vec<Job>>) -> Result<Box<dyn Solver>>;
+
+/// Algoritmo registrado.
+#[derive(Clone, Copy)]
+pub struct AlgorithmEntry {
+    pub algorithm: Algorithm,      // Variante correspondente de `Algorithm`.
+    pub name: &'static str,        // Nome usado em `--algorithm`.
+    pub description: &'static str, // Descrição curta do algoritmo.
+    pub params: &'static [&'static str], // Opções da linha de comando que o algoritmo usa.
+    pub factory: SolverFactory,    // Fábrica do solver.
+}
+
+impl AlgorithmEntry {
+    /// Constrói o solver do algoritmo (veja `SolverFactory`).
+    pub fn build(
+        &self,
+        instance: &FSSPInstance,
+        config: &SolverConfig,
+        initial_solutions: Vec<Vec<Job>>,
+    ) -> Result<Box<dyn Solver>> {
+        (self.factory)(instance, config, initial_solutions)
+    }
+}
+
+/// Opções do algoritmo memético (inclusive as da ILS usada como busca local).
+const MEMETIC_PARAMS: &[&str] = &[
+    "--ma.population-size",
+    "--ma.mutation-rate",
+    "--ma.local-search-rate",
+    "--ma.selection",
+    "--ma.crossover",
+    "--ma.mutation",
+    "--ma.local-search",
+    "--ma.replacement",
+    "--ma.elite-count",
+    "--ma.fitness-cache",
+    "--ma.adaptive",
+    "--ma.operator-stats",
+    "--ma.aos",
+    "--ma.aos-crossovers",
+    "--ma.aos-local-searches",
+    "--ma.ls-max-moves",
+    "--ma.ls-max-millis",
+    "--ma.ls-first-improvement",
+    "--ma.ls-strategy",
+    "--ma.ls-random-order",
+    "--ma.ls-dont-look-bits",
+    "--ma.ls-policy",
+    "--ma.restart-after",
+    "--ma.restart-elite",
+    "--neh.tie-breaking",
+    "--ma.path-relinking-every",
+    "--ma.path-relinking-elites",
+    "--ma.two-phase",
+    "--ma.exploration-mutation-rate",
+    "--ma.exploration-local-search-rate",
+    "--ma.intensification-elite",
+    "--ma.vns-iterations",
+    "--ils.iterations",
+    "--ils.strength",
+    "--ils.acceptance",
+    "--ils.temperature",
+    "--ils.restart-after",
+    "--ils.adaptive-perturbation",
+    "--ma.islands",
+    "--ma.migration-interval",
+    "--ma.migration-rate",
+    "--ma.topology",
+];
+
+/// Algoritmos disponíveis, na ordem em que são listados.
+pub static ALGORITHMS: [AlgorithmEntry; 16] = [
+    AlgorithmEntry {
+        algorithm: Algorithm::Memetic,
+        name: "memetic",
+        description: "Algoritmo memético (opcionalmente em modelo de ilhas)",
+        params: MEMETIC_PARAMS,
+        factory: _memetic,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Ig,
+        name: "ig",
+        description: "Iterated Greedy (destruição e reconstrução no estilo NEH)",
+        params: &[
+            "--ig.destruction-size",
+            "--ig.temperature",
+            "--ig.adaptive-perturbation",
+        ],
+        factory: _iterated_greedy,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Sa,
+        name: "sa",
+        description: "Recozimento simulado na vizinhança de inserção",
+        params: &["--sa.initial-temp", "--sa.cooling-rate"],
+        factory: _simulated_annealing,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Tabu,
+        name: "tabu",
+        description: "Busca tabu na vizinhança de inserção",
+        params: &["--tabu.tenure", "--tabu.candidates"],
+        factory: _tabu_search,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Ils,
+        name: "ils",
+        description: "Busca Local Iterada",
+        params: &[
+            "--ils.strength",
+            "--ils.acceptance",
+            "--ils.temperature",
+            "--ils.restart-after",
+            "--ils.adaptive-perturbation",
+        ],
+        factory: _iterated_local_search,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Aco,
+        name: "aco",
+        description: "Colônia de formigas MAX-MIN",
+        params: &["--aco.ants", "--aco.evaporation", "--aco.exploitation"],
+        factory: _ant_colony,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Grasp,
+        name: "grasp",
+        description: "GRASP com alfa reativo",
+        params: &[],
+        factory: _grasp,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::BranchAndBound,
+        name: "bb",
+        description: "Branch-and-bound exato (instâncias pequenas)",
+        params: &[],
+        factory: _branch_and_bound,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Differential,
+        name: "de",
+        description: "Evolução diferencial sobre chaves aleatórias",
+        params: &["--de.population-size", "--de.scale", "--de.crossover-rate"],
+        factory: _differential_evolution,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Brkga,
+        name: "brkga",
+        description: "Algoritmo genético de chaves aleatórias viciadas",
+        params: &[
+            "--brkga.population-size",
+            "--brkga.elite",
+            "--brkga.mutants",
+            "--brkga.bias",
+        ],
+        factory: _brkga,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Pso,
+        name: "pso",
+        description: "Enxame de partículas sobre chaves aleatórias",
+        params: &[
+            "--pso.population-size",
+            "--pso.inertia",
+            "--pso.cognitive",
+            "--pso.social",
+        ],
+        factory: _particle_swarm,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Neh,
+        name: "neh",
+        description: "Heurística construtiva NEH, sem busca local",
+        params: &["--neh.tie-breaking"],
+        factory: _neh,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Cds,
+        name: "cds",
+        description: "Heurística construtiva CDS, sem busca local",
+        params: &[],
+        factory: _cds,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Palmer,
+        name: "palmer",
+        description: "Heurística construtiva de Palmer, sem busca local",
+        params: &[],
+        factory: _palmer,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Hh,
+        name: "hh",
+        description: "Hiper-heurística: fatias dos demais algoritmos escolhidas por UCB",
+        params: &["--hh.algorithms", "--hh.slice-generations", "--hh.strategy"],
+        factory: _hyper_heuristic,
+    },
+    AlgorithmEntry {
+        algorithm: Algorithm::Auto,
+        name: "auto",
+        description:
+            "Portfólio: algoritmo e parâmetros escolhidos pelas características da instância",
+        params: &["--portfolio.model"],
+        factory: _portfolio,
+    },
+];
+
+/// Procura o algoritmo pelo nome (sem distinção de maiúsculas).
+pub fn find(name: &str) -> Option<&'static AlgorithmEntry> {
+    ALGORITHMS
+        .iter()
+        .find(|entry| entry.name.eq_ignore_ascii_case(name))
+}
+
+/// Entrada do registro de um algoritmo.
+pub fn entry(algorithm: Algorithm) -> &'static AlgorithmEntry {
+    ALGORITHMS
+        .iter()
+        .find(|entry| entry.algorithm == algorithm)
+        .expect("todo algoritmo possui uma entrada no registro")
+}
+
+/// Nomes dos algoritmos registrados.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    ALGORITHMS.iter().map(|entry| entry.name)
+}
+
+/// Número de gerações (ou iterações) configurado.
+fn _generations(config: &SolverConfig) -> usize {
+    config.max_generations.unwrap_or(100)
+}
+
+/// Melhor das soluções iniciais fornecidas, ponto de partida dos algoritmos de
+/// trajetória.
+fn _best_initial(instance: &FSSPInstance, initial_solutions: Vec<Vec<Job>>) -> Option<Vec<Job>> {
+    initial_solutions
+        .into_iter()
+        .min_by_key(|sequence| instance.evaluate(sequence))
+}
+
+fn _memetic(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let ma = &config.ma;
+    let build = |instance| -> Result<MemeticAlgorithm> {
+        let defaults = MemeticParams::default();
+        let params = MemeticParams {
+            population_size: ma.population_size.unwrap_or(defaults.population_size),
+            generations: _generations(config),
+            mutation_rate: ma.mutation_rate.unwrap_or(defaults.mutation_rate),
+            local_search_rate: ma.local_search_rate.unwrap_or(defaults.local_search_rate),
+        };
+        Ok(MemeticAlgorithm::new(instance, params)?
+            .with_selection(ma.selection.unwrap_or_default())
+            .with_crossover(ma.crossover.unwrap_or(Crossover::Ox))
+            .with_mutation(ma.mutation.clone().unwrap_or_default())
+            .with_local_search(ma.local_search.unwrap_or(LocalSearch::Swap))
+            .with_local_search_policy(ma.ls_policy.unwrap_or_default())
+            .with_replacement(ma.replacement.unwrap_or_default())
+            .with_elite_count(ma.elite_count.unwrap_or(1))
+            .with_fitness_cache(ma.fitness_cache.unwrap_or(fitness_cache::DEFAULT_CAPACITY))
+            .with_ils_params(config.ils.params())
+            .with_vns_iterations(ma.vns_iterations.unwrap_or(10))
+            .with_local_search_limits(SearchLimits {
+                max_moves: ma.ls_max_moves,
+                max_time: ma.ls_max_millis.map(Duration::from_millis),
+                deadline: None,
+                max_evaluations: None,
+                first_improvement: ma.ls_first_improvement.unwrap_or(false),
+                strategy: ma.ls_strategy.unwrap_or_default(),
+                random_order: ma.ls_random_order.unwrap_or(false),
+                dont_look_bits: ma.ls_dont_look_bits.unwrap_or(false),
+            })
+            .with_adaptive_mutation(ma.adaptive.unwrap_or(false))
+            .with_operator_stats(ma.operator_stats.unwrap_or(false))
+            .with_adaptive_operators(ma.aos.map(|strategy| {
+                AdaptiveOperators::new(
+                    strategy,
+                    ma.aos_crossovers
+                        .clone()
+                        .unwrap_or_else(|| Crossover::ALL.to_vec()),
+                    ma.aos_local_searches
+                        .clone()
+                        .unwrap_or_else(|| LocalSearch::ALL.to_vec()),
+                )
+            }))
+            .with_restart(ma.restart_after, ma.restart_elite.unwrap_or(2))
+            .with_neh_tie_breaking(config.neh.tie_breaking.unwrap_or_default())
+            .with_path_relinking(
+                ma.path_relinking_every,
+                ma.path_relinking_elites.unwrap_or(2),
+            )
+            .with_phase_schedule(ma.phase_schedule())?
+            .with_initial_solutions(initial_solutions.clone()))
+    };
+
+    let islands = ma.islands.unwrap_or(1);
+    if islands > 1 {
+        // As ilhas compartilham a reamostragem adaptativa entre si, mas não com
+        // outras execuções que usem a mesma instância.
+        let model = instance.clone();
+        return Ok(Box::new(
+            IslandModel::new(
+                (0..islands)
+                    .map(|_| build(model.share_sampling()))
+                    .collect::<Result<_>>()?,
+                ma.migration_interval.unwrap_or(10),
+                ma.migration_rate.unwrap_or(0.05),
+                ma.topology.unwrap_or(Topology::Ring),
+            )
+            .with_sharing(!config.no_sharing.unwrap_or(false)),
+        ));
+    }
+    Ok(Box::new(build(instance.clone())?))
+}
+
+fn _iterated_greedy(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = IgParams::default();
+    let mut ig = IteratedGreedy::new(
+        instance.clone(),
+        IgParams {
+            iterations: _generations(config),
+            destruction_size: config
+                .ig
+                .destruction_size
+                .unwrap_or(defaults.destruction_size),
+            temperature: config.ig.temperature.unwrap_or(defaults.temperature),
+            adaptive_perturbation: config.ig.adaptive_perturbation.unwrap_or(false),
+        },
+    );
+    if let Some(best) = _best_initial(instance, initial_solutions) {
+        ig = ig.with_initial_solution(best);
+    }
+    Ok(Box::new(ig))
+}
+
+fn _simulated_annealing(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = SaParams::default();
+    let mut sa = SimulatedAnnealing::new(
+        instance.clone(),
+        SaParams {
+            iterations: _generations(config),
+            initial_temperature: config
+                .sa
+                .initial_temp
+                .unwrap_or(defaults.initial_temperature),
+            cooling_rate: config.sa.cooling_rate.unwrap_or(defaults.cooling_rate),
+        },
+    );
+    if let Some(best) = _best_initial(instance, initial_solutions) {
+        sa = sa.with_initial_solution(best);
+    }
+    Ok(Box::new(sa))
+}
+
+fn _tabu_search(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = TabuParams::default();
+    let mut tabu = TabuSearch::new(
+        instance.clone(),
+        TabuParams {
+            iterations: _generations(config),
+            tenure: config.tabu.tenure.unwrap_or(defaults.tenure),
+            candidates: config.tabu.candidates.unwrap_or(defaults.candidates),
+        },
+    );
+    if let Some(best) = _best_initial(instance, initial_solutions) {
+        tabu = tabu.with_initial_solution(best);
+    }
+    Ok(Box::new(tabu))
+}
+
+fn _iterated_local_search(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let mut ils = IteratedLocalSearch::new(
+        instance.clone(),
+        IlsParams {
+            iterations: _generations(config),
+            ..config.ils.params()
+        },
+    );
+    if let Some(best) = _best_initial(instance, initial_solutions) {
+        ils = ils.with_initial_solution(best);
+    }
+    Ok(Box::new(ils))
+}
+
+fn _ant_colony(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = AcoParams::default();
+    Ok(Box::new(
+        AntColony::new(
+            instance.clone(),
+            AcoParams {
+                iterations: _generations(config),
+                ants: config.aco.ants.unwrap_or(defaults.ants),
+                evaporation: config.aco.evaporation.unwrap_or(defaults.evaporation),
+                exploitation: config.aco.exploitation.unwrap_or(defaults.exploitation),
+            },
+        )
+        .with_initial_solutions(initial_solutions),
+    ))
+}
+
+fn _grasp(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(
+        Grasp::new(instance.clone(), _generations(config))
+            .with_initial_solutions(initial_solutions),
+    ))
+}
+
+fn _branch_and_bound(
+    instance: &FSSPInstance,
+    _config: &SolverConfig,
+    _initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    if !instance.objective.function().separable() {
+        return Err(FsspError::Config(format!(
+            "o branch-and-bound não suporta o objetivo '{}'",
+            instance.objective
+        )));
+    }
+    Ok(Box::new(BranchAndBound::new(instance.clone())))
+}
+
+fn _differential_evolution(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(
+        DifferentialEvolution::new(
+            instance.clone(),
+            config.de.population_size.unwrap_or(100),
+            _generations(config),
+        )
+        .with_params(
+            config.de.scale.unwrap_or(0.5),
+            config.de.crossover_rate.unwrap_or(0.9),
+        )
+        .with_initial_solutions(initial_solutions),
+    ))
+}
+
+fn _brkga(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = BrkgaParams::default();
+    Ok(Box::new(
+        Brkga::new(
+            instance.clone(),
+            config.brkga.population_size.unwrap_or(100),
+            _generations(config),
+        )
+        .with_params(BrkgaParams {
+            elite_fraction: config.brkga.elite.unwrap_or(defaults.elite_fraction),
+            mutant_fraction: config.brkga.mutants.unwrap_or(defaults.mutant_fraction),
+            elite_bias: config.brkga.bias.unwrap_or(defaults.elite_bias),
+        })
+        .with_initial_solutions(initial_solutions),
+    ))
+}
+
+fn _particle_swarm(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = PsoParams::default();
+    Ok(Box::new(
+        ParticleSwarm::new(
+            instance.clone(),
+            config.pso.population_size.unwrap_or(100),
+            _generations(config),
+        )
+        .with_params(PsoParams {
+            inertia: config.pso.inertia.unwrap_or(defaults.inertia),
+            cognitive: config.pso.cognitive.unwrap_or(defaults.cognitive),
+            social: config.pso.social.unwrap_or(defaults.social),
+        })
+        .with_initial_solutions(initial_solutions),
+    ))
+}
+
+fn _neh(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    _initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(ConstructiveSolver::new(
+        instance.clone(),
+        ConstructiveHeuristic::Neh(config.neh.tie_breaking.unwrap_or_default()),
+    )))
+}
+
+fn _cds(
+    instance: &FSSPInstance,
+    _config: &SolverConfig,
+    _initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(ConstructiveSolver::new(
+        instance.clone(),
+        ConstructiveHeuristic::Cds,
+    )))
+}
+
+fn _palmer(
+    instance: &FSSPInstance,
+    _config: &SolverConfig,
+    _initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(ConstructiveSolver::new(
+        instance.clone(),
+        ConstructiveHeuristic::Palmer,
+    )))
+}
+
+fn _hyper_heuristic(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let defaults = HhParams::default();
+    Ok(Box::new(
+        HyperHeuristic::new(
+            instance.clone(),
+            config.clone(),
+            config
+                .hh
+                .algorithms
+                .clone()
+                .unwrap_or_else(|| hyper_heuristic::DEFAULT_ALGORITHMS.to_vec()),
+            HhParams {
+                slices: _generations(config),
+                slice_generations: config
+                    .hh
+                    .slice_generations
+                    .unwrap_or(defaults.slice_generations),
+                strategy: config.hh.strategy.unwrap_or(defaults.strategy),
+            },
+        )?
+        .with_initial_solutions(initial_solutions),
+    ))
+}
+
+fn _portfolio(
+    instance: &FSSPInstance,
+    config: &SolverConfig,
+    initial_solutions: Vec<Vec<Job>>,
+) -> Result<Box<dyn Solver>> {
+    let model = match &config.portfolio.model {
+        #[cfg(feature = "fs")]
+        Some(path) => PortfolioModel::load(path)?,
+        #[cfg(not(feature = "fs"))]
+        Some(_) => {
+            return Err(crate::error::FsspError::Config(
+                "o modelo do portfólio exige a feature `fs`".to_string(),
+            ))
+        }
+        None => PortfolioModel::builtin(),
+    };
+    Ok(Box::new(PortfolioSolver::new(
+        instance,
+        config,
+        &model,
+        initial_solutions,
+    )?))
+}
@@ -0,0 +1,115 @@
+//! Reescalonamento de um escalonamento em execução após uma perturbação (quebra de
+//! máquina, pedido urgente). As tarefas que já iniciaram na primeira máquina no
+//! instante atual ficam congeladas na sua ordem (um `FixedPrefix`), as demais só podem
+//! começar a partir desse instante, e as máquinas paradas ficam indisponíveis até o
+//! reparo. Operações concluídas ou em andamento mantêm os seus instantes, exceto as
+//! interrompidas por uma quebra, que recomeçam após o reparo.
+
+use crate::error::{FsspError, Result};
+use crate::fixed_prefix::FixedPrefix;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+
+/// Máquina parada no instante do reescalonamento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown {
+    pub machine: usize, // Máquina parada (a partir de 0).
+    pub until: Time,    // Instante previsto para o fim do reparo.
+}
+
+impl Breakdown {
+    /// Interpreta uma parada no formato `máquina:instante` (ex: `2:140.5`), com o
+    /// instante nas unidades da instância.
+    pub fn parse(text: &str, instance: &FSSPInstance) -> Result<Self> {
+        let error = || {
+            FsspError::Config(format!(
+                "parada de máquina inválida '{}': use o formato máquina:instante (ex: 2:140)",
+                text
+            ))
+        };
+        let (machine, until) = text.split_once(':').ok_or_else(error)?;
+        let machine: usize = machine.trim().parse().map_err(|_| error())?;
+        if machine >= instance.n_machines {
+            return Err(FsspError::Config(format!(
+                "máquina {} da parada fora do intervalo da instância (0 a {})",
+                machine,
+                instance.n_machines - 1
+            )));
+        }
+        Ok(Breakdown {
+            machine,
+            until: instance.parse_time_value(until.trim())?,
+        })
+    }
+}
+
+/// Problema de reescalonamento: a instância com as perturbações e as tarefas
+/// congeladas.
+#[derive(Debug, Clone)]
+pub struct Rescheduling {
+    pub instance: FSSPInstance, // Instância com as paradas e as liberações a partir do instante atual.
+    pub prefix: FixedPrefix,    // Tarefas já iniciadas, na ordem do escalonamento atual.
+    pub current: Vec<Job>,      // Escalonamento atual completo, usado como solução inicial.
+}
+
+impl Rescheduling {
+    /// Prepara o reescalonamento de `schedule` (sequência das tarefas a partir de 0)
+    /// no instante `now`. Tarefas da instância ausentes da sequência (ex: pedidos
+    /// urgentes) ainda não iniciaram e entram no final do escalonamento atual.
+    pub fn new(
+        instance: &FSSPInstance,
+        schedule: &[Job],
+        now: Time,
+        breakdowns: &[Breakdown],
+    ) -> Result<Self> {
+        // Escalonamento atual completo: a sequência seguida das tarefas ausentes.
+        let mut scheduled = vec![false; instance.n_jobs];
+        for &job in schedule {
+            if let Some(seen) = scheduled.get_mut(job as usize) {
+                *seen = true;
+            }
+        }
+        let mut current = schedule.to_vec();
+        current.extend((0..instance.n_jobs as Job).filter(|&job| !scheduled[job as usize]));
+        instance.validate_sequence(&current)?;
+
+        // Na permutação, as tarefas iniciadas na primeira máquina formam um prefixo.
+        let decoded = instance.decode_schedule(schedule);
+        let started = schedule
+            .iter()
+            .take_while(|&&job| decoded.start_times[job as usize][0] < now)
+            .count();
+        if started == instance.n_jobs {
+            return Err(FsspError::Config(format!(
+                "todas as tarefas já iniciaram no instante {}: não há o que reescalonar",
+                instance.time_value(now)
+            )));
+        }
+
+        let mut disrupted = instance.clone();
+        for breakdown in breakdowns {
+            if breakdown.until <= now {
+                return Err(FsspError::Config(format!(
+                    "o reparo da máquina {} ({}) deve terminar após o instante atual ({})",
+                    breakdown.machine,
+                    instance.time_value(breakdown.until),
+                    instance.time_value(now)
+                )));
+            }
+            disrupted.add_unavailability(breakdown.machine, now, breakdown.until);
+        }
+        let mut release_dates: Vec<Time> = (0..instance.n_jobs)
+            .map(|job| instance.release_date(job))
+            .collect();
+        for &job in &current[started..] {
+            let release = &mut release_dates[job as usize];
+            *release = (*release).max(now);
+        }
+        disrupted.release_dates = Some(release_dates);
+
+        Ok(Rescheduling {
+            prefix: FixedPrefix::new(&disrupted, current[..started].to_vec())?,
+            instance: disrupted,
+            current,
+        })
+    }
+}
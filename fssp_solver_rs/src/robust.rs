@@ -0,0 +1,155 @@
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{FSSPInstance, Time};
+use crate::stochastic::AdaptiveSampling;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Critério de agregação do valor da função objetivo entre os cenários.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RobustCriterion {
+    #[default]
+    WorstCase, // Pior valor entre os cenários.
+    Expected, // Média dos cenários, considerados equiprováveis.
+}
+
+impl FromStr for RobustCriterion {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "worst" | "worst-case" => Ok(RobustCriterion::WorstCase),
+            "expected" | "mean" => Ok(RobustCriterion::Expected),
+            _ => Err(format!(
+                "Critério robusto desconhecido '{}' (use worst ou expected)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for RobustCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RobustCriterion::WorstCase => "worst",
+            RobustCriterion::Expected => "expected",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Incerteza nos tempos de processamento, representada por cenários. Com ela, a
+/// avaliação de uma sequência (`FSSPInstance::evaluate`) calcula a função objetivo
+/// em cada cenário e os agrega segundo o critério. Na simulação estocástica
+/// (`stochastic`), os cenários são replicações sorteadas e a média é estimada com
+/// reamostragem adaptativa.
+#[derive(Debug, Clone)]
+pub struct Uncertainty {
+    pub scenarios: Vec<Vec<Vec<Time>>>, // Tempos [cenário][tarefa][máquina].
+    pub criterion: RobustCriterion,     // Agregação dos valores dos cenários.
+    pub sampling: Option<AdaptiveSampling>, // Reamostragem adaptativa, se simulado.
+}
+
+impl Uncertainty {
+    /// Agrega os valores da função objetivo nos cenários: o maior deles ou a média
+    /// arredondada (estimada apenas com as replicações necessárias, se simulado).
+    pub fn aggregate(&self, values: impl Iterator<Item = Time>) -> Time {
+        if let Some(sampling) = &self.sampling {
+            return sampling.estimate(values, self.scenarios.len());
+        }
+        match self.criterion {
+            RobustCriterion::WorstCase => values.max().unwrap_or(0),
+            RobustCriterion::Expected => {
+                let (sum, count) = values.fold((0u128, 0u128), |(sum, count), value| {
+                    (sum + value as u128, count + 1)
+                });
+                (sum + count / 2).checked_div(count).unwrap_or(0) as Time
+            }
+        }
+    }
+}
+
+/// Cenários discretos: os tempos da instância e os de cada instância de `scenarios`,
+/// que devem ter as mesmas dimensões. Os tempos são convertidos para o maior número
+/// de casas decimais entre as instâncias (a instância é reescalada se necessário).
+pub fn scenario_uncertainty(
+    instance: &mut FSSPInstance,
+    mut scenarios: Vec<FSSPInstance>,
+    criterion: RobustCriterion,
+) -> Result<Uncertainty> {
+    let decimals = scenarios
+        .iter()
+        .map(|s| s.time_decimals)
+        .fold(instance.time_decimals, u32::max);
+    instance.rescale(decimals)?;
+    let mut times = vec![instance.processing_times.clone()];
+    for scenario in &mut scenarios {
+        _check_dimensions(instance, scenario)?;
+        scenario.rescale(decimals)?;
+        times.push(std::mem::take(&mut scenario.processing_times));
+    }
+    Ok(Uncertainty {
+        scenarios: times,
+        criterion,
+        sampling: None,
+    })
+}
+
+/// Tempos em intervalos: cada tempo varia entre o da instância (mínimo) e o de
+/// `upper` (máximo). São sorteados `samples` cenários com tempos uniformes em cada
+/// intervalo, sempre incluindo os dois extremos (todos os tempos mínimos e todos os
+/// máximos). Como o makespan não diminui quando um tempo aumenta, o cenário dos
+/// máximos já é o pior caso do makespan.
+pub fn interval_uncertainty<R: Rng>(
+    instance: &mut FSSPInstance,
+    mut upper: FSSPInstance,
+    samples: usize,
+    criterion: RobustCriterion,
+    rng: &mut R,
+) -> Result<Uncertainty> {
+    _check_dimensions(instance, &upper)?;
+    let decimals = instance.time_decimals.max(upper.time_decimals);
+    instance.rescale(decimals)?;
+    upper.rescale(decimals)?;
+    let lower = &instance.processing_times;
+    if let Some((job, machine)) = (0..instance.n_jobs)
+        .flat_map(|job| (0..instance.n_machines).map(move |machine| (job, machine)))
+        .find(|&(job, machine)| upper.processing_times[job][machine] < lower[job][machine])
+    {
+        return Err(FsspError::Instance(format!(
+            "o tempo máximo da tarefa {} na máquina {} é menor que o mínimo",
+            job, machine
+        )));
+    }
+
+    let mut scenarios = vec![lower.clone(), upper.processing_times.clone()];
+    while scenarios.len() < samples {
+        let scenario = lower
+            .iter()
+            .zip(&upper.processing_times)
+            .map(|(low, high)| {
+                low.iter()
+                    .zip(high)
+                    .map(|(&low, &high)| rng.gen_range(low..=high))
+                    .collect()
+            })
+            .collect();
+        scenarios.push(scenario);
+    }
+    Ok(Uncertainty {
+        scenarios,
+        criterion,
+        sampling: None,
+    })
+}
+
+/// Verifica se o cenário tem as dimensões da instância.
+fn _check_dimensions(instance: &FSSPInstance, scenario: &FSSPInstance) -> Result<()> {
+    if (scenario.n_jobs, scenario.n_machines) != (instance.n_jobs, instance.n_machines) {
+        return Err(FsspError::Instance(format!(
+            "o cenário tem {}x{} tarefas e máquinas, esperadas {}x{}",
+            scenario.n_jobs, scenario.n_machines, instance.n_jobs, instance.n_machines
+        )));
+    }
+    Ok(())
+}
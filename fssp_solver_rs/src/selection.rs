@@ -0,0 +1,113 @@
+use crate::fssp_core::Time;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::index;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Tamanho padrão do torneio.
+pub const DEFAULT_TOURNAMENT_SIZE: usize = 3;
+
+/// Métodos de seleção de pais do algoritmo memético (aptidão menor é melhor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    Tournament(usize), // Torneio entre `k` indivíduos distintos sorteados.
+    Rank,              // Ranking linear: probabilidade proporcional a `N - posição`.
+    Roulette,          // Roleta: probabilidade proporcional a `pior - aptidão + 1`.
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Selection::Tournament(DEFAULT_TOURNAMENT_SIZE)
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        let (name, size) = match lower.split_once(':') {
+            Some((name, size)) => (name, Some(size)),
+            None => (lower.as_str(), None),
+        };
+        match (name, size) {
+            ("tournament", None) => Ok(Selection::default()),
+            ("tournament", Some(size)) => match size.parse::<usize>() {
+                Ok(k) if k >= 1 => Ok(Selection::Tournament(k)),
+                _ => Err(format!(
+                    "Tamanho de torneio inválido '{}' (use um inteiro positivo, ex: tournament:5)",
+                    size
+                )),
+            },
+            ("rank", None) => Ok(Selection::Rank),
+            ("roulette", None) => Ok(Selection::Roulette),
+            _ => Err(format!(
+                "Seleção desconhecida '{}' (use tournament[:k], rank ou roulette)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selection::Tournament(k) => write!(f, "tournament:{}", k),
+            Selection::Rank => write!(f, "rank"),
+            Selection::Roulette => write!(f, "roulette"),
+        }
+    }
+}
+
+impl Selection {
+    /// Prepara o sorteio de pais para uma população com as aptidões `fitness`. As
+    /// probabilidades do ranking e da roleta são calculadas uma única vez.
+    pub fn sampler<'a>(&self, fitness: &'a [Time]) -> Sampler<'a> {
+        let weights: Vec<f64> = match self {
+            Selection::Tournament(k) => {
+                return Sampler::Tournament {
+                    fitness,
+                    size: (*k).clamp(1, fitness.len().max(1)),
+                }
+            }
+            Selection::Rank => {
+                let mut order: Vec<usize> = (0..fitness.len()).collect();
+                order.sort_by_key(|&idx| fitness[idx]);
+                let n = fitness.len();
+                let mut weights = vec![0.0; n];
+                for (position, &idx) in order.iter().enumerate() {
+                    weights[idx] = (n - position) as f64;
+                }
+                weights
+            }
+            Selection::Roulette => {
+                let worst = fitness.iter().copied().max().unwrap_or(0);
+                fitness.iter().map(|&f| (worst - f) as f64 + 1.0).collect()
+            }
+        };
+        Sampler::Weighted(WeightedIndex::new(weights).expect("população vazia na seleção"))
+    }
+}
+
+/// Sorteador de pais preparado para uma geração (veja `Selection::sampler`).
+pub enum Sampler<'a> {
+    Tournament { fitness: &'a [Time], size: usize }, // Torneio de `size` indivíduos.
+    Weighted(WeightedIndex<f64>),                    // Sorteio com pesos fixos.
+}
+
+impl Sampler<'_> {
+    /// Sorteia o índice de um pai.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        match self {
+            Sampler::Tournament { fitness, size } => {
+                // O vencedor é o indivíduo com o menor makespan.
+                index::sample(rng, fitness.len(), *size)
+                    .into_iter()
+                    .min_by_key(|&idx| fitness[idx])
+                    .unwrap()
+            }
+            Sampler::Weighted(distribution) => distribution.sample(rng),
+        }
+    }
+}
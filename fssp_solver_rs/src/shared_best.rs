@@ -0,0 +1,93 @@
+//! Melhor solução global compartilhada entre execuções paralelas (ilhas ou
+//! multi-start): o makespan é mantido em um atômico e cada solução que o melhora é
+//! difundida aos demais participantes por canais, para que possam adotá-la.
+
+use crate::fssp_core::Time;
+use crate::observer::{Observer, Solution};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+/// Melhor makespan publicado e canais de difusão dos participantes.
+#[derive(Debug)]
+pub struct SharedBest {
+    best_makespan: AtomicU64,                  // Melhor makespan publicado.
+    subscribers: Mutex<Vec<Sender<Solution>>>, // Canal de cada participante [id].
+}
+
+impl SharedBest {
+    /// Cria um canal sem participantes e sem solução publicada.
+    pub fn new() -> Arc<Self> {
+        Arc::new(SharedBest {
+            best_makespan: AtomicU64::new(Time::MAX),
+            subscribers: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Melhor makespan publicado até o momento (`Time::MAX` se nenhum).
+    pub fn best_makespan(&self) -> Time {
+        self.best_makespan.load(Ordering::Acquire)
+    }
+
+    /// Registra um novo participante, que passa a receber as soluções publicadas
+    /// pelos demais a partir deste momento.
+    pub fn subscribe(self: &Arc<Self>) -> SharedBestHandle {
+        let (sender, receiver) = mpsc::channel();
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.push(sender);
+        SharedBestHandle {
+            shared: Arc::clone(self),
+            id: subscribers.len() - 1,
+            receiver,
+        }
+    }
+}
+
+/// Participante do canal. Como observador, publica cada nova melhor solução da
+/// execução e entrega a ela, como solução externa, a melhor solução recebida.
+#[derive(Debug)]
+pub struct SharedBestHandle {
+    shared: Arc<SharedBest>,      // Canal compartilhado.
+    id: usize,                    // Índice do participante no canal.
+    receiver: Receiver<Solution>, // Soluções publicadas pelos demais participantes.
+}
+
+impl SharedBestHandle {
+    /// Publica a solução se ela melhorar o melhor makespan global, enviando-a aos
+    /// demais participantes. Retorna `true` se a solução foi publicada.
+    pub fn publish(&self, solution: &Solution) -> bool {
+        let previous = self
+            .shared
+            .best_makespan
+            .fetch_min(solution.makespan, Ordering::AcqRel);
+        if solution.makespan >= previous {
+            return false;
+        }
+        let subscribers = self.shared.subscribers.lock().unwrap();
+        for (id, sender) in subscribers.iter().enumerate() {
+            if id != self.id {
+                // Participantes encerrados descartaram o receptor.
+                let _ = sender.send(solution.clone());
+            }
+        }
+        true
+    }
+
+    /// Melhor solução recebida desde a última consulta, sem bloquear. As demais
+    /// soluções pendentes são descartadas.
+    pub fn receive(&self) -> Option<Solution> {
+        self.receiver
+            .try_iter()
+            .min_by_key(|solution| solution.makespan)
+    }
+}
+
+impl Observer for SharedBestHandle {
+    fn on_new_best(&mut self, solution: &Solution) {
+        self.publish(solution);
+    }
+
+    fn incoming(&mut self) -> Option<Solution> {
+        self.receive()
+    }
+}
@@ -0,0 +1,159 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics;
+use crate::ils;
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::Rng;
+use tracing::{debug, debug_span};
+
+/// Movimentos de inserção avaliados em cada temperatura, por tarefa da instância.
+const MOVES_PER_JOB: usize = 20;
+
+/// Parâmetros do recozimento simulado.
+#[derive(Debug, Clone)]
+pub struct SaParams {
+    pub iterations: usize, // Número de temperaturas (patamares de resfriamento).
+    pub initial_temperature: f64, // Fator da temperatura inicial (escala de Osman e Potts).
+    pub cooling_rate: f64, // Fator de resfriamento geométrico entre patamares.
+}
+
+impl Default for SaParams {
+    fn default() -> Self {
+        SaParams {
+            iterations: 100,
+            initial_temperature: 5.0,
+            cooling_rate: 0.95,
+        }
+    }
+}
+
+/// Recozimento simulado (simulated annealing) na vizinhança de inserção: parte da
+/// solução do NEH e, em cada patamar de temperatura, sorteia `MOVES_PER_JOB * n`
+/// movimentos de inserção, aceitando os piores com a probabilidade de Metropolis.
+/// A temperatura inicial é `initial_temperature * soma(p) / (10 * n * m)` e é
+/// multiplicada por `cooling_rate` ao fim de cada patamar.
+pub struct SimulatedAnnealing {
+    instance: FSSPInstance,             // Instância do problema FSSP.
+    params: SaParams, // Parâmetros do resfriamento (patamares = critério de parada).
+    initial_solution: Option<Vec<Job>>, // Solução inicial fornecida (substitui o NEH).
+    pub best_sequence: Vec<Job>, // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time, // O makespan da melhor sequência encontrada.
+}
+
+impl SimulatedAnnealing {
+    /// Cria uma nova instância do `SimulatedAnnealing`.
+    pub fn new(instance: FSSPInstance, params: SaParams) -> Self {
+        SimulatedAnnealing {
+            instance,
+            params,
+            initial_solution: None,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Define a solução (já validada) a partir da qual a busca é iniciada.
+    pub fn with_initial_solution(mut self, solution: Vec<Job>) -> Self {
+        self.initial_solution = Some(solution);
+        self
+    }
+}
+
+impl Solver for SimulatedAnnealing {
+    fn name(&self) -> &'static str {
+        "sa"
+    }
+
+    /// Executa o recozimento até o número máximo de patamares ou o esgotamento do
+    /// orçamento, verificado também a cada movimento.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let n = self.instance.n_jobs;
+        let mut temperature = ils::temperature(&self.instance, self.params.initial_temperature);
+
+        let mut current = self
+            .initial_solution
+            .clone()
+            .unwrap_or_else(|| heuristics::neh(&self.instance));
+        let mut current_makespan = self.instance.evaluate(&current);
+        self.best_sequence = current.clone();
+        self.best_makespan = current_makespan;
+
+        for iter in 0..self.params.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1, temperature).entered();
+            let moves = if n < 2 { 0 } else { MOVES_PER_JOB * n };
+            for _ in 0..moves {
+                if budget.exhausted(self.instance.evaluations.get()) {
+                    break;
+                }
+                let from = rng.gen_range(0..n);
+                let to = rng.gen_range(0..n - 1);
+                let to = if to >= from { to + 1 } else { to };
+                let job = current.remove(from);
+                current.insert(to, job);
+                let makespan = self.instance.evaluate(&current);
+
+                if makespan <= current_makespan
+                    || rng.gen::<f64>()
+                        < (-((makespan - current_makespan) as f64) / temperature).exp()
+                {
+                    current_makespan = makespan;
+                    if makespan < self.best_makespan {
+                        self.best_makespan = makespan;
+                        self.best_sequence = current.clone();
+                        debug!(makespan, "Nova melhor solução");
+                        observer.on_new_best(&Solution {
+                            sequence: self.best_sequence.clone(),
+                            makespan,
+                        });
+                    }
+                } else {
+                    current.remove(to);
+                    current.insert(from, job);
+                }
+            }
+            temperature *= self.params.cooling_rate;
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_makespan,
+                generation_best: current_makespan,
+                mean_makespan: current_makespan as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -1,172 +1,1144 @@
-use crate::fssp_core::FSSPInstance;
+use crate::aos::AdaptiveOperators;
+use crate::budget::Budget;
+use crate::cancel::{CancellableObserver, CancellationToken};
+use crate::crossover::Crossover;
+use crate::error::{FsspError, Result};
+use crate::fitness_cache::{self, FitnessCache};
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics::{self, ConstructiveHeuristic, NehTieBreaking};
+use crate::ils::{self, IlsParams};
+use crate::local_search::{self, LocalSearch, SearchLimits};
+use crate::mutation::MutationMix;
+use crate::observer::{Control, GenerationStats, NoopObserver, Observer, OperatorStats, Solution};
+use crate::path_relinking;
+use crate::population::Population;
+use crate::registry;
+use crate::selection::Selection;
+use crate::time::Instant;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
-use std::time::Instant;
+use rand::{Rng, SeedableRng};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use tracing::{debug, debug_span, info};
+
+/// Diversidade (distância de Kendall-tau normalizada) abaixo da qual a taxa de mutação é ampliada.
+const DIVERSITY_THRESHOLD: f64 = 0.15;
+/// Taxa de mutação máxima permitida no modo adaptativo.
+const MAX_ADAPTIVE_MUTATION_RATE: f64 = 0.9;
+/// Maior número de pares de indivíduos comparados no cálculo da diversidade; acima
+/// dele, cada indivíduo é comparado apenas com alguns dos seguintes.
+const MAX_DIVERSITY_PAIRS: usize = 2048;
+/// Tentativas de gerar um substituto inédito para cada indivíduo duplicado.
+const DUPLICATE_REPLACEMENT_ATTEMPTS: usize = 10;
+/// Fração da população (os melhores indivíduos) de onde são sorteadas as elites da
+/// religação de caminhos.
+const PATH_RELINKING_POOL_FRACTION: f64 = 0.25;
+
+/// Algoritmos disponíveis para resolver o FSSP. Os nomes, as descrições e as fábricas
+/// dos solvers estão no registro (`registry::ALGORITHMS`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Memetic,        // Algoritmo Memético.
+    Ig,             // Iterated Greedy.
+    Sa,             // Recozimento simulado.
+    Tabu,           // Busca tabu.
+    Aco,            // Colônia de formigas MAX-MIN.
+    Grasp,          // GRASP com alfa reativo.
+    Ils,            // Busca Local Iterada.
+    BranchAndBound, // Branch-and-bound exato (instâncias pequenas).
+    Differential,   // Evolução diferencial sobre chaves aleatórias.
+    Brkga,          // Algoritmo genético de chaves aleatórias viciadas.
+    Pso,            // Otimização por enxame de partículas sobre chaves aleatórias.
+    Neh,            // Heurística construtiva NEH, sem busca local.
+    Cds,            // Heurística construtiva CDS, sem busca local.
+    Palmer,         // Heurística construtiva de Palmer, sem busca local.
+    Hh,             // Hiper-heurística sobre os demais algoritmos.
+    Auto,           // Portfólio: escolha pelas características da instância.
+}
+
+impl FromStr for Algorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        registry::find(s)
+            .map(|entry| entry.algorithm)
+            .ok_or_else(|| {
+                format!(
+                    "Algoritmo desconhecido '{}' (use {}; --list-algorithms descreve cada um)",
+                    s,
+                    registry::names().collect::<Vec<_>>().join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", registry::entry(*self).name)
+    }
+}
+
+/// Estratégia de substituição da população do algoritmo memético.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Replacement {
+    #[default]
+    Generational, // A população inteira é substituída pelos filhos (com elitismo).
+    SteadyState, // Cada filho substitui o pior indivíduo, se for melhor e não for duplicado.
+    Crowding,    // Aglomeração determinística: cada filho disputa a vaga do pai mais parecido.
+}
+
+impl FromStr for Replacement {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "generational" => Ok(Replacement::Generational),
+            "steady-state" => Ok(Replacement::SteadyState),
+            "crowding" => Ok(Replacement::Crowding),
+            _ => Err(format!(
+                "Estratégia de substituição desconhecida '{}' (use generational, steady-state ou crowding)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Replacement::Generational => "generational",
+            Replacement::SteadyState => "steady-state",
+            Replacement::Crowding => "crowding",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Fração padrão dos melhores indivíduos na política de busca local `elite`.
+pub const DEFAULT_LS_ELITE_FRACTION: f64 = 0.1;
+
+/// Política de escolha dos indivíduos que recebem a busca local no algoritmo memético.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LocalSearchPolicy {
+    #[default]
+    Random, // Cada filho é sorteado com a taxa de busca local.
+    Elite(f64), // Filhos que estariam entre a fração `q` dos melhores da população.
+    Unique,     // Filhos ainda não avaliados (ausentes do cache de aptidão).
+}
+
+impl FromStr for LocalSearchPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        let (name, fraction) = match lower.split_once(':') {
+            Some((name, fraction)) => (name, Some(fraction)),
+            None => (lower.as_str(), None),
+        };
+        match (name, fraction) {
+            ("random", None) => Ok(LocalSearchPolicy::Random),
+            ("elite", None) => Ok(LocalSearchPolicy::Elite(DEFAULT_LS_ELITE_FRACTION)),
+            ("elite", Some(fraction)) => match fraction.parse::<f64>() {
+                Ok(q) if q > 0.0 && q <= 1.0 => Ok(LocalSearchPolicy::Elite(q)),
+                _ => Err(format!(
+                    "Fração de elite inválida '{}' (use um número em (0, 1], ex: elite:0.2)",
+                    fraction
+                )),
+            },
+            ("unique", None) => Ok(LocalSearchPolicy::Unique),
+            _ => Err(format!(
+                "Política de busca local desconhecida '{}' (use random, elite[:q] ou unique)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LocalSearchPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalSearchPolicy::Random => write!(f, "random"),
+            LocalSearchPolicy::Elite(q) => write!(f, "elite:{}", q),
+            LocalSearchPolicy::Unique => write!(f, "unique"),
+        }
+    }
+}
+
+/// Interface comum aos algoritmos que resolvem o FSSP.
+pub trait Solver: Send {
+    /// Nome curto do algoritmo (ex: "memetic").
+    fn name(&self) -> &'static str;
+
+    /// Executa o algoritmo notificando o observador durante a execução, até o
+    /// critério de parada do algoritmo ou o esgotamento de `budget`.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    );
+
+    /// Executa o algoritmo até o critério de parada ou o esgotamento do orçamento.
+    fn run(&mut self, start_time: Instant, budget: &Budget) {
+        self.run_with_observer(start_time, budget, &mut NoopObserver);
+    }
+
+    /// Executa o algoritmo até o critério de parada, o esgotamento do orçamento ou o
+    /// cancelamento de `token`, verificado ao final de cada geração.
+    fn run_cancellable(&mut self, start_time: Instant, budget: &Budget, token: &CancellationToken) {
+        self.run_with_observer(
+            start_time,
+            budget,
+            &mut CancellableObserver::new(&mut NoopObserver, token.clone()),
+        );
+    }
+
+    /// Avaliações da função objetivo realizadas até o momento (inclusive as da
+    /// busca local). Consultas ao cache de aptidão não são contadas.
+    fn evaluations(&self) -> u64;
+
+    /// Makespan da melhor solução encontrada.
+    fn best_makespan(&self) -> Time;
+
+    /// Melhor sequência de tarefas encontrada.
+    fn best_sequence(&self) -> &[Job];
+
+    /// População atual: sequência e valor da função objetivo de cada indivíduo, em
+    /// ordem crescente de valor. Algoritmos sem população retornam apenas a melhor
+    /// solução encontrada.
+    fn population(&self) -> Vec<(Vec<Job>, Time)> {
+        if self.best_sequence().is_empty() {
+            return Vec::new();
+        }
+        vec![(self.best_sequence().to_vec(), self.best_makespan())]
+    }
+
+    /// Nome do algoritmo de baixo nível que encontrou a melhor solução, nos
+    /// algoritmos que combinam outros (a hiper-heurística e o portfólio).
+    fn winner(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Cronograma em duas fases do algoritmo memético. Na fase de exploração, a
+/// mutação é intensa e a busca local, rara; a partir de `switch_at` (fração do
+/// orçamento consumida), a busca local passa a ser aplicada apenas aos filhos que
+/// estariam entre os `elite_fraction` melhores da população, e a mutação volta à
+/// taxa configurada.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseSchedule {
+    pub switch_at: f64,                     // Fração do orçamento na troca de fase.
+    pub exploration_mutation_rate: f64,     // Taxa de mutação da fase de exploração.
+    pub exploration_local_search_rate: f64, // Taxa de busca local da fase de exploração.
+    pub elite_fraction: f64,                // Fração tratada como elite na intensificação.
+}
+
+impl Default for PhaseSchedule {
+    fn default() -> Self {
+        PhaseSchedule {
+            switch_at: 0.5,
+            exploration_mutation_rate: 0.6,
+            exploration_local_search_rate: 0.05,
+            elite_fraction: 0.1,
+        }
+    }
+}
+
+impl PhaseSchedule {
+    /// Verifica os intervalos: `switch_at` em [0, 1], taxas em [0, 1] e fração de
+    /// elite em (0, 1].
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("o início da intensificação", self.switch_at),
+            (
+                "a taxa de mutação da exploração",
+                self.exploration_mutation_rate,
+            ),
+            (
+                "a taxa de busca local da exploração",
+                self.exploration_local_search_rate,
+            ),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(FsspError::Config(format!(
+                    "{} deve estar em [0, 1] (recebido {})",
+                    name, value
+                )));
+            }
+        }
+        if !(self.elite_fraction > 0.0 && self.elite_fraction <= 1.0) {
+            return Err(FsspError::Config(format!(
+                "a fração de elite da intensificação deve estar em (0, 1] (recebido {})",
+                self.elite_fraction
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Parâmetros principais do algoritmo memético. Os demais (operadores, limites,
+/// reinícios etc.) são definidos pelos métodos `with_*` do `MemeticAlgorithm`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemeticParams {
+    pub population_size: usize, // Tamanho da população (ao menos 2).
+    pub generations: usize,     // Número máximo de gerações.
+    pub mutation_rate: f64,     // Probabilidade de mutação de cada filho, em [0, 1].
+    pub local_search_rate: f64, // Probabilidade de busca local em cada filho, em [0, 1].
+}
+
+impl Default for MemeticParams {
+    fn default() -> Self {
+        MemeticParams {
+            population_size: 100,
+            generations: 100,
+            mutation_rate: 0.3,
+            local_search_rate: 0.6,
+        }
+    }
+}
+
+impl MemeticParams {
+    /// Verifica os intervalos dos parâmetros: população com ao menos dois
+    /// indivíduos (o cruzamento exige dois pais) e taxas em [0, 1].
+    pub fn validate(&self) -> Result<()> {
+        if self.population_size < 2 {
+            return Err(FsspError::Config(format!(
+                "o tamanho da população deve ser ao menos 2 (recebido {})",
+                self.population_size
+            )));
+        }
+        for (name, rate) in [
+            ("mutação", self.mutation_rate),
+            ("busca local", self.local_search_rate),
+        ] {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(FsspError::Config(format!(
+                    "a taxa de {} deve estar em [0, 1] (recebido {})",
+                    name, rate
+                )));
+            }
+        }
+        Ok(())
+    }
+}
 
 /// Estrutura que representa o Algoritmo Memético para resolver o Problema de Escalonamento Flow Shop.
 pub struct MemeticAlgorithm {
-    instance: FSSPInstance,        // Instância do problema FSSP.
-    population_size: usize,        // Tamanho da população.
-    generations: usize,            // Número máximo de gerações.
-    mutation_rate: f64,            // Taxa de mutação.
-    local_search_rate: f64,        // Taxa de aplicação da busca local.
-    population: Vec<Vec<usize>>,   // População atual de sequências de tarefas.
-    fitness: Vec<u32>,             // Makespan (aptidão) de cada indivíduo na população.
-    pub best_sequence: Vec<usize>, // A melhor sequência de tarefas encontrada.
-    pub best_makespan: u32,        // O makespan da melhor sequência encontrada.
+    instance: FSSPInstance,                // Instância do problema FSSP.
+    population_size: usize,                // Tamanho da população.
+    generations: usize,                    // Número máximo de gerações.
+    mutation_rate: f64,                    // Taxa de mutação.
+    local_search_rate: f64,                // Taxa de aplicação da busca local.
+    selection: Selection,                  // Método de seleção dos pais.
+    crossover: Crossover,                  // Operador de cruzamento.
+    mutation: MutationMix,                 // Operadores de mutação e seus pesos.
+    local_search: LocalSearch,             // Mecanismo de busca local.
+    ls_policy: LocalSearchPolicy,          // Escolha dos indivíduos que recebem a busca local.
+    replacement: Replacement,              // Estratégia de substituição da população.
+    ils_params: IlsParams,                 // Parâmetros da ILS quando usada como busca local.
+    vns_iterations: usize,                 // Iterações da VNS quando usada como busca local.
+    ls_limits: SearchLimits,               // Limites de esforço de cada aplicação da busca local.
+    budget: Budget,                        // Orçamento da execução corrente.
+    adaptive_mutation: bool,               // Ajusta a taxa de mutação conforme a diversidade.
+    phase_schedule: Option<PhaseSchedule>, // Cronograma das fases de exploração e intensificação.
+    intensifying: bool,                    // Indica se a fase de intensificação já começou.
+    run_start: Instant,                    // Início da execução, para a fração do orçamento.
+    run_start_evaluations: u64,            // Avaliações registradas no início da execução.
+    current_mutation_rate: f64,            // Taxa de mutação efetivamente usada na geração atual.
+    last_diversity: Option<f64>,           // Diversidade da última geração (modo adaptativo).
+    last_duplicate_rate: Option<f64>,      // Fração de filhos duplicados na última geração.
+    initial_solutions: Vec<Vec<Job>>,      // Soluções fornecidas que iniciam a população.
+    restart_after: Option<usize>,          // Gerações sem melhoria antes de reiniciar a população.
+    restart_elite: usize,                  // Melhores indivíduos preservados em cada reinício.
+    neh_tie_breaking: NehTieBreaking,      // Desempate do NEH que semeia os reinícios.
+    elite_count: usize,                    // Melhores indivíduos preservados em cada geração.
+    path_relinking_every: Option<usize>,   // Gerações entre aplicações da religação de caminhos.
+    path_relinking_elites: usize,          // Elites religadas à melhor solução em cada aplicação.
+    aos: Option<AdaptiveOperators>,        // Seleção adaptativa do cruzamento e da busca local.
+    operator_stats: Option<OperatorStats>, // Estatísticas dos operadores desde a última consulta.
+    generation: usize,                     // Gerações executadas desde a inicialização.
+    stagnation: usize,                     // Gerações desde a última melhoria global.
+    fitness_cache: FitnessCache,           // Cache das avaliações por hash da sequência.
+    rng: StdRng,                           // Gerador de números aleatórios da execução.
+    population: Population,                // População atual de sequências de tarefas.
+    offspring: Population,                 // Armazenamento dos filhos, reusado entre gerações.
+    fitness: Vec<Time>,                    // Makespan (aptidão) de cada indivíduo na população.
+    pub best_sequence: Vec<Job>,           // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,               // O makespan da melhor sequência encontrada.
 }
 
 impl MemeticAlgorithm {
-    /// Cria uma nova instância do `MemeticAlgorithm`.
-    pub fn new(
-        instance: FSSPInstance,
-        population_size: usize,
-        generations: usize,
-        mutation_rate: f64,
-        local_search_rate: f64,
-    ) -> Self {
-        MemeticAlgorithm {
+    /// Cria uma nova instância do `MemeticAlgorithm`, recusando parâmetros fora dos
+    /// intervalos válidos (veja `MemeticParams::validate`).
+    pub fn new(instance: FSSPInstance, params: MemeticParams) -> Result<Self> {
+        params.validate()?;
+        let MemeticParams {
+            population_size,
+            generations,
+            mutation_rate,
+            local_search_rate,
+        } = params;
+        let n_jobs = instance.n_jobs;
+        let fitness_cache = FitnessCache::for_instance(&instance, fitness_cache::DEFAULT_CAPACITY);
+        Ok(MemeticAlgorithm {
             instance,
             population_size,
             generations,
             mutation_rate,
             local_search_rate,
-            population: Vec::new(),
+            selection: Selection::default(),
+            crossover: Crossover::Ox,
+            mutation: MutationMix::default(),
+            local_search: LocalSearch::Swap,
+            ls_policy: LocalSearchPolicy::Random,
+            replacement: Replacement::Generational,
+            ils_params: IlsParams::default(),
+            vns_iterations: 10,
+            ls_limits: SearchLimits::default(),
+            budget: Budget::default(),
+            adaptive_mutation: false,
+            phase_schedule: None,
+            intensifying: false,
+            run_start: Instant::now(),
+            run_start_evaluations: 0,
+            current_mutation_rate: mutation_rate,
+            last_diversity: None,
+            last_duplicate_rate: None,
+            initial_solutions: Vec::new(),
+            restart_after: None,
+            restart_elite: 2,
+            neh_tie_breaking: NehTieBreaking::First,
+            elite_count: 1,
+            path_relinking_every: None,
+            path_relinking_elites: 2,
+            aos: None,
+            operator_stats: None,
+            generation: 0,
+            stagnation: 0,
+            fitness_cache,
+            rng: StdRng::from_entropy(),
+            population: Population::new(n_jobs),
+            offspring: Population::new(n_jobs),
             fitness: Vec::new(),
             best_sequence: Vec::new(),
-            best_makespan: u32::MAX,
+            best_makespan: Time::MAX,
+        })
+    }
+
+    /// Define o método de seleção dos pais.
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Define o operador de cruzamento utilizado.
+    pub fn with_crossover(mut self, crossover: Crossover) -> Self {
+        self.crossover = crossover;
+        self
+    }
+
+    /// Define os operadores de mutação e seus pesos (padrão: apenas a troca).
+    pub fn with_mutation(mut self, mutation: MutationMix) -> Self {
+        self.mutation = mutation;
+        self
+    }
+
+    /// Define o mecanismo de busca local aplicado aos indivíduos.
+    pub fn with_local_search(mut self, local_search: LocalSearch) -> Self {
+        self.local_search = local_search;
+        self
+    }
+
+    /// Define a política de escolha dos indivíduos que recebem a busca local.
+    pub fn with_local_search_policy(mut self, policy: LocalSearchPolicy) -> Self {
+        self.ls_policy = policy;
+        self
+    }
+
+    /// Define a estratégia de substituição da população.
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Define os parâmetros da ILS usada como busca local (`LocalSearch::Ils`).
+    pub fn with_ils_params(mut self, params: IlsParams) -> Self {
+        self.ils_params = params;
+        self
+    }
+
+    /// Define o número de iterações da VNS usada como busca local (`LocalSearch::Vns`).
+    pub fn with_vns_iterations(mut self, iterations: usize) -> Self {
+        self.vns_iterations = iterations;
+        self
+    }
+
+    /// Define os limites de esforço (movimentos, tempo e estratégia de primeira
+    /// melhoria) de cada aplicação da busca local.
+    pub fn with_local_search_limits(mut self, limits: SearchLimits) -> Self {
+        self.ls_limits = limits;
+        self
+    }
+
+    /// Ativa a adaptação da taxa de mutação com base na diversidade da população.
+    pub fn with_adaptive_mutation(mut self, enabled: bool) -> Self {
+        self.adaptive_mutation = enabled;
+        self
+    }
+
+    /// Ativa o cronograma em duas fases (veja `PhaseSchedule`), recusando parâmetros
+    /// fora dos intervalos válidos.
+    pub fn with_phase_schedule(mut self, schedule: Option<PhaseSchedule>) -> Result<Self> {
+        if let Some(schedule) = &schedule {
+            schedule.validate()?;
         }
+        self.phase_schedule = schedule;
+        Ok(self)
     }
 
-    /// Executa o Algoritmo Memético.
-    pub fn run(&mut self, start_time: Instant, max_duration: Option<std::time::Duration>) {
-        self._initialize_population(); // Inicializa a população.
+    /// Define soluções iniciais (já validadas) inseridas na população inicial antes
+    /// das soluções gulosas e aleatórias. Soluções excedentes ao tamanho da população
+    /// são descartadas.
+    pub fn with_initial_solutions(mut self, solutions: Vec<Vec<Job>>) -> Self {
+        self.initial_solutions = solutions;
+        self
+    }
 
-        for gen in 0..self.generations {
-            // Verifica se o tempo de execução excedeu o limite.
-            if let Some(duration) = max_duration {
-                if start_time.elapsed() > duration {
-                    println!(
-                        "\nLimite de tempo de {:.1?}s atingido. Encerrando...",
-                        duration.as_secs_f32()
-                    );
-                    break;
-                }
-            }
+    /// Ativa o reinício da população após `after` gerações sem melhoria, preservando
+    /// os `elite` melhores indivíduos.
+    pub fn with_restart(mut self, after: Option<usize>, elite: usize) -> Self {
+        self.restart_after = after;
+        self.restart_elite = elite;
+        self
+    }
+
+    /// Define o critério de desempate do NEH cujas perturbações semeiam a população
+    /// nos reinícios.
+    pub fn with_neh_tie_breaking(mut self, tie_breaking: NehTieBreaking) -> Self {
+        self.neh_tie_breaking = tie_breaking;
+        self
+    }
 
-            self._evaluate_fitness(); // Avalia a aptidão dos indivíduos.
+    /// Define quantos dos melhores indivíduos de cada geração substituem os piores
+    /// filhos no modo geracional.
+    pub fn with_elite_count(mut self, count: usize) -> Self {
+        self.elite_count = count;
+        self
+    }
 
-            // Encontra o melhor indivíduo na geração atual.
-            let (current_best_idx, current_best_fitness) = self
-                .fitness
-                .iter()
-                .enumerate()
-                .min_by_key(|&(_, f)| f)
-                .unwrap();
+    /// Ativa a religação de caminhos a cada `every` gerações entre a melhor solução
+    /// global e `elites` indivíduos sorteados entre os melhores da população.
+    pub fn with_path_relinking(mut self, every: Option<usize>, elites: usize) -> Self {
+        self.path_relinking_every = every;
+        self.path_relinking_elites = elites;
+        self
+    }
 
-            // Atualiza a melhor solução global encontrada.
-            if *current_best_fitness < self.best_makespan {
-                self.best_makespan = *current_best_fitness;
-                self.best_sequence = self.population[current_best_idx].clone();
-            }
+    /// Ativa a seleção adaptativa de operadores: a cada geração, o cruzamento e a busca
+    /// local são escolhidos entre os disponíveis conforme as melhorias que produziram
+    /// (veja `aos::AdaptiveOperators`), no lugar dos operadores fixos.
+    pub fn with_adaptive_operators(mut self, aos: Option<AdaptiveOperators>) -> Self {
+        self.aos = aos;
+        self
+    }
+
+    /// Ativa o registro das aplicações e melhorias de cada operador (cruzamento,
+    /// mutação e busca local), incluído nas estatísticas de cada geração. Cada filho
+    /// passa a ser avaliado também antes da mutação e da busca local, o que consome
+    /// avaliações adicionais (reduzidas pelo cache de aptidão).
+    pub fn with_operator_stats(mut self, enabled: bool) -> Self {
+        self.operator_stats = enabled.then(OperatorStats::default);
+        self
+    }
 
-            // Imprime o progresso a cada 20 gerações.
-            if (gen + 1) % 20 == 0 {
-                println!(
-                    "Geração {}: Melhor Makespan = {}",
-                    gen + 1,
-                    self.best_makespan
+    /// Estatísticas dos operadores acumuladas desde a última consulta (ou desde a
+    /// inicialização), se o registro estiver ativo. As contagens são reiniciadas.
+    pub(crate) fn take_operator_stats(&mut self) -> Option<OperatorStats> {
+        self.operator_stats.as_mut().map(std::mem::take)
+    }
+
+    /// Define a capacidade do cache de aptidão (número de sequências); `0` desativa
+    /// o cache, que também fica desativado com estimativas adaptativas (veja
+    /// `FitnessCache::for_instance`).
+    pub fn with_fitness_cache(mut self, capacity: usize) -> Self {
+        self.fitness_cache = FitnessCache::for_instance(&self.instance, capacity);
+        self
+    }
+
+    /// Fixa a semente do gerador de números aleatórios, tornando a execução reprodutível.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Reúne as estatísticas da geração avaliada mais recentemente.
+    fn _generation_stats(&mut self, generation: usize, start_time: Instant) -> GenerationStats {
+        let generation_best = self.fitness.iter().copied().min().unwrap_or(Time::MAX);
+        let mean_makespan = if self.fitness.is_empty() {
+            0.0
+        } else {
+            self.fitness.iter().map(|&f| f as f64).sum::<f64>() / self.fitness.len() as f64
+        };
+        GenerationStats {
+            generation,
+            best_makespan: self.best_makespan,
+            generation_best,
+            mean_makespan,
+            mutation_rate: self.current_mutation_rate,
+            diversity: self.last_diversity,
+            duplicate_rate: self.last_duplicate_rate,
+            operators: self.take_operator_stats(),
+            evaluations: self.instance.evaluations.get(),
+            elapsed: start_time.elapsed(),
+        }
+    }
+
+    /// Número máximo de gerações configurado.
+    pub fn generations(&self) -> usize {
+        self.generations
+    }
+
+    /// Tamanho da população configurado.
+    pub fn population_size(&self) -> usize {
+        self.population_size
+    }
+
+    /// Cache de aptidão, com as estatísticas de acertos da execução.
+    pub fn fitness_cache(&self) -> &FitnessCache {
+        &self.fitness_cache
+    }
+
+    /// Define o orçamento da execução. O instante limite e o máximo de avaliações
+    /// também interrompem a busca local e os laços de cruzamento dentro de uma
+    /// geração.
+    pub(crate) fn set_budget(&mut self, budget: &Budget) {
+        self.budget = *budget;
+        self.ls_limits.deadline = budget.deadline;
+        self.ls_limits.max_evaluations = budget.max_evaluations;
+    }
+
+    /// Indica se o orçamento da execução (tempo ou avaliações) se esgotou.
+    pub(crate) fn budget_exhausted(&self) -> bool {
+        self.budget.exhausted(self.instance.evaluations.get())
+    }
+
+    /// Atualiza a melhor solução global a partir da população atual. Retorna `true`
+    /// se houve melhoria.
+    pub(crate) fn sync_best(&mut self) -> bool {
+        if self.fitness.len() != self.population.len() {
+            self._evaluate_fitness();
+        }
+        let Some((idx, &makespan)) = self.fitness.iter().enumerate().min_by_key(|&(_, f)| f) else {
+            return false;
+        };
+        if makespan < self.best_makespan {
+            self.best_makespan = makespan;
+            self.best_sequence = self.population[idx].to_vec();
+            return true;
+        }
+        false
+    }
+
+    /// Prepara o algoritmo para uma nova execução, inicializando a população.
+    pub(crate) fn initialize(&mut self) {
+        self._initialize_population();
+        self.run_start = Instant::now();
+        self.run_start_evaluations = self.instance.evaluations.get();
+        self.intensifying = false;
+        self.current_mutation_rate = self._base_mutation_rate();
+        self.last_diversity = None;
+        self.last_duplicate_rate = None;
+        self.take_operator_stats();
+        self.generation = 0;
+        self.stagnation = 0;
+        self.fitness_cache.clear();
+    }
+
+    /// Executa uma geração completa: avaliação, seleção, cruzamento, mutação,
+    /// busca local e substituição (elitismo, no modo geracional). Se o orçamento se
+    /// esgotar no meio da geração, os filhos ainda não gerados são substituídos pelos
+    /// indivíduos atuais. Retorna `true` se a melhor solução global foi melhorada.
+    pub(crate) fn evolve_generation(&mut self) -> bool {
+        // Avalia a aptidão dos indivíduos, se ainda não conhecida (população inicial,
+        // reinício ou migração), e atualiza a melhor solução global encontrada.
+        let mut improved = self.sync_best();
+
+        // Intensifica a busca religando a melhor solução às elites.
+        self.generation += 1;
+        if self
+            .path_relinking_every
+            .is_some_and(|every| every > 0 && self.generation.is_multiple_of(every))
+        {
+            improved |= self._path_relinking();
+        }
+
+        if improved {
+            self.stagnation = 0;
+        } else {
+            self.stagnation += 1;
+        }
+
+        // Passa à fase de intensificação quando a fração do orçamento é atingida.
+        if let Some(schedule) = self.phase_schedule {
+            if !self.intensifying && self._budget_fraction() >= schedule.switch_at {
+                self.intensifying = true;
+                info!(
+                    generation = self.generation,
+                    best = self.best_makespan,
+                    "Início da fase de intensificação"
                 );
             }
+        }
+        self.current_mutation_rate = self._base_mutation_rate();
 
-            let parents_indices = self._selection_tournament(); // Seleção dos pais.
-            let mut next_population = self._crossover(&parents_indices); // Cruzamento.
-            self._mutation(&mut next_population); // Mutação.
-            self._apply_local_search(&mut next_population); // Aplica busca local (memético).
-            self._elitism(&mut next_population); // Aplica elitismo.
+        // Ajusta a taxa de mutação conforme a diversidade da população.
+        if self.adaptive_mutation {
+            let diversity = self.population_diversity();
+            self._adapt_mutation_rate(diversity);
+            self.last_diversity = Some(diversity);
+        }
+
+        // Escolhe os operadores da geração (seleção adaptativa).
+        if let Some(aos) = &mut self.aos {
+            (self.crossover, self.local_search) =
+                aos.begin_generation(self.instance.evaluations.get(), &mut self.rng);
+        }
+
+        match self.replacement {
+            Replacement::Generational => {
+                let parents_indices = self._selection(); // Seleção dos pais.
+                let mut next_population = self._crossover(&parents_indices); // Cruzamento.
+                self._mutation(next_population.iter_mut()); // Mutação.
+                let mut known = self._apply_local_search(next_population.iter_mut()); // Aplica busca local (memético).
+                let children = next_population.len();
+                known.resize(children, None);
+                if next_population.len() < self.population_size {
+                    // O orçamento se esgotou durante o cruzamento: completa a população
+                    // com os primeiros indivíduos atuais, de aptidão conhecida.
+                    let missing = self.population_size - next_population.len();
+                    next_population.extend_from(&self.population, missing);
+                    known.extend((0..missing).map(|idx| self.fitness.get(idx).copied()));
+                }
+                let mut next_fitness = self._evaluate_children(&next_population, &known);
+                if let Some(aos) = &mut self.aos {
+                    // O filho `k` foi gerado a partir do pai `parents_indices[k]`.
+                    for (&parent, &child) in
+                        parents_indices.iter().zip(&next_fitness).take(children)
+                    {
+                        aos.record(self.fitness[parent], child);
+                    }
+                }
+                self._elitism(&mut next_population, &mut next_fitness); // Aplica elitismo.
+                let duplicates = self._replace_duplicates(&mut next_population, &mut next_fitness); // Remove clones.
+                self.last_duplicate_rate =
+                    Some(duplicates as f64 / next_population.len().max(1) as f64);
+
+                // Atualiza a população; o armazenamento da anterior recebe os filhos
+                // da próxima geração.
+                self.offspring = std::mem::replace(&mut self.population, next_population);
+                self.fitness = next_fitness;
+            }
+            Replacement::SteadyState => self._steady_state(),
+            Replacement::Crowding => self._crowding(),
+        }
 
-            self.population = next_population; // Atualiza a população.
+        // Recompensa os operadores da geração.
+        if let Some(aos) = &mut self.aos {
+            let generation_best = self.fitness.iter().copied().min().unwrap_or(Time::MAX);
+            let reward = aos.end_generation(
+                self.instance.evaluations.get(),
+                generation_best < self.best_makespan,
+            );
+            debug!(
+                crossover = %self.crossover,
+                local_search = %self.local_search,
+                reward,
+                "Operadores da geração"
+            );
         }
+
+        // Reinicia a população se a busca estagnou.
+        if self
+            .restart_after
+            .is_some_and(|limit| self.stagnation >= limit)
+        {
+            self._restart_population();
+        }
+        improved
+    }
+
+    /// Retorna cópias dos `count` melhores indivíduos da população atual.
+    pub(crate) fn best_individuals(&mut self, count: usize) -> Vec<Vec<Job>> {
+        let mut ranked = self._ranked_population();
+        ranked.sort_unstable();
+        ranked
+            .iter()
+            .take(count)
+            .map(|&(_, idx)| self.population[idx].to_vec())
+            .collect()
+    }
+
+    /// Substitui os piores indivíduos da população atual pelos indivíduos recebidos.
+    pub(crate) fn replace_worst(&mut self, incoming: Vec<Vec<Job>>) {
+        let mut ranked = self._ranked_population();
+        ranked.sort_unstable_by(|a, b| b.cmp(a));
+        // Preserva ao menos o melhor indivíduo da população.
+        let replaceable = ranked.len().saturating_sub(1);
+        for (&(_, idx), individual) in ranked.iter().take(replaceable).zip(incoming) {
+            self.population[idx].copy_from_slice(&individual);
+        }
+        self.fitness.clear(); // Reavaliada na próxima geração.
+    }
+
+    /// Pares (valor da função objetivo, índice) da população atual.
+    fn _ranked_population(&mut self) -> Vec<(Time, usize)> {
+        let (cache, instance) = (&mut self.fitness_cache, &self.instance);
+        self.population
+            .iter()
+            .enumerate()
+            .map(|(idx, seq)| (cache.evaluate(instance, seq), idx))
+            .collect()
     }
 
     /// Inicializa a população com soluções gulosas e aleatórias.
     fn _initialize_population(&mut self) {
         self.population.clear();
 
+        // Insere as soluções fornecidas pelo usuário.
+        for solution in self.initial_solutions.iter().take(self.population_size) {
+            self.population.push(solution);
+        }
+
         // Calcula o tempo total de processamento para cada tarefa.
-        let mut job_metrics: Vec<(usize, u32)> = (0..self.instance.n_jobs)
+        let mut job_metrics: Vec<(Job, Time)> = (0..self.instance.n_jobs as Job)
             .map(|job_idx| {
-                let total_time: u32 = self.instance.processing_times[job_idx].iter().sum();
+                let total_time: Time = self.instance.processing_times[job_idx as usize]
+                    .iter()
+                    .sum();
                 (job_idx, total_time)
             })
             .collect();
 
         // Adiciona a primeira solução gulosa (tempos ascendentes).
-        if self.population_size > 0 {
+        if self.population_size > self.population.len() {
             job_metrics.sort_by_key(|&(_, total_time)| total_time);
-            let greedy_solution_asc: Vec<usize> =
+            let greedy_solution_asc: Vec<Job> =
                 job_metrics.iter().map(|&(job_idx, _)| job_idx).collect();
-            self.population.push(greedy_solution_asc);
+            self.population.push(&greedy_solution_asc);
         }
 
         // Adiciona a segunda solução gulosa (tempos descendentes).
-        if self.population_size > 1 {
-            let greedy_solution_desc: Vec<usize> = job_metrics
+        if self.population_size > self.population.len() {
+            let greedy_solution_desc: Vec<Job> = job_metrics
                 .iter()
                 .rev()
                 .map(|&(job_idx, _)| job_idx)
                 .collect();
-            self.population.push(greedy_solution_desc);
+            self.population.push(&greedy_solution_desc);
+        }
+
+        // Adiciona as soluções das heurísticas CDS e de Palmer, que diversificam as
+        // sementes construtivas.
+        for heuristic in [ConstructiveHeuristic::Cds, ConstructiveHeuristic::Palmer] {
+            if self.population_size > self.population.len() {
+                self.population.push(&heuristic.build(&self.instance));
+            }
         }
 
         // Preenche o restante da população com soluções aleatórias.
-        let mut rng = rand::thread_rng();
         let num_random_to_generate = self.population_size.saturating_sub(self.population.len());
 
+        let mut random_solution: Vec<Job> = (0..self.instance.n_jobs as Job).collect();
         for _ in 0..num_random_to_generate {
-            let mut random_solution: Vec<usize> = (0..self.instance.n_jobs).collect();
-            random_solution.shuffle(&mut rng);
-            self.population.push(random_solution);
+            random_solution.shuffle(&mut self.rng);
+            self.population.push(&random_solution);
         }
     }
 
+    /// Reinicia a população preservando os melhores indivíduos. Metade dos demais
+    /// são perturbações aleatórias da solução NEH e a outra metade permutações
+    /// aleatórias, reintroduzindo diversidade após convergência prematura.
+    fn _restart_population(&mut self) {
+        let n = self.instance.n_jobs;
+        let elite = self.best_individuals(self.restart_elite.min(self.population_size));
+        let remaining = self.population_size - elite.len();
+        let neh_solution = heuristics::neh_with_tie_breaking(&self.instance, self.neh_tie_breaking);
+
+        debug!(
+            stagnation = self.stagnation,
+            preserved = elite.len(),
+            "Reiniciando a população"
+        );
+
+        self.population.clear();
+        for individual in &elite {
+            self.population.push(individual);
+        }
+        self.fitness.clear(); // Reavaliada na próxima geração.
+        for idx in 0..remaining {
+            let individual = if idx % 2 == 0 {
+                let mut perturbed = neh_solution.clone();
+                let strength = self.rng.gen_range(1..=(n / 5).max(1));
+                ils::perturb(&mut perturbed, strength, &mut self.rng);
+                perturbed
+            } else {
+                let mut random_solution: Vec<Job> = (0..n as Job).collect();
+                random_solution.shuffle(&mut self.rng);
+                random_solution
+            };
+            self.population.push(&individual);
+        }
+        self.stagnation = 0;
+    }
+
     /// Avalia o makespan (aptidão) de cada indivíduo na população.
     fn _evaluate_fitness(&mut self) {
         self.fitness = self
-            .population
-            .iter()
-            .map(|seq| self.instance.calculate_makespan(seq))
+            .fitness_cache
+            .evaluate_batch(&self.instance, &self.population);
+    }
+
+    /// Religa caminhos entre `path_relinking_elites` indivíduos sorteados na fração
+    /// `PATH_RELINKING_POOL_FRACTION` dos melhores da população e a melhor solução
+    /// global. Cada solução intermediária obtida substitui o pior indivíduo se for
+    /// melhor que ele e não for cópia de um indivíduo existente. Retorna `true` se a
+    /// melhor solução global foi melhorada.
+    fn _path_relinking(&mut self) -> bool {
+        let mut ranked: Vec<usize> = (0..self.population.len()).collect();
+        ranked.sort_by_key(|&idx| self.fitness[idx]);
+        let pool_size = ((ranked.len() as f64 * PATH_RELINKING_POOL_FRACTION).ceil() as usize)
+            .max(self.path_relinking_elites)
+            .min(ranked.len());
+        let elites: Vec<usize> = ranked[..pool_size]
+            .choose_multiple(&mut self.rng, self.path_relinking_elites)
+            .copied()
             .collect();
+
+        let mut improved = false;
+        for elite_idx in elites {
+            if self.budget_exhausted() {
+                break;
+            }
+            let Some((candidate, value)) = path_relinking::relink(
+                &self.instance,
+                &self.population[elite_idx],
+                &self.best_sequence,
+            ) else {
+                continue;
+            };
+            if value < self.best_makespan {
+                debug!(makespan = value, "Religação de caminhos melhorou a solução");
+                self.best_makespan = value;
+                self.best_sequence = candidate.clone();
+                improved = true;
+            }
+
+            let (worst_idx, &worst_value) = self
+                .fitness
+                .iter()
+                .enumerate()
+                .max_by_key(|&(_, &f)| f)
+                .unwrap();
+            if value < worst_value && !self.population.contains(&candidate) {
+                self.population[worst_idx].copy_from_slice(&candidate);
+                self.fitness[worst_idx] = value;
+            }
+        }
+        improved
     }
 
-    /// Realiza a seleção por torneio para escolher os pais.
-    fn _selection_tournament(&self) -> Vec<usize> {
-        let mut parents = Vec::with_capacity(self.population_size);
-        let mut rng = rand::thread_rng();
-        let candidates: Vec<usize> = (0..self.population_size).collect();
+    /// Seleciona os pais com o método configurado.
+    fn _selection(&mut self) -> Vec<usize> {
+        let sampler = self.selection.sampler(&self.fitness);
+        (0..self.population_size)
+            .map(|_| sampler.sample(&mut self.rng))
+            .collect()
+    }
 
+    /// Substituição em regime estacionário: gera `population_size` filhos, um por
+    /// vez (seleção, cruzamento, mutação e busca local). Cada filho
+    /// substitui o pior indivíduo da população se for melhor que ele e não for cópia
+    /// de um indivíduo existente, de modo que o melhor indivíduo nunca é perdido.
+    fn _steady_state(&mut self) {
+        // Número de cópias de cada sequência presente na população.
+        let mut members: HashMap<Vec<Job>, usize> = HashMap::new();
+        for individual in &self.population {
+            *members.entry(individual.to_vec()).or_default() += 1;
+        }
+
+        let mut duplicates = 0;
         for _ in 0..self.population_size {
-            // Seleciona 3 candidatos aleatórios para o torneio.
-            let selected_indices = candidates
-                .choose_multiple(&mut rng, 3)
-                .cloned()
-                .collect::<Vec<_>>();
-            // O vencedor é o indivíduo com o menor makespan.
-            let winner_index = *selected_indices
+            if self.budget_exhausted() {
+                break;
+            }
+            let (p1, p2) = {
+                let sampler = self.selection.sampler(&self.fitness);
+                (sampler.sample(&mut self.rng), sampler.sample(&mut self.rng))
+            };
+            let (mut child, _) =
+                self.crossover
+                    .apply(&self.population[p1], &self.population[p2], &mut self.rng);
+            self._record_crossover((p1, p2), &child);
+            self._mutation([child.as_mut_slice()]);
+            let known = self._apply_local_search([child.as_mut_slice()]);
+            if members.contains_key(&child) {
+                duplicates += 1;
+                continue;
+            }
+
+            let makespan = match known.first().copied().flatten() {
+                Some(makespan) => makespan,
+                None => self.fitness_cache.evaluate(&self.instance, &child),
+            };
+            if let Some(aos) = &mut self.aos {
+                aos.record(self.fitness[p1], makespan);
+            }
+            let (worst_idx, &worst_makespan) = self
+                .fitness
                 .iter()
-                .min_by_key(|&&idx| self.fitness[idx])
+                .enumerate()
+                .max_by_key(|&(_, &f)| f)
                 .unwrap();
-            parents.push(winner_index);
+            if makespan >= worst_makespan {
+                continue;
+            }
+
+            let replaced = self.population[worst_idx].to_vec();
+            self.population[worst_idx].copy_from_slice(&child);
+            if let Some(count) = members.get_mut(&replaced) {
+                *count -= 1;
+                if *count == 0 {
+                    members.remove(&replaced);
+                }
+            }
+            members.insert(child, 1);
+            self.fitness[worst_idx] = makespan;
+        }
+        self.last_duplicate_rate = Some(duplicates as f64 / self.population_size.max(1) as f64);
+    }
+
+    /// Aglomeração determinística (deterministic crowding): a população é embaralhada
+    /// e dividida em pares de pais, sem pressão seletiva. Cada par gera dois filhos
+    /// (cruzamento, mutação e busca local), e cada filho é associado ao pai mais
+    /// próximo segundo a distância de Kendall-tau, substituindo-o apenas se for
+    /// melhor e não for cópia de um indivíduo existente. Como filhos só competem com
+    /// pais estruturalmente semelhantes, diferentes regiões do espaço de busca
+    /// permanecem representadas na população, e o melhor indivíduo nunca é perdido.
+    fn _crowding(&mut self) {
+        // Número de cópias de cada sequência presente na população.
+        let mut members: HashMap<Vec<Job>, usize> = HashMap::new();
+        for individual in &self.population {
+            *members.entry(individual.to_vec()).or_default() += 1;
+        }
+        let mut order: Vec<usize> = (0..self.population.len()).collect();
+        order.shuffle(&mut self.rng);
+
+        let mut duplicates = 0;
+        let mut children_count = 0;
+        for pair in order.chunks_exact(2) {
+            if self.budget_exhausted() {
+                break;
+            }
+            let (p1, p2) = (pair[0], pair[1]);
+            let (c1, c2) =
+                self.crossover
+                    .apply(&self.population[p1], &self.population[p2], &mut self.rng);
+            self._record_crossover((p1, p2), &c1);
+            self._record_crossover((p1, p2), &c2);
+            let mut children = [c1, c2];
+            self._mutation(children.iter_mut().map(Vec::as_mut_slice));
+            let known = self._apply_local_search(children.iter_mut().map(Vec::as_mut_slice));
+            let value = |k: usize| known.get(k).copied().flatten();
+            let [c1, c2] = children;
+            let (c1, c2) = ((c1, value(0)), (c2, value(1)));
+
+            // Associa cada filho ao pai mais próximo.
+            let direct = kendall_tau_distance(&self.population[p1], &c1.0)
+                + kendall_tau_distance(&self.population[p2], &c2.0);
+            let crossed = kendall_tau_distance(&self.population[p1], &c2.0)
+                + kendall_tau_distance(&self.population[p2], &c1.0);
+            let matches = if direct <= crossed {
+                [(p1, c1), (p2, c2)]
+            } else {
+                [(p1, c2), (p2, c1)]
+            };
+
+            for (parent, (child, known)) in matches {
+                children_count += 1;
+                if members.contains_key(&child) {
+                    duplicates += 1;
+                    continue;
+                }
+                let makespan = match known {
+                    Some(makespan) => makespan,
+                    None => self.fitness_cache.evaluate(&self.instance, &child),
+                };
+                if let Some(aos) = &mut self.aos {
+                    aos.record(self.fitness[parent], makespan);
+                }
+                if makespan < self.fitness[parent] {
+                    if let Some(count) = members.get_mut(&self.population[parent]) {
+                        *count -= 1;
+                        if *count == 0 {
+                            members.remove(&self.population[parent]);
+                        }
+                    }
+                    self.population[parent].copy_from_slice(&child);
+                    members.insert(child, 1);
+                    self.fitness[parent] = makespan;
+                }
+            }
+        }
+        self.last_duplicate_rate = Some(duplicates as f64 / children_count.max(1) as f64);
+    }
+
+    /// Substitui os indivíduos repetidos de `population`, mantendo a primeira cópia,
+    /// por perturbações da cópia ou permutações aleatórias (alternadamente) ainda
+    /// ausentes da população. Em instâncias muito pequenas, em que não há
+    /// permutações distintas suficientes, alguns duplicados podem permanecer.
+    /// `fitness` é atualizada para os substitutos. Retorna o número de duplicados
+    /// encontrados.
+    fn _replace_duplicates(&mut self, population: &mut Population, fitness: &mut [Time]) -> usize {
+        let n = self.instance.n_jobs;
+        let mut seen: HashSet<Vec<Job>> = HashSet::with_capacity(population.len());
+        let mut duplicates = 0;
+        for (individual, value) in population.iter_mut().zip(fitness.iter_mut()) {
+            if seen.insert(individual.to_vec()) {
+                continue;
+            }
+            duplicates += 1;
+            for attempt in 0..DUPLICATE_REPLACEMENT_ATTEMPTS {
+                let mut candidate = individual.to_vec();
+                if attempt % 2 == 0 {
+                    let strength = self.rng.gen_range(1..=(n / 5).max(1));
+                    ils::perturb(&mut candidate, strength, &mut self.rng);
+                } else {
+                    candidate.shuffle(&mut self.rng);
+                }
+                if seen.insert(candidate.clone()) {
+                    *value = self.fitness_cache.evaluate(&self.instance, &candidate);
+                    individual.copy_from_slice(&candidate);
+                    break;
+                }
+            }
         }
-        parents
+        duplicates
     }
 
-    /// Realiza o cruzamento (OX) entre pares de pais para gerar filhos.
-    fn _crossover(&self, parents: &[usize]) -> Vec<Vec<usize>> {
-        let mut children = Vec::with_capacity(self.population_size);
-        let mut rng = rand::thread_rng();
+    /// Realiza o cruzamento entre pares de pais para gerar filhos, usando o operador
+    /// configurado, no armazenamento reaproveitado de `offspring`. Interrompe-se ao
+    /// fim do orçamento, retornando menos filhos.
+    fn _crossover(&mut self, parents: &[usize]) -> Population {
+        let mut children = std::mem::take(&mut self.offspring);
+        children.clear();
 
         for i in (0..self.population_size).step_by(2) {
+            if self.budget_exhausted() {
+                break;
+            }
             let p1_idx = parents[i];
             let p2_idx = if i + 1 < self.population_size {
                 parents[i + 1]
@@ -177,124 +1149,512 @@ impl MemeticAlgorithm {
             let p1 = &self.population[p1_idx];
             let p2 = &self.population[p2_idx];
 
-            let mut c1 = vec![usize::MAX; self.instance.n_jobs];
-            let mut c2 = vec![usize::MAX; self.instance.n_jobs];
-
-            // Define os pontos de corte para o cruzamento.
-            let (start, end) = {
-                let mut v = [
-                    rng.gen_range(0..self.instance.n_jobs),
-                    rng.gen_range(0..self.instance.n_jobs),
-                ];
-                v.sort_unstable();
-                (v[0], v[1])
-            };
+            let (c1, c2) = self.crossover.apply(p1, p2, &mut self.rng);
 
-            // Copia o segmento central dos pais para os filhos.
-            c1[start..=end].copy_from_slice(&p1[start..=end]);
-            c2[start..=end].copy_from_slice(&p2[start..=end]);
+            self._record_crossover((p1_idx, p2_idx), &c1);
+            children.push(&c1);
+            if children.len() < self.population_size {
+                self._record_crossover((p1_idx, p2_idx), &c2);
+                children.push(&c2);
+            }
+        }
+        children
+    }
 
-            // Preenche os restantes dos filhos com genes dos outros pais.
-            let p2_rem: Vec<usize> = p2
-                .iter()
-                .copied()
-                .filter(|&gene| !c1.contains(&gene))
-                .collect();
-            let p1_rem: Vec<usize> = p1
-                .iter()
-                .copied()
-                .filter(|&gene| !c2.contains(&gene))
-                .collect();
+    /// Registra um filho do cruzamento dos pais `parents`, comparando-o ao melhor
+    /// deles (apenas com o registro de operadores ativo).
+    fn _record_crossover(&mut self, parents: (usize, usize), child: &[Job]) {
+        if self.operator_stats.is_none() {
+            return;
+        }
+        let before = self.fitness[parents.0].min(self.fitness[parents.1]);
+        let after = self.fitness_cache.probe(&self.instance, child);
+        if let Some(stats) = &mut self.operator_stats {
+            stats.crossover.record(before, after);
+        }
+    }
 
-            let mut p2_iter = p2_rem.iter();
-            let mut p1_iter = p1_rem.iter();
+    /// Valor de `individual` antes da aplicação de um operador, se o registro de
+    /// operadores estiver ativo.
+    fn _value_before(&mut self, individual: &[Job]) -> Option<Time> {
+        self.operator_stats
+            .is_some()
+            .then(|| self.fitness_cache.probe(&self.instance, individual))
+    }
 
-            for i_gene in 0..self.instance.n_jobs {
-                if c1[i_gene] == usize::MAX {
-                    c1[i_gene] = *p2_iter.next().unwrap();
-                }
-                if c2[i_gene] == usize::MAX {
-                    c2[i_gene] = *p1_iter.next().unwrap();
+    /// Aplica mutação em indivíduos selecionados, sorteando o operador conforme os
+    /// pesos de `mutation`.
+    fn _mutation<'a>(&mut self, individuals: impl IntoIterator<Item = &'a mut [Job]>) {
+        for individual in individuals {
+            if self.rng.gen::<f64>() < self.current_mutation_rate {
+                let before = self._value_before(individual);
+                self.mutation.apply(individual, &mut self.rng);
+                if let Some(before) = before {
+                    let after = self.fitness_cache.probe(&self.instance, individual);
+                    if let Some(stats) = &mut self.operator_stats {
+                        stats.mutation.record(before, after);
+                    }
                 }
             }
+        }
+    }
 
-            children.push(c1);
-            if children.len() < self.population_size {
-                children.push(c2);
+    /// Calcula a diversidade da população como a distância média de Kendall-tau entre
+    /// pares de indivíduos, normalizada para o intervalo [0, 1]. Em populações com
+    /// mais de `MAX_DIVERSITY_PAIRS` pares, cada indivíduo é comparado apenas com os
+    /// seguintes na população (circularmente), o que mantém o custo por geração
+    /// limitado.
+    pub fn population_diversity(&self) -> f64 {
+        let n = self.population.len();
+        let max_distance = self.instance.n_jobs * self.instance.n_jobs.saturating_sub(1) / 2;
+        if n < 2 || max_distance == 0 {
+            return 0.0;
+        }
+
+        let (mut total, mut pairs) = (0usize, 0usize);
+        if n * (n - 1) / 2 <= MAX_DIVERSITY_PAIRS {
+            for a in 0..n {
+                for b in (a + 1)..n {
+                    total += kendall_tau_distance(&self.population[a], &self.population[b]);
+                    pairs += 1;
+                }
+            }
+        } else {
+            let offsets = (MAX_DIVERSITY_PAIRS / n).max(1);
+            for a in 0..n {
+                for offset in 1..=offsets {
+                    let b = (a + offset) % n;
+                    total += kendall_tau_distance(&self.population[a], &self.population[b]);
+                    pairs += 1;
+                }
             }
         }
-        children
+        total as f64 / (pairs * max_distance) as f64
     }
 
-    /// Aplica mutação por troca em indivíduos selecionados.
-    fn _mutation(&self, population: &mut Vec<Vec<usize>>) {
-        let mut rng = rand::thread_rng();
-        for individual in population.iter_mut() {
-            if rng.gen::<f64>() < self.mutation_rate {
-                let i = rng.gen_range(0..self.instance.n_jobs);
-                let j = rng.gen_range(0..self.instance.n_jobs);
-                individual.swap(i, j); // Troca dois elementos aleatórios na sequência.
-            }
+    /// Amplia a taxa de mutação proporcionalmente ao colapso da diversidade e retorna
+    /// à taxa base quando a diversidade está acima do limiar.
+    fn _adapt_mutation_rate(&mut self, diversity: f64) {
+        let base_rate = self._base_mutation_rate();
+        let max_rate = MAX_ADAPTIVE_MUTATION_RATE.max(base_rate);
+        self.current_mutation_rate = if diversity < DIVERSITY_THRESHOLD {
+            let collapse = 1.0 - diversity / DIVERSITY_THRESHOLD;
+            base_rate + (max_rate - base_rate) * collapse
+        } else {
+            base_rate
+        };
+    }
+
+    /// Taxa de mutação da fase atual: a da exploração, no cronograma em duas fases,
+    /// ou a configurada.
+    fn _base_mutation_rate(&self) -> f64 {
+        match self.phase_schedule {
+            Some(schedule) if !self.intensifying => schedule.exploration_mutation_rate,
+            _ => self.mutation_rate,
         }
     }
 
-    /// Aplica busca local (swap 2-opt) em indivíduos selecionados.
-    fn _apply_local_search(&self, population: &mut Vec<Vec<usize>>) {
-        let mut rng = rand::thread_rng();
-        for individual in population.iter_mut() {
-            if rng.gen::<f64>() < self.local_search_rate {
-                self._local_search_swap(individual);
-            }
+    /// Fração do orçamento já consumida: a maior entre as frações de tempo, de
+    /// avaliações e de gerações, considerando apenas os limites definidos (0 sem
+    /// nenhum limite).
+    fn _budget_fraction(&self) -> f64 {
+        let mut fraction: f64 = 0.0;
+        if let Some(deadline) = self.budget.deadline {
+            let total = deadline.saturating_duration_since(self.run_start);
+            fraction = fraction
+                .max(self.run_start.elapsed().as_secs_f64() / total.as_secs_f64().max(1e-9));
+        }
+        if let Some(max) = self.budget.max_evaluations {
+            let used = self.instance.evaluations.get() - self.run_start_evaluations;
+            fraction = fraction.max(used as f64 / max.max(1) as f64);
+        }
+        let generations = self
+            .budget
+            .max_generations
+            .map_or(self.generations, |max| max.min(self.generations));
+        if generations != usize::MAX {
+            fraction = fraction.max(self.generation as f64 / generations.max(1) as f64);
         }
+        fraction.min(1.0)
     }
 
-    /// Realiza uma busca local 2-opt para otimizar uma sequência.
-    fn _local_search_swap(&self, sequence: &mut Vec<usize>) {
-        let mut current_makespan = self.instance.calculate_makespan(sequence);
-        let mut improved = true;
+    /// Pior valor aceito como elite na busca local: o do último dos melhores
+    /// indivíduos da população atual, na fração `elite_fraction` da fase de
+    /// intensificação ou, fora dela, na fração da política `elite`. `None` quando a
+    /// busca local não é restrita às elites.
+    fn _elite_threshold(&self) -> Option<Time> {
+        let fraction = match (self.phase_schedule, self.ls_policy) {
+            (Some(schedule), _) if self.intensifying => schedule.elite_fraction,
+            (_, LocalSearchPolicy::Elite(q)) => q,
+            _ => return None,
+        };
+        if self.fitness.is_empty() {
+            return Some(Time::MAX);
+        }
+        let count =
+            ((fraction * self.fitness.len() as f64).ceil() as usize).clamp(1, self.fitness.len());
+        let mut fitness = self.fitness.clone();
+        Some(*fitness.select_nth_unstable(count - 1).1)
+    }
 
-        while improved {
-            improved = false;
-            for i in 0..self.instance.n_jobs {
-                for j in (i + 1)..self.instance.n_jobs {
-                    sequence.swap(i, j); // Tenta uma troca.
-                    let new_makespan = self.instance.calculate_makespan(sequence);
-                    if new_makespan < current_makespan {
-                        current_makespan = new_makespan;
-                        improved = true;
-                    } else {
-                        sequence.swap(i, j); // Desfaz a troca se não houver melhoria.
+    /// Aplica a busca local configurada em indivíduos selecionados, até o fim do
+    /// orçamento (os indivíduos restantes permanecem sem busca local). Na fase de
+    /// intensificação do cronograma em duas fases e na política `elite`, são
+    /// selecionados todos os indivíduos que estariam entre as elites da população
+    /// atual; na política `unique`, os ainda não avaliados; na política `random`,
+    /// cada indivíduo é sorteado com a taxa de busca local da fase. Retorna, na ordem
+    /// dos indivíduos visitados, o valor calculado pela busca local (`None` para os
+    /// não selecionados).
+    fn _apply_local_search<'a>(
+        &mut self,
+        individuals: impl IntoIterator<Item = &'a mut [Job]>,
+    ) -> Vec<Option<Time>> {
+        let elite_threshold = self._elite_threshold();
+        let local_search_rate = match self.phase_schedule {
+            Some(schedule) if !self.intensifying => schedule.exploration_local_search_rate,
+            _ => self.local_search_rate,
+        };
+        // A ILS e a VNS respeitam apenas o orçamento global.
+        let budget_only = SearchLimits {
+            deadline: self.ls_limits.deadline,
+            max_evaluations: self.ls_limits.max_evaluations,
+            ..SearchLimits::default()
+        };
+        // As buscas por inserção alteram o vetor da sequência; o indivíduo é copiado
+        // para um vetor auxiliar, reaproveitado, e o resultado é copiado de volta.
+        let mut scratch: Vec<Job> = Vec::with_capacity(self.instance.n_jobs);
+        let mut known = Vec::new();
+        for individual in individuals {
+            if self.budget_exhausted() {
+                break;
+            }
+            let selected = match elite_threshold {
+                Some(threshold) => {
+                    self.fitness_cache.evaluate(&self.instance, individual) <= threshold
+                }
+                None if self.ls_policy == LocalSearchPolicy::Unique => {
+                    !self.fitness_cache.contains(individual)
+                }
+                None => self.rng.gen::<f64>() < local_search_rate,
+            };
+            if selected {
+                let before = self._value_before(individual);
+                scratch.clear();
+                scratch.extend_from_slice(individual);
+                let sequence = &mut scratch;
+                let after = match self.local_search {
+                    LocalSearch::Swap => local_search::swap_descent_limited(
+                        &self.instance,
+                        sequence,
+                        &self.ls_limits,
+                        &mut self.rng,
+                    ),
+                    LocalSearch::Ils => ils::improve(
+                        &self.instance,
+                        sequence,
+                        &self.ils_params,
+                        &budget_only,
+                        &mut self.rng,
+                    ),
+                    LocalSearch::Vnd => {
+                        local_search::vnd_limited(&self.instance, sequence, &self.ls_limits)
                     }
+                    LocalSearch::Critical => local_search::critical_descent_limited(
+                        &self.instance,
+                        sequence,
+                        &self.ls_limits,
+                    ),
+                    LocalSearch::Vns => local_search::vns_limited(
+                        &self.instance,
+                        sequence,
+                        self.vns_iterations,
+                        &budget_only,
+                        &mut self.rng,
+                    ),
+                };
+                individual.copy_from_slice(sequence);
+                // A busca local já calculou o valor do resultado: retornado junto ao
+                // indivíduo (e registrado no cache), ele é reaproveitado na avaliação
+                // dos filhos, no elitismo e na próxima geração sem uma nova avaliação.
+                self.fitness_cache.insert(individual, after);
+                if let (Some(before), Some(stats)) = (before, &mut self.operator_stats) {
+                    stats.local_search.record(before, after);
                 }
+                known.push(Some(after));
+            } else {
+                known.push(None);
             }
         }
+        known
     }
 
-    /// Implementa o elitismo, preservando o melhor indivíduo da geração atual.
-    fn _elitism(&mut self, next_population: &mut Vec<Vec<usize>>) {
-        // Encontra o melhor indivíduo da população atual.
-        let best_current_idx = self
-            .fitness
+    /// Avalia os filhos da geração cujo valor não é conhecido em `known` (ex: o
+    /// calculado pela busca local), em lote (os ausentes do cache em paralelo). A
+    /// aptidão é calculada uma única vez e reaproveitada na próxima geração.
+    fn _evaluate_children(
+        &mut self,
+        next_population: &Population,
+        known: &[Option<Time>],
+    ) -> Vec<Time> {
+        let value = |idx: usize| known.get(idx).copied().flatten();
+        let unknown: Vec<&[Job]> = next_population
             .iter()
             .enumerate()
-            .min_by_key(|&(_, &f)| f)
-            .unwrap()
-            .0;
+            .filter(|&(idx, _)| value(idx).is_none())
+            .map(|(_, individual)| individual)
+            .collect();
+        let mut computed = self
+            .fitness_cache
+            .evaluate_batch(&self.instance, unknown)
+            .into_iter();
+        (0..next_population.len())
+            .map(|idx| value(idx).unwrap_or_else(|| computed.next().unwrap()))
+            .collect()
+    }
+
+    /// Implementa o elitismo: os `elite_count` melhores indivíduos da geração atual
+    /// substituem os piores filhos, atualizando a aptidão `next_fitness` da nova
+    /// população.
+    fn _elitism(&mut self, next_population: &mut Population, next_fitness: &mut [Time]) {
+        // Melhores indivíduos da população atual e piores filhos.
+        let mut elite: Vec<usize> = (0..self.fitness.len()).collect();
+        elite.sort_by_key(|&idx| self.fitness[idx]);
+        let mut worst: Vec<usize> = (0..next_population.len()).collect();
+        worst.sort_by_key(|&idx| Reverse(next_fitness[idx]));
 
-        let elite_individual = self.population[best_current_idx].clone();
+        for (&elite_idx, &worst_idx) in elite.iter().zip(&worst).take(self.elite_count) {
+            next_population[worst_idx].copy_from_slice(&self.population[elite_idx]);
+            next_fitness[worst_idx] = self.fitness[elite_idx];
+        }
+    }
+}
+
+impl Solver for MemeticAlgorithm {
+    fn name(&self) -> &'static str {
+        "memetic"
+    }
 
-        // Encontra o pior indivíduo na próxima população.
-        let mut worst_idx = 0;
-        let mut max_makespan = u32::MIN;
+    /// Executa o Algoritmo Memético notificando o observador a cada geração e a cada
+    /// nova melhor solução. O observador pode encerrar a execução antecipadamente.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        // O orçamento também interrompe a busca local e o cruzamento.
+        self.set_budget(budget);
+        self.initialize(); // Inicializa a população.
 
-        for (idx, seq) in next_population.iter().enumerate() {
-            let makespan = self.instance.calculate_makespan(seq);
-            if makespan > max_makespan {
-                max_makespan = makespan;
-                worst_idx = idx;
+        for gen in 0..self.generations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, gen, self.instance.evaluations.get()) {
+                break;
+            }
+
+            let _span = debug_span!("generation", gen = gen + 1).entered();
+            if self.evolve_generation() {
+                debug!(makespan = self.best_makespan, "Nova melhor solução");
+                observer.on_new_best(&Solution {
+                    sequence: self.best_sequence.clone(),
+                    makespan: self.best_makespan,
+                });
+            }
+
+            let stats = self._generation_stats(gen + 1, start_time);
+            stats.trace();
+            if let Some(operators) = &stats.operators {
+                debug!(
+                    crossover = %operators.crossover,
+                    mutation = %operators.mutation,
+                    local_search = %operators.local_search,
+                    "Operadores: melhorias/aplicações (melhoria média)"
+                );
+            }
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+            // Uma solução externa melhor entra no lugar do pior indivíduo.
+            if let Some(solution) = observer.incoming() {
+                if solution.makespan < self.best_makespan {
+                    self.replace_worst(vec![solution.sequence]);
+                }
+            }
+        }
+
+        // A população da última geração ainda não foi comparada à melhor solução.
+        if self.sync_best() {
+            debug!(makespan = self.best_makespan, "Nova melhor solução");
+            observer.on_new_best(&Solution {
+                sequence: self.best_sequence.clone(),
+                makespan: self.best_makespan,
+            });
+        }
+
+        debug!(
+            hits = self.fitness_cache.hits,
+            misses = self.fitness_cache.misses,
+            hit_rate = self.fitness_cache.hit_rate(),
+            "Cache de aptidão"
+        );
+        if let Some(aos) = &self.aos {
+            debug!(usage = %aos.usage(), "Seleção adaptativa de operadores");
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+
+    fn population(&self) -> Vec<(Vec<Job>, Time)> {
+        let mut population: Vec<(Vec<Job>, Time)> = if self.fitness.len() == self.population.len() {
+            self.population
+                .iter()
+                .map(<[Job]>::to_vec)
+                .zip(self.fitness.iter().copied())
+                .collect()
+        } else {
+            // Aptidão ainda não calculada (ex: após uma migração).
+            self.population
+                .iter()
+                .map(|seq| (seq.to_vec(), self.instance.evaluate(seq)))
+                .collect()
+        };
+        population.sort_by_key(|&(_, value)| value);
+        population
+    }
+}
+
+/// Distância de Kendall-tau entre duas permutações: número de pares de tarefas
+/// que aparecem em ordem relativa diferente nas duas sequências. As inversões são
+/// contadas por ordenação por intercalação, em O(n log n).
+pub fn kendall_tau_distance(a: &[Job], b: &[Job]) -> usize {
+    // Posição de cada tarefa na sequência `b`.
+    let mut position = vec![0; b.len()];
+    for (idx, &job) in b.iter().enumerate() {
+        position[job as usize] = idx;
+    }
+    let mut mapped: Vec<usize> = a.iter().map(|&job| position[job as usize]).collect();
+    let mut buffer = vec![0; mapped.len()];
+    _count_inversions(&mut mapped, &mut buffer)
+}
+
+/// Ordena `values` por intercalação, usando `buffer` (do mesmo tamanho) como área
+/// de trabalho, e retorna o número de pares fora de ordem.
+fn _count_inversions(values: &mut [usize], buffer: &mut [usize]) -> usize {
+    let n = values.len();
+    if n < 2 {
+        return 0;
+    }
+    let mid = n / 2;
+    let mut inversions = _count_inversions(&mut values[..mid], &mut buffer[..mid])
+        + _count_inversions(&mut values[mid..], &mut buffer[mid..]);
+    let (mut i, mut j) = (0, mid);
+    for slot in buffer.iter_mut() {
+        if j == n || (i < mid && values[i] <= values[j]) {
+            *slot = values[i];
+            i += 1;
+        } else {
+            // Cada elemento restante da metade esquerda forma uma inversão.
+            inversions += mid - i;
+            *slot = values[j];
+            j += 1;
+        }
+    }
+    values.copy_from_slice(buffer);
+    inversions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fssp_core::parse_instance;
+    use crate::observer::{Control, GenerationStats, NoopObserver, Observer, OperatorStats};
+
+    /// Soma as estatísticas dos operadores de todas as gerações.
+    #[derive(Default)]
+    struct OperatorTotals(OperatorStats);
+
+    impl Observer for OperatorTotals {
+        fn on_generation(&mut self, stats: &GenerationStats) -> Control {
+            if let Some(operators) = &stats.operators {
+                self.0.merge(operators);
+            }
+            Control::Continue
+        }
+    }
+
+    #[test]
+    fn unique_local_search_runs_with_operator_stats() {
+        let instance = parse_instance("6 3\n5 9 8\n9 3 10\n9 4 5\n4 8 8\n3 5 6\n7 2 9\n").unwrap();
+        let params = MemeticParams {
+            population_size: 10,
+            generations: 5,
+            ..MemeticParams::default()
+        };
+        let mut solver = MemeticAlgorithm::new(instance, params)
+            .unwrap()
+            .with_local_search_policy(LocalSearchPolicy::Unique)
+            .with_operator_stats(true)
+            .with_seed(3);
+        let mut totals = OperatorTotals::default();
+        let start_time = Instant::now();
+        solver.run_with_observer(start_time, &Budget::default(), &mut totals);
+        assert!(totals.0.crossover.applications > 0);
+        assert!(totals.0.local_search.applications > 0);
+    }
+
+    #[test]
+    fn child_fitness_is_kept_without_cache() {
+        let instance = parse_instance("6 3\n5 9 8\n9 3 10\n9 4 5\n4 8 8\n3 5 6\n7 2 9\n").unwrap();
+        for replacement in [
+            Replacement::Generational,
+            Replacement::SteadyState,
+            Replacement::Crowding,
+        ] {
+            let params = MemeticParams {
+                population_size: 10,
+                generations: 5,
+                mutation_rate: 0.2,
+                local_search_rate: 1.0,
+            };
+            let mut solver = MemeticAlgorithm::new(instance.clone(), params)
+                .unwrap()
+                .with_replacement(replacement)
+                .with_fitness_cache(0)
+                .with_seed(5);
+            let start_time = Instant::now();
+            solver.run_with_observer(start_time, &Budget::default(), &mut NoopObserver);
+            for (individual, &value) in solver.population.iter().zip(&solver.fitness) {
+                assert_eq!(value, solver.instance.evaluate(individual));
+            }
+        }
+    }
+
+    #[test]
+    fn kendall_tau_distance_matches_pair_count() {
+        let a: Vec<Job> = vec![3, 0, 5, 1, 4, 2, 6];
+        let b: Vec<Job> = vec![6, 2, 0, 4, 1, 3, 5];
+        let pos = |seq: &[Job], job: Job| seq.iter().position(|&j| j == job).unwrap();
+        let mut expected = 0;
+        for x in 0..7 {
+            for y in (x + 1)..7 {
+                if (pos(&a, x) < pos(&a, y)) != (pos(&b, x) < pos(&b, y)) {
+                    expected += 1;
+                }
             }
         }
-        // Substitui o pior indivíduo pelo elite.
-        next_population[worst_idx] = elite_individual;
+        assert_eq!(kendall_tau_distance(&a, &b), expected);
+        assert_eq!(kendall_tau_distance(&a, &a), 0);
+        let reversed: Vec<Job> = a.iter().rev().copied().collect();
+        assert_eq!(kendall_tau_distance(&a, &reversed), 21);
     }
 }
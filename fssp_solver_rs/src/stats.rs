@@ -0,0 +1,262 @@
+/// Maior número de diferenças não nulas para o qual o teste de Wilcoxon usa a
+/// distribuição exata; acima disso, usa a aproximação normal.
+const WILCOXON_EXACT_LIMIT: usize = 50;
+
+/// Resultado do teste de postos sinalizados de Wilcoxon (pareado, bilateral).
+#[derive(Debug, Clone)]
+pub struct WilcoxonResult {
+    pub w_plus: f64,  // Soma dos postos das diferenças positivas (x > y).
+    pub w_minus: f64, // Soma dos postos das diferenças negativas (x < y).
+    pub n: usize,     // Número de pares com diferença não nula.
+    pub p_value: f64, // Valor-p bilateral.
+}
+
+/// Resultado do teste de Friedman.
+#[derive(Debug, Clone)]
+pub struct FriedmanResult {
+    pub statistic: f64,       // Estatística qui-quadrado (corrigida para empates).
+    pub df: usize,            // Graus de liberdade (tratamentos - 1).
+    pub p_value: f64,         // Valor-p pela distribuição qui-quadrado.
+    pub mean_ranks: Vec<f64>, // Posto médio de cada tratamento (menor é melhor).
+}
+
+/// Postos (a partir de 1) dos valores, atribuindo a média dos postos aos empates.
+/// Retorna também a soma de `t³ - t` dos grupos de empates, usada nas correções.
+pub fn average_ranks(values: &[f64]) -> (Vec<f64>, f64) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut ties = 0.0;
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &idx in &order[start..end] {
+            ranks[idx] = rank;
+        }
+        let t = (end - start) as f64;
+        ties += t * t * t - t;
+        start = end;
+    }
+    (ranks, ties)
+}
+
+/// Teste de postos sinalizados de Wilcoxon para amostras pareadas `x` e `y`.
+/// Pares com diferença nula são descartados. Usa a distribuição exata para até
+/// `WILCOXON_EXACT_LIMIT` pares e a aproximação normal (com correções de empates e
+/// de continuidade) acima disso.
+pub fn wilcoxon_signed_rank(x: &[f64], y: &[f64]) -> WilcoxonResult {
+    let diffs: Vec<f64> = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| a - b)
+        .filter(|d| *d != 0.0)
+        .collect();
+    let n = diffs.len();
+    if n == 0 {
+        return WilcoxonResult {
+            w_plus: 0.0,
+            w_minus: 0.0,
+            n,
+            p_value: 1.0,
+        };
+    }
+
+    let abs: Vec<f64> = diffs.iter().map(|d| d.abs()).collect();
+    let (ranks, ties) = average_ranks(&abs);
+    let w_plus: f64 = ranks
+        .iter()
+        .zip(&diffs)
+        .filter(|(_, d)| **d > 0.0)
+        .fold(0.0, |sum, (r, _)| sum + r);
+    let total = (n * (n + 1)) as f64 / 2.0;
+    let w_minus = total - w_plus;
+    let w_min = w_plus.min(w_minus);
+
+    let p_value = if n <= WILCOXON_EXACT_LIMIT {
+        // Distribuição exata de W+ por programação dinâmica sobre os postos dobrados
+        // (inteiros mesmo com postos médios de empates).
+        let doubled: Vec<usize> = ranks.iter().map(|r| (2.0 * r).round() as usize).collect();
+        let max_sum: usize = doubled.iter().sum();
+        let mut counts = vec![0.0f64; max_sum + 1];
+        counts[0] = 1.0;
+        for &r in &doubled {
+            for s in (r..=max_sum).rev() {
+                counts[s] += counts[s - r];
+            }
+        }
+        let limit = (2.0 * w_min).round() as usize;
+        let tail: f64 = counts[..=limit].iter().sum::<f64>() / 2f64.powi(n as i32);
+        (2.0 * tail).min(1.0)
+    } else {
+        let mean = total / 2.0;
+        let variance = (n * (n + 1) * (2 * n + 1)) as f64 / 24.0 - ties / 48.0;
+        let z = ((w_min - mean).abs() - 0.5).max(0.0) / variance.sqrt();
+        (2.0 * normal_sf(z)).min(1.0)
+    };
+
+    WilcoxonResult {
+        w_plus,
+        w_minus,
+        n,
+        p_value,
+    }
+}
+
+/// Teste de Friedman: `blocks[b][t]` é o valor do tratamento `t` no bloco `b` (menor é
+/// melhor). Todos os blocos devem conter os mesmos tratamentos, na mesma ordem.
+pub fn friedman(blocks: &[Vec<f64>]) -> FriedmanResult {
+    let k = blocks.first().map_or(0, |b| b.len());
+    let n = blocks.len();
+    if k < 2 || n == 0 {
+        return FriedmanResult {
+            statistic: 0.0,
+            df: k.saturating_sub(1),
+            p_value: 1.0,
+            mean_ranks: vec![1.0; k],
+        };
+    }
+
+    let mut rank_sums = vec![0.0; k];
+    let mut ties = 0.0;
+    for block in blocks {
+        let (ranks, block_ties) = average_ranks(block);
+        for (sum, rank) in rank_sums.iter_mut().zip(ranks) {
+            *sum += rank;
+        }
+        ties += block_ties;
+    }
+
+    let (nf, kf) = (n as f64, k as f64);
+    let squares: f64 = rank_sums.iter().map(|r| r * r).sum();
+    let statistic = 12.0 / (nf * kf * (kf + 1.0)) * squares - 3.0 * nf * (kf + 1.0);
+    let correction = 1.0 - ties / (nf * (kf * kf * kf - kf));
+    let statistic = if correction > 0.0 {
+        statistic / correction
+    } else {
+        0.0
+    };
+
+    FriedmanResult {
+        statistic,
+        df: k - 1,
+        p_value: chi_square_sf(statistic, (k - 1) as f64),
+        mean_ranks: rank_sums.iter().map(|r| r / nf).collect(),
+    }
+}
+
+/// Ajuste de Holm dos valores-p para comparações múltiplas (na ordem original).
+pub fn holm(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+
+    let mut adjusted = vec![0.0; m];
+    let mut running_max: f64 = 0.0;
+    for (i, &idx) in order.iter().enumerate() {
+        running_max = running_max.max(((m - i) as f64 * p_values[idx]).min(1.0));
+        adjusted[idx] = running_max;
+    }
+    adjusted
+}
+
+/// Função de sobrevivência da normal padrão, P(Z > z).
+pub fn normal_sf(z: f64) -> f64 {
+    // erfc(x) = Q(1/2, x²) para x >= 0.
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let upper = 0.5 * gamma_q(0.5, x * x);
+    if z >= 0.0 {
+        upper
+    } else {
+        1.0 - upper
+    }
+}
+
+/// Função de sobrevivência da distribuição qui-quadrado com `df` graus de liberdade.
+pub fn chi_square_sf(x: f64, df: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    gamma_q(df / 2.0, x / 2.0)
+}
+
+/// Logaritmo da função gama (aproximação de Lanczos).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Fórmula de reflexão.
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (i, &c) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += c / (x + i as f64);
+    }
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Função gama incompleta superior regularizada Q(a, x), por série (x < a + 1) ou
+/// fração contínua de Lentz.
+fn gamma_q(a: f64, x: f64) -> f64 {
+    const EPS: f64 = 1e-14;
+    const MAX_ITER: usize = 500;
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let log_prefix = a * x.ln() - x - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // Série de P(a, x).
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITER {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        (1.0 - sum * log_prefix.exp()).max(0.0)
+    } else {
+        let tiny = f64::MIN_POSITIVE / EPS;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..MAX_ITER {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPS {
+                break;
+            }
+        }
+        (log_prefix.exp() * h).min(1.0)
+    }
+}
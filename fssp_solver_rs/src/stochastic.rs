@@ -0,0 +1,338 @@
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::robust::{RobustCriterion, Uncertainty};
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Família das distribuições dos tempos de processamento simulados. A média de
+/// cada operação é o seu tempo na instância.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseFamily {
+    Normal,      // Normal truncada em zero.
+    Uniform,     // Uniforme simétrica em torno da média.
+    LogNormal,   // Log-normal, sempre positiva e assimétrica à direita.
+    Exponential, // Exponencial, com desvio padrão igual à média.
+}
+
+/// Distribuição dos tempos de processamento simulados, no formato `família:cv`
+/// (ex: `normal:0.1`), onde `cv` é o coeficiente de variação (desvio padrão
+/// dividido pela média) comum a todas as operações. O coeficiente pode ser omitido
+/// quando os desvios de cada operação são informados à parte, e é sempre omitido
+/// na exponencial.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseModel {
+    pub family: NoiseFamily,    // Família da distribuição.
+    pub variation: Option<f64>, // Coeficiente de variação, se comum a todas.
+}
+
+impl FromStr for NoiseModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, variation) = match s.split_once(':') {
+            Some((name, variation)) => (name, Some(variation)),
+            None => (s, None),
+        };
+        let family = match name.to_ascii_lowercase().as_str() {
+            "normal" => NoiseFamily::Normal,
+            "uniform" => NoiseFamily::Uniform,
+            "lognormal" => NoiseFamily::LogNormal,
+            "exponential" => NoiseFamily::Exponential,
+            _ => {
+                return Err(format!(
+                "Distribuição desconhecida '{}' (use normal, uniform, lognormal ou exponential)",
+                name
+            ))
+            }
+        };
+        let variation = variation
+            .map(|v| match v.trim().parse::<f64>() {
+                Ok(v) if v.is_finite() && v >= 0.0 => Ok(v),
+                _ => Err(format!("Coeficiente de variação inválido '{}'", v)),
+            })
+            .transpose()?;
+        if family == NoiseFamily::Exponential && variation.is_some() {
+            return Err(
+                "A exponencial não aceita coeficiente de variação (o desvio é a média)".to_string(),
+            );
+        }
+        Ok(NoiseModel { family, variation })
+    }
+}
+
+impl fmt::Display for NoiseModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.family {
+            NoiseFamily::Normal => "normal",
+            NoiseFamily::Uniform => "uniform",
+            NoiseFamily::LogNormal => "lognormal",
+            NoiseFamily::Exponential => "exponential",
+        };
+        match self.variation {
+            Some(variation) => write!(f, "{}:{}", name, variation),
+            None => write!(f, "{}", name),
+        }
+    }
+}
+
+impl NoiseModel {
+    /// Sorteia um tempo com a média e o desvio padrão informados.
+    pub fn sample<R: Rng>(&self, mean: f64, std_dev: f64, rng: &mut R) -> f64 {
+        if mean <= 0.0 {
+            return 0.0;
+        }
+        match self.family {
+            NoiseFamily::Normal => (mean + std_dev * _standard_normal(rng)).max(0.0),
+            NoiseFamily::Uniform => {
+                let half_width = 3f64.sqrt() * std_dev;
+                (mean + half_width * rng.gen_range(-1.0..=1.0)).max(0.0)
+            }
+            NoiseFamily::LogNormal => {
+                let sigma2 = (1.0 + (std_dev / mean).powi(2)).ln();
+                (mean.ln() - sigma2 / 2.0 + sigma2.sqrt() * _standard_normal(rng)).exp()
+            }
+            NoiseFamily::Exponential => -mean * (1.0 - rng.gen::<f64>()).ln(),
+        }
+    }
+}
+
+/// Amostra da normal padrão pelo método de Box-Muller.
+fn _standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+/// Reamostragem adaptativa das replicações da simulação. Cada sequência é estimada
+/// inicialmente com `initial` replicações; enquanto a estimativa for promissora
+/// (média menos `z` erros padrão não maior que a melhor média conhecida), mais
+/// `initial` replicações são simuladas, até todas as disponíveis. Assim, as
+/// sequências ruins custam poucas simulações e as candidatas a melhor são
+/// estimadas com precisão. A melhor média conhecida é a menor entre as sequências
+/// estimadas com todas as replicações. Ela é compartilhada apenas entre as cópias
+/// obtidas com `share` (as ilhas de um mesmo modelo); `clone` começa uma estimativa
+/// independente, de modo que execuções distintas não influenciam umas às outras.
+#[derive(Debug)]
+pub struct AdaptiveSampling {
+    pub initial: usize,        // Replicações iniciais e de cada reamostragem.
+    pub z: f64,                // Erros padrão de tolerância para ser promissora.
+    threshold: Arc<AtomicU64>, // Melhor média com todas as replicações (bits de f64).
+}
+
+impl AdaptiveSampling {
+    pub fn new(initial: usize, z: f64) -> Self {
+        AdaptiveSampling {
+            initial: initial.max(2),
+            z,
+            threshold: Arc::new(AtomicU64::new(f64::INFINITY.to_bits())),
+        }
+    }
+
+    /// Cópia que compartilha a melhor média conhecida com esta.
+    pub fn share(&self) -> Self {
+        AdaptiveSampling {
+            initial: self.initial,
+            z: self.z,
+            threshold: Arc::clone(&self.threshold),
+        }
+    }
+
+    /// Média arredondada dos valores das replicações (`total` disponíveis),
+    /// consumidos em lotes enquanto a sequência for promissora.
+    pub fn estimate(&self, mut values: impl Iterator<Item = Time>, total: usize) -> Time {
+        let mut stats = Estimate::default();
+        loop {
+            values
+                .by_ref()
+                .take(self.initial)
+                .for_each(|value| stats.push(value));
+            let threshold = f64::from_bits(self.threshold.load(Ordering::Relaxed));
+            if stats.replications >= total || stats.mean - self.z * stats.std_error() > threshold {
+                break;
+            }
+        }
+        if stats.replications >= total {
+            let _ = self
+                .threshold
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                    (stats.mean < f64::from_bits(bits)).then_some(stats.mean.to_bits())
+                });
+        }
+        stats.mean.round() as Time
+    }
+}
+
+impl Clone for AdaptiveSampling {
+    fn clone(&self) -> Self {
+        AdaptiveSampling::new(self.initial, self.z)
+    }
+}
+
+/// Média e erro padrão da média dos valores de uma sequência nas replicações.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Estimate {
+    pub mean: f64,           // Média dos valores.
+    pub replications: usize, // Número de replicações.
+    sum_squares: f64,        // Soma dos quadrados dos desvios (método de Welford).
+}
+
+impl Estimate {
+    /// Acrescenta o valor de uma replicação.
+    pub fn push(&mut self, value: Time) {
+        let value = value as f64;
+        self.replications += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.replications as f64;
+        self.sum_squares += delta * (value - self.mean);
+    }
+
+    /// Erro padrão da média (infinito com menos de duas replicações).
+    pub fn std_error(&self) -> f64 {
+        if self.replications < 2 {
+            return f64::INFINITY;
+        }
+        let variance = self.sum_squares / (self.replications - 1) as f64;
+        (variance / self.replications as f64).sqrt()
+    }
+}
+
+/// Cenários simulados: `replications` replicações dos tempos de processamento,
+/// sorteados de `model` com média igual ao tempo da instância. O desvio padrão de
+/// cada operação é o coeficiente de variação vezes a média ou, se informado, o
+/// tempo correspondente em `std_devs` (instância com as mesmas dimensões). As
+/// mesmas replicações são usadas em todas as avaliações (números aleatórios
+/// comuns), de modo que as sequências são comparadas sob as mesmas condições.
+pub fn simulation_uncertainty<R: Rng>(
+    instance: &mut FSSPInstance,
+    model: NoiseModel,
+    std_devs: Option<FSSPInstance>,
+    replications: usize,
+    sampling: AdaptiveSampling,
+    rng: &mut R,
+) -> Result<Uncertainty> {
+    let deviations = match (std_devs, model.variation, model.family) {
+        (Some(_), Some(_), _) => {
+            return Err(FsspError::Config(
+                "informe o coeficiente de variação ou os desvios de cada operação, não ambos"
+                    .to_string(),
+            ))
+        }
+        (Some(mut std_devs), None, _) => {
+            if (std_devs.n_jobs, std_devs.n_machines) != (instance.n_jobs, instance.n_machines) {
+                return Err(FsspError::Instance(format!(
+                    "os desvios têm {}x{} tarefas e máquinas, esperadas {}x{}",
+                    std_devs.n_jobs, std_devs.n_machines, instance.n_jobs, instance.n_machines
+                )));
+            }
+            let decimals = instance.time_decimals.max(std_devs.time_decimals);
+            instance.rescale(decimals)?;
+            std_devs.rescale(decimals)?;
+            std_devs.processing_times
+        }
+        (None, Some(variation), _) => instance
+            .processing_times
+            .iter()
+            .map(|times| {
+                times
+                    .iter()
+                    .map(|&t| (t as f64 * variation).round() as Time)
+                    .collect()
+            })
+            .collect(),
+        (None, None, NoiseFamily::Exponential) => instance.processing_times.clone(),
+        (None, None, _) => {
+            return Err(FsspError::Config(format!(
+                "a distribuição '{}' exige o coeficiente de variação (ex: {}:0.1) ou os desvios de cada operação",
+                model, model
+            )))
+        }
+    };
+
+    let scenarios = (0..replications)
+        .map(|_| {
+            instance
+                .processing_times
+                .iter()
+                .zip(&deviations)
+                .map(|(means, devs)| {
+                    means
+                        .iter()
+                        .zip(devs)
+                        .map(|(&mean, &dev)| {
+                            model.sample(mean as f64, dev as f64, rng).round() as Time
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+    Ok(Uncertainty {
+        scenarios,
+        criterion: RobustCriterion::Expected,
+        sampling: Some(sampling),
+    })
+}
+
+/// Estimativa da função objetivo da sequência com todas as replicações da
+/// simulação da instância, se houver.
+pub fn full_estimate(instance: &FSSPInstance, sequence: &[Job]) -> Option<Estimate> {
+    let uncertainty = instance.uncertainty.as_ref()?;
+    uncertainty.sampling.as_ref()?;
+    let mut estimate = Estimate::default();
+    for times in &uncertainty.scenarios {
+        estimate.push(instance.evaluate_scenario(times, sequence));
+    }
+    Some(estimate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fitness_cache::FitnessCache;
+    use crate::fssp_core::parse_instance;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn adaptive_sampling_shares_threshold_only_when_shared() {
+        let sampling = AdaptiveSampling::new(2, 1.0);
+        let shared = sampling.share();
+        let independent = sampling.clone();
+        // Com todas as replicações, a média passa a ser a melhor conhecida.
+        assert_eq!(shared.estimate([10, 12, 14, 16].into_iter(), 4), 13);
+        // Uma sequência bem pior é descartada após as replicações iniciais.
+        let mut consumed = 0;
+        let values = [100, 102, 104, 106].into_iter().inspect(|_| consumed += 1);
+        assert_eq!(sampling.estimate(values, 4), 101);
+        assert_eq!(consumed, 2);
+        // A cópia independente não conhece a melhor média e usa todas.
+        let mut consumed = 0;
+        let values = [100, 102, 104, 106].into_iter().inspect(|_| consumed += 1);
+        assert_eq!(independent.estimate(values, 4), 103);
+        assert_eq!(consumed, 4);
+    }
+
+    #[test]
+    fn fitness_cache_is_disabled_with_adaptive_sampling() {
+        let mut instance = parse_instance("3 2\n3 4\n2 5\n4 1\n").unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        let model: NoiseModel = "normal:0.2".parse().unwrap();
+        let uncertainty = simulation_uncertainty(
+            &mut instance,
+            model,
+            None,
+            8,
+            AdaptiveSampling::new(2, 1.0),
+            &mut rng,
+        )
+        .unwrap();
+        instance.set_uncertainty(uncertainty).unwrap();
+
+        let mut cache = FitnessCache::for_instance(&instance, 100);
+        cache.evaluate(&instance, &[0, 1, 2]);
+        assert!(!cache.contains(&[0, 1, 2]));
+    }
+}
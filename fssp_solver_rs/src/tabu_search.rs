@@ -0,0 +1,171 @@
+use crate::budget::Budget;
+use crate::fssp_core::{FSSPInstance, Job, Time};
+use crate::heuristics;
+use crate::observer::{Control, GenerationStats, Observer, Solution};
+use crate::solver::Solver;
+use crate::time::Instant;
+use rand::seq::index;
+use tracing::{debug, debug_span};
+
+/// Parâmetros da busca tabu.
+#[derive(Debug, Clone)]
+pub struct TabuParams {
+    pub iterations: usize, // Número de movimentos realizados.
+    pub tenure: usize,     // Iterações em que uma tarefa movida permanece tabu.
+    pub candidates: usize, // Tarefas sorteadas para a lista de candidatos de cada iteração.
+}
+
+impl Default for TabuParams {
+    fn default() -> Self {
+        TabuParams {
+            iterations: 100,
+            tenure: 7,
+            candidates: 10,
+        }
+    }
+}
+
+/// Busca tabu na vizinhança de inserção: parte da solução do NEH e, a cada iteração,
+/// move para a melhor posição uma das `candidates` tarefas sorteadas, mesmo que o
+/// movimento piore a solução. A tarefa movida fica tabu (não pode ser movida de novo)
+/// por `tenure` iterações, exceto se o movimento produzir uma nova melhor solução
+/// (critério de aspiração).
+pub struct TabuSearch {
+    instance: FSSPInstance,             // Instância do problema FSSP.
+    params: TabuParams,                 // Parâmetros da busca (iterações = critério de parada).
+    initial_solution: Option<Vec<Job>>, // Solução inicial fornecida (substitui o NEH).
+    pub best_sequence: Vec<Job>,        // A melhor sequência de tarefas encontrada.
+    pub best_makespan: Time,            // O makespan da melhor sequência encontrada.
+}
+
+impl TabuSearch {
+    /// Cria uma nova instância do `TabuSearch`.
+    pub fn new(instance: FSSPInstance, params: TabuParams) -> Self {
+        TabuSearch {
+            instance,
+            params,
+            initial_solution: None,
+            best_sequence: Vec::new(),
+            best_makespan: Time::MAX,
+        }
+    }
+
+    /// Define a solução (já validada) a partir da qual a busca é iniciada.
+    pub fn with_initial_solution(mut self, solution: Vec<Job>) -> Self {
+        self.initial_solution = Some(solution);
+        self
+    }
+
+    /// Melhor reinserção da tarefa na posição `from` em outra posição da sequência.
+    /// Retorna a posição de destino e o makespan resultante.
+    fn _best_move(&self, sequence: &mut Vec<Job>, from: usize) -> (usize, Time) {
+        let job = sequence.remove(from);
+        let mut best = (from, Time::MAX);
+        for to in (0..=sequence.len()).filter(|&to| to != from) {
+            sequence.insert(to, job);
+            let makespan = self.instance.evaluate(sequence);
+            sequence.remove(to);
+            if makespan < best.1 {
+                best = (to, makespan);
+            }
+        }
+        sequence.insert(from, job);
+        best
+    }
+}
+
+impl Solver for TabuSearch {
+    fn name(&self) -> &'static str {
+        "tabu"
+    }
+
+    /// Executa a busca tabu até o número máximo de iterações ou o esgotamento do
+    /// orçamento.
+    fn run_with_observer(
+        &mut self,
+        start_time: Instant,
+        budget: &Budget,
+        observer: &mut dyn Observer,
+    ) {
+        let mut rng = rand::thread_rng();
+        let n = self.instance.n_jobs;
+        // Iteração até a qual cada tarefa permanece tabu.
+        let mut tabu_until = vec![0; n];
+
+        let mut current = self
+            .initial_solution
+            .clone()
+            .unwrap_or_else(|| heuristics::neh(&self.instance));
+        let mut current_makespan = self.instance.evaluate(&current);
+        self.best_sequence = current.clone();
+        self.best_makespan = current_makespan;
+
+        for iter in 0..self.params.iterations {
+            // Verifica se o orçamento da execução se esgotou.
+            if budget.should_stop(start_time, iter, self.instance.evaluations.get()) {
+                break;
+            }
+            if n < 2 {
+                break;
+            }
+
+            let _span = debug_span!("iteration", iter = iter + 1).entered();
+            // Melhor movimento (origem, destino, makespan) permitido entre os candidatos.
+            let mut chosen: Option<(usize, usize, Time)> = None;
+            for from in index::sample(&mut rng, n, self.params.candidates.clamp(1, n)) {
+                let (to, makespan) = self._best_move(&mut current, from);
+                let tabu = tabu_until[current[from] as usize] > iter;
+                if (tabu && makespan >= self.best_makespan)
+                    || chosen.is_some_and(|(_, _, best)| best <= makespan)
+                {
+                    continue;
+                }
+                chosen = Some((from, to, makespan));
+            }
+            if let Some((from, to, makespan)) = chosen {
+                let job = current.remove(from);
+                current.insert(to, job);
+                current_makespan = makespan;
+                tabu_until[job as usize] = iter + 1 + self.params.tenure;
+                if makespan < self.best_makespan {
+                    self.best_makespan = makespan;
+                    self.best_sequence = current.clone();
+                    debug!(makespan, "Nova melhor solução");
+                    observer.on_new_best(&Solution {
+                        sequence: self.best_sequence.clone(),
+                        makespan,
+                    });
+                }
+            }
+
+            let stats = GenerationStats {
+                generation: iter + 1,
+                best_makespan: self.best_makespan,
+                generation_best: current_makespan,
+                mean_makespan: current_makespan as f64,
+                mutation_rate: 0.0,
+                diversity: None,
+                duplicate_rate: None,
+                operators: None,
+                evaluations: self.instance.evaluations.get(),
+                elapsed: start_time.elapsed(),
+            };
+            stats.trace();
+            if observer.on_generation(&stats) == Control::Stop {
+                break;
+            }
+        }
+    }
+
+    fn evaluations(&self) -> u64 {
+        self.instance.evaluations.get()
+    }
+
+    fn best_makespan(&self) -> Time {
+        self.best_makespan
+    }
+
+    fn best_sequence(&self) -> &[Job] {
+        &self.best_sequence
+    }
+}
@@ -0,0 +1,8 @@
+//! Relógio dos solvers. Em `wasm32-unknown-unknown`, `std::time::Instant` não tem
+//! fonte de tempo e entra em pânico ao ser consultado; lá o instante vem de
+//! `performance.now()` (crate `web-time`), com a mesma interface.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub use web_time::Instant;
@@ -0,0 +1,172 @@
+use crate::config::{MemeticConfig, SolverConfig};
+use crate::crossover::Crossover;
+use crate::error::Result;
+use crate::fssp_core::Time;
+use crate::local_search::LocalSearch;
+use crate::solver::Algorithm;
+use rand::seq::SliceRandom;
+use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use tracing::info;
+
+/// Tamanhos de população considerados no ajuste.
+const POPULATION_SIZES: [usize; 4] = [20, 50, 100, 200];
+/// Operadores de cruzamento considerados no ajuste.
+const CROSSOVERS: [Crossover; 4] = [
+    Crossover::Ox,
+    Crossover::Pmx,
+    Crossover::TwoPoint,
+    Crossover::Sbx,
+];
+/// Buscas locais consideradas no ajuste.
+const LOCAL_SEARCHES: [LocalSearch; 5] = [
+    LocalSearch::Swap,
+    LocalSearch::Ils,
+    LocalSearch::Vnd,
+    LocalSearch::Vns,
+    LocalSearch::Critical,
+];
+
+/// Procedimento de ajuste de parâmetros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuningMethod {
+    Random, // Busca aleatória: todas as configurações em todas as instâncias.
+    Race,   // Corrida: a cada instância, descarta a pior metade das configurações.
+}
+
+impl FromStr for TuningMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "random" => Ok(TuningMethod::Random),
+            "race" => Ok(TuningMethod::Race),
+            _ => Err(format!(
+                "Método de ajuste desconhecido '{}' (use random ou race)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for TuningMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TuningMethod::Random => "random",
+            TuningMethod::Race => "race",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Configuração vencedora do ajuste.
+#[derive(Debug, Clone)]
+pub struct TunedConfig {
+    pub config: SolverConfig, // Parâmetros da configuração vencedora.
+    pub mean_deviation: f64,  // Desvio percentual médio em relação à melhor de cada instância.
+    pub evaluations: usize,   // Total de execuções realizadas no ajuste.
+}
+
+/// Sorteia uma configuração do algoritmo memético (tamanho da população, taxas,
+/// operadores e mutação adaptativa).
+pub fn sample_config<R: Rng>(rng: &mut R) -> SolverConfig {
+    // Taxas arredondadas a duas casas para um arquivo de configuração legível.
+    let round = |x: f64| (x * 100.0).round() / 100.0;
+    SolverConfig {
+        algorithm: Some(Algorithm::Memetic),
+        ma: MemeticConfig {
+            population_size: POPULATION_SIZES.choose(rng).copied(),
+            mutation_rate: Some(round(rng.gen_range(0.05..=0.5))),
+            local_search_rate: Some(round(rng.gen_range(0.1..=0.9))),
+            crossover: CROSSOVERS.choose(rng).copied(),
+            local_search: LOCAL_SEARCHES.choose(rng).copied(),
+            adaptive: Some(rng.gen_bool(0.5)),
+            ..MemeticConfig::default()
+        },
+        ..SolverConfig::default()
+    }
+}
+
+/// Ajusta os parâmetros comparando `candidates` em `n_instances` instâncias de
+/// treinamento. `evaluate(config, instance)` executa o solver com a configuração e
+/// retorna o makespan obtido; cada rodada avalia as configurações em paralelo. O
+/// primeiro erro de uma execução (ex: opções incompatíveis com a instância) encerra
+/// o ajuste e é retornado.
+///
+/// A qualidade de uma configuração é o desvio percentual médio em relação ao melhor
+/// makespan obtido na mesma rodada. Na busca aleatória cada configuração é avaliada
+/// uma vez em cada instância; na corrida, as rodadas percorrem as instâncias (em ciclo,
+/// se necessário) e a pior metade das configurações é descartada após cada rodada.
+pub fn tune<F>(
+    candidates: Vec<SolverConfig>,
+    n_instances: usize,
+    method: TuningMethod,
+    evaluate: F,
+) -> Result<TunedConfig>
+where
+    F: Fn(&SolverConfig, usize) -> Result<Time> + Sync,
+{
+    assert!(!candidates.is_empty(), "nenhuma configuração candidata");
+    assert!(n_instances > 0, "nenhuma instância de treinamento");
+
+    let mut deviations: Vec<Vec<f64>> = vec![Vec::new(); candidates.len()];
+    let mut alive: Vec<usize> = (0..candidates.len()).collect();
+    let mut evaluations = 0;
+    let mut round = 0;
+
+    loop {
+        let instance = round % n_instances;
+        let makespans: Vec<Time> = maybe_par_iter!(alive)
+            .map(|&idx| evaluate(&candidates[idx], instance))
+            .collect::<Result<_>>()?;
+        evaluations += makespans.len();
+
+        let best = makespans.iter().copied().min().unwrap_or(0).max(1) as f64;
+        for (&idx, &makespan) in alive.iter().zip(&makespans) {
+            deviations[idx].push(100.0 * (makespan as f64 - best) / best);
+        }
+        round += 1;
+
+        match method {
+            TuningMethod::Random => {
+                if round == n_instances {
+                    break;
+                }
+            }
+            TuningMethod::Race => {
+                alive.sort_by(|&a, &b| mean(&deviations[a]).total_cmp(&mean(&deviations[b])));
+                alive.truncate(alive.len().div_ceil(2));
+                info!(
+                    round,
+                    survivors = alive.len(),
+                    "Rodada da corrida concluída"
+                );
+                if alive.len() == 1 {
+                    break;
+                }
+            }
+        }
+    }
+
+    let winner = *alive
+        .iter()
+        .min_by(|&&a, &&b| mean(&deviations[a]).total_cmp(&mean(&deviations[b])))
+        .unwrap();
+    Ok(TunedConfig {
+        config: candidates[winner].clone(),
+        mean_deviation: mean(&deviations[winner]),
+        evaluations,
+    })
+}
+
+/// Média dos valores (zero se vazio).
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
@@ -0,0 +1,153 @@
+use crate::budget::Budget;
+use crate::config::SolverConfig;
+use crate::error::{FsspError, Result};
+use crate::fssp_core::{parse_instance, FSSPInstance, Job, Time};
+use crate::metrics::ScheduleMetrics;
+use crate::non_permutation::{NonPermutationSearch, ScheduleType};
+use crate::observer::{NoopObserver, Observer};
+use crate::time::Instant;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Resolve uma instância a partir de textos, sem acesso a arquivos: ponto de entrada
+/// para ambientes como o navegador (wasm32).
+///
+/// `instance_text` segue o formato dos arquivos de instância e `params_json` é um
+/// objeto JSON com os mesmos campos do arquivo de configuração TOML (ex:
+/// `{"algorithm": "grasp", "max_generations": 50}`); um texto vazio usa os padrões
+/// da linha de comando. Os campos de arquivos (`output_dir`, `best_known` e
+/// `portfolio.model`) são ignorados; `auto` usa o modelo embutido do portfólio.
+///
+/// Retorna um objeto JSON com `objective`, `value`, `makespan` (nas unidades da
+/// instância, com casas decimais se houver), `sequence` (base 0) e `metrics`
+/// (tempo ocioso, utilização e tempos de espera; veja `ScheduleMetrics`), mais
+/// `orders` no modo não permutacional, ou `{"error": "..."}` em caso de falha.
+pub fn solve_from_string(instance_text: &str, params_json: &str) -> String {
+    let result = match _solve(instance_text, params_json) {
+        Ok(result) => result,
+        Err(e) => json!({ "error": e.to_string() }),
+    };
+    result.to_string()
+}
+
+fn _solve(instance_text: &str, params_json: &str) -> Result<Value> {
+    let mut params: SolverConfig = if params_json.trim().is_empty() {
+        SolverConfig::default()
+    } else {
+        serde_json::from_str(params_json).map_err(|e| FsspError::Config(e.to_string()))?
+    };
+    params.portfolio.model = None;
+    let mut instance = parse_instance(instance_text)?;
+    instance.set_objective(params.objective.unwrap_or_default())?;
+    Ok(solve(&instance, &params, &mut NoopObserver)?.to_json(&instance))
+}
+
+/// Resultado de `solve`, com os tempos na escala armazenada (veja `Time`).
+#[derive(Debug, Clone)]
+pub struct SolveResult {
+    pub value: Time,                   // Valor da função objetivo.
+    pub makespan: Time,                // Makespan da solução.
+    pub sequence: Vec<Job>,            // Melhor sequência (base 0).
+    pub orders: Option<Vec<Vec<Job>>>, // Ordem em cada máquina (modo não permutacional).
+    pub metrics: ScheduleMetrics,      // Ociosidade, utilização e esperas da solução.
+}
+
+impl SolveResult {
+    /// Resultado em JSON, com os tempos nas unidades da instância: o formato de
+    /// `solve_from_string` e de `GET /jobs/{id}/result` do serviço HTTP.
+    pub fn to_json(&self, instance: &FSSPInstance) -> Value {
+        let mut result = json!({
+            "objective": instance.objective.to_string(),
+            "value": instance.time_value(self.value),
+            "makespan": instance.time_value(self.makespan),
+            "metrics": _metrics_json(instance, &self.metrics),
+            "sequence": self.sequence,
+        });
+        if let Some(orders) = &self.orders {
+            result["orders"] = json!(orders);
+        }
+        result
+    }
+}
+
+/// Executa o solver descrito por `params` sobre `instance` (já com a função objetivo
+/// definida), notificando `observer`. No modo não permutacional, metade do orçamento
+/// é usada pelo solver permutacional e o restante pela busca nas ordens por máquina.
+pub fn solve(
+    instance: &FSSPInstance,
+    params: &SolverConfig,
+    observer: &mut dyn Observer,
+) -> Result<SolveResult> {
+    let max_duration = params.max_duration.map(Duration::from_secs);
+    let schedule_type = params.schedule_type.unwrap_or_default();
+    let (permutation_duration, permutation_evaluations) = match schedule_type {
+        ScheduleType::Permutation => (max_duration, params.max_evaluations),
+        ScheduleType::NonPermutation => (
+            max_duration.map(|d| d / 2),
+            params.max_evaluations.map(|n| n / 2),
+        ),
+    };
+
+    let start_time = Instant::now();
+    let mut solver = params.build_solver(instance)?;
+    solver.run_with_observer(
+        start_time,
+        &Budget::with_duration(start_time, permutation_duration)
+            .with_max_evaluations(permutation_evaluations),
+        observer,
+    );
+    let sequence = solver.best_sequence().to_vec();
+    if sequence.is_empty() {
+        return Err(FsspError::Config(
+            "a execução foi cancelada antes de encontrar uma solução".to_string(),
+        ));
+    }
+
+    if schedule_type == ScheduleType::Permutation {
+        return Ok(SolveResult {
+            value: solver.best_makespan(),
+            makespan: instance.calculate_makespan(&sequence),
+            metrics: ScheduleMetrics::new(instance, &instance.decode_schedule(&sequence)),
+            sequence,
+            orders: None,
+        });
+    }
+
+    let mut search = NonPermutationSearch::new(
+        instance.clone(),
+        &sequence,
+        params.max_generations.unwrap_or(100),
+        params.ils.params().perturbation_strength,
+    );
+    search.run_with_observer(
+        start_time,
+        &Budget::with_duration(start_time, max_duration)
+            .with_max_evaluations(params.max_evaluations)
+            .remaining(solver.evaluations()),
+        observer,
+    );
+    Ok(SolveResult {
+        value: search.best_value,
+        makespan: search.best_schedule.makespan(instance),
+        metrics: ScheduleMetrics::new(instance, &search.best_schedule.decode_schedule(instance)),
+        sequence,
+        orders: Some(search.best_schedule.orders),
+    })
+}
+
+/// Indicadores do escalonamento em JSON, com os tempos nas unidades da instância.
+fn _metrics_json(instance: &FSSPInstance, metrics: &ScheduleMetrics) -> Value {
+    json!({
+        "total_idle_time": instance.time_value(metrics.total_idle),
+        "mean_utilization": metrics.mean_utilization(),
+        "utilization": metrics.machines.iter().map(|m| m.utilization).collect::<Vec<_>>(),
+        "total_waiting_time": instance.time_value(metrics.total_waiting),
+        "waiting_times": metrics
+            .waiting_times
+            .iter()
+            .map(|&w| instance.time_value(w))
+            .collect::<Vec<_>>(),
+        "weighted_flowtime": metrics.weighted_flowtime.map(|v| instance.time_value(v)),
+        "weighted_tardiness": metrics.weighted_tardiness.map(|v| instance.time_value(v)),
+    })
+}
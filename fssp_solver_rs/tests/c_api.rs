@@ -0,0 +1,74 @@
+use fssp_solver_rs::c_api::{
+    fssp_instance_free, fssp_instance_new, fssp_last_error, fssp_params_default, fssp_result_free,
+    fssp_solve, FsspParams,
+};
+use std::ffi::CStr;
+
+#[test]
+fn c_header_matches_c_api() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir)).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+    let header = std::fs::read(format!("{}/include/fssp_solver.h", crate_dir)).unwrap();
+    assert!(
+        generated == header,
+        "include/fssp_solver.h desatualizado: execute \
+         `cbindgen --config cbindgen.toml --output include/fssp_solver.h`"
+    );
+}
+
+#[test]
+fn instance_new_rejects_empty_dimensions() {
+    let times = [1u32; 4];
+    for (n_jobs, n_machines) in [(0, 2), (2, 0)] {
+        let instance = unsafe { fssp_instance_new(n_jobs, n_machines, times.as_ptr()) };
+        assert!(instance.is_null());
+        assert!(!fssp_last_error().is_null());
+    }
+}
+
+#[test]
+fn instance_new_rejects_overflowing_dimensions() {
+    let times = [1u32; 4];
+    let instance = unsafe { fssp_instance_new(usize::MAX, 2, times.as_ptr()) };
+    assert!(instance.is_null());
+    let message = unsafe { CStr::from_ptr(fssp_last_error()) };
+    assert!(message.to_str().unwrap().contains("muito grandes"));
+
+    let instance = unsafe { fssp_instance_new(2, 2, times.as_ptr()) };
+    assert!(!instance.is_null());
+    unsafe { fssp_instance_free(instance) };
+}
+
+#[test]
+fn solve_rejects_invalid_max_duration() {
+    let times = [3u32, 4, 2, 5];
+    let instance = unsafe { fssp_instance_new(2, 2, times.as_ptr()) };
+    for max_duration in [f64::NAN, f64::INFINITY, 1e30] {
+        let params = FsspParams {
+            max_duration,
+            ..fssp_params_default()
+        };
+        let result = unsafe { fssp_solve(instance, &params) };
+        assert!(result.is_null());
+        let message = unsafe { CStr::from_ptr(fssp_last_error()) };
+        assert!(message.to_str().unwrap().contains("max_duration"));
+    }
+    let params = FsspParams {
+        max_duration: -1.0,
+        max_generations: 5,
+        ..fssp_params_default()
+    };
+    let result = unsafe { fssp_solve(instance, &params) };
+    assert!(!result.is_null());
+    unsafe {
+        fssp_result_free(result);
+        fssp_instance_free(instance);
+    }
+}